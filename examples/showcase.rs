@@ -555,7 +555,7 @@ impl Render for Showcase {
                                     .max_w(rems(20.))
                                     .rounded_lg()
                                     .gap(px(12.))
-                                    .leading(
+                                    .prefix(
                                         Button::new("search_leading")
                                             .on_click(cx.listener(Self::toggle_disabled))
                                             .child(
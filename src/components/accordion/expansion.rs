@@ -0,0 +1,83 @@
+use gpui::SharedString;
+
+/// How many sections of an accordion can be expanded at once
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccordionMode {
+    /// Expanding a section collapses the one that was expanded
+    #[default]
+    Single,
+    /// Sections expand and collapse independently
+    Multiple,
+}
+
+/// The sections of an accordion that are expanded
+///
+/// Every change returns the sections it expanded or collapsed, in the order to report
+/// them, so a single-mode accordion reports the section it collapses before the one it
+/// expands.
+#[derive(Default)]
+pub struct Expansion {
+    mode: AccordionMode,
+    expanded: Vec<SharedString>,
+}
+
+impl Expansion {
+    pub fn new(mode: AccordionMode) -> Self {
+        Self {
+            mode,
+            expanded: Vec::new(),
+        }
+    }
+
+    pub fn mode(&self) -> AccordionMode {
+        self.mode
+    }
+
+    /// Switch modes, keeping only the first expanded section when switching to single
+    pub fn set_mode(&mut self, mode: AccordionMode) -> Vec<(SharedString, bool)> {
+        self.mode = mode;
+        if mode == AccordionMode::Multiple || self.expanded.len() <= 1 {
+            return Vec::new();
+        }
+        self.expanded
+            .drain(1..)
+            .map(|section| (section, false))
+            .collect()
+    }
+
+    pub fn is_expanded(&self, section: &SharedString) -> bool {
+        self.expanded.contains(section)
+    }
+
+    /// The expanded sections, in the order they were expanded
+    pub fn expanded(&self) -> &[SharedString] {
+        &self.expanded
+    }
+
+    /// Expand or collapse `section`, and in single mode collapse any other one
+    pub fn set_expanded(
+        &mut self,
+        section: &SharedString,
+        expanded: bool,
+    ) -> Vec<(SharedString, bool)> {
+        if self.is_expanded(section) == expanded {
+            return Vec::new();
+        }
+        if !expanded {
+            self.expanded.retain(|other| other != section);
+            return vec![(section.clone(), false)];
+        }
+        let mut changes = Vec::new();
+        if self.mode == AccordionMode::Single {
+            changes.extend(self.expanded.drain(..).map(|other| (other, false)));
+        }
+        self.expanded.push(section.clone());
+        changes.push((section.clone(), true));
+        changes
+    }
+
+    pub fn toggle(&mut self, section: &SharedString) -> Vec<(SharedString, bool)> {
+        let expanded = !self.is_expanded(section);
+        self.set_expanded(section, expanded)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::primitives::v_flex;
+use gpui::{prelude::FluentBuilder, *};
+
+mod expansion;
+mod navigation;
+mod section;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use expansion::AccordionMode;
+pub use section::AccordionSection;
+pub use state::*;
+
+/// A stack of sections that expand and collapse under their headers.
+///
+/// Which sections are expanded lives in an [`AccordionState`], in single mode, where
+/// expanding a section collapses the open one, or in multiple mode. Clicking a header, or
+/// pressing Enter or Space while it is focused, toggles its section, and the content
+/// grows or shrinks to its height. The arrow keys, Home and End move focus between the
+/// headers, skipping disabled sections.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|_| {
+///     AccordionState::new()
+///         .mode(AccordionMode::Multiple)
+///         .expanded(["shipping"])
+///         .on_toggle(|section, expanded, _window, _cx| {
+///             println!("{section} expanded: {expanded}");
+///         })
+/// });
+///
+/// Accordion::new("faq", state)
+///     .gap_1()
+///     .section(
+///         AccordionSection::new("shipping")
+///             .header(|header| header.p_2().child("Shipping"))
+///             .content(|content| content.p_2().child("Orders ship within two days.")),
+///     )
+///     .section(
+///         AccordionSection::new("returns")
+///             .header(|header| header.p_2().child("Returns"))
+///             .content(|content| content.p_2().child("Returns are free for 30 days.")),
+///     )
+/// ```
+#[derive(IntoElement)]
+pub struct Accordion {
+    base: Stateful<Div>,
+    state: Entity<AccordionState>,
+    sections: Vec<AccordionSection>,
+}
+
+impl Accordion {
+    /// Creates an empty accordion for the given state.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A unique identifier for the accordion element
+    /// * `state` - The state that tracks the expanded sections
+    pub fn new(id: impl Into<ElementId>, state: Entity<AccordionState>) -> Self {
+        Self {
+            base: v_flex().id(id),
+            state,
+            sections: Vec::new(),
+        }
+    }
+
+    /// Adds a section below the ones added before.
+    pub fn section(mut self, section: AccordionSection) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Adds several sections below the ones added before.
+    pub fn sections(mut self, sections: impl IntoIterator<Item = AccordionSection>) -> Self {
+        self.sections.extend(sections);
+        self
+    }
+}
+
+impl Styled for Accordion {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Accordion {
+    fn render_section(
+        entity: &Entity<AccordionState>,
+        state: &AccordionState,
+        ix: usize,
+        mut section: AccordionSection,
+        window: &mut Window,
+    ) -> Stateful<Div> {
+        let id = section.id.clone();
+        let expanded = state.is_expanded(&id);
+        if expanded && let Some(handler) = section.when_expanded_handler.take() {
+            section.header = handler(section.header);
+        }
+
+        let header = section
+            .header
+            .id(("accordion-header", ix))
+            .when(!section.disabled, |this| {
+                this.cursor_pointer()
+                    .when_some(state.header_focus_handle(&id), |this, handle| {
+                        this.track_focus(handle)
+                    })
+                    .on_key_down(
+                        window.listener_for(entity, move |state, event, window, cx| {
+                            state.on_header_key_down(ix, event, window, cx)
+                        }),
+                    )
+                    .on_click(window.listener_for(entity, {
+                        let id = id.clone();
+                        move |state, _, window, cx| state.toggle(&id, window, cx)
+                    }))
+            });
+
+        // Measure the content at its natural height, however much of it shows
+        let measured = entity.clone();
+        let measured_id = id.clone();
+        let bounds = canvas(
+            move |bounds, _, app| {
+                measured.update(app, |state, _| {
+                    state.heights.insert(measured_id, bounds.size.height);
+                })
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+        let content = div()
+            .overflow_hidden()
+            .child(section.content.relative().flex_none().child(bounds));
+
+        let toggles = state.toggle_count(&id);
+        let content = match (state.animation(), state.heights.get(&id).copied()) {
+            (Some(duration), Some(height)) if toggles > 0 => content
+                .with_animation(
+                    ("accordion-content", toggles),
+                    Animation::new(duration).with_easing(ease_in_out),
+                    move |this, delta| match expanded {
+                        // Let expanded content follow its height once it is fully open
+                        true if delta >= 1. => this,
+                        true => this.h(height * delta),
+                        false => this.h(height * (1. - delta)),
+                    },
+                )
+                .into_any_element(),
+            _ => content
+                .when(!expanded, |this| this.h_0())
+                .into_any_element(),
+        };
+
+        v_flex()
+            .id(("accordion-section", ix))
+            .child(header)
+            .child(content)
+    }
+}
+
+impl RenderOnce for Accordion {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let ids = self
+            .sections
+            .iter()
+            .map(|section| (section.id.clone(), section.disabled))
+            .collect();
+        self.state
+            .update(app, |state, cx| state.sync_sections(ids, cx));
+
+        let state = self.state.read(app);
+        let sections: Vec<_> = self
+            .sections
+            .into_iter()
+            .enumerate()
+            .map(|(ix, section)| Self::render_section(&self.state, state, ix, section, window))
+            .collect();
+        self.base.children(sections)
+    }
+}
@@ -0,0 +1,36 @@
+/// Which header the arrow keys move focus to, skipping disabled sections
+pub struct HeaderNavigation {
+    disabled: Vec<bool>,
+}
+
+impl HeaderNavigation {
+    pub fn new(disabled: Vec<bool>) -> Self {
+        Self { disabled }
+    }
+
+    /// The next enabled header after `from`, or before it when not `forward`, wrapping
+    /// around at either end
+    pub fn step(&self, from: usize, forward: bool) -> Option<usize> {
+        let len = self.disabled.len();
+        let from = from.min(len.checked_sub(1)?);
+        (1..=len)
+            .map(|offset| {
+                if forward {
+                    (from + offset) % len
+                } else {
+                    (from + len - offset) % len
+                }
+            })
+            .find(|&ix| !self.disabled[ix])
+    }
+
+    /// The first enabled header
+    pub fn first(&self) -> Option<usize> {
+        self.disabled.iter().position(|disabled| !disabled)
+    }
+
+    /// The last enabled header
+    pub fn last(&self) -> Option<usize> {
+        self.disabled.iter().rposition(|disabled| !disabled)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::Disableable;
+use gpui::*;
+
+/// One collapsible section of an [`Accordion`](super::Accordion): a header that toggles
+/// it and the content it shows while expanded.
+///
+/// # Examples
+///
+/// ```rust
+/// AccordionSection::new("shipping")
+///     .header(|header| header.p_2().child("Shipping"))
+///     .content(|content| content.p_2().child("Orders ship within two days."))
+///     .when_expanded(|header| header.font_weight(FontWeight::BOLD))
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct AccordionSection {
+    pub(super) id: SharedString,
+    pub(super) header: Div,
+    pub(super) content: Div,
+    pub(super) disabled: bool,
+    pub(super) when_expanded_handler: Option<Box<dyn FnOnce(Div) -> Div>>,
+}
+
+impl AccordionSection {
+    /// Creates an empty section with the specified ID, reported by `on_toggle`.
+    pub fn new(id: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            header: div(),
+            content: div(),
+            disabled: false,
+            when_expanded_handler: None,
+        }
+    }
+
+    /// Builds the header that toggles the section.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the header, adding its children
+    pub fn header(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.header = handler(self.header);
+        self
+    }
+
+    /// Builds the content shown while the section is expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the content, adding its children
+    pub fn content(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.content = handler(self.content);
+        self
+    }
+
+    /// Applies modifications to the header while the section is expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the header, applying modifications
+    pub fn when_expanded(mut self, handler: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.when_expanded_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Disableable for AccordionSection {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
@@ -0,0 +1,213 @@
+use crate::components::accordion::{
+    expansion::{AccordionMode, Expansion},
+    navigation::HeaderNavigation,
+};
+use gpui::*;
+use std::{collections::HashMap, time::Duration};
+
+/// How long a section takes to expand or collapse unless set
+const DEFAULT_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
+/// The state of an [`Accordion`](super::Accordion)
+///
+/// Handles which sections are expanded, focus moving between their headers and the
+/// heights the sections animate to.
+#[allow(clippy::type_complexity)]
+pub struct AccordionState {
+    expansion: Expansion,
+    sections: Vec<(SharedString, bool)>,
+    focus_handles: HashMap<SharedString, FocusHandle>,
+    /// The natural height of each section's content, measured as it was laid out last
+    pub(super) heights: HashMap<SharedString, Pixels>,
+    /// How many times each section was toggled, to restart its animation on every toggle
+    toggles: HashMap<SharedString, usize>,
+    animation_duration: Duration,
+    on_toggle: Option<Box<dyn Fn(&SharedString, bool, &mut Window, &mut Context<Self>)>>,
+}
+
+impl Default for AccordionState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccordionState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new AccordionState with every section collapsed, in single mode
+    pub fn new() -> Self {
+        Self {
+            expansion: Expansion::default(),
+            sections: Vec::new(),
+            focus_handles: HashMap::new(),
+            heights: HashMap::new(),
+            toggles: HashMap::new(),
+            animation_duration: DEFAULT_ANIMATION_DURATION,
+            on_toggle: None,
+        }
+    }
+
+    /// Set whether one or several sections can be expanded at once
+    pub fn mode(mut self, mode: AccordionMode) -> Self {
+        self.expansion.set_mode(mode);
+        self
+    }
+
+    /// Set the sections to start expanded
+    pub fn expanded(mut self, sections: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        for section in sections {
+            self.expansion.set_expanded(&section.into(), true);
+        }
+        self
+    }
+
+    /// Set how long expanding and collapsing take, or zero to skip the animation
+    pub fn animation_duration(mut self, duration: Duration) -> Self {
+        self.animation_duration = duration;
+        self
+    }
+
+    /// Call `on_toggle` with the section and whether it is now expanded, including for the
+    /// section a single-mode accordion collapses
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(&SharedString, bool, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn accordion_mode(&self) -> AccordionMode {
+        self.expansion.mode()
+    }
+
+    pub fn is_expanded(&self, section: &SharedString) -> bool {
+        self.expansion.is_expanded(section)
+    }
+
+    /// The expanded sections, in the order they were expanded
+    pub fn expanded_sections(&self) -> &[SharedString] {
+        self.expansion.expanded()
+    }
+
+    /// Expand or collapse `section`, calling `on_toggle` for every section that changes
+    pub fn set_expanded(
+        &mut self,
+        section: &SharedString,
+        expanded: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let changes = self.expansion.set_expanded(section, expanded);
+        self.apply(changes, window, cx);
+    }
+
+    pub fn toggle(&mut self, section: &SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let changes = self.expansion.toggle(section);
+        self.apply(changes, window, cx);
+    }
+
+    fn apply(
+        &mut self,
+        changes: Vec<(SharedString, bool)>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if changes.is_empty() {
+            return;
+        }
+        for (section, expanded) in changes {
+            *self.toggles.entry(section.clone()).or_default() += 1;
+            if let Some(on_toggle) = &self.on_toggle {
+                on_toggle(&section, expanded, window, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// How many times `section` was toggled, and so which animation it is in
+    pub(super) fn toggle_count(&self, section: &SharedString) -> usize {
+        self.toggles.get(section).copied().unwrap_or(0)
+    }
+
+    pub(super) fn animation(&self) -> Option<Duration> {
+        (!self.animation_duration.is_zero()).then_some(self.animation_duration)
+    }
+
+    /// Keep a focus handle for each section rendered, dropping those of removed sections
+    pub(super) fn sync_sections(
+        &mut self,
+        sections: Vec<(SharedString, bool)>,
+        cx: &mut Context<Self>,
+    ) {
+        self.focus_handles
+            .retain(|section, _| sections.iter().any(|(id, _)| id == section));
+        for (section, _) in &sections {
+            self.focus_handles
+                .entry(section.clone())
+                .or_insert_with(|| cx.focus_handle());
+        }
+        self.sections = sections;
+    }
+
+    pub(super) fn header_focus_handle(&self, section: &SharedString) -> Option<&FocusHandle> {
+        self.focus_handles.get(section)
+    }
+
+    // ============================================================================
+    // Keyboard Handling
+    // ============================================================================
+
+    fn focus_section(&self, ix: usize, window: &mut Window) {
+        if let Some(handle) = self
+            .sections
+            .get(ix)
+            .and_then(|(section, _)| self.focus_handles.get(section))
+        {
+            handle.focus(window);
+        }
+    }
+
+    /// Toggle the header at `ix` on Enter or Space, and move focus between the headers
+    /// with the arrow keys, Home and End
+    pub(super) fn on_header_key_down(
+        &mut self,
+        ix: usize,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let navigation = HeaderNavigation::new(
+            self.sections
+                .iter()
+                .map(|(_, disabled)| *disabled)
+                .collect(),
+        );
+        let target = match event.keystroke.key.as_str() {
+            "enter" | "space" => {
+                cx.stop_propagation();
+                if !event.is_held
+                    && let Some((section, _)) = self.sections.get(ix).cloned()
+                {
+                    self.toggle(&section, window, cx);
+                }
+                return;
+            }
+            "down" => navigation.step(ix, true),
+            "up" => navigation.step(ix, false),
+            "home" => navigation.first(),
+            "end" => navigation.last(),
+            _ => return,
+        };
+        cx.stop_propagation();
+        if let Some(target) = target {
+            self.focus_section(target, window);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod expansion {
+    use crate::components::accordion::expansion::{AccordionMode, Expansion};
+    use gpui::SharedString;
+
+    fn id(id: &'static str) -> SharedString {
+        SharedString::from(id)
+    }
+
+    #[test]
+    fn toggling_expands_and_collapses() {
+        let mut expansion = Expansion::new(AccordionMode::Single);
+        assert_eq!(expansion.toggle(&id("a")), vec![(id("a"), true)]);
+        assert!(expansion.is_expanded(&id("a")));
+        assert_eq!(expansion.toggle(&id("a")), vec![(id("a"), false)]);
+        assert!(expansion.expanded().is_empty());
+    }
+
+    #[test]
+    fn single_mode_keeps_one_section_open() {
+        let mut expansion = Expansion::new(AccordionMode::Single);
+        expansion.toggle(&id("a"));
+        // The open section is reported collapsed before the new one expands
+        assert_eq!(
+            expansion.toggle(&id("b")),
+            vec![(id("a"), false), (id("b"), true)]
+        );
+        assert_eq!(expansion.expanded(), &[id("b")]);
+
+        for section in ["c", "a", "b", "c"] {
+            expansion.toggle(&id(section));
+            assert_eq!(expansion.expanded(), &[id(section)]);
+        }
+    }
+
+    #[test]
+    fn multiple_mode_expands_sections_independently() {
+        let mut expansion = Expansion::new(AccordionMode::Multiple);
+        expansion.toggle(&id("a"));
+        assert_eq!(expansion.toggle(&id("b")), vec![(id("b"), true)]);
+        assert_eq!(expansion.expanded(), &[id("a"), id("b")]);
+        assert_eq!(expansion.toggle(&id("a")), vec![(id("a"), false)]);
+        assert_eq!(expansion.expanded(), &[id("b")]);
+    }
+
+    #[test]
+    fn setting_the_current_state_changes_nothing() {
+        let mut expansion = Expansion::new(AccordionMode::Single);
+        assert!(expansion.set_expanded(&id("a"), false).is_empty());
+        expansion.set_expanded(&id("a"), true);
+        assert!(expansion.set_expanded(&id("a"), true).is_empty());
+    }
+
+    #[test]
+    fn switching_to_single_mode_keeps_the_first_expanded_section() {
+        let mut expansion = Expansion::new(AccordionMode::Multiple);
+        for section in ["b", "a", "c"] {
+            expansion.toggle(&id(section));
+        }
+        assert_eq!(
+            expansion.set_mode(AccordionMode::Single),
+            vec![(id("a"), false), (id("c"), false)]
+        );
+        assert_eq!(expansion.expanded(), &[id("b")]);
+        assert!(expansion.set_mode(AccordionMode::Multiple).is_empty());
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod navigation {
+    use crate::components::accordion::navigation::HeaderNavigation;
+
+    #[test]
+    fn arrows_move_between_headers_and_wrap() {
+        let headers = HeaderNavigation::new(vec![false; 3]);
+        assert_eq!(headers.step(0, true), Some(1));
+        assert_eq!(headers.step(2, true), Some(0));
+        assert_eq!(headers.step(1, false), Some(0));
+        assert_eq!(headers.step(0, false), Some(2));
+    }
+
+    #[test]
+    fn arrows_skip_disabled_headers() {
+        let headers = HeaderNavigation::new(vec![false, true, true, false]);
+        assert_eq!(headers.step(0, true), Some(3));
+        assert_eq!(headers.step(3, false), Some(0));
+        assert_eq!(headers.step(3, true), Some(0));
+    }
+
+    #[test]
+    fn home_and_end_go_to_the_first_and_last_enabled_header() {
+        let headers = HeaderNavigation::new(vec![true, false, false, true]);
+        assert_eq!(headers.first(), Some(1));
+        assert_eq!(headers.last(), Some(2));
+    }
+
+    #[test]
+    fn a_single_enabled_header_keeps_focus() {
+        let headers = HeaderNavigation::new(vec![true, false, true]);
+        assert_eq!(headers.step(1, true), Some(1));
+        assert_eq!(headers.step(1, false), Some(1));
+    }
+
+    #[test]
+    fn no_enabled_headers_go_nowhere() {
+        assert_eq!(HeaderNavigation::new(vec![true; 2]).step(0, true), None);
+        assert_eq!(HeaderNavigation::new(Vec::new()).step(0, true), None);
+        assert_eq!(HeaderNavigation::new(Vec::new()).first(), None);
+    }
+}
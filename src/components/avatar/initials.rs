@@ -0,0 +1,41 @@
+use gpui::{Hsla, hsla};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The initials of `name`: the first letters of its first and last words, like `JD` for
+/// `Jane Doe`, or the first letter alone for a single word
+pub fn initials(name: &str) -> String {
+    let mut words = name
+        .split(|ch: char| ch.is_whitespace() || ch.is_ascii_punctuation())
+        .filter(|word| !word.is_empty());
+    let first_letter = |word: &str| {
+        word.graphemes(true)
+            .next()
+            .unwrap_or_default()
+            .to_uppercase()
+    };
+    let Some(first) = words.next() else {
+        return String::new();
+    };
+    let mut initials = first_letter(first);
+    if let Some(last) = words.next_back() {
+        initials.push_str(&first_letter(last));
+    }
+    initials
+}
+
+/// A background color for the initials of `name`, the same for every spelling of it
+/// that only differs in case or surrounding whitespace
+///
+/// The hue comes from an FNV-1a hash of the name, which unlike the standard hasher is
+/// the same across runs and Rust versions, and the lightness is dark enough for white
+/// text.
+pub fn initials_color(name: &str) -> Hsla {
+    let hash = name
+        .trim()
+        .to_lowercase()
+        .bytes()
+        .fold(0x811c9dc5_u32, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        });
+    hsla((hash % 360) as f32 / 360., 0.55, 0.45, 1.)
+}
@@ -0,0 +1,140 @@
+use gpui::*;
+
+mod initials;
+#[cfg(test)]
+mod tests;
+
+use initials::{initials, initials_color};
+
+/// The outline of an [`Avatar`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AvatarShape {
+    #[default]
+    Circle,
+    /// A square with corners rounded by a fifth of its size
+    Square,
+}
+
+/// A picture of a person, falling back to the initials of their name.
+///
+/// The avatar is 32px by default and shows the image at [`src`](Self::src), cropped to
+/// fill it. While the image loads, when it fails to load or without one, it shows the
+/// initials of the [`name`](Self::name) in white on a color picked from the name, so the
+/// same person always gets the same color.
+///
+/// # Examples
+///
+/// ```rust
+/// Avatar::new()
+///     .src("https://example.com/jane.png")
+///     .name("Jane Doe")
+///     .size(px(40))
+///     .shape(AvatarShape::Square)
+/// ```
+#[derive(IntoElement)]
+pub struct Avatar {
+    base: Div,
+    src: Option<SharedString>,
+    name: SharedString,
+    size: Pixels,
+    shape: AvatarShape,
+    color: Option<Hsla>,
+}
+
+impl Default for Avatar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Avatar {
+    /// Creates a 32px round avatar without an image or a name.
+    pub fn new() -> Self {
+        Self {
+            base: div(),
+            src: None,
+            name: SharedString::default(),
+            size: px(32.),
+            shape: AvatarShape::Circle,
+            color: None,
+        }
+    }
+
+    /// Sets the URL or path of the image.
+    pub fn src(mut self, src: impl Into<SharedString>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Sets the name the initials and their background color come from.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the width and height, scaling the initials along.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the background of the initials instead of picking it from the name.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    fn radius(&self) -> Pixels {
+        match self.shape {
+            AvatarShape::Circle => self.size / 2.,
+            AvatarShape::Square => self.size / 5.,
+        }
+    }
+}
+
+impl Styled for Avatar {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let radius = self.radius();
+        let initials: SharedString = initials(&self.name).into();
+        let color = self.color.unwrap_or_else(|| initials_color(&self.name));
+        let size = self.size;
+        let fallback = move || {
+            div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .rounded(radius)
+                .bg(color)
+                .text_color(white())
+                .text_size(size * 0.4)
+                .line_height(size)
+                .child(initials.clone())
+                .into_any_element()
+        };
+
+        let base = self.base.flex_none().size(size).rounded(radius);
+        match self.src {
+            Some(src) => base.child(
+                img(src)
+                    .size_full()
+                    .rounded(radius)
+                    .object_fit(ObjectFit::Cover)
+                    .with_loading(fallback.clone())
+                    .with_fallback(fallback),
+            ),
+            None => base.child(fallback()),
+        }
+    }
+}
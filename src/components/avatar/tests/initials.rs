@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod initials {
+    use crate::components::avatar::initials::{initials, initials_color};
+
+    #[test]
+    fn takes_the_first_and_last_words() {
+        assert_eq!(initials("Jane Doe"), "JD");
+        assert_eq!(initials("Mary Jane Watson"), "MW");
+        assert_eq!(initials("  jane   doe "), "JD");
+    }
+
+    #[test]
+    fn a_single_word_gives_one_letter() {
+        assert_eq!(initials("Jane"), "J");
+        assert_eq!(initials("jane-doe"), "JD");
+    }
+
+    #[test]
+    fn empty_names_have_no_initials() {
+        assert_eq!(initials(""), "");
+        assert_eq!(initials("   "), "");
+        assert_eq!(initials("-- !"), "");
+    }
+
+    #[test]
+    fn keeps_non_ascii_letters_whole() {
+        assert_eq!(initials("Élodie Ñúñez"), "ÉÑ");
+        assert_eq!(initials("ßara"), "SS");
+    }
+
+    #[test]
+    fn the_color_is_deterministic() {
+        assert_eq!(initials_color("Jane Doe"), initials_color("Jane Doe"));
+        assert_eq!(initials_color("Jane Doe"), initials_color(" jane doe "));
+        // Pinned, so the colors people are used to don't change between releases
+        assert_eq!(initials_color("Jane Doe").h, 185. / 360.);
+    }
+
+    #[test]
+    fn different_names_spread_over_the_hues() {
+        let names = [
+            "Jane Doe",
+            "John Smith",
+            "Ada Lovelace",
+            "Alan Turing",
+            "Grace Hopper",
+        ];
+        let hues = names.map(|name| initials_color(name).h);
+        for (ix, hue) in hues.iter().enumerate() {
+            assert!((0. ..1.).contains(hue));
+            assert!(
+                !hues[ix + 1..].contains(hue),
+                "{} shares its hue",
+                names[ix]
+            );
+        }
+    }
+
+    #[test]
+    fn only_the_hue_depends_on_the_name() {
+        let (a, b) = (initials_color("Jane Doe"), initials_color("Alan Turing"));
+        assert_eq!((a.s, a.l, a.a), (b.s, b.l, b.a));
+    }
+}
@@ -0,0 +1,28 @@
+use gpui::SharedString;
+
+/// What a badge shows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BadgeContent {
+    /// Nothing, without a count
+    Hidden,
+    /// A dot without a number
+    Dot,
+    /// A number, or `max` followed by a plus once the count exceeds it
+    Count(SharedString),
+}
+
+impl BadgeContent {
+    /// The content for `count`, capped at `max` like `99+`
+    ///
+    /// A count of zero is still present and shows as a dot, and so does any count with
+    /// `dot` set.
+    pub fn new(count: Option<usize>, max: usize, dot: bool) -> Self {
+        match count {
+            None => Self::Hidden,
+            Some(0) => Self::Dot,
+            Some(_) if dot => Self::Dot,
+            Some(count) if count > max => Self::Count(format!("{max}+").into()),
+            Some(count) => Self::Count(count.to_string().into()),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use gpui::{prelude::FluentBuilder, *};
+
+mod content;
+#[cfg(test)]
+mod tests;
+
+use content::BadgeContent;
+
+/// The corner of the wrapped element a [`Badge`] sits on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BadgePosition {
+    #[default]
+    TopRight,
+    TopLeft,
+}
+
+/// The color of a [`Badge`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BadgeVariant {
+    /// Something that needs attention, in red.
+    #[default]
+    Danger,
+    /// The accent color.
+    Primary,
+    /// A neutral gray.
+    Secondary,
+    Success,
+    Warning,
+}
+
+impl BadgeVariant {
+    fn background(self) -> Hsla {
+        match self {
+            Self::Danger => rgb(0xef4444),
+            Self::Primary => rgb(0x3b82f6),
+            Self::Secondary => rgb(0x6b7280),
+            Self::Success => rgb(0x10b981),
+            Self::Warning => rgb(0xf59e0b),
+        }
+        .into()
+    }
+}
+
+/// A count or a dot on a corner of another element, like unread notifications.
+///
+/// The badge shows its [`count`](Self::count), or [`max`](Self::max) followed by a plus
+/// once the count exceeds it, like `99+`. A count of zero shows a dot, as does any count
+/// in [`dot`](Self::dot) mode, so a bare dot is `.count(0)`. Without a count the badge is
+/// hidden. With a child it overlays the child's corner, and without one it is laid out
+/// inline.
+///
+/// # Examples
+///
+/// ```rust
+/// Badge::new()
+///     .count(self.unread)
+///     .variant(BadgeVariant::Primary)
+///     .child(div().size(px(24)).child("✉"))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Badge {
+    base: Div,
+    child: Option<AnyElement>,
+    indicator: Option<Box<dyn FnOnce(Div) -> Div>>,
+    count: Option<usize>,
+    max: usize,
+    dot: bool,
+    position: BadgePosition,
+    variant: BadgeVariant,
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Badge {
+    /// Creates a hidden badge capped at 99.
+    pub fn new() -> Self {
+        Self {
+            base: div(),
+            child: None,
+            indicator: None,
+            count: None,
+            max: 99,
+            dot: false,
+            position: BadgePosition::TopRight,
+            variant: BadgeVariant::Danger,
+        }
+    }
+
+    /// Sets the element the badge sits on.
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.child = Some(child.into_any_element());
+        self
+    }
+
+    /// Sets the count, or hides the badge with `None`.
+    pub fn count(mut self, count: impl Into<Option<usize>>) -> Self {
+        self.count = count.into();
+        self
+    }
+
+    /// Sets the highest count shown as a number, 99 by default.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Shows a dot instead of the number while there is a count.
+    pub fn dot(mut self, dot: bool) -> Self {
+        self.dot = dot;
+        self
+    }
+
+    pub fn position(mut self, position: BadgePosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn variant(mut self, variant: BadgeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Customizes the dot or the pill with the count, over its default style.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the indicator, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let badge = Badge::new().count(3).indicator(|indicator| indicator.border_2());
+    /// ```
+    pub fn indicator(mut self, handler: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.indicator = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Styled for Badge {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Badge {
+    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let content = BadgeContent::new(self.count, self.max, self.dot);
+        let indicator = match content {
+            BadgeContent::Hidden => None,
+            BadgeContent::Dot => Some((px(8.), div().size(px(8.)))),
+            BadgeContent::Count(label) => Some((
+                px(16.),
+                div()
+                    .h(px(16.))
+                    .min_w(px(16.))
+                    .px(px(4.))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_size(px(10.))
+                    .text_color(white())
+                    .child(label),
+            )),
+        };
+        let indicator = indicator.map(|(height, indicator)| {
+            let offset = -height / 2.;
+            let indicator = indicator
+                .flex_none()
+                .rounded_full()
+                .bg(self.variant.background())
+                .when(self.child.is_some(), |this| {
+                    this.absolute().top(offset).map(|this| match self.position {
+                        BadgePosition::TopRight => this.right(offset),
+                        BadgePosition::TopLeft => this.left(offset),
+                    })
+                });
+            match self.indicator {
+                Some(handler) => handler(indicator),
+                None => indicator,
+            }
+        });
+
+        self.base
+            .relative()
+            .flex_none()
+            .children(self.child)
+            .children(indicator)
+    }
+}
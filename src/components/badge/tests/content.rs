@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod content {
+    use crate::components::badge::content::BadgeContent;
+
+    fn count(text: &str) -> BadgeContent {
+        BadgeContent::Count(text.into())
+    }
+
+    #[test]
+    fn shows_counts_up_to_the_max() {
+        assert_eq!(BadgeContent::new(Some(1), 99, false), count("1"));
+        assert_eq!(BadgeContent::new(Some(42), 99, false), count("42"));
+        assert_eq!(BadgeContent::new(Some(99), 99, false), count("99"));
+    }
+
+    #[test]
+    fn caps_counts_over_the_max() {
+        assert_eq!(BadgeContent::new(Some(100), 99, false), count("99+"));
+        assert_eq!(BadgeContent::new(Some(12345), 99, false), count("99+"));
+        assert_eq!(BadgeContent::new(Some(10), 9, false), count("9+"));
+    }
+
+    #[test]
+    fn zero_is_a_dot() {
+        assert_eq!(BadgeContent::new(Some(0), 99, false), BadgeContent::Dot);
+        assert_eq!(BadgeContent::new(Some(0), 0, false), BadgeContent::Dot);
+    }
+
+    #[test]
+    fn dot_mode_hides_the_number() {
+        assert_eq!(BadgeContent::new(Some(5), 99, true), BadgeContent::Dot);
+        assert_eq!(BadgeContent::new(Some(500), 99, true), BadgeContent::Dot);
+    }
+
+    #[test]
+    fn no_count_hides_the_badge() {
+        assert_eq!(BadgeContent::new(None, 99, false), BadgeContent::Hidden);
+        assert_eq!(BadgeContent::new(None, 99, true), BadgeContent::Hidden);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::primitives::{h_flex, v_flex};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+mod overflow;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use state::*;
+
+/// What a segment of the trail stands for, handed to [`Breadcrumb::segment_style`] to
+/// style it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbSegment {
+    pub index: usize,
+    pub label: SharedString,
+    /// Whether it is the last segment, the current page
+    pub current: bool,
+    /// Whether it is shown in the "…" menu rather than the trail
+    pub collapsed: bool,
+}
+
+/// A trail of segments leading to the current page, like `Home / Docs / Components`.
+///
+/// When the trail is wider than the breadcrumb, the segments after the first are
+/// collapsed into a "…" that opens a menu with them, keeping as many segments before the
+/// last as fit. The breadcrumb needs a width of its own for this, like `w_full`, and
+/// measures the segments as they are laid out, so a new trail is shown whole for a frame
+/// before collapsing. Space the segments with padding rather than a gap, which isn't
+/// part of what is measured.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|_| BreadcrumbState::new());
+///
+/// Breadcrumb::new(state)
+///     .w_full()
+///     .segments(["Home", "Projects", "Lapislazuli", "Components", "Breadcrumb"])
+///     .segment_style(|segment, info| segment.px_1().when(info.current, |this| this.font_bold()))
+///     .menu(|menu| menu.p_1().bg(rgb(0xffffff)).border_1().rounded_md())
+///     .on_click(cx.listener(|this, ix, _, cx| this.navigate_up_to(*ix, cx)))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Breadcrumb {
+    base: Div,
+    state: Entity<BreadcrumbState>,
+    labels: Vec<SharedString>,
+    separator: SharedString,
+    ellipsis: Option<AnyElement>,
+    menu: Div,
+    segment_style: Option<Rc<dyn Fn(Stateful<Div>, &BreadcrumbSegment) -> Stateful<Div>>>,
+    on_click: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+}
+
+impl Breadcrumb {
+    /// Creates an empty breadcrumb for the given state.
+    pub fn new(state: Entity<BreadcrumbState>) -> Self {
+        Self {
+            base: h_flex(),
+            state,
+            labels: Vec::new(),
+            separator: "/".into(),
+            ellipsis: None,
+            menu: v_flex(),
+            segment_style: None,
+            on_click: None,
+        }
+    }
+
+    /// Appends a segment to the trail.
+    pub fn segment(mut self, label: impl Into<SharedString>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    /// Appends segments to the trail.
+    pub fn segments(mut self, labels: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.labels.extend(labels.into_iter().map(Into::into));
+        self
+    }
+
+    /// Replaces the "/" between segments.
+    pub fn separator(mut self, separator: impl Into<SharedString>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Replaces the "…" that stands for the collapsed segments.
+    pub fn ellipsis(mut self, ellipsis: impl IntoElement) -> Self {
+        self.ellipsis = Some(ellipsis.into_any_element());
+        self
+    }
+
+    /// Customizes the menu of collapsed segments below the "…".
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the menu, applying modifications
+    pub fn menu(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.menu = handler(self.menu);
+        self
+    }
+
+    /// Styles each segment, in the trail or in the menu.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes a segment and what it stands for, and returns
+    ///   the styled segment
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let breadcrumb = Breadcrumb::new(state).segment_style(|segment, info| {
+    ///     segment
+    ///         .when(info.collapsed, |this| this.px_2().py_1())
+    ///         .when(!info.current, |this| this.text_color(rgb(0x6b7280)))
+    /// });
+    /// ```
+    pub fn segment_style(
+        mut self,
+        handler: impl Fn(Stateful<Div>, &BreadcrumbSegment) -> Stateful<Div> + 'static,
+    ) -> Self {
+        self.segment_style = Some(Rc::new(handler));
+        self
+    }
+
+    /// Sets the handler called with the index of a segment when it is clicked, in the
+    /// trail or in the menu.
+    pub fn on_click(mut self, on_click: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+
+    fn render_segment(&self, ix: usize, collapsed: bool) -> Stateful<Div> {
+        let info = BreadcrumbSegment {
+            index: ix,
+            label: self.labels[ix].clone(),
+            current: ix + 1 == self.labels.len(),
+            collapsed,
+        };
+        let state = self.state.clone();
+        let segment = div()
+            .id(("breadcrumb-segment", ix))
+            .child(info.label.clone())
+            .when_some(self.on_click.clone(), |this, on_click| {
+                this.cursor_pointer().on_click(move |_, window, cx| {
+                    state.update(cx, |state, cx| state.set_open(false, cx));
+                    on_click(&ix, window, cx);
+                })
+            });
+        match &self.segment_style {
+            Some(style) => style(segment, &info),
+            None => segment,
+        }
+    }
+
+    /// A canvas covering its parent that reports the parent's bounds through `measure`
+    fn measure<F>(state: &Entity<BreadcrumbState>, measure: F) -> impl IntoElement
+    where
+        F: Fn(&mut BreadcrumbState, Bounds<Pixels>, &mut Context<BreadcrumbState>) + 'static,
+    {
+        let state = state.clone();
+        canvas(
+            move |bounds, _, app| state.update(app, |state, cx| measure(state, bounds, cx)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full()
+    }
+}
+
+impl Styled for Breadcrumb {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Breadcrumb {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        self.state
+            .update(app, |state, _| state.sync_segments(&self.labels));
+        let state = self.state.read(app);
+        let collapsed = state.collapsed().unwrap_or(0..0);
+        let open = state.is_open() && !collapsed.is_empty();
+        let last = self.labels.len().saturating_sub(1);
+
+        let separator =
+            |ix: usize| (ix != last).then(|| div().flex_none().child(self.separator.clone()));
+        let item = |ix: usize| {
+            h_flex()
+                .flex_none()
+                .relative()
+                .child(Self::measure(&self.state, move |state, bounds, cx| {
+                    state.measure_segment(ix, bounds.size.width, cx)
+                }))
+                .child(self.render_segment(ix, false))
+                .children(separator(ix))
+        };
+
+        let available = Self::measure(&self.state, |state, bounds, cx| {
+            state.measure_available(bounds.size.width, cx)
+        });
+        let mut items = (0..self.labels.len())
+            .filter(|ix| !collapsed.contains(ix))
+            .map(item)
+            .collect::<Vec<_>>();
+        if !collapsed.is_empty() {
+            let hidden = collapsed
+                .clone()
+                .map(|ix| self.render_segment(ix, true))
+                .collect::<Vec<_>>();
+            let menu = open.then(|| {
+                deferred(
+                    self.menu
+                        .absolute()
+                        .top_full()
+                        .left_0()
+                        .on_mouse_down_out(
+                            window.listener_for(&self.state, BreadcrumbState::on_mouse_down_out),
+                        )
+                        .children(hidden),
+                )
+            });
+            let ellipsis = h_flex()
+                .flex_none()
+                .relative()
+                .child(Self::measure(&self.state, |state, bounds, cx| {
+                    state.trigger_bounds = Some(bounds);
+                    state.measure_ellipsis(bounds.size.width, cx);
+                }))
+                .child(
+                    div()
+                        .id("breadcrumb-ellipsis")
+                        .cursor_pointer()
+                        .child(
+                            self.ellipsis
+                                .take()
+                                .unwrap_or_else(|| "…".into_any_element()),
+                        )
+                        .on_click(window.listener_for(&self.state, BreadcrumbState::toggle_open)),
+                )
+                .child(div().flex_none().child(self.separator.clone()))
+                .children(menu);
+            items.insert(1, ellipsis);
+        }
+
+        self.base.relative().child(available).children(items)
+    }
+}
@@ -0,0 +1,19 @@
+use std::ops::Range;
+
+/// The middle segments to hide so a trail fits in `available`, or `None` when it fits
+///
+/// Each width includes the separator after the segment, and `ellipsis` the one after the
+/// "…" that stands for the hidden segments. The first and last segments always stay, and
+/// segments are hidden from the second one on, so the trail keeps the ones closest to the
+/// current page. When even the first, the "…" and the last don't fit, everything in
+/// between is hidden anyway.
+pub fn collapsed_range(widths: &[f32], ellipsis: f32, available: f32) -> Option<Range<usize>> {
+    if widths.len() <= 2 || widths.iter().sum::<f32>() <= available {
+        return None;
+    }
+    let last = widths.len() - 1;
+    let end = (2..last)
+        .find(|&end| widths[0] + ellipsis + widths[end..].iter().sum::<f32>() <= available)
+        .unwrap_or(last);
+    Some(1..end)
+}
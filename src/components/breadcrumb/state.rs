@@ -0,0 +1,101 @@
+use crate::components::breadcrumb::overflow::collapsed_range;
+use gpui::*;
+use std::ops::Range;
+
+/// The state of a [`Breadcrumb`](super::Breadcrumb)
+///
+/// Remembers the widths the segments were laid out with and the width available to the
+/// trail, to decide which segments to collapse into the "…" menu, and whether that menu
+/// is open.
+#[derive(Default)]
+pub struct BreadcrumbState {
+    labels: Vec<SharedString>,
+    widths: Vec<Option<f32>>,
+    ellipsis_width: f32,
+    available: Option<f32>,
+    open: bool,
+    /// The bounds of the "…", where a press doesn't close the menu
+    pub(super) trigger_bounds: Option<Bounds<Pixels>>,
+}
+
+impl BreadcrumbState {
+    /// Create a new BreadcrumbState, showing every segment until they are measured
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The segments hidden in the "…" menu, if any
+    ///
+    /// Nothing is hidden until every segment was measured, so a new trail is laid out
+    /// whole once before collapsing.
+    pub fn collapsed(&self) -> Option<Range<usize>> {
+        let available = self.available?;
+        let widths = self.widths.iter().copied().collect::<Option<Vec<_>>>()?;
+        collapsed_range(&widths, self.ellipsis_width, available)
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_open(&mut self, open: bool, cx: &mut Context<Self>) {
+        if self.open != open {
+            self.open = open;
+            cx.notify();
+        }
+    }
+
+    /// Forget the widths once the segments change
+    pub(super) fn sync_segments(&mut self, labels: &[SharedString]) {
+        if self.labels != labels {
+            self.labels = labels.to_vec();
+            self.widths = vec![None; labels.len()];
+            self.open = false;
+        }
+    }
+
+    pub(super) fn measure_segment(&mut self, ix: usize, width: Pixels, cx: &mut Context<Self>) {
+        let width = Some(f32::from(width));
+        if let Some(slot) = self.widths.get_mut(ix)
+            && *slot != width
+        {
+            *slot = width;
+            cx.notify();
+        }
+    }
+
+    pub(super) fn measure_ellipsis(&mut self, width: Pixels, cx: &mut Context<Self>) {
+        let width = f32::from(width);
+        if self.ellipsis_width != width {
+            self.ellipsis_width = width;
+            cx.notify();
+        }
+    }
+
+    pub(super) fn measure_available(&mut self, width: Pixels, cx: &mut Context<Self>) {
+        let width = Some(f32::from(width));
+        if self.available != width {
+            self.available = width;
+            cx.notify();
+        }
+    }
+
+    pub(super) fn toggle_open(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.set_open(!self.open, cx);
+    }
+
+    /// Close the menu on a press outside of it and the "…"
+    pub(super) fn on_mouse_down_out(
+        &mut self,
+        event: &MouseDownEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self
+            .trigger_bounds
+            .is_none_or(|bounds| !bounds.contains(&event.position))
+        {
+            self.set_open(false, cx);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod overflow {
+    use crate::components::breadcrumb::overflow::collapsed_range;
+
+    #[test]
+    fn fitting_trails_stay_whole() {
+        assert_eq!(collapsed_range(&[50., 60., 70.], 20., 180.), None);
+        assert_eq!(collapsed_range(&[50., 60., 70.], 20., 500.), None);
+        assert_eq!(collapsed_range(&[], 20., 0.), None);
+    }
+
+    #[test]
+    fn two_segments_never_collapse() {
+        assert_eq!(collapsed_range(&[100., 100.], 20., 50.), None);
+    }
+
+    #[test]
+    fn hides_the_fewest_segments_after_the_first() {
+        let widths = [50., 60., 70., 80., 90.];
+        // All of it takes 350
+        assert_eq!(collapsed_range(&widths, 20., 349.), Some(1..2));
+        // First, "…" and the last three take 310
+        assert_eq!(collapsed_range(&widths, 20., 310.), Some(1..2));
+        assert_eq!(collapsed_range(&widths, 20., 309.), Some(1..3));
+        // First, "…" and the last two take 240
+        assert_eq!(collapsed_range(&widths, 20., 240.), Some(1..3));
+        assert_eq!(collapsed_range(&widths, 20., 239.), Some(1..4));
+    }
+
+    #[test]
+    fn keeps_the_first_and_last_when_nothing_else_fits() {
+        let widths = [50., 60., 70., 80., 90.];
+        assert_eq!(collapsed_range(&widths, 20., 160.), Some(1..4));
+        assert_eq!(collapsed_range(&widths, 20., 10.), Some(1..4));
+    }
+
+    #[test]
+    fn a_wide_ellipsis_hides_more() {
+        let widths = [50., 60., 70., 80., 90.];
+        assert_eq!(collapsed_range(&widths, 20., 310.), Some(1..2));
+        assert_eq!(collapsed_range(&widths, 40., 310.), Some(1..3));
+    }
+
+    #[test]
+    fn collapsing_may_hide_a_single_segment() {
+        // Hiding the one middle segment still saves space over showing it
+        assert_eq!(collapsed_range(&[50., 100., 50.], 20., 150.), Some(1..2));
+    }
+}
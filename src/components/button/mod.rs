@@ -0,0 +1,584 @@
+use crate::{Disableable, components::Spinner};
+use gpui::{prelude::FluentBuilder, *};
+use smallvec::SmallVec;
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+mod variant;
+
+pub use variant::*;
+
+/// A clickable button component that can contain child elements and handle user interactions.
+///
+/// The button provides a flexible interactive element that can be styled, disabled, and
+/// configured with custom click handlers. It supports event propagation control and can
+/// contain any number of child elements.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```rust
+/// Button::new("my-button")
+///     .child(span("Click me"))
+///     .on_click(|_event, _window, _cx| {
+///         println!("Button clicked!");
+///     })
+/// ```
+///
+/// With styling and disabled state:
+/// ```rust
+/// Button::new("styled-button")
+///     .child(span("Submit"))
+///     .bg(rgb(0x3b82f6))
+///     .hover(|this| this.bg(rgb(0x2563eb)))
+///     .disabled(true)
+///     .px(rems(1.5))
+///     .py(rems(0.75))
+///     .rounded_md()
+/// ```
+///
+/// Focusable from the keyboard, where Enter and Space click it:
+/// ```rust
+/// Button::new("submit-button")
+///     .child(span("Submit"))
+///     .track_focus(&self.submit_focus_handle)
+///     .focus(|this| this.border_color(rgb(0x60a5fa)))
+///     .on_click(|_event, _window, _cx| submit())
+/// ```
+///
+/// With a variant from the [`ButtonTheme`]:
+/// ```rust
+/// Button::new("delete-button")
+///     .child(span("Delete"))
+///     .danger()
+///     .px(rems(1.))
+/// ```
+///
+/// With only an icon:
+/// ```rust
+/// Button::icon("close-button", svg().path("icons/x.svg").size_4(), "Close")
+///     .ghost()
+/// ```
+///
+/// While loading:
+/// ```rust
+/// Button::new("save-button")
+///     .child(span("Save"))
+///     .loading(true)
+///     .when_loading(|this| this.opacity(0.8))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Button {
+    id: ElementId,
+    base: Stateful<Div>,
+    disabled: bool,
+    loading: bool,
+    variant: Option<ButtonVariant>,
+    label: Option<SharedString>,
+    spinner: Option<AnyElement>,
+    children: SmallVec<[AnyElement; 2]>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    stop_propagation: bool,
+    when_loading_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    hover_style: Option<StyleRefinement>,
+    active_style: Option<StyleRefinement>,
+    focus_style: Option<StyleRefinement>,
+}
+
+impl Button {
+    /// Creates a new button with the specified ID.
+    ///
+    /// The button starts in an enabled state with no children and no click handler.
+    /// Event propagation is enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A unique identifier for the button element
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::new("submit-button");
+    /// ```
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        let id = id.into();
+        Self {
+            id: id.clone(),
+            base: div().id(id),
+            disabled: false,
+            loading: false,
+            variant: None,
+            label: None,
+            spinner: None,
+            children: SmallVec::new(),
+            on_click: None,
+            stop_propagation: true,
+            when_loading_handler: None,
+            hover_style: None,
+            active_style: None,
+            focus_style: None,
+        }
+    }
+
+    /// Creates a button that shows only an icon.
+    ///
+    /// Without any text, the button needs a label to be understood. The label is shown as
+    /// a tooltip on hover and returned by [`label`](Self::label). The icon is centered with
+    /// equal padding on every side, and the button otherwise behaves and styles like any
+    /// other.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A unique identifier for the button element
+    /// * `icon` - The element to show as the icon
+    /// * `label` - What the button does, for the tooltip and assistive technologies
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::icon("close-button", svg().path("icons/x.svg").size_4(), "Close");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `label` is empty.
+    pub fn icon(
+        id: impl Into<ElementId>,
+        icon: impl IntoElement,
+        label: impl Into<SharedString>,
+    ) -> Self {
+        let label = label.into();
+        debug_assert!(!label.is_empty(), "an icon button needs a label");
+        let tooltip = label.clone();
+        let mut button = Self::new(id);
+        button.base = button
+            .base
+            .flex()
+            .items_center()
+            .justify_center()
+            .p_2()
+            .tooltip(move |_window, app| app.new(|_| IconButtonTooltip(tooltip.clone())).into());
+        button.label = Some(label);
+        button.child(icon)
+    }
+
+    /// Returns the label of an icon button, or `None` for other buttons.
+    pub fn label(&self) -> Option<&SharedString> {
+        self.label.as_ref()
+    }
+
+    /// Styles the button with a variant from the [`ButtonTheme`].
+    ///
+    /// The variant sets the background, border and text colors for the normal, hover,
+    /// active and disabled states. Styles set on the button take precedence over the
+    /// variant's in each state, so a `hover` that only changes the border keeps the
+    /// variant's hover background. Without a variant the button is unstyled.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The variant whose style tokens to use
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::new("cancel-button").variant(ButtonVariant::Secondary);
+    /// ```
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Same as [`variant(ButtonVariant::Primary)`](Self::variant).
+    pub fn primary(self) -> Self {
+        self.variant(ButtonVariant::Primary)
+    }
+
+    /// Same as [`variant(ButtonVariant::Secondary)`](Self::variant).
+    pub fn secondary(self) -> Self {
+        self.variant(ButtonVariant::Secondary)
+    }
+
+    /// Same as [`variant(ButtonVariant::Ghost)`](Self::variant).
+    pub fn ghost(self) -> Self {
+        self.variant(ButtonVariant::Ghost)
+    }
+
+    /// Same as [`variant(ButtonVariant::Danger)`](Self::variant).
+    pub fn danger(self) -> Self {
+        self.variant(ButtonVariant::Danger)
+    }
+
+    /// Same as [`variant(ButtonVariant::Link)`](Self::variant).
+    pub fn link(self) -> Self {
+        self.variant(ButtonVariant::Link)
+    }
+
+    /// Applies the variant's styles under the ones set on the button.
+    fn apply_variant(mut self, app: &App) -> Self {
+        let [hover, active, focus] = self.state_styles(app);
+        if let Some(hover) = hover {
+            self.base = self.base.hover(move |_| hover);
+        }
+        if let Some(active) = active {
+            self.base = self.base.active(move |_| active);
+        }
+        if let Some(focus) = focus {
+            self.base = self.base.focus(move |_| focus);
+        }
+        self
+    }
+
+    /// Applies the variant's colors to the button and returns its hover, active and focus
+    /// styles, with those set on the button laid over the variant's.
+    fn state_styles(&mut self, app: &App) -> [Option<StyleRefinement>; 3] {
+        let (mut hover, mut active, mut focus) = (None, None, None);
+        if let Some(variant) = self.variant {
+            let theme = ButtonTheme::global(app);
+            let style = *theme.variant(variant);
+            let colors = if self.disabled {
+                style.disabled
+            } else {
+                style.normal
+            };
+
+            let mut refinement = Self::colors(colors);
+            refinement.refine(self.base.style());
+            *self.base.style() = refinement;
+
+            if !self.disabled && !self.loading {
+                hover = Some(Self::colors(style.hover));
+                active = Some(Self::colors(style.active));
+                focus = Some(StyleRefinement::default().border_color(theme.focus_ring));
+            }
+        }
+
+        [
+            merge_styles(hover, self.hover_style.take()),
+            merge_styles(active, self.active_style.take()),
+            merge_styles(focus, self.focus_style.take()),
+        ]
+    }
+
+    /// Runs the [`when_loading`](Self::when_loading) handler of a loading button, before
+    /// the variant is applied so that what it sets is merged like any other style.
+    fn apply_loading(mut self) -> Self {
+        if self.loading
+            && let Some(handler) = self.when_loading_handler.take()
+        {
+            self = handler(self);
+        }
+        self
+    }
+
+    fn colors(colors: ButtonColors) -> StyleRefinement {
+        StyleRefinement::default()
+            .bg(colors.background)
+            .border_color(colors.border)
+            .text_color(colors.text)
+    }
+
+    /// Sets whether the button is loading.
+    ///
+    /// A loading button shows a spinner in place of its children and ignores clicks and
+    /// keys. Like a disabled one, it doesn't take focus when pressed and has no hover,
+    /// active or focus styles from its variant. The children stay laid out but invisible,
+    /// so the button keeps its size.
+    ///
+    /// # Arguments
+    ///
+    /// * `loading` - Whether the button should show the spinner (`true`) or its children (`false`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::new("save-button")
+    ///     .child(span("Save"))
+    ///     .loading(is_saving);
+    /// ```
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Returns whether the button is loading.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Replaces the default [`Spinner`] shown while loading.
+    ///
+    /// # Arguments
+    ///
+    /// * `spinner` - The element to center over the button while it is loading
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::new("save-button")
+    ///     .child(span("Save"))
+    ///     .spinner(span("Saving…"))
+    ///     .loading(true);
+    /// ```
+    pub fn spinner(mut self, spinner: impl IntoElement) -> Self {
+        self.spinner = Some(spinner.into_any_element());
+        self
+    }
+
+    /// Conditionally applies styling or modifications when the button is loading.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the button, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::new("save-button")
+    ///     .loading(true)
+    ///     .when_loading(|this| this.cursor_default().opacity(0.8));
+    /// ```
+    pub fn when_loading(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_loading_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// The click event handed to `on_click` when a key activates the button.
+    pub(crate) fn key_click(keystroke: &Keystroke) -> ClickEvent {
+        ClickEvent {
+            down: MouseDownEvent {
+                button: MouseButton::Left,
+                modifiers: keystroke.modifiers,
+                click_count: 1,
+                ..Default::default()
+            },
+            up: MouseUpEvent {
+                button: MouseButton::Left,
+                modifiers: keystroke.modifiers,
+                click_count: 1,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn default_spinner() -> AnyElement {
+        Spinner::new().into_any_element()
+    }
+
+    /// Controls whether the button stops event propagation when clicked.
+    ///
+    /// When set to `true` (default), the button will prevent the click event from
+    /// bubbling up to parent elements. When `false`, the event will continue to propagate.
+    ///
+    /// # Arguments
+    ///
+    /// * `stop` - Whether to stop event propagation (`true`) or allow it (`false`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::new("my-button")
+    ///     .stop_propagation(false); // Allow event to bubble up
+    /// ```
+    pub fn stop_propagation(mut self, stop: bool) -> Self {
+        self.stop_propagation = stop;
+        self
+    }
+
+    /// Sets a callback function that is called when the button is clicked.
+    ///
+    /// The callback receives the click event and provides access to the window and app context.
+    /// When the button tracks a focus handle and is focused, it is also called on Enter key
+    /// down and on Space key up after Space went down on the button, as with a click at the
+    /// button's origin. Keys pressed with a modifier are ignored. This callback will not
+    /// be triggered if the button is disabled or loading.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_click` - A function that handles the button click event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let button = Button::new("my-button")
+    ///     .on_click(|event, _window, _cx| {
+    ///         println!("Button clicked at position: {:?}", event.position);
+    ///     });
+    /// ```
+    pub fn on_click<F>(mut self, on_click: F) -> Self
+    where
+        F: Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+}
+
+impl Disableable for Button {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl ParentElement for Button {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Button {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Hover, active and focus styles are kept until render, so that they can be laid over
+/// the variant's.
+impl InteractiveElement for Button {
+    fn interactivity(&mut self) -> &mut Interactivity {
+        self.base.interactivity()
+    }
+
+    fn hover(mut self, f: impl FnOnce(StyleRefinement) -> StyleRefinement) -> Self {
+        self.hover_style = Some(f(StyleRefinement::default()));
+        self
+    }
+
+    fn focus(mut self, f: impl FnOnce(StyleRefinement) -> StyleRefinement) -> Self {
+        self.focus_style = Some(f(StyleRefinement::default()));
+        self
+    }
+}
+
+impl StatefulInteractiveElement for Button {
+    fn active(mut self, f: impl FnOnce(StyleRefinement) -> StyleRefinement) -> Self {
+        self.active_style = Some(f(StyleRefinement::default()));
+        self
+    }
+}
+
+/// Lays `user` over `variant`, so that what is set in `user` wins.
+fn merge_styles(
+    variant: Option<StyleRefinement>,
+    user: Option<StyleRefinement>,
+) -> Option<StyleRefinement> {
+    match (variant, user) {
+        (Some(mut variant), Some(user)) => {
+            variant.refine(&user);
+            Some(variant)
+        }
+        (variant, user) => variant.or(user),
+    }
+}
+
+impl RenderOnce for Button {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        self = self.apply_loading().apply_variant(app);
+
+        let loading = self.loading;
+        let spinner = loading.then(|| {
+            div()
+                .absolute()
+                .inset_0()
+                .flex()
+                .items_center()
+                .justify_center()
+                .debug_selector(|| "button-spinner".into())
+                .child(self.spinner.take().unwrap_or_else(Self::default_spinner))
+        });
+
+        // Lay the hidden children out like the button would, so it keeps its size
+        let layout = self.base.style().clone();
+        let mut hidden = div().invisible();
+        let style = hidden.style();
+        style.display = layout.display;
+        style.flex_direction = layout.flex_direction;
+        style.flex_wrap = layout.flex_wrap;
+        style.align_items = layout.align_items;
+        style.justify_content = layout.justify_content;
+        style.gap = layout.gap;
+
+        self.base
+            .when(loading, |this| this.relative())
+            // Pressing the button doesn't focus it either
+            .when(self.disabled || loading, |this| {
+                this.on_mouse_down(MouseButton::Left, |_, window, _| window.prevent_default())
+            })
+            .when_some(
+                self.on_click.filter(|_| !self.disabled && !loading),
+                |this, on_click| {
+                    let stop_propagation = self.stop_propagation;
+                    let on_key_down = on_click.clone();
+                    let on_key_up = on_click.clone();
+                    // Whether Space went down on the button, so that releasing it clicks
+                    let space_pressed = window.use_keyed_state(self.id.clone(), app, |_, _| false);
+                    let space_released = space_pressed.clone();
+                    this.on_mouse_down(MouseButton::Left, move |_, window, app| {
+                        window.prevent_default();
+                        if stop_propagation {
+                            app.stop_propagation();
+                        }
+                    })
+                    .on_click(move |event, window, app| on_click(event, window, app))
+                    // Enter clicks as soon as it is pressed, Space once it is released. Keys
+                    // pressed with a modifier are left to shortcuts.
+                    .on_key_down(move |event, window, app| {
+                        if event.keystroke.modifiers.modified() {
+                            return;
+                        }
+                        match event.keystroke.key.as_str() {
+                            "enter" if !event.is_held => {
+                                app.stop_propagation();
+                                on_key_down(&Self::key_click(&event.keystroke), window, app);
+                            }
+                            "space" => {
+                                app.stop_propagation();
+                                space_pressed.update(app, |pressed, _| *pressed = true);
+                            }
+                            _ => {}
+                        }
+                    })
+                    .on_key_up(move |event, window, app| {
+                        if event.keystroke.key != "space" {
+                            return;
+                        }
+                        let pressed = space_released
+                            .update(app, |pressed, _| std::mem::replace(pressed, false));
+                        if pressed && !event.keystroke.modifiers.modified() {
+                            app.stop_propagation();
+                            on_key_up(&Self::key_click(&event.keystroke), window, app);
+                        }
+                    })
+                },
+            )
+            .map(|this| {
+                if loading {
+                    this.child(hidden.children(self.children))
+                } else {
+                    this.children(self.children)
+                }
+            })
+            .children(spinner)
+    }
+}
+
+struct IconButtonTooltip(SharedString);
+
+impl Render for IconButtonTooltip {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .bg(rgb(0x111827))
+            .text_color(white())
+            .text_sm()
+            .child(self.0.clone())
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod icon {
+    use crate::{Disableable, components::Button};
+    use gpui::*;
+
+    #[test]
+    fn icon_button_has_its_label() {
+        let button = Button::icon("close", svg().path("icons/x.svg").size_4(), "Close");
+        assert_eq!(button.label(), Some(&SharedString::from("Close")));
+    }
+
+    #[test]
+    fn text_button_has_no_label() {
+        assert!(Button::new("save").child("Save").label().is_none());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "an icon button needs a label")]
+    fn icon_button_without_a_label_panics() {
+        Button::icon("close", svg().path("icons/x.svg").size_4(), "");
+    }
+
+    #[test]
+    fn icon_button_pads_every_side_equally() {
+        let mut button = Button::icon("close", svg().path("icons/x.svg").size_4(), "Close");
+        let padding = button.base.style().padding.clone();
+        assert!(padding.top.is_some());
+        assert_eq!(padding.top, padding.right);
+        assert_eq!(padding.top, padding.bottom);
+        assert_eq!(padding.top, padding.left);
+    }
+
+    fn colors(style: Option<StyleRefinement>) -> Option<(Option<Fill>, Option<Hsla>)> {
+        style.map(|style| (style.background, style.border_color))
+    }
+
+    #[gpui::test]
+    fn icon_button_shares_the_variant_styles(cx: &mut TestAppContext) {
+        let icon = || Button::icon("close", svg().path("icons/x.svg").size_4(), "Close").primary();
+        let text = || Button::new("save").child("Save").primary();
+        cx.update(|app| {
+            let [icon_hover, icon_active, _] = icon().state_styles(app);
+            let [text_hover, text_active, _] = text().state_styles(app);
+            assert!(icon_hover.is_some());
+            assert_eq!(colors(icon_hover), colors(text_hover));
+            assert_eq!(colors(icon_active), colors(text_active));
+
+            let mut icon = icon().disabled(true);
+            let mut text = text().disabled(true);
+            let [icon_hover, ..] = icon.state_styles(app);
+            let [text_hover, ..] = text.state_styles(app);
+            assert!(icon_hover.is_none() && text_hover.is_none());
+            assert_eq!(icon.base.style().background, text.base.style().background);
+        });
+    }
+}
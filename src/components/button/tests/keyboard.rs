@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod keyboard {
+    use crate::components::Button;
+    use gpui::*;
+    use std::{cell::Cell, rc::Rc};
+
+    struct FocusedButton {
+        focus_handle: FocusHandle,
+        clicks: Rc<Cell<usize>>,
+    }
+
+    impl Render for FocusedButton {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            div().child(
+                Button::new("submit")
+                    .track_focus(&self.focus_handle)
+                    .child("Submit")
+                    .on_click(move |_, _, _| clicks.set(clicks.get() + 1)),
+            )
+        }
+    }
+
+    /// Open a window with a focused button, returning how many times it was clicked
+    fn focused_button(cx: &mut TestAppContext) -> (Rc<Cell<usize>>, &mut VisualTestContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let (view, cx) = cx.add_window_view(|_, cx| FocusedButton {
+            focus_handle: cx.focus_handle(),
+            clicks: clicks.clone(),
+        });
+        view.update_in(cx, |view, window, _| view.focus_handle.focus(window));
+        cx.run_until_parked();
+        (clicks, cx)
+    }
+
+    fn key_up(cx: &mut VisualTestContext, key: &str) {
+        cx.simulate_event(KeyUpEvent {
+            keystroke: Keystroke::parse(key).unwrap(),
+        });
+    }
+
+    #[gpui::test]
+    fn enter_clicks_once_on_key_down(cx: &mut TestAppContext) {
+        let (clicks, cx) = focused_button(cx);
+
+        cx.simulate_keystrokes("enter");
+        assert_eq!(clicks.get(), 1);
+        key_up(cx, "enter");
+        assert_eq!(clicks.get(), 1);
+    }
+
+    #[gpui::test]
+    fn space_clicks_once_on_key_up(cx: &mut TestAppContext) {
+        let (clicks, cx) = focused_button(cx);
+
+        cx.simulate_keystrokes("space");
+        assert_eq!(clicks.get(), 0);
+        key_up(cx, "space");
+        assert_eq!(clicks.get(), 1);
+
+        // A release without a press on the button, e.g. after focus moved to it
+        key_up(cx, "space");
+        assert_eq!(clicks.get(), 1);
+    }
+
+    #[gpui::test]
+    fn keys_with_modifiers_are_ignored(cx: &mut TestAppContext) {
+        let (clicks, cx) = focused_button(cx);
+
+        cx.simulate_keystrokes("cmd-enter shift-enter");
+        cx.simulate_keystrokes("cmd-space");
+        key_up(cx, "cmd-space");
+        assert_eq!(clicks.get(), 0);
+    }
+
+    #[gpui::test]
+    fn keys_need_focus(cx: &mut TestAppContext) {
+        let (clicks, cx) = focused_button(cx);
+        cx.update(|window, _| window.blur());
+
+        cx.simulate_keystrokes("enter space");
+        key_up(cx, "space");
+        assert_eq!(clicks.get(), 0);
+    }
+}
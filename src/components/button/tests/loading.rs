@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod loading {
+    use crate::components::Button;
+    use gpui::*;
+    use std::{cell::Cell, rc::Rc};
+
+    struct LoadingButton {
+        loading: bool,
+        clicks: Rc<Cell<usize>>,
+    }
+
+    impl Render for LoadingButton {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            div().child(
+                Button::new("save")
+                    .debug_selector(|| "save".into())
+                    .primary()
+                    .child("Save")
+                    .loading(self.loading)
+                    .on_click(move |_, _, _| clicks.set(clicks.get() + 1)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn loading_button_ignores_clicks(cx: &mut TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let (view, cx) = cx.add_window_view(|_, _| LoadingButton {
+            loading: true,
+            clicks: clicks.clone(),
+        });
+        assert!(cx.debug_bounds("button-spinner").is_some());
+
+        let button = cx.debug_bounds("save").unwrap();
+        cx.simulate_click(button.center(), Modifiers::none());
+        assert_eq!(clicks.get(), 0);
+
+        // Done loading, the spinner goes and clicks get through
+        view.update(cx, |view, cx| {
+            view.loading = false;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("button-spinner").is_none());
+        assert_eq!(cx.debug_bounds("save"), Some(button));
+        cx.simulate_click(button.center(), Modifiers::none());
+        assert_eq!(clicks.get(), 1);
+    }
+
+    #[gpui::test]
+    fn loading_handler_styles_merge_with_the_variant(cx: &mut TestAppContext) {
+        cx.update(|app| {
+            let mut button = Button::new("save")
+                .primary()
+                .loading(true)
+                .when_loading(|this| this.hover(|style| style.bg(red())).opacity(0.8))
+                .apply_loading();
+            let [hover, _, _] = button.state_styles(app);
+
+            let expected = StyleRefinement::default().bg(red());
+            assert_eq!(
+                hover.and_then(|hover| hover.background),
+                expected.background
+            );
+            assert_eq!(button.base.style().opacity, Some(0.8));
+        });
+    }
+}
@@ -0,0 +1,4 @@
+mod icon;
+mod keyboard;
+mod loading;
+mod variant;
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod variant {
+    use crate::components::button::{ButtonTheme, ButtonVariant, merge_styles};
+    use gpui::*;
+
+    fn color(hex: u32) -> Hsla {
+        rgb(hex).into()
+    }
+
+    #[test]
+    fn each_variant_resolves_its_tokens() {
+        let theme = ButtonTheme::default();
+        let cases = [
+            (ButtonVariant::Primary, &theme.primary),
+            (ButtonVariant::Secondary, &theme.secondary),
+            (ButtonVariant::Ghost, &theme.ghost),
+            (ButtonVariant::Danger, &theme.danger),
+            (ButtonVariant::Link, &theme.link),
+        ];
+        for (variant, style) in cases {
+            assert_eq!(theme.variant(variant), style);
+        }
+    }
+
+    #[test]
+    fn default_tokens() {
+        let theme = ButtonTheme::default();
+        let primary = theme.variant(ButtonVariant::Primary);
+        assert_eq!(primary.normal.background, color(0x3b82f6));
+        assert_eq!(primary.hover.background, color(0x2563eb));
+        assert_eq!(primary.active.background, color(0x1d4ed8));
+        assert_eq!(primary.normal.text, color(0xffffff));
+
+        let secondary = theme.variant(ButtonVariant::Secondary);
+        assert_eq!(secondary.normal.border, color(0xd1d5db));
+        assert_eq!(secondary.active.border, color(0x9ca3af));
+
+        let ghost = theme.variant(ButtonVariant::Ghost);
+        assert_eq!(ghost.normal.background, transparent_black());
+        assert_eq!(ghost.hover.background, color(0xf3f4f6));
+
+        let danger = theme.variant(ButtonVariant::Danger);
+        assert_eq!(danger.normal.background, color(0xef4444));
+        assert_eq!(danger.hover.background, color(0xdc2626));
+
+        let link = theme.variant(ButtonVariant::Link);
+        assert_eq!(link.normal.text, color(0x2563eb));
+        assert_eq!(link.hover.background, transparent_black());
+    }
+
+    #[test]
+    fn disabled_tokens_are_muted() {
+        let theme = ButtonTheme::default();
+        for variant in [ButtonVariant::Primary, ButtonVariant::Danger] {
+            assert_eq!(theme.variant(variant).disabled.text, color(0x9ca3af));
+        }
+    }
+
+    #[test]
+    fn button_styles_win_over_the_variant() {
+        let variant = StyleRefinement::default()
+            .bg(color(0x2563eb))
+            .border_color(color(0x2563eb));
+        let user = StyleRefinement::default()
+            .border_color(color(0xffffff))
+            .opacity(0.5);
+
+        let merged = merge_styles(Some(variant), Some(user)).unwrap();
+        assert_eq!(merged.border_color, Some(color(0xffffff)));
+        assert_eq!(merged.opacity, Some(0.5));
+        assert!(merged.background.is_some());
+    }
+
+    #[test]
+    fn either_style_alone_is_kept() {
+        let style = StyleRefinement::default().opacity(0.5);
+        assert_eq!(
+            merge_styles(Some(style.clone()), None).unwrap().opacity,
+            Some(0.5)
+        );
+        assert_eq!(merge_styles(None, Some(style)).unwrap().opacity, Some(0.5));
+        assert!(merge_styles(None, None).is_none());
+    }
+}
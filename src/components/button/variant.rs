@@ -0,0 +1,126 @@
+use gpui::*;
+
+/// The look of a [`Button`](super::Button), resolved through the [`ButtonTheme`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ButtonVariant {
+    /// The main action of a view, filled with the accent color.
+    #[default]
+    Primary,
+    /// A less prominent action, on a neutral surface.
+    Secondary,
+    /// No background or border until hovered.
+    Ghost,
+    /// A destructive action.
+    Danger,
+    /// Styled like a text link.
+    Link,
+}
+
+/// The background, border and text colors of a button in one state.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonColors {
+    pub background: Hsla,
+    pub border: Hsla,
+    pub text: Hsla,
+}
+
+impl ButtonColors {
+    fn new(background: impl Into<Hsla>, border: impl Into<Hsla>, text: impl Into<Hsla>) -> Self {
+        Self {
+            background: background.into(),
+            border: border.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// The colors of a button variant in each of its states.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonVariantStyle {
+    pub normal: ButtonColors,
+    pub hover: ButtonColors,
+    pub active: ButtonColors,
+    pub disabled: ButtonColors,
+}
+
+/// The style tokens for each [`ButtonVariant`].
+///
+/// Set it as a global to restyle every button with a variant, or leave it unset to use
+/// the defaults.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut theme = ButtonTheme::default();
+/// theme.primary.normal.background = rgb(0x7c3aed).into();
+/// cx.set_global(theme);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ButtonTheme {
+    pub primary: ButtonVariantStyle,
+    pub secondary: ButtonVariantStyle,
+    pub ghost: ButtonVariantStyle,
+    pub danger: ButtonVariantStyle,
+    pub link: ButtonVariantStyle,
+    /// The border color of an enabled button while it is focused.
+    pub focus_ring: Hsla,
+}
+
+impl Global for ButtonTheme {}
+
+impl ButtonTheme {
+    /// Returns the theme set as a global, or the default one.
+    pub fn global(app: &App) -> Self {
+        app.try_global::<Self>().cloned().unwrap_or_default()
+    }
+
+    /// Returns the style tokens for the variant.
+    pub fn variant(&self, variant: ButtonVariant) -> &ButtonVariantStyle {
+        match variant {
+            ButtonVariant::Primary => &self.primary,
+            ButtonVariant::Secondary => &self.secondary,
+            ButtonVariant::Ghost => &self.ghost,
+            ButtonVariant::Danger => &self.danger,
+            ButtonVariant::Link => &self.link,
+        }
+    }
+}
+
+impl Default for ButtonTheme {
+    fn default() -> Self {
+        let clear = transparent_black();
+        let white = rgb(0xffffff);
+        let disabled = ButtonColors::new(rgb(0xe5e7eb), clear, rgb(0x9ca3af));
+        let filled = |normal: u32, hover: u32, active: u32| ButtonVariantStyle {
+            normal: ButtonColors::new(rgb(normal), clear, white),
+            hover: ButtonColors::new(rgb(hover), clear, white),
+            active: ButtonColors::new(rgb(active), clear, white),
+            disabled,
+        };
+        let text = |normal: u32, hover: u32| ButtonVariantStyle {
+            normal: ButtonColors::new(clear, clear, rgb(normal)),
+            hover: ButtonColors::new(clear, clear, rgb(hover)),
+            active: ButtonColors::new(clear, clear, rgb(hover)),
+            disabled: ButtonColors::new(clear, clear, rgb(0x9ca3af)),
+        };
+
+        Self {
+            primary: filled(0x3b82f6, 0x2563eb, 0x1d4ed8),
+            secondary: ButtonVariantStyle {
+                normal: ButtonColors::new(white, rgb(0xd1d5db), rgb(0x111827)),
+                hover: ButtonColors::new(rgb(0xf3f4f6), rgb(0xd1d5db), rgb(0x111827)),
+                active: ButtonColors::new(rgb(0xe5e7eb), rgb(0x9ca3af), rgb(0x111827)),
+                disabled: ButtonColors::new(white, rgb(0xe5e7eb), rgb(0x9ca3af)),
+            },
+            ghost: ButtonVariantStyle {
+                normal: ButtonColors::new(clear, clear, rgb(0x111827)),
+                hover: ButtonColors::new(rgb(0xf3f4f6), clear, rgb(0x111827)),
+                active: ButtonColors::new(rgb(0xe5e7eb), clear, rgb(0x111827)),
+                disabled: ButtonColors::new(clear, clear, rgb(0x9ca3af)),
+            },
+            danger: filled(0xef4444, 0xdc2626, 0xb91c1c),
+            link: text(0x2563eb, 0x1d4ed8),
+            focus_ring: rgb(0x60a5fa).into(),
+        }
+    }
+}
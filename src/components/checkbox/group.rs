@@ -0,0 +1,233 @@
+use crate::{
+    components::checkbox::{Checkbox, selection::GroupSelection},
+    primitives::v_flex,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// A set of checkboxes bound to the list of values that are checked.
+///
+/// The group decides which of its checkboxes are checked from its value and reports every
+/// click through `on_change` as the new list of checked values. An optional "select all"
+/// checkbox checks or unchecks every option, and is indeterminate while only some of them
+/// are checked.
+///
+/// With a focus handle for each option, the arrow keys move focus between the options,
+/// wrapping around at either end.
+///
+/// # Examples
+///
+/// ```rust
+/// CheckboxGroup::new("toppings")
+///     .value(self.toppings.clone())
+///     .select_all(Checkbox::new("all").indicator(span("✓")))
+///     .checkbox("cheese", Checkbox::new("cheese").indicator(span("✓")))
+///     .checkbox("olives", Checkbox::new("olives").indicator(span("✓")))
+///     .on_change(cx.listener(|this, toppings: &Vec<SharedString>, _window, cx| {
+///         this.toppings = toppings.clone();
+///         cx.notify();
+///     }))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct CheckboxGroup {
+    base: Stateful<Div>,
+    options: Vec<(SharedString, Checkbox)>,
+    select_all: Option<Checkbox>,
+    value: Vec<SharedString>,
+    focus_handles: Vec<FocusHandle>,
+    on_change: Option<Rc<dyn Fn(&Vec<SharedString>, &mut Window, &mut App) + 'static>>,
+}
+
+impl CheckboxGroup {
+    /// Creates a new, empty checkbox group with the specified ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A unique identifier for the group element
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = CheckboxGroup::new("toppings");
+    /// ```
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: v_flex().id(id),
+            options: Vec::new(),
+            select_all: None,
+            value: Vec::new(),
+            focus_handles: Vec::new(),
+            on_change: None,
+        }
+    }
+
+    /// Sets the values that are checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The values of the checked options
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = CheckboxGroup::new("toppings").value(["cheese"]);
+    /// ```
+    pub fn value(mut self, value: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.value = value.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds an option to the group.
+    ///
+    /// The group sets whether the checkbox is checked and handles its changes, so any
+    /// state or `on_change` set on it is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value the option stands for
+    /// * `checkbox` - The checkbox to render for the option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = CheckboxGroup::new("toppings")
+    ///     .checkbox("cheese", Checkbox::new("cheese").indicator(span("✓")));
+    /// ```
+    pub fn checkbox(mut self, value: impl Into<SharedString>, checkbox: Checkbox) -> Self {
+        self.options.push((value.into(), checkbox));
+        self
+    }
+
+    /// Adds a "select all" checkbox before the options.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkbox` - The checkbox that checks or unchecks every option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = CheckboxGroup::new("toppings")
+    ///     .select_all(Checkbox::new("all").indicator(span("✓")));
+    /// ```
+    pub fn select_all(mut self, checkbox: Checkbox) -> Self {
+        self.select_all = Some(checkbox);
+        self
+    }
+
+    /// Sets the focus handles of the options, in the order they were added.
+    ///
+    /// Each option tracks its handle, and the arrow keys move focus between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `handles` - One focus handle for each option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = CheckboxGroup::new("toppings").focus_handles(self.focus_handles.clone());
+    /// ```
+    pub fn focus_handles(mut self, handles: impl IntoIterator<Item = FocusHandle>) -> Self {
+        self.focus_handles = handles.into_iter().collect();
+        self
+    }
+
+    /// Sets a callback function that is called when an option is checked or unchecked.
+    ///
+    /// The callback receives the checked values after the change, in the order of the
+    /// options.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_change` - A function that handles the new list of checked values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = CheckboxGroup::new("toppings")
+    ///     .on_change(|toppings, _window, _cx| {
+    ///         println!("Toppings: {:?}", toppings);
+    ///     });
+    /// ```
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&Vec<SharedString>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl Styled for CheckboxGroup {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for CheckboxGroup {
+    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let options = self
+            .options
+            .iter()
+            .map(|(value, _)| value.clone())
+            .collect();
+        let selection = Rc::new(GroupSelection::new(options, self.value));
+        let on_change = self.on_change;
+
+        let select_all = self.select_all.map(|checkbox| {
+            let selection = selection.clone();
+            checkbox
+                .state(selection.all_state())
+                .when_some(on_change.clone(), |this, on_change| {
+                    this.on_change(move |_, window, app| {
+                        on_change(&selection.toggle_all(), window, app)
+                    })
+                })
+        });
+
+        let focus_handles = Rc::new(self.focus_handles);
+        let checkboxes = self
+            .options
+            .into_iter()
+            .enumerate()
+            .map(|(ix, (value, checkbox))| {
+                let checked = selection.is_checked(&value);
+                let selection = selection.clone();
+                checkbox
+                    .checked(checked)
+                    .when_some(focus_handles.get(ix), |this, handle| {
+                        this.track_focus(handle)
+                    })
+                    .when_some(on_change.clone(), |this, on_change| {
+                        this.on_change(move |_, window, app| {
+                            on_change(&selection.toggle(&value), window, app)
+                        })
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        self.base
+            .on_key_down(move |event, window, app| {
+                let forward = match event.keystroke.key.as_str() {
+                    "down" | "right" => true,
+                    "up" | "left" => false,
+                    _ => return,
+                };
+                let Some(focused) = focus_handles
+                    .iter()
+                    .position(|handle| handle.is_focused(window))
+                else {
+                    return;
+                };
+                app.stop_propagation();
+                let next = selection.roving_index(focused, forward);
+                if let Some(handle) = focus_handles.get(next) {
+                    handle.focus(window);
+                }
+            })
+            .children(select_all)
+            .children(checkboxes)
+    }
+}
@@ -1,6 +1,18 @@
-use crate::{Disableable, primitives::h_flex_center};
+use crate::{
+    Disableable,
+    primitives::{h_flex_center, span},
+};
 use gpui::{prelude::FluentBuilder, *};
 
+mod group;
+mod selection;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use group::*;
+pub use state::*;
+
 /// A checkbox component that allows users to toggle between checked and unchecked states.
 ///
 /// The checkbox provides a boolean input control that can be styled and customized.
@@ -27,15 +39,25 @@ use gpui::{prelude::FluentBuilder, *};
 ///     .rounded_md()
 ///     .size(rems(1.5))
 /// ```
+///
+/// As a "select all" header for some checked rows:
+/// ```rust
+/// Checkbox::new("select-all")
+///     .state(CheckState::Indeterminate)
+///     .indicator(span("✓"))
+///     .on_change(|checked, _window, _cx| select_all(*checked))
+/// ```
 #[allow(clippy::type_complexity)]
 #[derive(IntoElement)]
 pub struct Checkbox {
     base: Stateful<Div>,
     disabled: bool,
-    checked: bool,
+    state: CheckState,
     on_change: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
     when_checked_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_indeterminate_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
     indicator: AnyElement,
+    indeterminate_indicator: Option<AnyElement>,
 }
 
 impl Checkbox {
@@ -56,10 +78,12 @@ impl Checkbox {
         Self {
             base: h_flex_center().id(id),
             disabled: false,
-            checked: false,
+            state: CheckState::Unchecked,
             indicator: div().into_any_element(),
+            indeterminate_indicator: None,
             on_change: None,
             when_checked_handler: None,
+            when_indeterminate_handler: None,
         }
     }
 
@@ -75,7 +99,23 @@ impl Checkbox {
     /// let checkbox = Checkbox::new("my-checkbox").checked(true);
     /// ```
     pub fn checked(mut self, checked: bool) -> Self {
-        self.checked = checked;
+        self.state = checked.into();
+        self
+    }
+
+    /// Sets the state of the checkbox, including the indeterminate one.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Whether the checkbox is unchecked, checked or indeterminate
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let checkbox = Checkbox::new("select-all").state(CheckState::Indeterminate);
+    /// ```
+    pub fn state(mut self, state: CheckState) -> Self {
+        self.state = state;
         self
     }
 
@@ -99,6 +139,26 @@ impl Checkbox {
         self
     }
 
+    /// Sets the indicator element that appears when the checkbox is indeterminate.
+    ///
+    /// Without one, an indeterminate checkbox shows a dash.
+    ///
+    /// # Arguments
+    ///
+    /// * `indicator` - An element to display when the checkbox is indeterminate
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let checkbox = Checkbox::new("select-all")
+    ///     .state(CheckState::Indeterminate)
+    ///     .indeterminate_indicator(span("−").text_color(rgb(0xffffff)));
+    /// ```
+    pub fn indeterminate_indicator(mut self, indicator: impl IntoElement) -> Self {
+        self.indeterminate_indicator = Some(indicator.into_any_element());
+        self
+    }
+
     /// Conditionally applies styling or modifications when the checkbox is checked.
     ///
     /// This method allows you to apply different styles or properties based on the
@@ -120,10 +180,29 @@ impl Checkbox {
         self
     }
 
+    /// Conditionally applies styling or modifications when the checkbox is indeterminate.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the checkbox, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let checkbox = Checkbox::new("select-all")
+    ///     .state(CheckState::Indeterminate)
+    ///     .when_indeterminate(|this| this.bg(rgb(0x93c5fd)));
+    /// ```
+    pub fn when_indeterminate(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_indeterminate_handler = Some(Box::new(handler));
+        self
+    }
+
     /// Sets a callback function that is called when the checkbox state changes.
     ///
-    /// The callback receives the new checked state as a boolean value. This callback
-    /// will not be triggered if the checkbox is disabled.
+    /// The callback receives the new checked state as a boolean value. Clicking an
+    /// indeterminate checkbox checks it. This callback will not be triggered if the
+    /// checkbox is disabled.
     ///
     /// # Arguments
     ///
@@ -177,23 +256,35 @@ impl InteractiveElement for Checkbox {
 
 impl RenderOnce for Checkbox {
     fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
-        if self.checked {
-            if let Some(handler) = self.when_checked_handler.take() {
-                self = handler(self);
-            }
+        let handler = match self.state {
+            CheckState::Checked => self.when_checked_handler.take(),
+            CheckState::Indeterminate => self.when_indeterminate_handler.take(),
+            CheckState::Unchecked => None,
+        };
+        if let Some(handler) = handler {
+            self = handler(self);
         }
 
+        let state = self.state;
         self.base
             .when_some(
                 self.on_change.filter(|_| !self.disabled),
                 |this, on_click| {
                     this.on_click(move |_, window, cx| {
                         cx.stop_propagation();
-                        let checked = !self.checked;
+                        let checked = state.toggled().is_checked();
                         on_click(&checked, window, cx);
                     })
                 },
             )
-            .when(self.checked, |this| this.child(self.indicator))
+            .map(|this| match state {
+                CheckState::Unchecked => this,
+                CheckState::Checked => this.child(self.indicator),
+                CheckState::Indeterminate => {
+                    this.child(self.indeterminate_indicator.unwrap_or_else(|| {
+                        span(state.glyph().unwrap_or_default()).into_any_element()
+                    }))
+                }
+            })
     }
 }
@@ -0,0 +1,70 @@
+use crate::components::checkbox::CheckState;
+use gpui::SharedString;
+
+/// The values checked in a [`CheckboxGroup`](super::CheckboxGroup), against all of its
+/// options.
+///
+/// Every change returns the new set of checked values in the order of the options.
+pub struct GroupSelection {
+    options: Vec<SharedString>,
+    checked: Vec<SharedString>,
+}
+
+impl GroupSelection {
+    pub fn new(options: Vec<SharedString>, checked: Vec<SharedString>) -> Self {
+        Self { options, checked }
+    }
+
+    pub fn is_checked(&self, value: &SharedString) -> bool {
+        self.checked.contains(value)
+    }
+
+    /// Returns the checked values after toggling `value`.
+    pub fn toggle(&self, value: &SharedString) -> Vec<SharedString> {
+        self.options
+            .iter()
+            .filter(|option| self.is_checked(option) != (*option == value))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the checked values after clicking the "select all" checkbox.
+    ///
+    /// Everything is unchecked when every option is checked, and checked otherwise.
+    pub fn toggle_all(&self) -> Vec<SharedString> {
+        if self.all_state().toggled().is_checked() {
+            self.options.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns the state of the "select all" checkbox.
+    ///
+    /// It is indeterminate while only some of the options are checked.
+    pub fn all_state(&self) -> CheckState {
+        let checked = self
+            .options
+            .iter()
+            .filter(|option| self.is_checked(option))
+            .count();
+        if checked == 0 {
+            CheckState::Unchecked
+        } else if checked == self.options.len() {
+            CheckState::Checked
+        } else {
+            CheckState::Indeterminate
+        }
+    }
+
+    /// Returns the option to move focus to from `focused` with the arrow keys, wrapping
+    /// around at either end.
+    pub fn roving_index(&self, focused: usize, forward: bool) -> usize {
+        let len = self.options.len().max(1);
+        if forward {
+            (focused + 1) % len
+        } else {
+            (focused + len - 1) % len
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/// The state of a [`Checkbox`](super::Checkbox).
+///
+/// `Indeterminate` is for checkboxes that stand for a set of others, like a "select all"
+/// header when only some of the rows are checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl CheckState {
+    /// Returns whether the state is [`Checked`](Self::Checked).
+    pub fn is_checked(self) -> bool {
+        self == Self::Checked
+    }
+
+    /// Returns the state after a click.
+    ///
+    /// A checked checkbox becomes unchecked, and an unchecked or indeterminate one becomes
+    /// checked.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Checked => Self::Unchecked,
+            Self::Unchecked | Self::Indeterminate => Self::Checked,
+        }
+    }
+
+    /// Returns the glyph shown for the state when no indicator is set for it.
+    ///
+    /// Only the indeterminate state has one, a dash. A checked checkbox shows nothing
+    /// until given an [`indicator`](super::Checkbox::indicator).
+    pub fn glyph(self) -> Option<&'static str> {
+        match self {
+            Self::Indeterminate => Some("–"),
+            Self::Unchecked | Self::Checked => None,
+        }
+    }
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
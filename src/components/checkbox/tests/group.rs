@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod group {
+    use crate::components::checkbox::{CheckState, selection::GroupSelection};
+    use gpui::SharedString;
+
+    fn selection(checked: &[&'static str]) -> GroupSelection {
+        GroupSelection::new(
+            vec!["a".into(), "b".into(), "c".into()],
+            checked.iter().map(|&value| value.into()).collect(),
+        )
+    }
+
+    fn values(values: &[&'static str]) -> Vec<SharedString> {
+        values.iter().map(|&value| value.into()).collect()
+    }
+
+    #[test]
+    fn toggling_a_child_checks_it() {
+        let selection = selection(&["a"]);
+        assert_eq!(selection.toggle(&"c".into()), values(&["a", "c"]));
+    }
+
+    #[test]
+    fn toggling_a_checked_child_unchecks_it() {
+        let selection = selection(&["a", "b"]);
+        assert_eq!(selection.toggle(&"a".into()), values(&["b"]));
+    }
+
+    #[test]
+    fn checked_values_follow_the_order_of_the_options() {
+        let selection = selection(&["c"]);
+        assert_eq!(selection.toggle(&"a".into()), values(&["a", "c"]));
+    }
+
+    #[test]
+    fn unknown_values_are_dropped() {
+        let selection = selection(&["a", "z"]);
+        assert_eq!(selection.toggle(&"b".into()), values(&["a", "b"]));
+    }
+
+    #[test]
+    fn parent_follows_the_children() {
+        assert_eq!(selection(&[]).all_state(), CheckState::Unchecked);
+        assert_eq!(selection(&["b"]).all_state(), CheckState::Indeterminate);
+        assert_eq!(selection(&["a", "b", "c"]).all_state(), CheckState::Checked);
+    }
+
+    #[test]
+    fn select_all_checks_every_child() {
+        assert_eq!(selection(&[]).toggle_all(), values(&["a", "b", "c"]));
+        assert_eq!(selection(&["b"]).toggle_all(), values(&["a", "b", "c"]));
+    }
+
+    #[test]
+    fn select_all_unchecks_every_child_once_all_are_checked() {
+        assert_eq!(selection(&["a", "b", "c"]).toggle_all(), values(&[]));
+    }
+
+    #[test]
+    fn empty_group_is_unchecked() {
+        let selection = GroupSelection::new(Vec::new(), Vec::new());
+        assert_eq!(selection.all_state(), CheckState::Unchecked);
+        assert_eq!(selection.toggle_all(), values(&[]));
+    }
+
+    #[test]
+    fn focus_roves_with_wrapping() {
+        let selection = selection(&[]);
+        assert_eq!(selection.roving_index(0, true), 1);
+        assert_eq!(selection.roving_index(2, true), 0);
+        assert_eq!(selection.roving_index(0, false), 2);
+        assert_eq!(selection.roving_index(1, false), 0);
+    }
+}
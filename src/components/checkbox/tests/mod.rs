@@ -0,0 +1,2 @@
+mod group;
+mod state;
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod state {
+    use crate::components::checkbox::state::CheckState;
+
+    #[test]
+    fn clicks_cycle_between_checked_and_unchecked() {
+        let state = CheckState::Unchecked.toggled();
+        assert_eq!(state, CheckState::Checked);
+        assert_eq!(state.toggled(), CheckState::Unchecked);
+    }
+
+    #[test]
+    fn clicking_indeterminate_checks() {
+        assert_eq!(CheckState::Indeterminate.toggled(), CheckState::Checked);
+        assert_eq!(
+            CheckState::Indeterminate.toggled().toggled(),
+            CheckState::Unchecked
+        );
+    }
+
+    #[test]
+    fn only_checked_is_checked() {
+        assert!(CheckState::Checked.is_checked());
+        assert!(!CheckState::Unchecked.is_checked());
+        assert!(!CheckState::Indeterminate.is_checked());
+    }
+
+    #[test]
+    fn converts_from_bool() {
+        assert_eq!(CheckState::from(true), CheckState::Checked);
+        assert_eq!(CheckState::from(false), CheckState::Unchecked);
+    }
+
+    #[test]
+    fn indeterminate_shows_a_dash() {
+        assert_eq!(CheckState::Indeterminate.glyph(), Some("–"));
+        assert_eq!(CheckState::Checked.glyph(), None);
+        assert_eq!(CheckState::Unchecked.glyph(), None);
+    }
+}
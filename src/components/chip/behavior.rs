@@ -0,0 +1,44 @@
+/// What an interaction with a chip does
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChipAction {
+    Click,
+    Remove,
+}
+
+/// The part of a chip a click lands on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChipPart {
+    Body,
+    RemoveButton,
+}
+
+/// The handlers a chip has, deciding what clicks and keys do
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChipBehavior {
+    pub clickable: bool,
+    pub removable: bool,
+    pub disabled: bool,
+}
+
+impl ChipBehavior {
+    /// The action of a click on `part`
+    ///
+    /// A click on the remove button only removes, and never also clicks the body beneath.
+    pub fn click(&self, part: ChipPart) -> Option<ChipAction> {
+        match part {
+            _ if self.disabled => None,
+            ChipPart::Body => self.clickable.then_some(ChipAction::Click),
+            ChipPart::RemoveButton => self.removable.then_some(ChipAction::Remove),
+        }
+    }
+
+    /// The action of a key pressed while the chip is focused: Delete and Backspace remove
+    /// it and Enter clicks it
+    pub fn key(&self, key: &str) -> Option<ChipAction> {
+        match key {
+            "delete" | "backspace" => self.click(ChipPart::RemoveButton),
+            "enter" => self.click(ChipPart::Body),
+            _ => None,
+        }
+    }
+}
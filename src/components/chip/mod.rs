@@ -0,0 +1,217 @@
+use crate::{Disableable, components::Button};
+use gpui::{prelude::FluentBuilder, *};
+use smallvec::SmallVec;
+use std::rc::Rc;
+
+mod behavior;
+#[cfg(test)]
+mod tests;
+
+use behavior::{ChipAction, ChipBehavior, ChipPart};
+
+/// The colors of a [`Chip`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChipVariant {
+    /// A light gray.
+    #[default]
+    Neutral,
+    /// A tint of the accent color.
+    Primary,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl ChipVariant {
+    /// The background and text colors
+    fn colors(self) -> (Hsla, Hsla) {
+        let (background, text) = match self {
+            Self::Neutral => (rgb(0xe5e7eb), rgb(0x374151)),
+            Self::Primary => (rgb(0xdbeafe), rgb(0x1d4ed8)),
+            Self::Success => (rgb(0xd1fae5), rgb(0x047857)),
+            Self::Warning => (rgb(0xfef3c7), rgb(0xb45309)),
+            Self::Danger => (rgb(0xfee2e2), rgb(0xb91c1c)),
+        };
+        (background.into(), text.into())
+    }
+}
+
+/// A compact tag, like a filter or a picked value, that can be clicked and removed.
+///
+/// With [`on_remove`](Self::on_remove) the chip ends in a "×" that removes it. A click on
+/// it only removes, and never also clicks the chip beneath it. When the chip tracks a
+/// focus handle and is focused, Delete and Backspace remove it and Enter clicks it, so a
+/// row of chips can be emptied from the keyboard.
+///
+/// # Examples
+///
+/// ```rust
+/// Chip::new(("tag", ix))
+///     .child(tag.clone())
+///     .variant(ChipVariant::Primary)
+///     .px_2()
+///     .rounded_full()
+///     .track_focus(&self.tag_focus_handles[ix])
+///     .on_click(cx.listener(move |this, _, _, cx| this.filter_by(ix, cx)))
+///     .on_remove(cx.listener(move |this, _, cx| this.remove_tag(ix, cx)))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Chip {
+    base: Stateful<Div>,
+    children: SmallVec<[AnyElement; 1]>,
+    variant: Option<ChipVariant>,
+    disabled: bool,
+    remove_button: Option<AnyElement>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    on_remove: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+}
+
+impl Chip {
+    /// Creates a chip with the specified ID, without a remove button.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: div().id(id),
+            children: SmallVec::new(),
+            variant: None,
+            disabled: false,
+            remove_button: None,
+            on_click: None,
+            on_remove: None,
+        }
+    }
+
+    /// Sets the colors, leaving the chip unstyled without one.
+    pub fn variant(mut self, variant: ChipVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Replaces the "×" of the remove button.
+    pub fn remove_button(mut self, remove_button: impl IntoElement) -> Self {
+        self.remove_button = Some(remove_button.into_any_element());
+        self
+    }
+
+    /// Sets the handler called when the body of the chip is clicked, or Enter is pressed
+    /// while it is focused.
+    pub fn on_click(
+        mut self,
+        on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+
+    /// Shows the remove button and sets the handler called when it is clicked, or Delete
+    /// or Backspace is pressed while the chip is focused.
+    ///
+    /// The chip doesn't remove itself; the owner drops it from the chips it renders.
+    pub fn on_remove(mut self, on_remove: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_remove = Some(Rc::new(on_remove));
+        self
+    }
+
+    fn behavior(&self) -> ChipBehavior {
+        ChipBehavior {
+            clickable: self.on_click.is_some(),
+            removable: self.on_remove.is_some(),
+            disabled: self.disabled,
+        }
+    }
+}
+
+impl Disableable for Chip {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl ParentElement for Chip {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Chip {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl InteractiveElement for Chip {
+    fn interactivity(&mut self) -> &mut Interactivity {
+        self.base.interactivity()
+    }
+}
+
+impl RenderOnce for Chip {
+    fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let behavior = self.behavior();
+        let remove_button = behavior.removable.then(|| {
+            let on_remove = self.on_remove.clone();
+            div()
+                .id("remove")
+                .flex_none()
+                .child(
+                    self.remove_button
+                        .take()
+                        .unwrap_or_else(|| "×".into_any_element()),
+                )
+                .when(!behavior.disabled, |this| this.cursor_pointer())
+                // Don't let the press or the click reach the body beneath
+                .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                .on_click(move |_, window, cx| {
+                    cx.stop_propagation();
+                    if let (Some(ChipAction::Remove), Some(on_remove)) =
+                        (behavior.click(ChipPart::RemoveButton), &on_remove)
+                    {
+                        on_remove(window, cx);
+                    }
+                })
+        });
+
+        let on_click = self.on_click.clone();
+        let on_key_down = (self.on_click.clone(), self.on_remove.clone());
+        self.base
+            .flex()
+            .items_center()
+            .when_some(self.variant, |this, variant| {
+                let (background, text) = variant.colors();
+                this.bg(background).text_color(text)
+            })
+            .when(behavior.clickable && !behavior.disabled, |this| {
+                this.cursor_pointer()
+            })
+            .on_click(move |event, window, cx| {
+                if let (Some(ChipAction::Click), Some(on_click)) =
+                    (behavior.click(ChipPart::Body), &on_click)
+                {
+                    on_click(event, window, cx);
+                }
+            })
+            .on_key_down(move |event, window, cx| {
+                if event.is_held && event.keystroke.key == "enter" {
+                    return;
+                }
+                match (behavior.key(&event.keystroke.key), &on_key_down) {
+                    (Some(ChipAction::Click), (Some(on_click), _)) => {
+                        cx.stop_propagation();
+                        on_click(&Button::key_click(&event.keystroke), window, cx);
+                    }
+                    (Some(ChipAction::Remove), (_, Some(on_remove))) => {
+                        cx.stop_propagation();
+                        on_remove(window, cx);
+                    }
+                    _ => {}
+                }
+            })
+            .children(self.children)
+            .children(remove_button)
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod behavior {
+    use crate::components::chip::behavior::{ChipAction, ChipBehavior, ChipPart};
+
+    const BOTH: ChipBehavior = ChipBehavior {
+        clickable: true,
+        removable: true,
+        disabled: false,
+    };
+
+    #[test]
+    fn the_body_clicks_and_the_button_removes() {
+        assert_eq!(BOTH.click(ChipPart::Body), Some(ChipAction::Click));
+        assert_eq!(BOTH.click(ChipPart::RemoveButton), Some(ChipAction::Remove));
+    }
+
+    #[test]
+    fn each_part_only_does_what_it_has_a_handler_for() {
+        let removable = ChipBehavior {
+            clickable: false,
+            ..BOTH
+        };
+        assert_eq!(removable.click(ChipPart::Body), None);
+        assert_eq!(
+            removable.click(ChipPart::RemoveButton),
+            Some(ChipAction::Remove)
+        );
+
+        let clickable = ChipBehavior {
+            removable: false,
+            ..BOTH
+        };
+        assert_eq!(clickable.click(ChipPart::Body), Some(ChipAction::Click));
+        assert_eq!(clickable.click(ChipPart::RemoveButton), None);
+    }
+
+    #[test]
+    fn delete_and_backspace_remove_a_focused_chip() {
+        assert_eq!(BOTH.key("delete"), Some(ChipAction::Remove));
+        assert_eq!(BOTH.key("backspace"), Some(ChipAction::Remove));
+        assert_eq!(BOTH.key("enter"), Some(ChipAction::Click));
+        assert_eq!(BOTH.key("a"), None);
+        assert_eq!(BOTH.key("space"), None);
+    }
+
+    #[test]
+    fn keys_only_remove_removable_chips() {
+        let clickable = ChipBehavior {
+            removable: false,
+            ..BOTH
+        };
+        assert_eq!(clickable.key("delete"), None);
+        assert_eq!(clickable.key("backspace"), None);
+        assert_eq!(clickable.key("enter"), Some(ChipAction::Click));
+    }
+
+    #[test]
+    fn disabled_chips_do_nothing() {
+        let disabled = ChipBehavior {
+            disabled: true,
+            ..BOTH
+        };
+        assert_eq!(disabled.click(ChipPart::Body), None);
+        assert_eq!(disabled.click(ChipPart::RemoveButton), None);
+        assert_eq!(disabled.key("delete"), None);
+        assert_eq!(disabled.key("enter"), None);
+    }
+}
@@ -0,0 +1,104 @@
+use gpui::Rgba;
+
+/// A color as hue, saturation, value and alpha, the way the picker lays it out
+///
+/// The hue is in degrees from 0 to 360, and the rest go from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsva {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub a: f32,
+}
+
+impl Hsva {
+    /// Create a color, clamping each component to its range
+    pub fn new(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Self {
+            h: h.clamp(0., 360.),
+            s: s.clamp(0., 1.),
+            v: v.clamp(0., 1.),
+            a: a.clamp(0., 1.),
+        }
+    }
+
+    /// Convert from RGB, with a hue of 0 for grays
+    pub fn from_rgba(color: Rgba) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let delta = max - color.r.min(color.g).min(color.b);
+        let h = if delta == 0. {
+            0.
+        } else if max == color.r {
+            60. * ((color.g - color.b) / delta).rem_euclid(6.)
+        } else if max == color.g {
+            60. * ((color.b - color.r) / delta + 2.)
+        } else {
+            60. * ((color.r - color.g) / delta + 4.)
+        };
+        let s = if max == 0. { 0. } else { delta / max };
+        Self::new(h, s, max, color.a)
+    }
+
+    pub fn to_rgba(self) -> Rgba {
+        let chroma = self.v * self.s;
+        let sector = (self.h / 60.) % 6.;
+        let x = chroma * (1. - (sector % 2. - 1.).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.),
+            1 => (x, chroma, 0.),
+            2 => (0., chroma, x),
+            3 => (0., x, chroma),
+            4 => (x, 0., chroma),
+            _ => (chroma, 0., x),
+        };
+        let m = self.v - chroma;
+        Rgba {
+            r: r + m,
+            g: g + m,
+            b: b + m,
+            a: self.a,
+        }
+    }
+}
+
+/// Parse `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, with or without the `#`
+pub fn parse_hex(text: &str) -> Option<Rgba> {
+    let digits = text.trim().strip_prefix('#').unwrap_or(text.trim());
+    if !digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |ix: usize, width: usize| {
+        let value = u8::from_str_radix(&digits[ix * width..(ix + 1) * width], 16).ok()?;
+        // A short digit stands for itself repeated, so `f` is `ff`
+        let value = if width == 1 { value * 17 } else { value };
+        Some(value as f32 / 255.)
+    };
+    let (width, alpha) = match digits.len() {
+        3 => (1, false),
+        4 => (1, true),
+        6 => (2, false),
+        8 => (2, true),
+        _ => return None,
+    };
+    Some(Rgba {
+        r: channel(0, width)?,
+        g: channel(1, width)?,
+        b: channel(2, width)?,
+        a: if alpha { channel(3, width)? } else { 1. },
+    })
+}
+
+/// Format as `#RRGGBB`, appending the alpha when `alpha` is set and the color isn't opaque
+pub fn format_hex(color: Rgba, alpha: bool) -> String {
+    let channel = |value: f32| (value.clamp(0., 1.) * 255.).round() as u8;
+    let mut hex = format!(
+        "#{:02X}{:02X}{:02X}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    );
+    if alpha && channel(color.a) < 255 {
+        hex.push_str(&format!("{:02X}", channel(color.a)));
+    }
+    hex
+}
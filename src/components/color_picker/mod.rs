@@ -0,0 +1,253 @@
+use crate::{
+    components::{input::TextInput, slider::Slider},
+    primitives::v_flex,
+};
+use gpui::{prelude::FluentBuilder, *};
+
+mod color;
+mod model;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use color::{Hsva, format_hex, parse_hex};
+pub use state::*;
+
+/// The number of hues the strip blends between, red, yellow, green, cyan, blue and
+/// magenta
+const HUE_SEGMENTS: usize = 6;
+
+/// A saturation and value square above a hue strip, an optional opacity strip and a hex
+/// input, all showing the same color.
+///
+/// Dragging in the square picks the saturation to the right and the value to the top,
+/// for the hue picked on the strip. The input takes hex colors like `#3B82F6`, or
+/// `#3B82F680` with [`alpha`](ColorPickerState::alpha) enabled, and moves the square and
+/// the strips once the text is a valid color; invalid text is reported by the input's
+/// validation and leaves the color alone. Dragging rewrites the input once the drag
+/// ends.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     ColorPickerState::new(window, cx)
+///         .alpha(true, cx)
+///         .value(rgb(0x3b82f6), cx)
+///         .on_change(|color, _window, _cx| println!("Accent: {}", format_hex(color, true)))
+/// });
+///
+/// ColorPicker::new(state)
+///     .gap_2()
+///     .w(px(200))
+///     .square(|square| square.h(px(150)).rounded_md())
+///     .square_thumb(|thumb| thumb.size(px(12)).m(px(-6)).rounded_full().border_2())
+///     .hue(|hue| hue.h(px(12)).thumb(|thumb| thumb.size(px(12)).ml(px(-6)).border_2()))
+///     .alpha(|alpha| alpha.h(px(12)).thumb(|thumb| thumb.size(px(12)).ml(px(-6)).border_2()))
+///     .input(|input| input.px_2().border_1())
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct ColorPicker {
+    base: Div,
+    state: Entity<ColorPickerState>,
+    square: Div,
+    square_thumb: Div,
+    hue: Option<Box<dyn FnOnce(Slider) -> Slider>>,
+    alpha: Option<Box<dyn FnOnce(Slider) -> Slider>>,
+    input: Option<Box<dyn FnOnce(TextInput) -> TextInput>>,
+}
+
+impl ColorPicker {
+    /// Creates a color picker for the given state.
+    pub fn new(state: Entity<ColorPickerState>) -> Self {
+        Self {
+            base: v_flex(),
+            state,
+            square: div(),
+            square_thumb: div(),
+            hue: None,
+            alpha: None,
+            input: None,
+        }
+    }
+
+    /// Customizes the saturation and value square, which needs a height.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the square, applying modifications
+    pub fn square(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.square = handler(self.square);
+        self
+    }
+
+    /// Customizes the thumb of the square.
+    ///
+    /// The thumb's top left corner sits at the color, so give it negative margins of half
+    /// its size to center it.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the thumb, applying modifications
+    pub fn square_thumb(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.square_thumb = handler(self.square_thumb);
+        self
+    }
+
+    /// Customizes the slider of the hue strip, drawn over the hues.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the slider, applying modifications
+    pub fn hue(mut self, handler: impl FnOnce(Slider) -> Slider + 'static) -> Self {
+        self.hue = Some(Box::new(handler));
+        self
+    }
+
+    /// Customizes the slider of the opacity strip, drawn over the color fading out.
+    ///
+    /// The strip is only shown with [`alpha`](ColorPickerState::alpha) enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the slider, applying modifications
+    pub fn alpha(mut self, handler: impl FnOnce(Slider) -> Slider + 'static) -> Self {
+        self.alpha = Some(Box::new(handler));
+        self
+    }
+
+    /// Customizes the hex input.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the input, applying modifications
+    pub fn input(mut self, handler: impl FnOnce(TextInput) -> TextInput + 'static) -> Self {
+        self.input = Some(Box::new(handler));
+        self
+    }
+
+    /// A strip laid behind `slider`, filled by `background`
+    fn strip(
+        slider: Slider,
+        handler: Option<Box<dyn FnOnce(Slider) -> Slider>>,
+        background: impl IntoElement,
+    ) -> Div {
+        let slider = match handler {
+            Some(handler) => handler(slider),
+            None => slider,
+        };
+        div()
+            .relative()
+            .child(div().absolute().size_full().child(background))
+            .child(slider)
+    }
+
+    fn hue_segments() -> Div {
+        let hue = |ix: usize| hsla(ix as f32 / HUE_SEGMENTS as f32, 1., 0.5, 1.);
+        div()
+            .size_full()
+            .relative()
+            .children((0..HUE_SEGMENTS).map(|ix| {
+                div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left(relative(ix as f32 / HUE_SEGMENTS as f32))
+                    .w(relative(1. / HUE_SEGMENTS as f32))
+                    .bg(linear_gradient(
+                        90.,
+                        linear_color_stop(hue(ix), 0.),
+                        linear_color_stop(hue(ix + 1), 1.),
+                    ))
+            }))
+    }
+}
+
+impl Styled for ColorPicker {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for ColorPicker {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = self.state.read(app);
+        let color = state.model.color();
+        let (x, y) = state.model.square_position();
+        let opaque = Hsla::from(Rgba {
+            a: 1.,
+            ..state.model.rgba()
+        });
+        let hue = Slider::new(state.hue_slider().clone()).size_full();
+        let alpha = state
+            .alpha_slider()
+            .map(|alpha| Slider::new(alpha.clone()).size_full());
+        let mut input = TextInput::new(state.input().clone());
+        if let Some(handler) = self.input.take() {
+            input = handler(input);
+        }
+
+        let tracked = self.state.clone();
+        // Keep the square bounds for mapping mouse positions to saturation and value
+        let bounds = canvas(
+            move |bounds, _, app| tracked.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let square = self
+            .square
+            .relative()
+            .bg(hsla(color.h / 360., 1., 0.5, 1.))
+            .child(bounds)
+            .child(div().absolute().size_full().bg(linear_gradient(
+                90.,
+                linear_color_stop(white(), 0.),
+                linear_color_stop(white().opacity(0.), 1.),
+            )))
+            .child(div().absolute().size_full().bg(linear_gradient(
+                180.,
+                linear_color_stop(black().opacity(0.), 0.),
+                linear_color_stop(black(), 1.),
+            )))
+            .child(
+                div()
+                    .absolute()
+                    .left(relative(x))
+                    .top(relative(y))
+                    .child(self.square_thumb),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                window.listener_for(&self.state, ColorPickerState::on_square_mouse_down),
+            )
+            .on_mouse_up(
+                MouseButton::Left,
+                window.listener_for(&self.state, ColorPickerState::on_mouse_up),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                window.listener_for(&self.state, ColorPickerState::on_mouse_up),
+            )
+            .on_mouse_move(window.listener_for(&self.state, ColorPickerState::on_mouse_move));
+
+        let alpha_handler = self.alpha.take();
+        self.base
+            .child(square)
+            .child(Self::strip(hue, self.hue.take(), Self::hue_segments()))
+            .when_some(alpha, |this, alpha| {
+                this.child(Self::strip(
+                    alpha,
+                    alpha_handler,
+                    div().size_full().bg(linear_gradient(
+                        90.,
+                        linear_color_stop(opaque.opacity(0.), 0.),
+                        linear_color_stop(opaque, 1.),
+                    )),
+                ))
+            })
+            .child(input)
+    }
+}
@@ -0,0 +1,117 @@
+use crate::components::color_picker::color::{Hsva, format_hex, parse_hex};
+use gpui::Rgba;
+
+/// The color of a picker and the hex text that spells it
+///
+/// The square, the hue strip and the opacity strip edit the color, and the text follows
+/// it. Typed text moves them once it holds a valid hex color and is ignored until then.
+/// A typed gray keeps the hue and a typed black keeps the saturation too, since neither
+/// says what they are, so the strip and the square don't snap back to red.
+pub struct ColorModel {
+    color: Hsva,
+    hex: String,
+    alpha: bool,
+}
+
+impl ColorModel {
+    /// Create a model for `color`, without alpha
+    pub fn new(color: Rgba) -> Self {
+        let color = Hsva::from_rgba(Rgba { a: 1., ..color });
+        Self {
+            hex: format_hex(color.to_rgba(), false),
+            color,
+            alpha: false,
+        }
+    }
+
+    pub fn color(&self) -> Hsva {
+        self.color
+    }
+
+    pub fn rgba(&self) -> Rgba {
+        self.color.to_rgba()
+    }
+
+    /// The color as hex text, which the input is rewritten to
+    pub fn hex(&self) -> &str {
+        &self.hex
+    }
+
+    /// Allow colors that aren't opaque, or make the color opaque when disabling it
+    pub fn set_alpha_enabled(&mut self, alpha: bool) {
+        self.alpha = alpha;
+        if !alpha {
+            self.color.a = 1.;
+        }
+        self.update_hex();
+    }
+
+    fn update_hex(&mut self) {
+        self.hex = format_hex(self.color.to_rgba(), self.alpha);
+    }
+
+    /// Replace the color, returning whether it changed
+    fn change_to(&mut self, color: Hsva) -> bool {
+        if color == self.color {
+            return false;
+        }
+        self.color = color;
+        self.update_hex();
+        true
+    }
+
+    /// Set the hue in degrees, returning whether it changed
+    pub fn set_hue(&mut self, h: f32) -> bool {
+        self.change_to(Hsva::new(h, self.color.s, self.color.v, self.color.a))
+    }
+
+    /// Set the alpha, returning whether it changed, or `false` without alpha
+    pub fn set_alpha(&mut self, a: f32) -> bool {
+        self.alpha && self.change_to(Hsva::new(self.color.h, self.color.s, self.color.v, a))
+    }
+
+    /// Where the thumb of the square sits, as fractions of its width and height from the
+    /// top left: saturation grows to the right and value to the top
+    pub fn square_position(&self) -> (f32, f32) {
+        (self.color.s, 1. - self.color.v)
+    }
+
+    /// Pick the saturation and value under a point of the square, returning whether the
+    /// color changed
+    pub fn set_square_position(&mut self, x: f32, y: f32) -> bool {
+        self.change_to(Hsva::new(self.color.h, x, 1. - y, self.color.a))
+    }
+
+    /// Replace the color, keeping the hue and saturation when `color` doesn't determine
+    /// them, and return whether it changed
+    pub fn set_rgba(&mut self, color: Rgba) -> bool {
+        let mut next = Hsva::from_rgba(color);
+        if !self.alpha {
+            next.a = 1.;
+        }
+        if next.v == 0. {
+            next.s = self.color.s;
+        }
+        if next.s == 0. || next.v == 0. {
+            next.h = self.color.h;
+        }
+        self.change_to(next)
+    }
+
+    /// Follow typed text, returning whether the color changed
+    ///
+    /// Invalid text leaves the color alone, and so does text spelling the color already
+    /// picked, so the rounding of the hex digits doesn't move the thumbs.
+    pub fn sync_from_text(&mut self, text: &str) -> bool {
+        let Some(mut color) = parse_hex(text) else {
+            return false;
+        };
+        if !self.alpha {
+            color.a = 1.;
+        }
+        if format_hex(color, self.alpha) == self.hex {
+            return false;
+        }
+        self.set_rgba(color)
+    }
+}
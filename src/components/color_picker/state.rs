@@ -0,0 +1,277 @@
+use crate::components::{
+    color_picker::{color::parse_hex, model::ColorModel},
+    input::InputState,
+    slider::SliderState,
+};
+use gpui::*;
+
+/// The state of a [`ColorPicker`](super::ColorPicker)
+///
+/// Owns the hex input and the sliders of the hue strip and the opacity strip, and keeps
+/// them in sync with the square: typing a valid hex color moves the square and the
+/// strips, and dragging them rewrites the input once the drag ends, as an undo step of
+/// the input's history.
+#[allow(clippy::type_complexity)]
+pub struct ColorPickerState {
+    input: Entity<InputState>,
+    hue: Entity<SliderState>,
+    alpha: Option<Entity<SliderState>>,
+    pub(super) model: ColorModel,
+    dragging: bool,
+    /// The bounds of the square, for mapping mouse positions to saturation and value
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    last_text: SharedString,
+    on_change: Option<Box<dyn Fn(Rgba, &mut Window, &mut Context<Self>)>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ColorPickerState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new ColorPickerState on opaque black, without an opacity strip
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let model = ColorModel::new(rgb(0x000000));
+        let last_text: SharedString = model.hex().to_string().into();
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .value(last_text.clone())
+                .placeholder("#RRGGBB")
+                .allow(|ch| ch == '#' || ch.is_ascii_hexdigit())
+                .max_length(9)
+                .validate(|text| {
+                    parse_hex(text)
+                        .map(|_| ())
+                        .ok_or_else(|| "Enter a hex color like #3B82F6".into())
+                })
+        });
+        let _subscriptions = vec![cx.observe_in(&input, window, |this, _, window, cx| {
+            this.sync_from_input(window, cx)
+        })];
+        let hue = Self::strip(360., 1., Self::set_hue, cx);
+
+        Self {
+            input,
+            hue,
+            alpha: None,
+            model,
+            dragging: false,
+            last_bounds: None,
+            last_text,
+            on_change: None,
+            _subscriptions,
+        }
+    }
+
+    /// A slider from 0 to `max` that sets a component of the color through `set`
+    fn strip(
+        max: f32,
+        step: f32,
+        set: fn(&mut Self, f32, &mut Window, &mut Context<Self>),
+        cx: &mut Context<Self>,
+    ) -> Entity<SliderState> {
+        let on_change = cx.weak_entity();
+        let on_commit = on_change.clone();
+        cx.new(|cx| {
+            SliderState::new(cx)
+                .max(max)
+                .step(step)
+                .on_change(move |value, window, cx| {
+                    on_change
+                        .update(cx, |this, cx| set(this, *value, window, cx))
+                        .ok();
+                })
+                .on_commit(move |_, window, cx| {
+                    on_commit
+                        .update(cx, |this, cx| this.write_text(window, cx))
+                        .ok();
+                })
+        })
+    }
+
+    /// Show an opacity strip and let the hex input take an alpha, like `#3B82F680`
+    pub fn alpha(mut self, alpha: bool, cx: &mut Context<Self>) -> Self {
+        self.model.set_alpha_enabled(alpha);
+        self.alpha = alpha.then(|| Self::strip(1., 0.01, Self::set_alpha, cx));
+        self.set_text(cx);
+        self.sync_strips(cx);
+        self
+    }
+
+    /// Set the initial color, which is not an undo step
+    ///
+    /// Its alpha is dropped unless [`alpha`](Self::alpha) is enabled first.
+    pub fn value(mut self, color: impl Into<Rgba>, cx: &mut App) -> Self {
+        self.model.set_rgba(color.into());
+        self.set_text(cx);
+        self.sync_strips(cx);
+        self
+    }
+
+    /// Call `on_change` with the color whenever it changes, including every step of a
+    /// drag
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(Rgba, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    /// The hex input, to render with a [`TextInput`](crate::components::input::TextInput)
+    pub fn input(&self) -> &Entity<InputState> {
+        &self.input
+    }
+
+    /// The slider of the hue strip, going from 0 to 360 degrees
+    pub fn hue_slider(&self) -> &Entity<SliderState> {
+        &self.hue
+    }
+
+    /// The slider of the opacity strip, if alpha is enabled
+    pub fn alpha_slider(&self) -> Option<&Entity<SliderState>> {
+        self.alpha.as_ref()
+    }
+
+    pub fn color(&self) -> Rgba {
+        self.model.rgba()
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Set the color as an undo step of the input, without calling `on_change`
+    pub fn set_color(
+        &mut self,
+        color: impl Into<Rgba>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.model.set_rgba(color.into()) {
+            self.sync_strips(cx);
+            self.write_text(window, cx);
+            cx.notify();
+        }
+    }
+
+    fn set_hue(&mut self, hue: f32, window: &mut Window, cx: &mut Context<Self>) {
+        if self.model.set_hue(hue) {
+            self.changed(window, cx);
+        }
+    }
+
+    fn set_alpha(&mut self, alpha: f32, window: &mut Window, cx: &mut Context<Self>) {
+        if self.model.set_alpha(alpha) {
+            self.changed(window, cx);
+        }
+    }
+
+    fn changed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(on_change) = &self.on_change {
+            on_change(self.model.rgba(), window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Move the strips to the color, which doesn't call their `on_change`
+    fn sync_strips(&self, cx: &mut App) {
+        let color = self.model.color();
+        self.hue.update(cx, |hue, cx| hue.set_value(color.h, cx));
+        if let Some(alpha) = &self.alpha {
+            alpha.update(cx, |alpha, cx| alpha.set_value(color.a, cx));
+        }
+    }
+
+    /// Replace the text of the input with the color, without an undo step
+    fn set_text(&mut self, cx: &mut App) {
+        self.last_text = self.model.hex().to_string().into();
+        let text = self.last_text.clone();
+        self.input.update(cx, |input, cx| input.set_value(text, cx));
+    }
+
+    /// Rewrite the input with the color as an undo step, unless it already spells it
+    fn write_text(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.model.hex() == self.last_text.as_ref() {
+            return;
+        }
+        self.last_text = self.model.hex().to_string().into();
+        let text = self.last_text.clone();
+        self.input
+            .update(cx, |input, cx| input.replace_value(&text, window, cx));
+    }
+
+    /// Follow the text of the input once it changes, including through undo and redo
+    fn sync_from_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.input.read(cx).formatted_value().clone();
+        if text == self.last_text {
+            return;
+        }
+        self.last_text = text;
+        if self.model.sync_from_text(&self.last_text) {
+            self.sync_strips(cx);
+            self.changed(window, cx);
+        }
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// Pick the saturation and value under `position`, in the square laid out last
+    fn pick_at(&mut self, position: Point<Pixels>, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(bounds) = self.last_bounds else {
+            return;
+        };
+        if bounds.size.width <= px(0.) || bounds.size.height <= px(0.) {
+            return;
+        }
+        let x = (position.x - bounds.left()) / bounds.size.width;
+        let y = (position.y - bounds.top()) / bounds.size.height;
+        if self.model.set_square_position(x, y) {
+            self.changed(window, cx);
+        }
+    }
+
+    /// Start a drag and jump to the color under the mouse
+    pub(super) fn on_square_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.dragging = true;
+        self.pick_at(event.position, window, cx);
+    }
+
+    pub(super) fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.dragging {
+            self.pick_at(event.position, window, cx);
+        }
+    }
+
+    /// End a drag, writing its final color to the input
+    pub(super) fn on_mouse_up(
+        &mut self,
+        _: &MouseUpEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.dragging {
+            return;
+        }
+        self.dragging = false;
+        self.write_text(window, cx);
+        cx.notify();
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod color {
+    use crate::components::color_picker::color::{Hsva, format_hex, parse_hex};
+    use gpui::Rgba;
+
+    fn rgba(r: f32, g: f32, b: f32, a: f32) -> Rgba {
+        Rgba { r, g, b, a }
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn parses_every_hex_form() {
+        assert_eq!(parse_hex("#ff0000"), Some(rgba(1., 0., 0., 1.)));
+        assert_eq!(parse_hex("00FF00"), Some(rgba(0., 1., 0., 1.)));
+        assert_eq!(parse_hex("#00f"), Some(rgba(0., 0., 1., 1.)));
+        assert_eq!(parse_hex("#fff0"), Some(rgba(1., 1., 1., 0.)));
+        assert_eq!(parse_hex("#00000080"), Some(rgba(0., 0., 0., 128. / 255.)));
+    }
+
+    #[test]
+    fn rejects_invalid_hex() {
+        for text in [
+            "", "#", "#12", "#12345", "#1234567", "#gg0000", "#ff 000", "red",
+        ] {
+            assert_eq!(parse_hex(text), None, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn formats_alpha_only_when_asked_and_not_opaque() {
+        let color = rgba(1., 0.5, 0., 0.5);
+        assert_eq!(format_hex(color, false), "#FF8000");
+        assert_eq!(format_hex(color, true), "#FF800080");
+        assert_eq!(format_hex(Rgba { a: 1., ..color }, true), "#FF8000");
+    }
+
+    #[test]
+    fn converts_primaries_to_their_hues() {
+        let red = Hsva::from_rgba(rgba(1., 0., 0., 1.));
+        assert_eq!((red.h, red.s, red.v), (0., 1., 1.));
+        assert_close(Hsva::from_rgba(rgba(0., 1., 0., 1.)).h, 120.);
+        assert_close(Hsva::from_rgba(rgba(0., 0., 1., 1.)).h, 240.);
+        assert_close(Hsva::from_rgba(rgba(1., 0., 1., 1.)).h, 300.);
+    }
+
+    #[test]
+    fn grays_have_no_saturation() {
+        let gray = Hsva::from_rgba(rgba(0.5, 0.5, 0.5, 1.));
+        assert_eq!((gray.h, gray.s, gray.v), (0., 0., 0.5));
+    }
+
+    #[test]
+    fn round_trips_through_rgb() {
+        for hex in [
+            "#3B82F6", "#F59E0B", "#10B981", "#EF4444", "#808080", "#000000",
+        ] {
+            let color = Hsva::from_rgba(parse_hex(hex).unwrap());
+            assert_eq!(format_hex(color.to_rgba(), false), hex);
+        }
+    }
+
+    #[test]
+    fn a_hue_of_360_is_red_again() {
+        let color = Hsva::new(360., 1., 1., 1.).to_rgba();
+        assert_eq!(format_hex(color, false), "#FF0000");
+    }
+
+    #[test]
+    fn clamps_components() {
+        let color = Hsva::new(400., 2., -1., 1.5);
+        assert_eq!((color.h, color.s, color.v, color.a), (360., 1., 0., 1.));
+    }
+}
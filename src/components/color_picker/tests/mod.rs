@@ -0,0 +1,2 @@
+mod color;
+mod model;
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod model {
+    use crate::components::color_picker::model::ColorModel;
+    use gpui::Rgba;
+
+    fn blue() -> ColorModel {
+        ColorModel::new(Rgba {
+            r: 0.,
+            g: 0.,
+            b: 1.,
+            a: 1.,
+        })
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn typing_hex_moves_the_strip_and_the_square() {
+        let mut model = blue();
+        assert!(model.sync_from_text("#008000"));
+        assert_close(model.color().h, 120.);
+        let (x, y) = model.square_position();
+        assert_close(x, 1.);
+        assert_close(y, 1. - 128. / 255.);
+        assert_eq!(model.hex(), "#008000");
+    }
+
+    #[test]
+    fn short_and_lowercase_hex_is_rewritten_in_full() {
+        let mut model = blue();
+        assert!(model.sync_from_text("f00"));
+        assert_eq!(model.hex(), "#FF0000");
+        assert_close(model.color().h, 0.);
+    }
+
+    #[test]
+    fn moving_the_square_rewrites_the_hex() {
+        let mut model = blue();
+        assert!(model.set_square_position(0.5, 0.));
+        assert_eq!(model.hex(), "#8080FF");
+        assert!(model.set_square_position(1., 0.5));
+        assert_eq!(model.hex(), "#000080");
+        // The same point again changes nothing
+        assert!(!model.set_square_position(1., 0.5));
+    }
+
+    #[test]
+    fn moving_the_strip_rewrites_the_hex() {
+        let mut model = blue();
+        assert!(model.set_hue(60.));
+        assert_eq!(model.hex(), "#FFFF00");
+        assert_eq!(model.square_position(), (1., 0.));
+    }
+
+    #[test]
+    fn invalid_hex_leaves_the_color_alone() {
+        let mut model = blue();
+        for text in ["", "#", "#00", "#00ff0", "#zzzzzz", "blue"] {
+            assert!(!model.sync_from_text(text), "{text:?}");
+        }
+        assert_eq!(model.hex(), "#0000FF");
+        assert_close(model.color().h, 240.);
+    }
+
+    #[test]
+    fn partly_typed_hex_applies_once_valid() {
+        let mut model = blue();
+        assert!(!model.sync_from_text("#1"));
+        assert!(!model.sync_from_text("#10"));
+        // Three digits already spell a color
+        assert!(model.sync_from_text("#10b"));
+        assert_eq!(model.hex(), "#1100BB");
+        assert!(model.sync_from_text("#10b981"));
+        assert_eq!(model.hex(), "#10B981");
+    }
+
+    #[test]
+    fn text_spelling_the_picked_color_keeps_the_thumbs() {
+        let mut model = blue();
+        model.set_square_position(0.333, 0.25);
+        let position = model.square_position();
+        let hex = model.hex().to_string();
+        assert!(!model.sync_from_text(&hex));
+        assert!(!model.sync_from_text(&hex.to_lowercase()));
+        assert_eq!(model.square_position(), position);
+    }
+
+    #[test]
+    fn typed_grays_keep_the_hue() {
+        let mut model = blue();
+        assert!(model.sync_from_text("#808080"));
+        assert_close(model.color().h, 240.);
+        assert_close(model.square_position().0, 0.);
+    }
+
+    #[test]
+    fn typed_black_keeps_the_hue_and_saturation() {
+        let mut model = blue();
+        model.set_square_position(0.4, 0.);
+        assert!(model.sync_from_text("#000000"));
+        assert_close(model.color().h, 240.);
+        let (x, y) = model.square_position();
+        assert_close(x, 0.4);
+        assert_close(y, 1.);
+    }
+
+    #[test]
+    fn alpha_is_ignored_until_enabled() {
+        let mut model = blue();
+        assert!(!model.set_alpha(0.5));
+        assert!(!model.sync_from_text("#0000FF80"));
+        assert_eq!(model.rgba().a, 1.);
+
+        model.set_alpha_enabled(true);
+        assert!(model.sync_from_text("#0000FF80"));
+        assert_close(model.rgba().a, 128. / 255.);
+        assert_eq!(model.hex(), "#0000FF80");
+        assert!(model.set_alpha(1.));
+        assert_eq!(model.hex(), "#0000FF");
+    }
+
+    #[test]
+    fn disabling_alpha_makes_the_color_opaque() {
+        let mut model = blue();
+        model.set_alpha_enabled(true);
+        model.set_alpha(0.25);
+        model.set_alpha_enabled(false);
+        assert_eq!(model.rgba().a, 1.);
+        assert_eq!(model.hex(), "#0000FF");
+    }
+}
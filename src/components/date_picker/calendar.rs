@@ -0,0 +1,134 @@
+use crate::components::date_picker::date::CalendarDate;
+
+/// The names of the months, from January
+pub const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The short names of the days of the week, from Monday
+pub const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// How many days the calendar grid shows, six full weeks
+pub const GRID_DAYS: usize = 42;
+
+/// The calendar of a date picker: the selected date, the date focused in the grid, and
+/// the range of dates that can be picked
+///
+/// The grid shows the month of the focused date, and the focus never leaves the range.
+/// A date typed outside of the range isn't selected.
+pub struct Calendar {
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    selected: Option<CalendarDate>,
+    focused: CalendarDate,
+}
+
+impl Calendar {
+    /// Create a calendar focused on `today`, with nothing selected
+    pub fn new(today: CalendarDate) -> Self {
+        Self {
+            min: None,
+            max: None,
+            selected: None,
+            focused: today,
+        }
+    }
+
+    /// Limit the dates that can be picked, dropping a selection that falls outside
+    pub fn set_range(&mut self, min: Option<CalendarDate>, max: Option<CalendarDate>) {
+        self.min = min;
+        self.max = match (min, max) {
+            (Some(min), Some(max)) => Some(max.max(min)),
+            _ => max,
+        };
+        self.selected = self.selected.filter(|&date| self.is_selectable(date));
+        self.focused = self.clamp(self.focused);
+    }
+
+    pub fn min(&self) -> Option<CalendarDate> {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<CalendarDate> {
+        self.max
+    }
+
+    pub fn is_selectable(&self, date: CalendarDate) -> bool {
+        self.min.is_none_or(|min| date >= min) && self.max.is_none_or(|max| date <= max)
+    }
+
+    /// The closest date to `date` that can be picked
+    pub fn clamp(&self, date: CalendarDate) -> CalendarDate {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    pub fn selected(&self) -> Option<CalendarDate> {
+        self.selected
+    }
+
+    pub fn focused(&self) -> CalendarDate {
+        self.focused
+    }
+
+    /// Follow the text of the input, selecting and showing the date it spells out
+    ///
+    /// Text that isn't a complete date, or a date out of range, clears the selection.
+    /// Returns whether the selection changed.
+    pub fn sync_from_text(&mut self, text: &str) -> bool {
+        let date = CalendarDate::parse(text);
+        if let Some(date) = date {
+            self.focused = self.clamp(date);
+        }
+        let selected = date.filter(|&date| self.is_selectable(date));
+        let changed = selected != self.selected;
+        self.selected = selected;
+        changed
+    }
+
+    /// Pick `date` in the grid, returning the text for the input, or `None` when it is
+    /// out of range
+    pub fn select(&mut self, date: CalendarDate) -> Option<String> {
+        if !self.is_selectable(date) {
+            return None;
+        }
+        self.selected = Some(date);
+        self.focused = date;
+        Some(date.to_string())
+    }
+
+    /// Move the focus by `days`, stopping at the ends of the range
+    pub fn move_focus(&mut self, days: i64) {
+        self.focused = self.clamp(self.focused.add_days(days));
+    }
+
+    /// Move the focus by `months`, stopping at the ends of the range
+    pub fn move_focus_months(&mut self, months: i32) {
+        self.focused = self.clamp(self.focused.add_months(months));
+    }
+
+    /// Move the focus to the start of its week, or the end when not `start`
+    pub fn move_focus_to_week_edge(&mut self, start: bool) {
+        let weekday = self.focused.weekday() as i64;
+        self.move_focus(if start { -weekday } else { 6 - weekday });
+    }
+
+    /// The days shown in the grid: six weeks from the Monday on or before the first of
+    /// the focused month
+    pub fn grid(&self) -> Vec<CalendarDate> {
+        let first = self.focused.first_of_month();
+        let start = first.add_days(-(first.weekday() as i64));
+        (0..GRID_DAYS as i64).map(|ix| start.add_days(ix)).collect()
+    }
+}
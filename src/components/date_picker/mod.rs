@@ -0,0 +1,262 @@
+use crate::{
+    components::input::TextInput,
+    primitives::{h_flex, v_flex},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+mod calendar;
+mod date;
+mod state;
+#[cfg(test)]
+mod tests;
+
+use calendar::{GRID_DAYS, MONTH_NAMES, WEEKDAY_NAMES};
+pub use date::CalendarDate;
+pub use state::*;
+
+/// What a day of the calendar grid stands for, handed to [`DatePicker::day`] to style it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalendarDay {
+    pub date: CalendarDate,
+    /// Whether the day is in the month shown, rather than a neighboring one
+    pub in_month: bool,
+    pub selected: bool,
+    /// Whether the keyboard focus of the grid is on the day
+    pub focused: bool,
+    /// Whether the day is out of the range that can be picked
+    pub disabled: bool,
+    pub today: bool,
+}
+
+/// A date input with a calendar that opens below it.
+///
+/// The input takes dates typed like `2026-10-14` and the trigger after it opens the
+/// calendar, which shows the month of the typed date with it selected. Clicking a day, or
+/// pressing Enter on it, writes it to the input as an undo step of the input's history
+/// and closes the calendar. In the open calendar the arrow keys move between days, Home
+/// and End to the edges of the week, PageUp and PageDown between months, and Escape
+/// closes it. Days outside the [`min`](DatePickerState::min) and
+/// [`max`](DatePickerState::max) of the state can't be picked.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     DatePickerState::new(window, cx)
+///         .min(CalendarDate::new(2026, 1, 1).unwrap())
+///         .on_change(|date, _window, _cx| println!("Due: {:?}", date))
+/// });
+///
+/// DatePicker::new(state)
+///     .input(|input| input.w(px(160)).px_2().border_1())
+///     .calendar(|calendar| calendar.p_2().bg(rgb(0xffffff)).border_1().rounded_md())
+///     .day(|cell, day| cell.when(day.selected, |cell| cell.bg(rgb(0x3b82f6))))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct DatePicker {
+    base: Div,
+    state: Entity<DatePickerState>,
+    input: Option<Box<dyn FnOnce(TextInput) -> TextInput>>,
+    trigger: Option<AnyElement>,
+    calendar: Div,
+    day: Option<Rc<dyn Fn(Stateful<Div>, &CalendarDay) -> Stateful<Div>>>,
+}
+
+impl DatePicker {
+    /// Creates a date picker for the given state.
+    pub fn new(state: Entity<DatePickerState>) -> Self {
+        Self {
+            base: div(),
+            state,
+            input: None,
+            trigger: None,
+            calendar: v_flex(),
+            day: None,
+        }
+    }
+
+    /// Customizes the date input.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the input, applying modifications
+    pub fn input(mut self, handler: impl FnOnce(TextInput) -> TextInput + 'static) -> Self {
+        self.input = Some(Box::new(handler));
+        self
+    }
+
+    /// Replaces the "▾" after the input that opens and closes the calendar.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    /// Customizes the calendar below the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the calendar, applying modifications
+    pub fn calendar(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.calendar = handler(self.calendar);
+        self
+    }
+
+    /// Styles each day of the calendar grid.
+    ///
+    /// Without it, the selected day is highlighted and days out of the month or the range
+    /// are faded.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes a day's cell and what the day stands for, and
+    ///   returns the styled cell
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let picker = DatePicker::new(state).day(|cell, day| {
+    ///     cell.size(px(28))
+    ///         .when(day.today, |cell| cell.border_1())
+    ///         .when(day.disabled, |cell| cell.opacity(0.3))
+    /// });
+    /// ```
+    pub fn day(
+        mut self,
+        handler: impl Fn(Stateful<Div>, &CalendarDay) -> Stateful<Div> + 'static,
+    ) -> Self {
+        self.day = Some(Rc::new(handler));
+        self
+    }
+
+    fn default_day(cell: Stateful<Div>, day: &CalendarDay) -> Stateful<Div> {
+        cell.when(day.selected, |cell| {
+            cell.bg(rgb(0x3b82f6)).text_color(rgb(0xffffff))
+        })
+        .when(day.focused && !day.selected, |cell| cell.bg(rgb(0xe5e7eb)))
+        .when(!day.in_month, |cell| cell.opacity(0.5))
+        .when(day.disabled, |cell| cell.opacity(0.3))
+    }
+
+    fn render_calendar(&self, state: &DatePickerState, window: &mut Window) -> Div {
+        let calendar = &state.calendar;
+        let focused = calendar.focused();
+        let today = CalendarDate::today();
+        let day = self.day.clone();
+        let mut days = calendar.grid().into_iter().enumerate().map(|(ix, date)| {
+            let info = CalendarDay {
+                date,
+                in_month: date.month() == focused.month(),
+                selected: calendar.selected() == Some(date),
+                focused: date == focused,
+                disabled: !calendar.is_selectable(date),
+                today: date == today,
+            };
+            let cell = div()
+                .id(("date-picker-day", ix))
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(date.day().to_string())
+                .when(!info.disabled, |this| {
+                    this.cursor_pointer().on_click(
+                        window.listener_for(&self.state, move |state, _, window, cx| {
+                            state.select(date, window, cx)
+                        }),
+                    )
+                });
+            match &day {
+                Some(day) => day(cell, &info),
+                None => Self::default_day(cell, &info),
+            }
+        });
+        let weeks = (0..GRID_DAYS / 7)
+            .map(|_| h_flex().children(days.by_ref().take(7)))
+            .collect::<Vec<_>>();
+
+        let month_button = |id: &'static str, label: &'static str, months: i32| {
+            div().id(id).cursor_pointer().child(label).on_click(
+                window.listener_for(&self.state, move |state, _, _, cx| {
+                    state.show_month(months, cx)
+                }),
+            )
+        };
+
+        v_flex()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .child(month_button("date-picker-previous", "‹", -1))
+                    .child(format!(
+                        "{} {}",
+                        MONTH_NAMES[focused.month() as usize - 1],
+                        focused.year()
+                    ))
+                    .child(month_button("date-picker-next", "›", 1)),
+            )
+            .child(h_flex().children(
+                WEEKDAY_NAMES.map(|name| div().flex_1().flex().justify_center().child(name)),
+            ))
+            .children(weeks)
+    }
+}
+
+impl Styled for DatePicker {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for DatePicker {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = self.state.read(app);
+        let calendar = state.is_open().then(|| self.render_calendar(state, window));
+        let calendar_focus_handle = state.calendar_focus_handle().clone();
+
+        let trigger = div()
+            .id("date-picker-trigger")
+            .cursor_pointer()
+            .child(
+                self.trigger
+                    .take()
+                    .unwrap_or_else(|| "▾".into_any_element()),
+            )
+            .on_click(window.listener_for(&self.state, DatePickerState::toggle_open));
+        let mut input = TextInput::new(state.input().clone()).suffix(trigger);
+        if let Some(handler) = self.input.take() {
+            input = handler(input);
+        }
+
+        let tracked = self.state.clone();
+        // Keep the input bounds, so pressing the trigger doesn't count as a press outside
+        let bounds = canvas(
+            move |bounds, _, app| tracked.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        self.base
+            .relative()
+            .child(bounds)
+            .child(input)
+            .when_some(calendar, |this, calendar| {
+                this.child(deferred(
+                    self.calendar
+                        .absolute()
+                        .top_full()
+                        .left_0()
+                        .track_focus(&calendar_focus_handle)
+                        .on_key_down(
+                            window.listener_for(&self.state, DatePickerState::on_calendar_key_down),
+                        )
+                        .on_mouse_down_out(
+                            window.listener_for(&self.state, DatePickerState::on_mouse_down_out),
+                        )
+                        .child(calendar),
+                ))
+            })
+    }
+}
@@ -0,0 +1,218 @@
+use crate::components::{
+    date_picker::{calendar::Calendar, date::CalendarDate},
+    input::InputState,
+};
+use gpui::*;
+
+/// The pattern of the date input, which [`CalendarDate::parse`] reads
+const DATE_PATTERN: &str = "####-##-##";
+
+/// The state of a [`DatePicker`](super::DatePicker)
+///
+/// Owns the date input and the calendar shown below it, and keeps them in sync: typing a
+/// date selects it in the calendar, and picking a day replaces the text of the input as
+/// an undo step of the input's history, so undo steps through both.
+#[allow(clippy::type_complexity)]
+pub struct DatePickerState {
+    input: Entity<InputState>,
+    pub(super) calendar: Calendar,
+    focus_handle: FocusHandle,
+    open: bool,
+    /// The bounds of the input and trigger, where a press doesn't close the calendar
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    last_text: SharedString,
+    on_change: Option<Box<dyn Fn(Option<CalendarDate>, &mut Window, &mut Context<Self>)>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl DatePickerState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new DatePickerState with an empty input and the calendar on today
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .input_mask(DATE_PATTERN)
+                .placeholder("YYYY-MM-DD")
+        });
+        let _subscriptions = vec![cx.observe_in(&input, window, |this, _, window, cx| {
+            this.sync_from_input(window, cx)
+        })];
+
+        Self {
+            input,
+            calendar: Calendar::new(CalendarDate::today()),
+            focus_handle: cx.focus_handle(),
+            open: false,
+            last_bounds: None,
+            last_text: SharedString::default(),
+            on_change: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set the earliest date that can be picked
+    pub fn min(mut self, min: CalendarDate) -> Self {
+        self.calendar.set_range(Some(min), self.calendar.max());
+        self
+    }
+
+    /// Set the latest date that can be picked
+    pub fn max(mut self, max: CalendarDate) -> Self {
+        self.calendar.set_range(self.calendar.min(), Some(max));
+        self
+    }
+
+    /// Set the initial date, which is not an undo step
+    pub fn value(mut self, date: CalendarDate, cx: &mut App) -> Self {
+        let text: SharedString = date.to_string().into();
+        self.input
+            .update(cx, |input, cx| input.set_value(text.clone(), cx));
+        self.calendar.sync_from_text(&text);
+        self.last_text = text;
+        self
+    }
+
+    /// Call `on_change` with the selected date whenever it changes, or `None` once the
+    /// input no longer holds a date that can be picked
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(Option<CalendarDate>, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    /// The date input, to render with a [`TextInput`](crate::components::input::TextInput)
+    pub fn input(&self) -> &Entity<InputState> {
+        &self.input
+    }
+
+    /// The selected date, if the input holds one that can be picked
+    pub fn date(&self) -> Option<CalendarDate> {
+        self.calendar.selected()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show or hide the calendar, focusing it when shown
+    pub fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if self.open == open {
+            return;
+        }
+        self.open = open;
+        if open {
+            self.focus_handle.focus(window);
+        } else if self.focus_handle.is_focused(window) {
+            self.input.focus_handle(cx).focus(window);
+        }
+        cx.notify();
+    }
+
+    pub(super) fn toggle_open(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.set_open(!self.open, window, cx);
+    }
+
+    /// Close the calendar on a press outside of it and the input
+    pub(super) fn on_mouse_down_out(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self
+            .last_bounds
+            .is_none_or(|bounds| !bounds.contains(&event.position))
+        {
+            self.set_open(false, window, cx);
+        }
+    }
+
+    /// Show the month `months` from the one shown
+    pub(super) fn show_month(&mut self, months: i32, cx: &mut Context<Self>) {
+        self.calendar.move_focus_months(months);
+        cx.notify();
+    }
+
+    pub(super) fn calendar_focus_handle(&self) -> &FocusHandle {
+        &self.focus_handle
+    }
+
+    /// Pick `date`, writing it to the input and closing the calendar
+    pub fn select(&mut self, date: CalendarDate, window: &mut Window, cx: &mut Context<Self>) {
+        let previous = self.calendar.selected();
+        let Some(text) = self.calendar.select(date) else {
+            return;
+        };
+        self.input
+            .update(cx, |input, cx| input.replace_value(&text, window, cx));
+        if previous != Some(date)
+            && let Some(on_change) = &self.on_change
+        {
+            on_change(Some(date), window, cx);
+        }
+        self.set_open(false, window, cx);
+    }
+
+    /// Follow the text of the input once it changes, including through undo and redo
+    fn sync_from_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.input.read(cx).formatted_value().clone();
+        if text == self.last_text {
+            return;
+        }
+        self.last_text = text;
+        let previous = self.calendar.selected();
+        self.calendar.sync_from_text(&self.last_text);
+        if self.calendar.selected() != previous
+            && let Some(on_change) = &self.on_change
+        {
+            on_change(self.calendar.selected(), window, cx);
+        }
+        cx.notify();
+    }
+
+    // ============================================================================
+    // Keyboard Handling
+    // ============================================================================
+
+    /// Move the calendar focus with the arrow keys, Home and End by days and PageUp and
+    /// PageDown by months, pick it with Enter or Space, and close with Escape
+    pub(super) fn on_calendar_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match event.keystroke.key.as_str() {
+            "left" => self.calendar.move_focus(-1),
+            "right" => self.calendar.move_focus(1),
+            "up" => self.calendar.move_focus(-7),
+            "down" => self.calendar.move_focus(7),
+            "home" => self.calendar.move_focus_to_week_edge(true),
+            "end" => self.calendar.move_focus_to_week_edge(false),
+            "pageup" => self.calendar.move_focus_months(-1),
+            "pagedown" => self.calendar.move_focus_months(1),
+            "enter" | "space" => {
+                let focused = self.calendar.focused();
+                self.select(focused, window, cx);
+            }
+            "escape" => self.set_open(false, window, cx),
+            _ => return,
+        }
+        cx.stop_propagation();
+        cx.notify();
+    }
+}
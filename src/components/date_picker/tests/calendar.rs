@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod calendar {
+    use crate::components::date_picker::{
+        calendar::{Calendar, GRID_DAYS},
+        date::CalendarDate,
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    fn october() -> Calendar {
+        let mut calendar = Calendar::new(date(2026, 10, 14));
+        calendar.set_range(Some(date(2026, 10, 5)), Some(date(2026, 11, 20)));
+        calendar
+    }
+
+    #[test]
+    fn typing_a_date_selects_and_shows_it() {
+        let mut calendar = october();
+        assert!(calendar.sync_from_text("2026-11-03"));
+        assert_eq!(calendar.selected(), Some(date(2026, 11, 3)));
+        assert_eq!(calendar.focused(), date(2026, 11, 3));
+        assert_eq!(calendar.grid()[0], date(2026, 10, 26));
+        // The same text again changes nothing
+        assert!(!calendar.sync_from_text("2026-11-03"));
+    }
+
+    #[test]
+    fn partly_typed_dates_clear_the_selection() {
+        let mut calendar = october();
+        calendar.sync_from_text("2026-10-20");
+        assert!(calendar.sync_from_text("2026-10-2"));
+        assert_eq!(calendar.selected(), None);
+        // The grid stays where it was
+        assert_eq!(calendar.focused(), date(2026, 10, 20));
+    }
+
+    #[test]
+    fn picking_a_day_gives_the_text_for_the_input() {
+        let mut calendar = october();
+        assert_eq!(
+            calendar.select(date(2026, 10, 21)),
+            Some("2026-10-21".to_string())
+        );
+        assert_eq!(calendar.selected(), Some(date(2026, 10, 21)));
+        // Syncing the text back from the input keeps the selection
+        assert!(!calendar.sync_from_text("2026-10-21"));
+        assert_eq!(calendar.selected(), Some(date(2026, 10, 21)));
+    }
+
+    #[test]
+    fn dates_out_of_range_cannot_be_picked() {
+        let mut calendar = october();
+        assert_eq!(calendar.select(date(2026, 10, 4)), None);
+        assert_eq!(calendar.select(date(2026, 11, 21)), None);
+        assert_eq!(calendar.selected(), None);
+        assert!(calendar.select(date(2026, 10, 5)).is_some());
+        assert!(calendar.select(date(2026, 11, 20)).is_some());
+    }
+
+    #[test]
+    fn typed_dates_out_of_range_show_the_closest_day_unselected() {
+        let mut calendar = october();
+        calendar.sync_from_text("2027-01-01");
+        assert_eq!(calendar.selected(), None);
+        assert_eq!(calendar.focused(), date(2026, 11, 20));
+        calendar.sync_from_text("2020-01-01");
+        assert_eq!(calendar.focused(), date(2026, 10, 5));
+    }
+
+    #[test]
+    fn narrowing_the_range_drops_a_selection_outside_it() {
+        let mut calendar = october();
+        calendar.select(date(2026, 11, 15));
+        calendar.set_range(None, Some(date(2026, 10, 31)));
+        assert_eq!(calendar.selected(), None);
+        assert_eq!(calendar.focused(), date(2026, 10, 31));
+    }
+
+    #[test]
+    fn a_max_before_the_min_is_raised_to_it() {
+        let mut calendar = Calendar::new(date(2026, 10, 14));
+        calendar.set_range(Some(date(2026, 10, 10)), Some(date(2026, 10, 1)));
+        assert!(calendar.is_selectable(date(2026, 10, 10)));
+        assert!(!calendar.is_selectable(date(2026, 10, 11)));
+
+        calendar.set_range(Some(date(2026, 10, 10)), None);
+        assert!(calendar.is_selectable(date(2030, 1, 1)));
+    }
+
+    #[test]
+    fn keyboard_focus_moves_by_days_and_months_within_range() {
+        let mut calendar = october();
+        calendar.move_focus(7);
+        assert_eq!(calendar.focused(), date(2026, 10, 21));
+        calendar.move_focus_months(1);
+        assert_eq!(calendar.focused(), date(2026, 11, 20));
+        calendar.move_focus(1);
+        assert_eq!(calendar.focused(), date(2026, 11, 20));
+        calendar.move_focus_months(-3);
+        assert_eq!(calendar.focused(), date(2026, 10, 5));
+        calendar.move_focus(-1);
+        assert_eq!(calendar.focused(), date(2026, 10, 5));
+    }
+
+    #[test]
+    fn home_and_end_go_to_the_edges_of_the_week() {
+        let mut calendar = Calendar::new(date(2026, 10, 14));
+        calendar.move_focus_to_week_edge(true);
+        assert_eq!(calendar.focused(), date(2026, 10, 12));
+        calendar.move_focus_to_week_edge(false);
+        assert_eq!(calendar.focused(), date(2026, 10, 18));
+    }
+
+    #[test]
+    fn the_grid_shows_six_weeks_from_monday() {
+        let calendar = Calendar::new(date(2026, 10, 14));
+        let grid = calendar.grid();
+        assert_eq!(grid.len(), GRID_DAYS);
+        assert_eq!(grid[0], date(2026, 9, 28));
+        assert_eq!(grid[3], date(2026, 10, 1));
+        assert!(grid.iter().all(|day| day.weekday() < 7));
+        assert_eq!(grid[GRID_DAYS - 1], date(2026, 11, 8));
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod date {
+    use crate::components::date_picker::date::CalendarDate;
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn only_real_days_are_dates() {
+        assert!(CalendarDate::new(2024, 2, 29).is_some());
+        assert!(CalendarDate::new(2023, 2, 29).is_none());
+        assert!(CalendarDate::new(1900, 2, 29).is_none());
+        assert!(CalendarDate::new(2000, 2, 29).is_some());
+        assert!(CalendarDate::new(2026, 4, 31).is_none());
+        assert!(CalendarDate::new(2026, 13, 1).is_none());
+        assert!(CalendarDate::new(2026, 1, 0).is_none());
+    }
+
+    #[test]
+    fn parses_and_formats_iso_dates() {
+        assert_eq!(CalendarDate::parse("2026-10-14"), Some(date(2026, 10, 14)));
+        assert_eq!(date(2026, 3, 5).to_string(), "2026-03-05");
+        // Incomplete or impossible text isn't a date
+        assert_eq!(CalendarDate::parse("2026-10-1"), None);
+        assert_eq!(CalendarDate::parse("2026-02-30"), None);
+        assert_eq!(CalendarDate::parse("2026/10/14"), None);
+        assert_eq!(CalendarDate::parse(""), None);
+    }
+
+    #[test]
+    fn knows_the_day_of_the_week() {
+        assert_eq!(date(1970, 1, 1).weekday(), 3);
+        assert_eq!(date(2026, 10, 12).weekday(), 0);
+        assert_eq!(date(2000, 2, 29).weekday(), 1);
+        assert_eq!(date(1969, 12, 28).weekday(), 6);
+    }
+
+    #[test]
+    fn adding_days_crosses_months_and_years() {
+        assert_eq!(date(2026, 12, 31).add_days(1), date(2027, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2026, 10, 14).add_days(-7), date(2026, 10, 7));
+        assert_eq!(date(1970, 1, 1).add_days(-1), date(1969, 12, 31));
+    }
+
+    #[test]
+    fn adding_months_keeps_the_day_when_it_exists() {
+        assert_eq!(date(2026, 10, 14).add_months(1), date(2026, 11, 14));
+        assert_eq!(date(2026, 1, 31).add_months(1), date(2026, 2, 28));
+        assert_eq!(date(2026, 1, 15).add_months(-1), date(2025, 12, 15));
+        assert_eq!(date(2024, 2, 29).add_months(12), date(2025, 2, 28));
+    }
+}
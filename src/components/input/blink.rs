@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+/// The caret blink state machine, without the timers that drive it
+///
+/// Every scheduled tick carries the epoch it was scheduled for, and a tick whose epoch
+/// has moved on is ignored. Pausing keeps the caret solid until the matching resume.
+pub struct Blink {
+    interval: Option<Duration>,
+    active: bool,
+    visible: bool,
+    paused: bool,
+    epoch: usize,
+    pause_epoch: usize,
+}
+
+impl Blink {
+    /// Create a blink that toggles every `interval`, or never when `None`
+    pub fn new(interval: Option<Duration>) -> Self {
+        Self {
+            interval,
+            active: false,
+            visible: true,
+            paused: false,
+            epoch: 0,
+            pause_epoch: 0,
+        }
+    }
+
+    pub fn interval(&self) -> Option<Duration> {
+        self.interval
+    }
+
+    pub fn visible(&self) -> bool {
+        // Keep showing the caret while paused or when it doesn't blink
+        self.interval.is_none() || self.paused || self.visible
+    }
+
+    fn next_epoch(&mut self) -> usize {
+        self.epoch += 1;
+        self.epoch
+    }
+
+    /// Change the interval, returning the epoch of the next tick to schedule if the
+    /// blink is running
+    pub fn set_interval(&mut self, interval: Option<Duration>) -> Option<usize> {
+        self.interval = interval;
+        let epoch = self.next_epoch();
+        if !self.active || self.paused || interval.is_none() {
+            return None;
+        }
+        self.visible = true;
+        Some(epoch)
+    }
+
+    /// Show the caret and start blinking, returning the epoch of the first tick
+    pub fn start(&mut self) -> Option<usize> {
+        self.active = true;
+        self.visible = true;
+        let epoch = self.next_epoch();
+        (!self.paused && self.interval.is_some()).then_some(epoch)
+    }
+
+    /// Hide the caret and drop any scheduled tick
+    pub fn stop(&mut self) {
+        self.active = false;
+        self.visible = false;
+        self.paused = false;
+        self.next_epoch();
+    }
+
+    /// Toggle the caret for a tick scheduled at `epoch`, returning the epoch of the next
+    /// tick, or `None` if this one is stale
+    pub fn tick(&mut self, epoch: usize) -> Option<usize> {
+        if !self.active || self.paused || epoch != self.epoch || self.interval.is_none() {
+            return None;
+        }
+        self.visible = !self.visible;
+        Some(self.next_epoch())
+    }
+
+    /// Keep the caret solid, returning the pause epoch to resume with
+    pub fn pause(&mut self) -> usize {
+        self.paused = true;
+        self.pause_epoch += 1;
+        self.pause_epoch
+    }
+
+    /// Resume blinking after the pause at `pause_epoch`, unless another pause followed it,
+    /// returning the epoch of the next tick
+    pub fn resume(&mut self, pause_epoch: usize) -> Option<usize> {
+        if pause_epoch != self.pause_epoch || !self.paused {
+            return None;
+        }
+        self.paused = false;
+        self.visible = true;
+        let epoch = self.next_epoch();
+        (self.active && self.interval.is_some()).then_some(epoch)
+    }
+}
@@ -2,6 +2,7 @@
  * https://github.com/longbridge/gpui-component/blob/main/crates/ui/src/input/blink_cursor.rs
  */
 
+use crate::components::input::blink::Blink;
 use gpui::*;
 use std::time::Duration;
 
@@ -9,53 +10,51 @@ static INTERVAL: Duration = Duration::from_millis(500);
 static PAUSE_DELAY: Duration = Duration::from_millis(500);
 
 pub struct Cursor {
-    visible: bool,
-    paused: bool,
-    epoch: usize,
-    pause_epoch: usize,
+    blink: Blink,
 }
 
 impl Cursor {
     pub fn new() -> Self {
         Self {
-            visible: true,
-            paused: false,
-            epoch: 0,
-            pause_epoch: 0,
+            blink: Blink::new(Some(INTERVAL)),
         }
     }
 
+    /// Set how often the cursor blinks, or keep it solid with `None`
+    pub fn set_interval(&mut self, interval: Option<Duration>, cx: &mut Context<Self>) {
+        let epoch = self.blink.set_interval(interval);
+        self.schedule(epoch, cx);
+        cx.notify();
+    }
+
     /// Start the blinking
     pub fn start(&mut self, cx: &mut Context<Self>) {
-        self.blink(self.epoch, cx);
-        self.visible = true;
+        let epoch = self.blink.start();
+        self.schedule(epoch, cx);
+        cx.notify();
     }
 
     /// Stop the blinking
     pub fn stop(&mut self, cx: &mut Context<Self>) {
-        self.epoch = 0;
-        self.visible = false;
-        self.paused = false;
+        self.blink.stop();
         cx.notify();
     }
 
-    fn next_epoch(&mut self) -> usize {
-        self.epoch += 1;
-        self.epoch
-    }
-
     fn blink(&mut self, epoch: usize, cx: &mut Context<Self>) {
-        if self.paused || epoch != self.epoch {
-            return;
+        let epoch = self.blink.tick(epoch);
+        if epoch.is_some() {
+            cx.notify();
         }
+        self.schedule(epoch, cx);
+    }
 
-        self.visible = !self.visible;
-        cx.notify();
-
-        // Schedule the next blink
-        let epoch = self.next_epoch();
+    /// Schedule the next blink
+    fn schedule(&mut self, epoch: Option<usize>, cx: &mut Context<Self>) {
+        let (Some(epoch), Some(interval)) = (epoch, self.blink.interval()) else {
+            return;
+        };
         cx.spawn(async move |this, cx| {
-            Timer::after(INTERVAL).await;
+            Timer::after(interval).await;
             if let Some(this) = this.upgrade() {
                 this.update(cx, |this, cx| this.blink(epoch, cx)).ok();
             }
@@ -64,28 +63,21 @@ impl Cursor {
     }
 
     pub fn visible(&self) -> bool {
-        // Keep showing the cursor if paused
-        self.paused || self.visible
+        self.blink.visible()
     }
 
     /// Pause the blinking and wait for resuming.
     pub fn pause(&mut self, cx: &mut Context<Self>) {
-        self.paused = true;
+        let pause_epoch = self.blink.pause();
         cx.notify();
 
-        self.pause_epoch += 1;
-        let pause_epoch = self.pause_epoch;
-        let resume_epoch = self.next_epoch();
-
         cx.spawn(async move |this, cx| {
             Timer::after(PAUSE_DELAY).await;
 
             if let Some(this) = this.upgrade() {
                 this.update(cx, |this, cx| {
-                    if this.pause_epoch == pause_epoch {
-                        this.paused = false;
-                        this.blink(resume_epoch, cx);
-                    }
+                    let epoch = this.blink.resume(pause_epoch);
+                    this.schedule(epoch, cx);
                 })
                 .ok();
             }
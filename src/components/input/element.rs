@@ -1,11 +1,11 @@
-use crate::components::input::state::InputState;
+use crate::components::input::{
+    placeholder::display_highlight, state::InputState, text_ops::TextOps,
+};
 use gpui::*;
 use std::ops::Range;
-use unicode_segmentation::UnicodeSegmentation;
 
 pub const CURSOR_WIDTH: f32 = 1.0;
 const MARKED_TEXT_UNDERLINE_THICKNESS: f32 = 1.0;
-const SELECTION_COLOR: u32 = 0x3390FF80;
 
 /// A text input element that renders editable text with cursor and selection support.
 ///
@@ -27,9 +27,63 @@ impl TextElement {
 }
 
 pub struct PrepaintState {
-    line: Option<ShapedLine>,
+    layout: Option<TextLayout>,
     cursor: Option<PaintQuad>,
-    selection: Option<PaintQuad>,
+    selection: Vec<PaintQuad>,
+}
+
+/// The displayed text shaped line by line, split at each line break in multiline inputs
+pub struct TextLayout {
+    /// The lines with the display text range each one shows, newlines excluded
+    lines: Vec<(Range<usize>, ShapedLine)>,
+    line_height: Pixels,
+}
+
+impl TextLayout {
+    /// The width of the widest line
+    pub fn width(&self) -> Pixels {
+        self.lines
+            .iter()
+            .fold(px(0.0), |width, (_, line)| width.max(line.width))
+    }
+
+    /// The height of all the lines
+    pub fn height(&self) -> Pixels {
+        self.line_height * self.lines.len() as f32
+    }
+
+    /// The height of each line
+    pub fn line_height(&self) -> Pixels {
+        self.line_height
+    }
+
+    /// The row of the line holding display offset `offset`
+    fn row_for_index(&self, offset: usize) -> usize {
+        self.lines
+            .partition_point(|(range, _)| range.start <= offset)
+            .saturating_sub(1)
+    }
+
+    /// Where display offset `offset` is, relative to the top left of the text
+    pub fn position_for_index(&self, offset: usize) -> Point<Pixels> {
+        let row = self.row_for_index(offset);
+        let (range, line) = &self.lines[row];
+        point(
+            line.x_for_index(offset.min(range.end) - range.start),
+            self.line_height * row as f32,
+        )
+    }
+
+    /// The display offset of the grapheme boundary closest to `position`, relative to the top
+    /// left of the text
+    pub fn index_for_position(&self, position: Point<Pixels>) -> usize {
+        let row = (f32::from(position.y) / f32::from(self.line_height)).max(0.0) as usize;
+        let (range, line) = &self.lines[row.min(self.lines.len() - 1)];
+        range.start
+            + TextOps::offset_for_x(&line.text, f32::from(position.x), |index| {
+                f32::from(line.x_for_index(index))
+            })
+    }
 }
 
 impl IntoElement for TextElement {
@@ -41,140 +95,218 @@ impl IntoElement for TextElement {
 }
 
 impl TextElement {
-    /// Creates the layout style for the text input
-    fn create_layout_style(&self, window: &Window) -> Style {
+    /// Creates the layout style for the text input, as tall as the lines it shows
+    fn create_layout_style(&self, window: &Window, app: &App) -> Style {
+        let visible_lines = self.input.read(app).visible_lines();
         let mut style = Style::default();
         style.size.width = relative(1.).into();
-        style.size.height = (window.line_height()).into();
+        style.size.height = (window.line_height() * visible_lines as f32).into();
         style
     }
 
-    /// Paints the text line at the specified origin
+    /// Paints the text lines, the first one at the specified origin
     fn paint_text(
         &self,
-        line: ShapedLine,
+        layout: &TextLayout,
         text_origin: Point<Pixels>,
         window: &mut Window,
         app: &mut App,
     ) {
-        line.paint(text_origin, window.line_height(), window, app)
-            .unwrap();
+        for (row, (_, line)) in layout.lines.iter().enumerate() {
+            let origin = text_origin + point(px(0.0), layout.line_height * row as f32);
+            line.paint(origin, layout.line_height, window, app).unwrap();
+        }
     }
 
-    /// Prepares the display text and color based on content and placeholder
-    fn prepare_display_text(&self, input: &InputState, text_color: Hsla) -> (SharedString, Hsla) {
-        if input.value.is_empty() {
-            return (input.placeholder.clone(), input.placeholder_color);
+    /// Shapes the display text, one line per logical line when `multiline`
+    fn shape_text(
+        &self,
+        display_text: SharedString,
+        runs: Vec<TextRun>,
+        multiline: bool,
+        window: &mut Window,
+    ) -> TextLayout {
+        let style = window.text_style();
+        let font_size = style.font_size.to_pixels(window.rem_size());
+        let line_height = window.line_height();
+        if !multiline {
+            let end = display_text.len();
+            let line = window
+                .text_system()
+                .shape_line(display_text, font_size, &runs);
+            return TextLayout {
+                lines: vec![(0..end, line)],
+                line_height,
+            };
         }
 
-        if !input.masked {
-            return (input.value.clone(), text_color);
-        }
+        let mut start = 0;
+        let lines = display_text
+            .split('\n')
+            .map(|text| {
+                let range = start..start + text.len();
+                start = range.end + 1;
+                let runs = Self::runs_for_range(&runs, &range);
+                let line =
+                    window
+                        .text_system()
+                        .shape_line(text.to_string().into(), font_size, &runs);
+                (range, line)
+            })
+            .collect();
+        TextLayout { lines, line_height }
+    }
+
+    /// The part of `runs` covering `range` of the text they style
+    fn runs_for_range(runs: &[TextRun], range: &Range<usize>) -> Vec<TextRun> {
+        let mut start = 0;
+        runs.iter()
+            .filter_map(|run| {
+                let run_range = start..start + run.len;
+                start = run_range.end;
+                let len = run_range
+                    .end
+                    .min(range.end)
+                    .saturating_sub(run_range.start.max(range.start));
+                (len > 0).then(|| TextRun { len, ..run.clone() })
+            })
+            .collect()
+    }
+
+    /// Prepares the display text and its style based on content and placeholder
+    ///
+    /// The placeholder is only ever displayed, so it can't be selected or edited and never
+    /// reaches the history.
+    fn prepare_display_text(
+        &self,
+        input: &InputState,
+        text_color: Hsla,
+        focused: bool,
+    ) -> (SharedString, HighlightStyle) {
+        let placeholder = input.value.is_empty();
+        let highlight = display_highlight(
+            placeholder,
+            text_color,
+            input.placeholder_color,
+            input.placeholder_style,
+        );
 
-        if input.mask.is_empty() {
-            return (SharedString::from(""), text_color);
+        if placeholder {
+            if focused && input.hide_placeholder_on_focus {
+                return (SharedString::new(""), highlight);
+            }
+            return (input.placeholder.clone(), highlight);
         }
 
-        let committed_grapheme_count = if let Some(marked_range) = &input.marked_range {
-            let before_count = input.value[..marked_range.start].graphemes(true).count();
-            let after_count = input.value[marked_range.end..].graphemes(true).count();
-            before_count + after_count
-        } else {
-            input.value.graphemes(true).count()
-        };
+        if !input.masked {
+            return (input.value.clone(), highlight);
+        }
 
-        (
-            input.mask.repeat(committed_grapheme_count).into(),
-            text_color,
-        )
+        let display_text =
+            TextOps::mask_text(&input.value, &input.mask, input.marked_range.as_ref());
+        (display_text.into(), highlight)
     }
 
-    /// Creates text runs with proper styling including marked text underlines
+    /// Creates text runs with proper styling including marked text underlines and the
+    /// selected text color
     fn create_text_runs(
         &self,
         display_text: &str,
         base_run: TextRun,
         marked_range: Option<&Range<usize>>,
-        is_masked: bool,
+        selection: Option<(&Range<usize>, Hsla)>,
     ) -> Vec<TextRun> {
-        // For masked text, we've already excluded marked text from display_text,
-        // so no need for marked text styling
-        if is_masked || marked_range.is_none() {
-            return vec![base_run];
-        }
-
-        if let Some(marked_range) = marked_range {
-            // Ensure marked_range doesn't exceed display_text bounds
-            let display_len = display_text.len();
-            if marked_range.start >= display_len || marked_range.end > display_len {
-                return vec![base_run];
-            }
+        let display_len = display_text.len();
+        let covers = |range: &Range<usize>, segment: &Range<usize>| {
+            range.start <= segment.start && segment.end <= range.end
+        };
+        // Ranges that don't fit the display text are ignored
+        let marked_range = marked_range.filter(|range| range.end <= display_len);
+        let selection = selection.filter(|(range, _)| range.end <= display_len);
 
-            vec![
-                TextRun {
-                    len: marked_range.start,
+        let ranges: Vec<Range<usize>> = marked_range
+            .into_iter()
+            .chain(selection.map(|(range, _)| range))
+            .cloned()
+            .collect();
+        TextOps::split_at_ranges(display_len, &ranges)
+            .into_iter()
+            .map(|segment| {
+                let mut run = TextRun {
+                    len: segment.len(),
                     ..base_run.clone()
-                },
-                TextRun {
-                    len: marked_range.end - marked_range.start,
-                    underline: Some(UnderlineStyle {
+                };
+                if marked_range.is_some_and(|range| covers(range, &segment)) {
+                    run.underline = Some(UnderlineStyle {
                         color: Some(base_run.color),
                         thickness: px(MARKED_TEXT_UNDERLINE_THICKNESS),
                         wavy: false,
-                    }),
-                    ..base_run.clone()
-                },
-                TextRun {
-                    len: display_len - marked_range.end,
-                    ..base_run.clone()
-                },
-            ]
-            .into_iter()
-            .filter(|run| run.len > 0)
+                    });
+                }
+                if let Some((range, color)) = selection
+                    && covers(range, &segment)
+                {
+                    run.color = color;
+                }
+                run
+            })
             .collect()
-        } else {
-            vec![base_run]
-        }
     }
 
     /// Creates cursor paint quad for rendering
     fn create_cursor_quad(
         &self,
         bounds: Bounds<Pixels>,
-        cursor_pos: Pixels,
+        cursor_pos: Point<Pixels>,
+        line_height: Pixels,
         scroll_offset: Point<Pixels>,
         text_color: Hsla,
     ) -> PaintQuad {
         fill(
             Bounds::new(
-                point(bounds.left() + cursor_pos - scroll_offset.x, bounds.top()),
-                size(px(CURSOR_WIDTH), bounds.bottom() - bounds.top()),
+                bounds.origin + cursor_pos - scroll_offset,
+                size(px(CURSOR_WIDTH), line_height),
             ),
             text_color,
         )
     }
 
-    /// Creates selection paint quad for rendering
-    fn create_selection_quad(
+    /// Creates the selection paint quads for rendering, one for each line the selection
+    /// touches
+    fn create_selection_quads(
         &self,
         bounds: Bounds<Pixels>,
-        line: &ShapedLine,
+        layout: &TextLayout,
         selected_range: &Range<usize>,
         scroll_offset: Point<Pixels>,
-    ) -> PaintQuad {
-        fill(
-            Bounds::from_corners(
-                point(
-                    bounds.left() + line.x_for_index(selected_range.start) - scroll_offset.x,
-                    bounds.top(),
-                ),
-                point(
-                    bounds.left() + line.x_for_index(selected_range.end) - scroll_offset.x,
-                    bounds.bottom(),
-                ),
-            ),
-            rgba(SELECTION_COLOR),
-        )
+        color: Hsla,
+    ) -> Vec<PaintQuad> {
+        layout
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, (range, _))| {
+                selected_range.start <= range.end && selected_range.end > range.start
+            })
+            .map(|(row, (range, line))| {
+                let start = selected_range.start.max(range.start) - range.start;
+                let end = selected_range.end.min(range.end) - range.start;
+                let top = bounds.top() + layout.line_height * row as f32 - scroll_offset.y;
+                fill(
+                    Bounds::from_corners(
+                        point(
+                            bounds.left() + line.x_for_index(start) - scroll_offset.x,
+                            top,
+                        ),
+                        point(
+                            bounds.left() + line.x_for_index(end) - scroll_offset.x,
+                            top + layout.line_height,
+                        ),
+                    ),
+                    color,
+                )
+            })
+            .collect()
     }
 }
 
@@ -197,7 +329,7 @@ impl Element for TextElement {
         window: &mut Window,
         app: &mut App,
     ) -> (LayoutId, Self::RequestLayoutState) {
-        let style = self.create_layout_style(window);
+        let style = self.create_layout_style(window, app);
         (window.request_layout(style, [], app), ())
     }
     fn prepaint(
@@ -212,56 +344,71 @@ impl Element for TextElement {
         let input = self.input.read(app);
         let style = window.text_style();
 
-        let (display_text, text_color) = self.prepare_display_text(&input, style.color);
+        let focused = input.focus_handle.is_focused(window);
+        let (display_text, highlight) = self.prepare_display_text(&input, style.color, focused);
 
+        let mut font = style.font();
+        font.weight = highlight.font_weight.unwrap_or(font.weight);
+        font.style = highlight.font_style.unwrap_or(font.style);
+        let mut text_color = highlight.color.unwrap_or(style.color);
+        text_color.a *= 1.0 - highlight.fade_out.unwrap_or(0.0);
         let base_run = TextRun {
             len: display_text.len(),
-            font: style.font(),
+            font,
             color: text_color,
-            background_color: None,
-            underline: None,
-            strikethrough: None,
+            background_color: highlight.background_color,
+            underline: highlight.underline,
+            strikethrough: highlight.strikethrough,
         };
 
+        let selected_range = input.display_selection_range();
+        let selection = input
+            .selected_text_color
+            .filter(|_| !input.value.is_empty() && !selected_range.is_empty())
+            .map(|color| (&selected_range, color));
         let runs = self.create_text_runs(
             &display_text,
             base_run,
-            input.marked_range.as_ref(),
-            input.masked,
+            input.display_marked_range().as_ref(),
+            selection,
         );
 
-        let font_size = style.font_size.to_pixels(window.rem_size());
-        let line = window
-            .text_system()
-            .shape_line(display_text, font_size, &runs);
+        let layout = self.shape_text(display_text, runs, input.multiline, window);
 
         self.input.update(app, |input, _| {
-            input.auto_scroll_to_cursor(&line, bounds);
+            input.auto_scroll_to_cursor(&layout, bounds);
         });
 
         let input = self.input.read(app);
-        let scroll_offset = input.scroll_handle.offset();
-        let cursor_pos = line.x_for_index(input.display_cursor_offset());
+        let scroll_offset = input.text_offset(&layout, bounds);
+        let cursor_pos = layout.position_for_index(input.display_cursor_offset());
 
         let (selection, cursor) = if input.selected_range.is_empty() {
             (
-                None,
-                Some(self.create_cursor_quad(bounds, cursor_pos, scroll_offset, style.color)),
+                Vec::new(),
+                Some(self.create_cursor_quad(
+                    bounds,
+                    cursor_pos,
+                    layout.line_height,
+                    scroll_offset,
+                    style.color,
+                )),
             )
         } else {
             (
-                Some(self.create_selection_quad(
+                self.create_selection_quads(
                     bounds,
-                    &line,
-                    &input.display_selection_range(),
+                    &layout,
+                    &selected_range,
                     scroll_offset,
-                )),
+                    input.selection_background(focused),
+                ),
                 None,
             )
         };
 
         PrepaintState {
-            line: Some(line),
+            layout: Some(layout),
             cursor,
             selection,
         }
@@ -284,14 +431,13 @@ impl Element for TextElement {
             app,
         );
 
-        if let Some(selection) = prepaint.selection.take() {
+        for selection in prepaint.selection.drain(..) {
             window.paint_quad(selection);
         }
 
-        let line = prepaint.line.take().unwrap();
-        let scroll_offset = self.input.read(app).scroll_handle.offset();
-        let text_origin = point(bounds.origin.x - scroll_offset.x, bounds.origin.y);
-        self.paint_text(line.clone(), text_origin, window, app);
+        let layout = prepaint.layout.take().unwrap();
+        let scroll_offset = self.input.read(app).text_offset(&layout, bounds);
+        self.paint_text(&layout, bounds.origin - scroll_offset, window, app);
 
         if focus_handle.is_focused(window) && self.input.read(app).cursor_visible(window, app) {
             if let Some(cursor) = prepaint.cursor.take() {
@@ -300,7 +446,7 @@ impl Element for TextElement {
         }
 
         self.input.update(app, |input, _cx| {
-            input.last_layout = Some(line);
+            input.last_layout = Some(layout);
             input.last_bounds = Some(bounds);
         });
     }
@@ -1,7 +1,30 @@
+use crate::components::input::text_ops::TextOps;
 use gpui::SharedString;
-use std::ops::Range;
+use std::{
+    collections::VecDeque,
+    ops::Range,
+    time::{Duration, Instant},
+};
 
+/// Changes pushed further apart than this are never merged into the same entry.
+const DEFAULT_COALESCE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// A single edit recorded in the [`History`].
+///
+/// Ranges are UTF-8 byte offsets into the buffer as it was *before* the change was applied,
+/// and all range arithmetic uses byte lengths (`str::len`):
+/// - `Insert` uses an empty range at the insertion point (`at..at`).
+/// - `Delete` uses the removed range, with `text` holding the removed text.
+/// - `Replace` uses the range of `old_text`, which is replaced by `new_text`.
+///
+/// Two inserts are adjacent when the second one starts exactly where the text of
+/// the first one ends (`first.start + first_text.len() == second.start`), or exactly
+/// where it starts (`first.start == second.start`), in which case its text is prepended.
+///
+/// A `Batch` groups changes that are undone and redone as a single unit. Its changes
+/// are applied in order, each one against the buffer left by the previous one.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Change {
     Insert {
         range: Range<usize>,
@@ -17,10 +40,101 @@ pub enum Change {
         new_text: SharedString,
         marked: bool,
     },
+    Batch {
+        changes: Vec<Change>,
+    },
 }
 
 impl Change {
-    fn inverse(self) -> Change {
+    /// Builds an insert of `text` at byte offset `at`.
+    pub fn insert(text: impl Into<SharedString>, at: usize) -> Change {
+        Change::Insert {
+            range: at..at,
+            text: text.into(),
+        }
+    }
+
+    /// Builds a delete of `text`, which must span exactly `range`.
+    ///
+    /// Panics in debug builds if the range is reversed or its length differs from the
+    /// text's.
+    pub fn delete(text: impl Into<SharedString>, range: Range<usize>) -> Change {
+        let text = text.into();
+        Self::check_range("delete", &range, &text);
+        Change::Delete { range, text }
+    }
+
+    /// Builds a replacement of `old_text`, which must span exactly `range`, with
+    /// `new_text`.
+    ///
+    /// Panics in debug builds if the range is reversed or its length differs from
+    /// `old_text`'s.
+    pub fn replace(
+        old_text: impl Into<SharedString>,
+        new_text: impl Into<SharedString>,
+        range: Range<usize>,
+    ) -> Change {
+        let old_text = old_text.into();
+        Self::check_range("replace", &range, &old_text);
+        Change::Replace {
+            range,
+            old_text,
+            new_text: new_text.into(),
+            marked: false,
+        }
+    }
+
+    fn check_range(kind: &str, range: &Range<usize>, text: &str) {
+        debug_assert!(
+            range.start <= range.end,
+            "{kind} range {range:?} is reversed"
+        );
+        debug_assert!(
+            range.len() == text.len(),
+            "{kind} range {range:?} spans {} bytes but the text is {} bytes long",
+            range.len(),
+            text.len()
+        );
+    }
+
+    /// Builds the delete a backspace at `offset` performs on `text`.
+    ///
+    /// The whole grapheme cluster before `offset` is removed, so combining marks and ZWJ
+    /// emoji sequences go away in one step. An offset inside a cluster deletes that
+    /// cluster. Returns `None` at the start of the text.
+    pub fn delete_grapheme_before(text: &str, offset: usize) -> Option<Change> {
+        let offset = offset.min(text.len());
+        if offset == 0 {
+            return None;
+        }
+        let start = TextOps::previous_boundary(text, offset);
+        let end = TextOps::next_boundary(text, start);
+        Some(Change::Delete {
+            range: start..end,
+            text: SharedString::from(text[start..end].to_string()),
+        })
+    }
+
+    /// Builds the delete a forward delete at `offset` performs on `text`.
+    ///
+    /// The whole grapheme cluster after `offset` is removed, the counterpart of
+    /// [`delete_grapheme_before`](Self::delete_grapheme_before). An offset inside a
+    /// cluster deletes that cluster. Returns `None` at the end of the text.
+    pub fn delete_grapheme_after(text: &str, offset: usize) -> Option<Change> {
+        let offset = offset.min(text.len());
+        if offset == text.len() {
+            return None;
+        }
+        let end = TextOps::next_boundary(text, offset);
+        let start = TextOps::previous_boundary(text, end);
+        Some(Change::Delete {
+            range: start..end,
+            text: SharedString::from(text[start..end].to_string()),
+        })
+    }
+
+    /// Returns the change that reverts this one.
+    pub fn inverse(self) -> Change {
         match self {
             Change::Insert { range, text } => Change::Delete {
                 range: range.start..range.start + text.len(),
@@ -28,7 +142,7 @@ impl Change {
             },
             Change::Delete { range, text } => Change::Insert {
                 range: range.start..range.start,
-                text: text,
+                text,
             },
             Change::Replace {
                 range,
@@ -41,6 +155,23 @@ impl Change {
                 new_text: old_text,
                 marked,
             },
+            Change::Batch { changes } => Change::Batch {
+                changes: changes.into_iter().rev().map(Change::inverse).collect(),
+            },
+        }
+    }
+
+    /// Applies the change to `buffer` and returns the caret offset it leaves behind.
+    ///
+    /// Panics if a range is out of bounds or doesn't fall on char boundaries, which means
+    /// the change was recorded against a different buffer.
+    pub fn apply(&self, buffer: &mut String) -> usize {
+        match self {
+            Change::Batch { changes } => changes.iter().fold(0, |_, change| change.apply(buffer)),
+            change => {
+                buffer.replace_range(change.range(), &change.text());
+                change.selection_range().end
+            }
         }
     }
 
@@ -49,6 +180,7 @@ impl Change {
             Change::Insert { text, .. } => text.clone(),
             Change::Delete { .. } => SharedString::new(""),
             Change::Replace { new_text, .. } => new_text.clone(),
+            Change::Batch { .. } => SharedString::new(""),
         }
     }
 
@@ -57,6 +189,11 @@ impl Change {
             Change::Insert { range, .. } => range.clone(),
             Change::Delete { range, .. } => range.clone(),
             Change::Replace { range, .. } => range.clone(),
+            Change::Batch { changes } => changes
+                .iter()
+                .map(Change::range)
+                .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+                .unwrap_or(0..0),
         }
     }
 
@@ -67,6 +204,94 @@ impl Change {
             Change::Replace {
                 range, new_text, ..
             } => range.start..range.start + new_text.len(),
+            Change::Batch { changes } => {
+                changes.last().map(Change::selection_range).unwrap_or(0..0)
+            }
+        }
+    }
+
+    /// Returns the number of bytes of text held by the change.
+    pub fn byte_len(&self) -> usize {
+        match self {
+            Change::Insert { text, .. } | Change::Delete { text, .. } => text.len(),
+            Change::Replace {
+                old_text, new_text, ..
+            } => old_text.len() + new_text.len(),
+            Change::Batch { changes } => changes.iter().map(Change::byte_len).sum(),
+        }
+    }
+
+    /// Returns whether applying the change would leave the buffer untouched.
+    fn is_empty(&self) -> bool {
+        match self {
+            Change::Insert { text, .. } => text.is_empty(),
+            Change::Delete { range, .. } => range.is_empty(),
+            Change::Replace { .. } => false,
+            Change::Batch { changes } => changes.iter().all(Change::is_empty),
+        }
+    }
+
+    /// Folds a delete into the insert that precedes it.
+    ///
+    /// A delete that removes exactly the inserted text cancels it out. A delete that
+    /// overlaps the inserted text and reaches into the surrounding text becomes a single
+    /// change from the original buffer. Deletes strictly inside the inserted text are
+    /// left alone so corrections stay undoable on their own.
+    fn merge_delete_into_insert(
+        insert_range: &Range<usize>,
+        inserted: &str,
+        delete_range: &Range<usize>,
+        deleted: &str,
+    ) -> Option<Change> {
+        let insert_start = insert_range.start;
+        let insert_end = insert_start + inserted.len();
+
+        if *delete_range == (insert_start..insert_end) && deleted == inserted {
+            return Some(Change::Insert {
+                range: insert_start..insert_start,
+                text: SharedString::new(""),
+            });
+        }
+
+        let overlap = delete_range.start.max(insert_start)..delete_range.end.min(insert_end);
+        let extends_outside = delete_range.start < insert_start || delete_range.end > insert_end;
+        if overlap.is_empty() || !extends_outside || deleted.len() != delete_range.len() {
+            return None;
+        }
+
+        let deleted_inserted =
+            deleted.get(overlap.start - delete_range.start..overlap.end - delete_range.start)?;
+        if inserted.get(overlap.start - insert_start..overlap.end - insert_start)
+            != Some(deleted_inserted)
+        {
+            return None;
+        }
+
+        let kept = format!(
+            "{}{}",
+            inserted.get(..overlap.start - insert_start)?,
+            inserted.get(overlap.end - insert_start..)?
+        );
+        let removed = format!(
+            "{}{}",
+            deleted.get(..overlap.start - delete_range.start)?,
+            deleted.get(overlap.end - delete_range.start..)?
+        );
+        let start = delete_range.start.min(insert_start);
+        let range = start..start + removed.len();
+
+        if kept.is_empty() {
+            Some(Change::Delete {
+                range,
+                text: removed.into(),
+            })
+        } else {
+            Some(Change::Replace {
+                range,
+                old_text: removed.into(),
+                new_text: kept.into(),
+                marked: false,
+            })
         }
     }
 
@@ -85,6 +310,29 @@ impl Change {
                 range: r1.start..r1.start,
                 text: SharedString::from(format!("{}{}", t1, t2)),
             }),
+            (
+                Change::Insert {
+                    range: r1,
+                    text: t1,
+                },
+                Change::Insert {
+                    range: r2,
+                    text: t2,
+                },
+            ) if r1.start == r2.start => Some(Change::Insert {
+                range: r1.start..r1.start,
+                text: SharedString::from(format!("{}{}", t2, t1)),
+            }),
+            (
+                Change::Insert {
+                    range: r1,
+                    text: t1,
+                },
+                Change::Delete {
+                    range: r2,
+                    text: t2,
+                },
+            ) => Self::merge_delete_into_insert(&r1, &t1, r2, t2),
             (
                 Change::Delete {
                     range: r1,
@@ -133,8 +381,11 @@ impl Change {
                     old_text,
                     ..
                 },
-                Change::Insert { text: t2, .. },
-            ) => Some(Change::Replace {
+                Change::Insert {
+                    range: r2,
+                    text: t2,
+                },
+            ) if r1.start + t1.len() == r2.start => Some(Change::Replace {
                 range: r1,
                 new_text: SharedString::from(format!("{}{}", t1, t2)),
                 old_text,
@@ -178,21 +429,180 @@ impl Change {
                 new_text: SharedString::from(format!("{}{}", &nt1[..nt1.len() - ot2.len()], nt2)),
                 marked: false,
             }),
+            (
+                Change::Replace {
+                    range: r1,
+                    new_text: nt1,
+                    old_text: ot1,
+                    ..
+                },
+                Change::Replace {
+                    range: r2,
+                    new_text: nt2,
+                    old_text: ot2,
+                    marked: false,
+                },
+            ) if r1.start + nt1.len() == r2.start => Some(Change::Replace {
+                range: r1.start..r1.start + ot1.len() + ot2.len(),
+                old_text: SharedString::from(format!("{}{}", ot1, ot2)),
+                new_text: SharedString::from(format!("{}{}", nt1, nt2)),
+                marked: false,
+            }),
+            (
+                Change::Replace {
+                    range: r1,
+                    new_text: nt1,
+                    old_text: ot1,
+                    ..
+                },
+                Change::Replace {
+                    range: r2,
+                    new_text: nt2,
+                    old_text: ot2,
+                    marked: false,
+                },
+            ) if r1.start == r2.start && nt1 == *ot2 => Some(Change::Replace {
+                range: r1,
+                old_text: ot1,
+                new_text: nt2.clone(),
+                marked: false,
+            }),
             _ => None,
         }
     }
 }
 
+/// Default word boundary predicate: whitespace and ASCII punctuation end a word.
+pub fn is_word_boundary(text: &str) -> bool {
+    text.chars()
+        .last()
+        .is_some_and(|ch| ch.is_whitespace() || ch.is_ascii_punctuation())
+}
+
+/// Where a change came from. Only changes from the same origin are merged together.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeOrigin {
+    /// Typing and deleting from the keyboard.
+    #[default]
+    UserInput,
+    /// Text pasted from the clipboard. Each paste is a step of its own.
+    Paste,
+    /// Edits made by the application rather than the user.
+    Programmatic,
+    /// Text composed through an input method.
+    Ime,
+    /// Edits received from a collaborator.
+    Collaboration,
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HistoryEntry {
     pub change: Change,
+    pub origin: ChangeOrigin,
+    /// Selection before the change was applied, restored on undo.
+    ///
+    /// When `None`, undo selects whatever the inverse change brings back.
+    pub selection_before: Option<Range<usize>>,
+    /// Selection after the change was applied, restored on redo.
+    ///
+    /// When `None`, redo places the caret at the end of the change.
+    pub selection_after: Option<Range<usize>>,
+    /// When the entry was last extended, used for time-based coalescing.
+    ///
+    /// Not serialized; restored entries are never merged into anyway.
+    #[cfg_attr(feature = "serde", serde(skip, default = "Instant::now"))]
+    pub timestamp: Instant,
+    /// Whether later changes are kept out of this entry, see [`History::commit`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub sealed: bool,
+}
+
+impl HistoryEntry {
+    pub fn new(change: Change) -> Self {
+        Self {
+            change,
+            origin: ChangeOrigin::default(),
+            selection_before: None,
+            selection_after: None,
+            timestamp: Instant::now(),
+            sealed: false,
+        }
+    }
+}
+
+/// A change returned by [`History::undo`] or [`History::redo`], along with the
+/// selection to restore once it has been applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryStep {
+    pub change: Change,
+    pub origin: ChangeOrigin,
+    pub selection: Range<usize>,
+}
+
+/// The recorded state of a [`History`], used to persist it across sessions.
+///
+/// Only the stacks and `max_size` are captured, not the branches of a
+/// [`HistoryMode::Tree`] history. Settings such as the coalescing timeout belong to the
+/// history the snapshot is restored into.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistorySnapshot {
+    pub undo_stack: Vec<HistoryEntry>,
+    pub redo_stack: Vec<HistoryEntry>,
+    pub max_size: usize,
+}
+
+/// How a [`History`] treats the redo stack when a change is pushed after undoing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HistoryMode {
+    /// The redo stack is discarded.
+    #[default]
+    Linear,
+    /// The redo stack is kept as a branch that can be switched back to, see
+    /// [`History::switch_branch`].
+    Tree,
+}
+
+/// Undone entries set aside when a new change was pushed in [`HistoryMode::Tree`].
+struct Branch {
+    id: usize,
+    /// Undo depth at which the branch forks off.
+    depth: usize,
+    /// The entries to redo, in the same order as the redo stack.
+    entries: VecDeque<HistoryEntry>,
+    /// Branches forking off `entries`, with depths on the same scale.
+    branches: Vec<Branch>,
+}
+
+impl Branch {
+    /// Accounts for the oldest undo entry being evicted, dropping branches that forked
+    /// off before it.
+    fn shift(branches: &mut Vec<Branch>) {
+        branches.retain(|branch| branch.depth > 0);
+        for branch in branches {
+            branch.depth -= 1;
+            Self::shift(&mut branch.branches);
+        }
+    }
 }
 
 pub struct History {
-    undo_stack: Vec<HistoryEntry>,
-    redo_stack: Vec<HistoryEntry>,
+    undo_stack: VecDeque<HistoryEntry>,
+    redo_stack: VecDeque<HistoryEntry>,
     max_size: usize,
-    can_merge: bool,
+    max_bytes: Option<usize>,
+    undo_bytes: usize,
+    coalesce_timeout: Duration,
+    word_boundary: Option<fn(&str) -> bool>,
+    transaction_depth: usize,
+    transaction: Vec<HistoryEntry>,
+    savepoint: Option<usize>,
+    mode: HistoryMode,
+    branches: Vec<Branch>,
+    next_branch_id: usize,
+    on_change: Option<Box<dyn Fn(&History)>>,
 }
 
 impl Default for History {
@@ -208,58 +618,558 @@ impl History {
 
     pub fn with_max_size(max_size: usize) -> Self {
         Self {
-            undo_stack: Vec::new(),
-            redo_stack: Vec::new(),
+            undo_stack: VecDeque::new(),
+            redo_stack: VecDeque::new(),
             max_size,
-            can_merge: true,
+            max_bytes: None,
+            undo_bytes: 0,
+            coalesce_timeout: DEFAULT_COALESCE_TIMEOUT,
+            word_boundary: None,
+            transaction_depth: 0,
+            transaction: Vec::new(),
+            savepoint: Some(0),
+            mode: HistoryMode::default(),
+            branches: Vec::new(),
+            next_branch_id: 0,
+            on_change: None,
         }
     }
 
-    pub fn push(&mut self, change: Change) {
+    /// Changes the maximum number of undo entries, evicting the oldest ones if the undo
+    /// stack is already deeper than `max_size`.
+    ///
+    /// The redo stack is not capped: it only holds undone entries, and redoing them
+    /// evicts from the undo stack as usual.
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        let len = self.undo_stack.len();
+        self.evict();
+        if self.undo_stack.len() != len {
+            self.notify();
+        }
+    }
+
+    /// Caps the total size of the text held by the undo stack, see [`Change::byte_len`].
+    ///
+    /// The oldest entries are evicted until the stack fits. A single change larger than
+    /// the budget cannot be kept, and since older entries can't be undone without it,
+    /// recording one leaves the undo stack empty and discards the redo stack. Inside a
+    /// transaction the budget applies to the whole transaction, which is evicted as one.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Sets whether undone changes survive a new change, see [`HistoryMode`].
+    pub fn with_mode(mut self, mode: HistoryMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets how close in time two changes must be to be merged into one entry.
+    pub fn with_coalesce_timeout(mut self, coalesce_timeout: Duration) -> Self {
+        self.coalesce_timeout = coalesce_timeout;
+        self
+    }
+
+    /// Starts a new entry whenever typing resumes after a word boundary.
+    ///
+    /// Separators (see [`is_word_boundary`]) are merged into the word they follow, and
+    /// once an entry ends in one it no longer absorbs the next word, so each undo removes
+    /// one word together with its trailing separator.
+    pub fn with_word_boundary(mut self, word_boundary: fn(&str) -> bool) -> Self {
+        self.word_boundary = Some(word_boundary);
+        self
+    }
+
+    /// Sets a callback invoked whenever the undo or redo stacks change.
+    ///
+    /// The callback has no access to the gpui context, so an entity owning the history
+    /// should share a flag with it (e.g. an `Rc<Cell<bool>>`) and call `cx.notify()`
+    /// once the operation that touched the history returns and the flag is set.
+    pub fn set_on_change(&mut self, on_change: impl Fn(&History) + 'static) {
+        self.on_change = Some(Box::new(on_change));
+    }
+
+    fn notify(&self) {
+        if let Some(on_change) = &self.on_change {
+            on_change(self);
+        }
+    }
+
+    /// Records a change, returning whether it discarded the redo stack, see
+    /// [`push_entry`](Self::push_entry).
+    pub fn push(&mut self, change: Change) -> bool {
+        self.push_entry(HistoryEntry::new(change))
+    }
+
+    /// Pushes a change as if it happened at `timestamp`.
+    pub fn push_at(&mut self, change: Change, timestamp: Instant) -> bool {
+        self.push_entry(HistoryEntry {
+            timestamp,
+            ..HistoryEntry::new(change)
+        })
+    }
+
+    /// Pushes a change recorded as coming from `origin`.
+    pub fn push_with_origin(&mut self, change: Change, origin: ChangeOrigin) -> bool {
+        self.push_entry(HistoryEntry {
+            origin,
+            ..HistoryEntry::new(change)
+        })
+    }
+
+    /// Pushes a change along with the selections to restore when undoing and redoing it.
+    pub fn push_with_selection(
+        &mut self,
+        change: Change,
+        selection_before: Range<usize>,
+        selection_after: Range<usize>,
+    ) -> bool {
+        self.push_entry(HistoryEntry {
+            selection_before: Some(selection_before),
+            selection_after: Some(selection_after),
+            ..HistoryEntry::new(change)
+        })
+    }
+
+    /// Records an entry, merging it into the previous one when they coalesce.
+    ///
+    /// Returns whether there were undone entries that can no longer be redone, so a UI
+    /// showing whether redo is available knows to update without polling
+    /// [`can_redo`](Self::can_redo). In [`HistoryMode::Tree`] they are kept as a branch,
+    /// but still leave the redo stack.
+    pub fn push_entry(&mut self, entry: HistoryEntry) -> bool {
+        let redo_discarded = !self.redo_stack.is_empty();
+        if self.mode == HistoryMode::Tree && redo_discarded {
+            self.stash_redo_stack();
+        }
         self.redo_stack.clear();
+        if self
+            .savepoint
+            .is_some_and(|savepoint| savepoint > self.undo_stack.len())
+        {
+            self.savepoint = None;
+        }
+
+        if self.transaction_depth > 0 {
+            self.transaction.push(entry);
+            return redo_discarded;
+        }
 
-        if self.can_merge
-            && let Some(last_entry) = self.undo_stack.last_mut()
-            && let Some(merged_change) = last_entry.change.clone().merge_with(&change)
+        // A composition update always folds into the composition, however long the user
+        // takes to pick a candidate. A paste is always a step of its own, and is sealed
+        // so that typing after it starts a new one.
+        let composing = matches!(entry.change, Change::Replace { marked: true, .. });
+        let pasted = entry.origin == ChangeOrigin::Paste;
+        let coalesces = !pasted
+            && self.undo_stack.back().is_some_and(|last_entry| {
+                let within_timeout = entry
+                    .timestamp
+                    .saturating_duration_since(last_entry.timestamp)
+                    <= self.coalesce_timeout;
+                composing
+                    || (within_timeout
+                        && !Self::starts_new_word(
+                            self.word_boundary,
+                            &last_entry.change,
+                            &entry.change,
+                        ))
+            });
+        if coalesces
+            && let Some(last_entry) = self.undo_stack.back_mut()
+            && !last_entry.sealed
+            && last_entry.origin == entry.origin
+            && let Some(merged_change) = last_entry.change.clone().merge_with(&entry.change)
         {
-            last_entry.change = merged_change;
-            return;
+            self.undo_bytes -= last_entry.change.byte_len();
+            if merged_change.is_empty() {
+                self.undo_stack.pop_back();
+            } else {
+                self.undo_bytes += merged_change.byte_len();
+                last_entry.change = merged_change;
+                last_entry.selection_after = entry.selection_after;
+                last_entry.timestamp = entry.timestamp;
+                last_entry.sealed |= Self::ends_line(&last_entry.change);
+            }
+            self.evict();
+            self.notify();
+            return redo_discarded;
+        }
+
+        self.undo_bytes += entry.change.byte_len();
+        let ends_line = Self::ends_line(&entry.change);
+        self.undo_stack.push_back(entry);
+        if ends_line || pasted {
+            self.commit();
+        }
+        self.evict();
+        self.notify();
+        redo_discarded
+    }
+
+    /// Drops the oldest entries until the undo stack fits both `max_size` and `max_bytes`.
+    fn evict(&mut self) {
+        while self.undo_stack.len() > self.max_size
+            || self
+                .max_bytes
+                .is_some_and(|max_bytes| self.undo_bytes > max_bytes)
+        {
+            let Some(entry) = self.undo_stack.pop_front() else {
+                break;
+            };
+            self.undo_bytes -= entry.change.byte_len();
+            self.savepoint = self
+                .savepoint
+                .and_then(|savepoint| savepoint.checked_sub(1));
+            Branch::shift(&mut self.branches);
+        }
+    }
+
+    /// Moves the redo stack into a new branch at the current depth, along with the
+    /// branches forking off it.
+    fn stash_redo_stack(&mut self) {
+        let depth = self.undo_stack.len();
+        let (nested, branches) = std::mem::take(&mut self.branches)
+            .into_iter()
+            .partition(|branch| branch.depth > depth);
+        self.branches = branches;
+        self.branches.push(Branch {
+            id: self.next_branch_id,
+            depth,
+            entries: std::mem::take(&mut self.redo_stack),
+            branches: nested,
+        });
+        self.next_branch_id += 1;
+        // The branch starts from the top entry as it is now, so it can't be extended.
+        self.commit();
+    }
+
+    /// Returns the ids of the branches that fork off at the current position, in the
+    /// order they were created. Always empty in [`HistoryMode::Linear`].
+    pub fn branches_at_current(&self) -> Vec<usize> {
+        let depth = self.undo_stack.len();
+        self.branches
+            .iter()
+            .filter(|branch| branch.depth == depth)
+            .map(|branch| branch.id)
+            .collect()
+    }
+
+    /// Makes the branch `id` the redo stack, keeping the current redo stack as a branch.
+    ///
+    /// Only branches returned by [`branches_at_current`](Self::branches_at_current) can
+    /// be switched to. Returns whether the switch happened.
+    pub fn switch_branch(&mut self, id: usize) -> bool {
+        let depth = self.undo_stack.len();
+        let Some(index) = self
+            .branches
+            .iter()
+            .position(|branch| branch.id == id && branch.depth == depth)
+        else {
+            return false;
+        };
+
+        let branch = self.branches.remove(index);
+        if !self.redo_stack.is_empty() {
+            self.stash_redo_stack();
+        }
+        // A savepoint ahead of the current depth was on the redo stack just stashed
+        if self.savepoint.is_some_and(|savepoint| savepoint > depth) {
+            self.savepoint = None;
         }
+        self.redo_stack = branch.entries;
+        self.branches.extend(branch.branches);
+        self.notify();
+        true
+    }
+
+    /// Starts grouping pushed changes into a single undo entry.
+    ///
+    /// Transactions can be nested; the entry is recorded once the outermost one ends.
+    pub fn begin_transaction(&mut self) {
+        self.transaction_depth += 1;
+    }
 
-        self.undo_stack.push(HistoryEntry { change });
-        if self.undo_stack.len() > self.max_size {
-            self.undo_stack.remove(0);
+    /// Ends the current transaction, recording everything pushed since the matching
+    /// [`begin_transaction`](Self::begin_transaction) as one entry that never merges
+    /// with its neighbours.
+    pub fn end_transaction(&mut self) {
+        if self.transaction_depth == 0 {
+            return;
+        }
+        self.transaction_depth -= 1;
+        if self.transaction_depth > 0 || self.transaction.is_empty() {
+            return;
         }
-        self.can_merge = true;
+
+        let mut entries = std::mem::take(&mut self.transaction);
+        let entry = if entries.len() == 1 {
+            entries.remove(0)
+        } else {
+            let first = entries.first().unwrap();
+            let last = entries.last().unwrap();
+            HistoryEntry {
+                origin: first.origin,
+                selection_before: first.selection_before.clone(),
+                selection_after: last.selection_after.clone(),
+                timestamp: last.timestamp,
+                sealed: false,
+                change: Change::Batch {
+                    changes: entries.into_iter().map(|entry| entry.change).collect(),
+                },
+            }
+        };
+
+        self.commit();
+        self.push_entry(entry);
+        self.commit();
     }
 
-    pub fn undo(&mut self) -> Option<Change> {
-        self.prevent_merge();
-        if let Some(entry) = self.undo_stack.pop() {
-            self.redo_stack.push(entry.clone());
-            let inverse_change = entry.change.inverse();
-            Some(inverse_change)
+    /// Runs `f` inside a transaction, see [`begin_transaction`](Self::begin_transaction).
+    pub fn transaction<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.begin_transaction();
+        let result = f(self);
+        self.end_transaction();
+        result
+    }
+
+    /// Records `changes` as a single entry that never merges with its neighbours.
+    ///
+    /// Meant for programmatic edits such as formatting. The changes are applied in order,
+    /// so each range refers to the buffer left by the previous change. Returns whether
+    /// the batch discarded the redo stack.
+    pub fn push_batch(&mut self, changes: Vec<Change>) -> bool {
+        self.transaction(|history| {
+            let mut discarded = false;
+            for change in changes {
+                discarded |= history.push(change);
+            }
+            discarded
+        })
+    }
+
+    /// Inserting a newline finishes the entry, so each line is undone on its own.
+    fn ends_line(change: &Change) -> bool {
+        change.text().ends_with('\n')
+    }
+
+    fn starts_new_word(
+        word_boundary: Option<fn(&str) -> bool>,
+        last: &Change,
+        change: &Change,
+    ) -> bool {
+        let (Some(is_boundary), Change::Insert { text, .. }) = (word_boundary, change) else {
+            return false;
+        };
+        let Some(first) = text.chars().next() else {
+            return false;
+        };
+
+        is_boundary(&last.text()) && !is_boundary(&text[..first.len_utf8()])
+    }
+
+    pub fn undo(&mut self) -> Option<HistoryStep> {
+        if let Some(entry) = self.undo_stack.pop_back() {
+            self.undo_bytes -= entry.change.byte_len();
+            self.redo_stack.push_back(entry.clone());
+            self.commit();
+            let change = entry.change.inverse();
+            let selection = entry
+                .selection_before
+                .unwrap_or_else(|| change.selection_range());
+            self.notify();
+            Some(HistoryStep {
+                change,
+                origin: entry.origin,
+                selection,
+            })
         } else {
             None
         }
     }
 
-    pub fn redo(&mut self) -> Option<Change> {
-        self.prevent_merge();
-        if let Some(entry) = self.redo_stack.pop() {
-            self.undo_stack.push(entry.clone());
-            Some(entry.change)
+    pub fn redo(&mut self) -> Option<HistoryStep> {
+        if let Some(entry) = self.redo_stack.pop_back() {
+            self.undo_bytes += entry.change.byte_len();
+            self.undo_stack.push_back(entry.clone());
+            self.evict();
+            self.commit();
+            let selection = entry.selection_after.unwrap_or_else(|| {
+                let end = entry.change.selection_range().end;
+                end..end
+            });
+            self.notify();
+            Some(HistoryStep {
+                change: entry.change,
+                origin: entry.origin,
+                selection,
+            })
         } else {
             None
         }
     }
 
+    /// Returns the change the next [`undo`](Self::undo) would revert, as it was recorded.
+    pub fn peek_undo(&self) -> Option<&Change> {
+        self.undo_stack.back().map(|entry| &entry.change)
+    }
+
+    /// Returns the change the next [`redo`](Self::redo) would reapply.
+    pub fn peek_redo(&self) -> Option<&Change> {
+        self.redo_stack.back().map(|entry| &entry.change)
+    }
+
+    /// Returns whether there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns whether there is a change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Returns the number of entries that can be undone, one per coalesced run.
+    pub fn undo_len(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Returns the number of entries that can be redone.
+    pub fn redo_len(&self) -> usize {
+        self.redo_stack.len()
+    }
+
+    /// Returns whether there is nothing to undo or redo.
+    pub fn is_empty(&self) -> bool {
+        self.undo_stack.is_empty() && self.redo_stack.is_empty()
+    }
+
+    /// Returns the total size of the text held by the undo stack.
+    pub fn undo_bytes(&self) -> usize {
+        self.undo_bytes
+    }
+
+    /// Captures the undo and redo stacks so they can be restored later.
+    ///
+    /// Changes pushed inside an unfinished transaction are not included.
+    pub fn snapshot(&self) -> HistorySnapshot {
+        HistorySnapshot {
+            undo_stack: self.undo_stack.iter().cloned().collect(),
+            redo_stack: self.redo_stack.iter().cloned().collect(),
+            max_size: self.max_size,
+        }
+    }
+
+    /// Replaces the recorded history with `snapshot`.
+    ///
+    /// The next change always starts a new entry, so nothing typed after restoring is
+    /// merged into a change from the previous session. The restored state is treated as
+    /// saved.
+    pub fn restore(&mut self, snapshot: HistorySnapshot) {
+        self.transaction.clear();
+        self.branches.clear();
+        self.undo_bytes = snapshot
+            .undo_stack
+            .iter()
+            .map(|entry| entry.change.byte_len())
+            .sum();
+        self.undo_stack = snapshot.undo_stack.into();
+        self.redo_stack = snapshot.redo_stack.into();
+        self.max_size = snapshot.max_size;
+        self.savepoint = Some(self.undo_stack.len());
+        self.evict();
+        self.commit();
+        self.notify();
+    }
+
+    /// Marks the current state as saved, see [`is_dirty`](Self::is_dirty).
+    ///
+    /// The top entry is sealed so that further typing can't extend the saved state.
+    pub fn save(&mut self) {
+        self.commit();
+        self.savepoint = Some(self.undo_stack.len());
+        self.notify();
+    }
+
+    /// Returns the undo depth at which the history was last saved.
+    ///
+    /// `None` when the saved state can no longer be reached by undoing or redoing,
+    /// for example after undoing past it and making a new change.
+    pub fn savepoint(&self) -> Option<usize> {
+        self.savepoint
+    }
+
+    /// Returns whether the buffer differs from the state marked by [`save`](Self::save).
+    ///
+    /// A new history starts out clean.
+    pub fn is_dirty(&self) -> bool {
+        self.savepoint != Some(self.undo_stack.len())
+    }
+
+    /// Returns the changes that turn the saved buffer into the current one, in the order
+    /// they apply, e.g. to persist or sync only what is unsaved.
+    ///
+    /// After undoing past the savepoint these are the inverses of the undone entries.
+    /// Empty when the history is clean, and also when the saved state can no longer be
+    /// reached, see [`savepoint`](Self::savepoint); [`is_dirty`](Self::is_dirty) tells
+    /// the two apart. Changes pushed inside an unfinished transaction are not included.
+    pub fn changes_since_savepoint(&self) -> Vec<Change> {
+        let Some(savepoint) = self.savepoint else {
+            return Vec::new();
+        };
+        let depth = self.undo_stack.len();
+        if savepoint <= depth {
+            return self
+                .undo_stack
+                .range(savepoint..)
+                .map(|entry| entry.change.clone())
+                .collect();
+        }
+
+        // The redo stack holds the most recently undone entry last, so the entries
+        // between the current depth and the savepoint are at its end, deepest first.
+        let Some(start) = self.redo_stack.len().checked_sub(savepoint - depth) else {
+            return Vec::new();
+        };
+        self.redo_stack
+            .range(start..)
+            .map(|entry| entry.change.clone().inverse())
+            .collect()
+    }
+
+    /// Clears both stacks. A clean history stays clean; a dirty one can't become
+    /// clean again until it is saved.
     pub fn clear(&mut self) {
+        self.savepoint = (!self.is_dirty()).then_some(0);
+        self.undo_bytes = 0;
         self.undo_stack.clear();
         self.redo_stack.clear();
+        self.transaction.clear();
+        self.branches.clear();
+        self.notify();
     }
 
+    /// Seals the most recent entry so the next change always starts a new one, regardless
+    /// of timing or adjacency.
+    pub fn commit(&mut self) {
+        if let Some(entry) = self.undo_stack.back_mut() {
+            entry.sealed = true;
+        }
+    }
+
+    /// Seals the most recent entry so the next change always starts a new one.
+    #[deprecated(note = "use `commit` instead")]
     pub fn prevent_merge(&mut self) {
-        self.can_merge = false;
+        self.commit();
+    }
+
+    /// Seals the most recent entry once typing has gone idle, so the next change starts a
+    /// new one even if it is adjacent and within the coalescing timeout.
+    ///
+    /// Same as [`commit`](Self::commit), meant to be called from an idle timer rather
+    /// than in response to an edit.
+    pub fn flush(&mut self) {
+        self.commit();
     }
 }
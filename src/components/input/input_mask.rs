@@ -0,0 +1,127 @@
+use gpui::SharedString;
+use std::ops::Range;
+
+/// A pattern that formats an input as it is typed, like `"(###) ###-####"`
+///
+/// `#` takes a digit, `A` a letter and `*` either. Every other character is a literal that
+/// is inserted once the slot after it is filled, so a value never ends with a separator.
+pub struct InputMask {
+    pattern: SharedString,
+}
+
+/// The result of editing a formatted value, as a single replacement of `range`
+#[derive(Debug, PartialEq)]
+pub struct MaskEdit {
+    pub range: Range<usize>,
+    pub text: String,
+    pub cursor: usize,
+}
+
+impl InputMask {
+    pub fn new(pattern: impl Into<SharedString>) -> Self {
+        Self {
+            pattern: pattern.into(),
+        }
+    }
+
+    /// Whether `ch` fits `slot`, or `None` when `slot` is a literal
+    fn accepts(slot: char, ch: char) -> Option<bool> {
+        match slot {
+            '#' => Some(ch.is_ascii_digit()),
+            'A' => Some(ch.is_alphabetic()),
+            '*' => Some(ch.is_alphanumeric()),
+            _ => None,
+        }
+    }
+
+    /// Fit the characters of `raw` into the pattern, dropping the ones that don't fit
+    pub fn format(&self, raw: &str) -> String {
+        let mut formatted = String::new();
+        let mut pending = String::new();
+        let mut raw = raw.chars().peekable();
+        for slot in self.pattern.chars() {
+            if Self::accepts(slot, ' ').is_none() {
+                pending.push(slot);
+                continue;
+            }
+            while let Some(&ch) = raw.peek()
+                && !Self::accepts(slot, ch).unwrap_or(false)
+            {
+                raw.next();
+            }
+            let Some(ch) = raw.next() else {
+                break;
+            };
+            formatted.push_str(&pending);
+            formatted.push(ch);
+            pending.clear();
+        }
+        formatted
+    }
+
+    /// The characters of a formatted value that fill slots, with their byte offsets
+    fn slots<'a>(&'a self, text: &'a str) -> impl Iterator<Item = (usize, char)> + 'a {
+        text.char_indices()
+            .zip(self.pattern.chars())
+            .filter(|&(_, slot)| Self::accepts(slot, ' ').is_some())
+            .map(|(entry, _)| entry)
+    }
+
+    /// Strip the literals from a formatted value
+    pub fn unformat(&self, text: &str) -> String {
+        self.slots(text).map(|(_, ch)| ch).collect()
+    }
+
+    /// Replace `range` of the formatted `text` with `new_text` and reformat the result
+    ///
+    /// Deleting only literals deletes the slot before them instead. Returns `None` when
+    /// the formatted value doesn't change.
+    pub fn edit(&self, text: &str, range: Range<usize>, new_text: &str) -> Option<MaskEdit> {
+        let mut before: String = self.unformat(&text[..range.start]);
+        let removed = self
+            .slots(text)
+            .filter(|&(ix, _)| range.contains(&ix))
+            .count();
+        if new_text.is_empty() && removed == 0 && !range.is_empty() {
+            before.pop();
+        }
+        let after: String = self
+            .slots(text)
+            .filter(|&(ix, _)| ix >= range.end)
+            .map(|(_, ch)| ch)
+            .collect();
+
+        before.push_str(new_text);
+        let filled = self.slots(&self.format(&before)).count();
+        before.push_str(&after);
+        let formatted = self.format(&before);
+        // Keep the caret before the next slot, past any literals leading up to it
+        let cursor = self
+            .slots(&formatted)
+            .nth(filled)
+            .map_or(formatted.len(), |(ix, _)| ix);
+
+        // Narrow the edit to the part that changed, so it reaches the history as one change
+        let prefix = text
+            .char_indices()
+            .zip(formatted.chars())
+            .take_while(|&((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((ix, ch), _)| ix + ch.len_utf8());
+        let suffix = text[prefix..]
+            .chars()
+            .rev()
+            .zip(formatted[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(ch, _)| ch.len_utf8())
+            .sum::<usize>();
+        if prefix + suffix == text.len() && text.len() == formatted.len() {
+            return None;
+        }
+        Some(MaskEdit {
+            range: prefix..text.len() - suffix,
+            text: formatted[prefix..formatted.len() - suffix].to_string(),
+            cursor,
+        })
+    }
+}
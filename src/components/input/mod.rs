@@ -1,11 +1,19 @@
+mod blink;
 mod cursor;
 mod element;
 mod history;
+mod input_mask;
+mod placeholder;
 mod state;
+mod suggestions;
 #[cfg(test)]
 mod tests;
 mod text_input;
 mod text_ops;
 
+pub use history::{
+    Change, ChangeOrigin, History, HistoryEntry, HistoryMode, HistorySnapshot, HistoryStep,
+    is_word_boundary,
+};
 pub use state::*;
 pub use text_input::TextInput;
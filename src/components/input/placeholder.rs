@@ -0,0 +1,29 @@
+use gpui::{HighlightStyle, Hsla};
+
+/// The style to draw the displayed text with, over the input's text style
+///
+/// Real text keeps the text color. The placeholder takes the color and other properties of
+/// `style`, then `color`, and falls back to the text color at half opacity.
+pub fn display_highlight(
+    placeholder: bool,
+    text_color: Hsla,
+    color: Option<Hsla>,
+    style: Option<HighlightStyle>,
+) -> HighlightStyle {
+    if !placeholder {
+        return HighlightStyle {
+            color: Some(text_color),
+            ..Default::default()
+        };
+    }
+
+    let style = style.unwrap_or_default();
+    let dimmed = Hsla {
+        a: text_color.a * 0.5,
+        ..text_color
+    };
+    HighlightStyle {
+        color: style.color.or(color).or(Some(dimmed)),
+        ..style
+    }
+}
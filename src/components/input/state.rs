@@ -1,16 +1,25 @@
 use crate::components::input::{
     cursor::Cursor,
-    element::{CURSOR_WIDTH, TextElement},
-    history::{Change, History},
+    element::{CURSOR_WIDTH, TextElement, TextLayout},
+    history::{Change, ChangeOrigin, History, HistoryEntry, HistorySnapshot, is_word_boundary},
+    input_mask::InputMask,
+    suggestions::Suggestions,
     text_ops::TextOps,
 };
-use gpui::*;
-use std::ops::Range;
-use unicode_segmentation::UnicodeSegmentation;
+use gpui::{prelude::FluentBuilder, *};
+use std::{cell::Cell, ops::Range, rc::Rc, time::Duration};
 
 /// Context identifier for input key bindings
 pub(super) const CONTEXT: &str = "input";
 
+/// How long typing has to pause before the value is validated
+const VALIDATION_DELAY: Duration = Duration::from_millis(300);
+
+/// How often, and how many pixels at most, the text scrolls while a drag selection is
+/// held past its edge
+const DRAG_SCROLL_INTERVAL: Duration = Duration::from_millis(16);
+const DRAG_SCROLL_MAX_STEP: f32 = 20.0;
+
 /// Initialize input key bindings and actions
 pub fn init(cx: &mut App) {
     cx.bind_keys([
@@ -19,9 +28,19 @@ pub fn init(cx: &mut App) {
         KeyBinding::new("right", Right, Some(CONTEXT)),
         KeyBinding::new("home", Home, Some(CONTEXT)),
         KeyBinding::new("end", End, Some(CONTEXT)),
+        // Line movement, for multiline inputs
+        KeyBinding::new("up", Up, Some(CONTEXT)),
+        KeyBinding::new("down", Down, Some(CONTEXT)),
+        KeyBinding::new("shift-up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("shift-down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("enter", Newline, Some(CONTEXT)),
         // Word movement
         KeyBinding::new("alt-left", WordLeft, Some(CONTEXT)),
         KeyBinding::new("alt-right", WordRight, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-left", WordLeft, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-right", WordRight, Some(CONTEXT)),
         // macOS cursor movement alternatives
         #[cfg(target_os = "macos")]
         KeyBinding::new("ctrl-a", Home, Some(CONTEXT)),
@@ -40,6 +59,10 @@ pub fn init(cx: &mut App) {
         // Word selection
         KeyBinding::new("alt-shift-left", SelectWordLeft, Some(CONTEXT)),
         KeyBinding::new("alt-shift-right", SelectWordRight, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-left", SelectWordLeft, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-right", SelectWordRight, Some(CONTEXT)),
         // Line selection
         KeyBinding::new("shift-home", SelectToHome, Some(CONTEXT)),
         KeyBinding::new("shift-end", SelectToEnd, Some(CONTEXT)),
@@ -53,6 +76,10 @@ pub fn init(cx: &mut App) {
         // Word deletion
         KeyBinding::new("alt-backspace", DeleteWordLeft, Some(CONTEXT)),
         KeyBinding::new("alt-delete", DeleteWordRight, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-backspace", DeleteWordLeft, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-delete", DeleteWordRight, Some(CONTEXT)),
         // Line deletion
         KeyBinding::new("cmd-backspace", DeleteToBeginning, Some(CONTEXT)),
         KeyBinding::new("cmd-delete", DeleteToEnd, Some(CONTEXT)),
@@ -77,6 +104,16 @@ pub fn init(cx: &mut App) {
         KeyBinding::new("ctrl-shift-z", Redo, Some(CONTEXT)),
         KeyBinding::new("cmd-z", Undo, Some(CONTEXT)),
         KeyBinding::new("cmd-shift-z", Redo, Some(CONTEXT)),
+        // Suggestions
+        KeyBinding::new("down", SelectNextSuggestion, Some(CONTEXT)),
+        KeyBinding::new("up", SelectPreviousSuggestion, Some(CONTEXT)),
+        KeyBinding::new("enter", AcceptSuggestion, Some(CONTEXT)),
+        KeyBinding::new("escape", DismissSuggestions, Some(CONTEXT)),
+        // Tab
+        KeyBinding::new("tab", Tab, Some(CONTEXT)),
+        KeyBinding::new("shift-tab", Backtab, Some(CONTEXT)),
+        // Bound after tab so that it runs first, and lets tab through while the list is closed
+        KeyBinding::new("tab", CompleteSuggestion, Some(CONTEXT)),
     ]);
 }
 
@@ -92,6 +129,11 @@ actions!(
         SelectAll,
         Home,
         End,
+        Up,
+        Down,
+        SelectUp,
+        SelectDown,
+        Newline,
         ShowCharacterPalette,
         Copy,
         Paste,
@@ -109,31 +151,105 @@ actions!(
         SelectToEnd,
         Undo,
         Redo,
+        SelectNextSuggestion,
+        SelectPreviousSuggestion,
+        AcceptSuggestion,
+        CompleteSuggestion,
+        DismissSuggestions,
+        Tab,
+        Backtab,
     ]
 );
 
+/// What pasting does with the line breaks in the pasted text, see
+/// [`InputState::paste_newline_behavior`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PasteNewlines {
+    /// Replace each line break with a space
+    #[default]
+    Space,
+    /// Remove the line breaks, joining the lines
+    Strip,
+}
+
+/// The base direction of the text, see [`InputState::direction`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Follow the first strongly directional character of the value
+    #[default]
+    Auto,
+    LeftToRight,
+    RightToLeft,
+}
+
+/// What the tab key does in an input, see [`InputState::tab_behavior`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabBehavior {
+    /// Move focus to the next control, and back with shift-tab
+    #[default]
+    MoveFocus,
+    /// Insert a tab character
+    InsertTab,
+    /// Insert this many spaces
+    InsertSpaces(usize),
+}
+
 /// State management for text input components
 ///
 /// Handles text editing, cursor positioning, selection, and scrolling
-/// for single-line text input fields.
+/// for single-line text input fields, or multiline ones with [`InputState::multiline`].
+#[allow(clippy::type_complexity)]
 pub struct InputState {
     pub(super) focus_handle: FocusHandle,
     pub(super) value: SharedString,
     pub(super) placeholder: SharedString,
-    pub(super) placeholder_color: Hsla,
+    pub(super) placeholder_color: Option<Hsla>,
+    pub(super) placeholder_style: Option<HighlightStyle>,
+    pub(super) hide_placeholder_on_focus: bool,
+    pub(super) selection_color: Hsla,
+    pub(super) inactive_selection_color: Option<Hsla>,
+    pub(super) selected_text_color: Option<Hsla>,
     pub(super) selected_range: Range<usize>,
     pub(super) selection_reversed: bool,
     pub(super) marked_range: Option<Range<usize>>,
-    pub(super) last_layout: Option<ShapedLine>,
+    /// The value before the composition in progress, which `on_change` compares the
+    /// committed value with
+    composing_from: Option<SharedString>,
+    pub(super) last_layout: Option<TextLayout>,
     pub(super) last_bounds: Option<Bounds<Pixels>>,
     pub(super) selecting: bool,
+    drag_anchor: Option<(Range<usize>, fn(&str, usize) -> Range<usize>)>,
+    drag_position: Option<Point<Pixels>>,
+    drag_scrolling: bool,
     pub(super) scroll_handle: ScrollHandle,
     pub(super) should_auto_scroll: bool,
     pub(super) cursor: Entity<Cursor>,
     pub(super) masked: bool,
     pub(super) mask: SharedString,
+    pub(super) max_length: Option<usize>,
+    input_mask: Option<InputMask>,
+    pub(super) multiline: bool,
+    auto_grow: Option<(usize, usize)>,
+    paste_newlines: PasteNewlines,
+    direction: TextDirection,
+    tab_behavior: TabBehavior,
+    pub(super) read_only: bool,
+    allow: Option<Box<dyn Fn(char) -> bool>>,
+    validator: Option<Box<dyn Fn(&str) -> Result<(), SharedString>>>,
+    on_change: Option<Box<dyn Fn(&str, &mut Window, &mut Context<Self>)>>,
+    on_focus: Option<Box<dyn Fn(&mut Window, &mut Context<Self>)>>,
+    on_blur: Option<Box<dyn Fn(&mut Window, &mut Context<Self>)>>,
+    on_history_change: Option<Box<dyn Fn(&History, &mut Window, &mut Context<Self>)>>,
+    suggest: Option<Box<dyn Fn(&str) -> Vec<SharedString>>>,
+    complete_word: bool,
+    pub(super) suggestions: Suggestions,
+    error: Option<SharedString>,
+    validation_epoch: usize,
     history: History,
-    ignore_history: bool,
+    history_changed: Rc<Cell<bool>>,
+    history_idle_timeout: Option<Duration>,
+    history_flush_epoch: usize,
+    history_origin: ChangeOrigin,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -145,7 +261,8 @@ impl InputState {
     /// Create a new InputState with default values
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let cursor = cx.new(|_| Cursor::new());
-        let focus_handle = cx.focus_handle();
+        // A tab stop, so that tab and shift-tab can move focus to and from the input
+        let focus_handle = cx.focus_handle().tab_stop(true);
 
         let _subscriptions = vec![
             cx.observe(&cursor, |_, _, cx| cx.notify()),
@@ -159,27 +276,67 @@ impl InputState {
                     }
                 }
             }),
-            cx.on_focus(&focus_handle, window, Self::on_focus),
-            cx.on_blur(&focus_handle, window, Self::on_blur),
+            cx.on_focus(&focus_handle, window, Self::handle_focus),
+            cx.on_blur(&focus_handle, window, Self::handle_blur),
         ];
 
+        // The history can't reach the context, so it only flags a change for
+        // `notify_history` to report
+        let history_changed = Rc::new(Cell::new(false));
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        history.set_on_change({
+            let history_changed = history_changed.clone();
+            move |_| history_changed.set(true)
+        });
+
         Self {
             focus_handle,
             value: SharedString::new(""),
             placeholder: SharedString::new(""),
-            placeholder_color: hsla(0., 0., 0.5, 0.5),
+            placeholder_color: None,
+            placeholder_style: None,
+            hide_placeholder_on_focus: false,
+            selection_color: rgba(0x3390FF80).into(),
+            inactive_selection_color: None,
+            selected_text_color: None,
             selected_range: 0..0,
             selection_reversed: false,
             marked_range: None,
+            composing_from: None,
             last_layout: None,
             last_bounds: None,
             selecting: false,
+            drag_anchor: None,
+            drag_position: None,
+            drag_scrolling: false,
             scroll_handle: ScrollHandle::new(),
             should_auto_scroll: false,
             masked: false,
             mask: SharedString::new("•"),
-            history: History::new(),
-            ignore_history: false,
+            max_length: None,
+            input_mask: None,
+            multiline: false,
+            auto_grow: None,
+            paste_newlines: PasteNewlines::default(),
+            direction: TextDirection::default(),
+            tab_behavior: TabBehavior::default(),
+            read_only: false,
+            allow: None,
+            validator: None,
+            on_change: None,
+            on_focus: None,
+            on_blur: None,
+            on_history_change: None,
+            suggest: None,
+            complete_word: false,
+            suggestions: Suggestions::default(),
+            error: None,
+            validation_epoch: 0,
+            history,
+            history_changed,
+            history_idle_timeout: None,
+            history_flush_epoch: 0,
+            history_origin: ChangeOrigin::UserInput,
             cursor,
             _subscriptions,
         }
@@ -191,15 +348,65 @@ impl InputState {
         self
     }
 
-    /// Set the placeholder text color
+    /// Set the placeholder text color, which is the text color at half opacity by default
     pub fn placeholder_color(mut self, color: impl Into<Hsla>) -> Self {
-        self.placeholder_color = color.into();
+        self.placeholder_color = Some(color.into());
+        self
+    }
+
+    /// Style the placeholder independently of the text, such as in italics or faded out
+    ///
+    /// A color in the style takes precedence over
+    /// [`placeholder_color`](Self::placeholder_color). The line keeps its height, so
+    /// replacing the placeholder with the first character typed doesn't move anything.
+    pub fn placeholder_style(mut self, style: HighlightStyle) -> Self {
+        self.placeholder_style = Some(style);
+        self
+    }
+
+    /// Set how often the caret blinks, or keep it solid with `None`
+    ///
+    /// The caret stays solid while typing and resumes blinking once input stops.
+    pub fn cursor_blink(self, interval: Option<Duration>, cx: &mut App) -> Self {
+        self.cursor
+            .update(cx, |cursor, cx| cursor.set_interval(interval, cx));
+        self
+    }
+
+    /// Set the background of the selection while the input is focused
+    pub fn selection_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.selection_color = color.into();
+        self
+    }
+
+    /// Set the background of the selection while the input is not focused
+    ///
+    /// Defaults to the focused selection color at half its opacity.
+    pub fn inactive_selection_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.inactive_selection_color = Some(color.into());
+        self
+    }
+
+    /// Set the color of selected text, which otherwise keeps the text color
+    pub fn selected_text_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.selected_text_color = Some(color.into());
+        self
+    }
+
+    /// Set whether the placeholder is hidden while the input is focused
+    ///
+    /// By default the placeholder stays visible until the first character is typed.
+    pub fn hide_placeholder_on_focus(mut self, hide: bool) -> Self {
+        self.hide_placeholder_on_focus = hide;
         self
     }
 
     /// Set the initial value
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
         self.value = value.into();
+        if let Some(input_mask) = &self.input_mask {
+            self.value = input_mask.format(&self.value).into();
+        }
         self.history.clear();
         self
     }
@@ -251,17 +458,369 @@ impl InputState {
         self
     }
 
-    fn on_focus(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+    /// Set the maximum length of the value, in grapheme clusters
+    ///
+    /// Typing stops at the limit and pasted text is truncated to fit. The rejected text is
+    /// never applied, so it never reaches the undo history. An initial value set with
+    /// [`value`](Self::value) is not truncated.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Format the value with a pattern like `"(###) ###-####"` or `"##/##/####"`
+    ///
+    /// Only characters that fit the pattern can be entered: `#` takes a digit, `A` a letter
+    /// and `*` either. The literals between them are inserted as the slots after them are
+    /// filled, in the same undo step as the character that filled them. The value is
+    /// reformatted to fit, and [`raw_value`](Self::raw_value) returns it without the
+    /// literals.
+    pub fn input_mask(mut self, pattern: impl Into<SharedString>) -> Self {
+        let input_mask = InputMask::new(pattern);
+        self.value = input_mask.format(&self.value).into();
+        self.input_mask = Some(input_mask);
+        self
+    }
+
+    /// Let the input hold several lines
+    ///
+    /// Enter inserts a line break instead of submitting, up and down move between lines and
+    /// pasted line breaks are kept. The input is as tall as its lines, and each line is
+    /// undone on its own.
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Make the input multiline and as tall as its lines, from `min_lines` up to
+    /// `max_lines`, scrolling the lines past that
+    ///
+    /// The height follows the text, so deleting lines or undoing them shrinks the input
+    /// again. `min_lines` wins when it is larger than `max_lines`.
+    pub fn auto_grow(mut self, min_lines: usize, max_lines: usize) -> Self {
+        self.multiline = true;
+        self.auto_grow = Some((min_lines, max_lines));
+        self
+    }
+
+    /// Set what pasting does with line breaks, which a single-line input can't show
+    ///
+    /// `"\n"`, `"\r\n"` and `"\r"` each count as one line break. The default replaces them
+    /// with spaces.
+    pub fn paste_newline_behavior(mut self, behavior: PasteNewlines) -> Self {
+        self.paste_newlines = behavior;
+        self
+    }
+
+    /// Set the base direction of the text, which is detected from the value by default
+    ///
+    /// Right-to-left text is aligned to the right while it fits, and the arrow keys move
+    /// through it in the direction they point: left goes forward in the text. Home and
+    /// end still go to the start and end of the text, which are on the right and left.
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set what the tab key does, which is moving focus by default
+    ///
+    /// When tab inserts text, each press is an undo step of its own, and shift-tab removes
+    /// one level of indentation from the start of the caret's line. While the
+    /// [suggestion list](Self::suggestions) is open, tab accepts a suggestion instead.
+    pub fn tab_behavior(mut self, behavior: TabBehavior) -> Self {
+        self.tab_behavior = behavior;
+        self
+    }
+
+    /// Make the input read-only
+    ///
+    /// The text can still be focused, selected and copied, but typing, pasting, cutting,
+    /// deleting and undo are ignored and nothing is recorded in the history.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Only accept characters for which `allow` returns true
+    ///
+    /// Rejected characters are dropped as they are typed or pasted, so pasting "a1b2"
+    /// inserts "12". Text with nothing left to insert doesn't replace the selection.
+    pub fn allow(mut self, allow: impl Fn(char) -> bool + 'static) -> Self {
+        self.allow = Some(Box::new(allow));
+        self
+    }
+
+    /// Only accept digits, a sign and a decimal point
+    pub fn numeric(self) -> Self {
+        self.allow(TextOps::is_numeric)
+    }
+
+    /// Set a validator that checks the value after it changes and when the input loses focus
+    ///
+    /// Validation waits for typing to pause rather than running on every keystroke. The
+    /// returned error is available through [`error`](Self::error) until the value passes
+    /// again.
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), SharedString> + 'static,
+    ) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Call `on_change` with the new value after every edit that changes it, including
+    /// undo and redo
+    ///
+    /// IME composition is reported once, when the composed text is committed.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&str, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Call `on_focus` when the input gains focus
+    pub fn on_focus(
+        mut self,
+        on_focus: impl Fn(&mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_focus = Some(Box::new(on_focus));
+        self
+    }
+
+    /// Call `on_blur` when the input loses focus, after its history and validation are
+    /// brought up to date
+    pub fn on_blur(mut self, on_blur: impl Fn(&mut Window, &mut Context<Self>) + 'static) -> Self {
+        self.on_blur = Some(Box::new(on_blur));
+        self
+    }
+
+    /// Call `on_history_change` when the undo or redo stacks change, e.g. to enable undo and
+    /// redo buttons
+    ///
+    /// This includes an edit made after undoing, which discards the edits that could have
+    /// been redone.
+    pub fn on_history_change(
+        mut self,
+        on_history_change: impl Fn(&History, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_history_change = Some(Box::new(on_history_change));
+        self
+    }
+
+    /// Start a new undo step once typing pauses for `timeout`
+    ///
+    /// Without it, an edit only starts a new step when it isn't adjacent to the previous
+    /// one, follows a word boundary, or comes after the history's coalescing timeout.
+    pub fn history_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.history_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Offer the suggestions `suggest` returns for the value in a list below the input
+    ///
+    /// The list follows typing while the input is focused. Up and down move through it,
+    /// enter accepts the highlighted suggestion, tab the highlighted or first one, and
+    /// escape closes it. Accepting replaces the value as a single undo step.
+    pub fn suggestions(mut self, suggest: impl Fn(&str) -> Vec<SharedString> + 'static) -> Self {
+        self.suggest = Some(Box::new(suggest));
+        self
+    }
+
+    /// Complete the word before the caret instead of the whole value
+    pub fn complete_word(mut self, complete_word: bool) -> Self {
+        self.complete_word = complete_word;
+        self
+    }
+
+    /// The length of the value in grapheme clusters, the unit
+    /// [`max_length`](Self::max_length) limits
+    pub fn length(&self) -> usize {
+        TextOps::grapheme_count(&self.value)
+    }
+
+    /// The value as shown, including any [`input_mask`](Self::input_mask) literals
+    pub fn formatted_value(&self) -> &SharedString {
+        &self.value
+    }
+
+    /// The value without the [`input_mask`](Self::input_mask) literals
+    pub fn raw_value(&self) -> SharedString {
+        match &self.input_mask {
+            Some(input_mask) => input_mask.unformat(&self.value).into(),
+            None => self.value.clone(),
+        }
+    }
+
+    /// Whether the text runs right to left, see [`direction`](Self::direction)
+    pub fn is_rtl(&self) -> bool {
+        match self.direction {
+            TextDirection::Auto => TextOps::is_rtl(&self.value),
+            TextDirection::LeftToRight => false,
+            TextDirection::RightToLeft => true,
+        }
+    }
+
+    /// How many lines tall the input is
+    pub(super) fn visible_lines(&self) -> usize {
+        if !self.multiline {
+            return 1;
+        }
+        let line_count = TextOps::line_count(&self.value);
+        match self.auto_grow {
+            Some((min_lines, max_lines)) => {
+                TextOps::visible_lines(line_count, min_lines, max_lines)
+            }
+            None => line_count,
+        }
+    }
+
+    /// Whether the input ignores edits, see [`read_only`](Self::read_only)
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Whether there is an edit to undo
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Whether there is an undone edit to redo
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    /// Whether the value changed since the last [`save`](Self::save)
+    pub fn is_dirty(&self) -> bool {
+        self.history.is_dirty()
+    }
+
+    /// Mark the current value as saved, see [`History::save`]
+    pub fn save(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.history.save();
+        self.notify_history(window, cx);
+        cx.notify();
+    }
+
+    /// The undo history of the input
+    ///
+    /// Use it to inspect the undo and redo stacks without changing them, e.g. with
+    /// [`History::undo_len`] or [`History::peek_undo`].
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// Whether the value passed the last validation
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The error returned by the last validation, if it failed
+    pub fn error(&self) -> Option<&SharedString> {
+        self.error.as_ref()
+    }
+
+    /// Validate the value once typing pauses
+    fn schedule_validation(&mut self, cx: &mut Context<Self>) {
+        if self.validator.is_none() {
+            return;
+        }
+
+        self.validation_epoch += 1;
+        let epoch = self.validation_epoch;
+        cx.spawn(async move |this, cx| {
+            Timer::after(VALIDATION_DELAY).await;
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.validation_epoch == epoch {
+                        this.run_validation(cx);
+                    }
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    /// Seal the current undo step once typing pauses, see
+    /// [`history_idle_timeout`](Self::history_idle_timeout)
+    fn schedule_history_flush(&mut self, cx: &mut Context<Self>) {
+        let Some(timeout) = self.history_idle_timeout else {
+            return;
+        };
+
+        self.history_flush_epoch += 1;
+        let epoch = self.history_flush_epoch;
+        cx.spawn(async move |this, cx| {
+            Timer::after(timeout).await;
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, _| {
+                    // A composition stays one step however long picking a candidate takes
+                    if this.history_flush_epoch == epoch && this.marked_range.is_none() {
+                        this.history.flush();
+                    }
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    /// Validate the value right away, cancelling any scheduled validation
+    fn run_validation(&mut self, cx: &mut Context<Self>) {
+        self.validation_epoch += 1;
+        if let Some(validator) = &self.validator {
+            let error = validator(&self.value).err();
+            if error != self.error {
+                self.error = error;
+                cx.notify();
+            }
+        }
+    }
+
+    /// Report the value to `on_change` if an edit changed it from `previous`, or from the
+    /// value before the composition it ends
+    fn notify_change(&mut self, previous: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let composing_from = self.composing_from.take();
+        let previous = composing_from.as_deref().unwrap_or(previous);
+        if let Some(on_change) = &self.on_change
+            && previous != &*self.value
+        {
+            on_change(&self.value, window, cx);
+        }
+        self.notify_history(window, cx);
+    }
+
+    /// Report the history to `on_history_change` if it changed since the last report
+    fn notify_history(&self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.history_changed.replace(false) {
+            return;
+        }
+        if let Some(on_history_change) = &self.on_history_change {
+            on_history_change(&self.history, window, cx);
+        }
+    }
+
+    fn handle_focus(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         self.cursor.update(cx, |cursor, cx| {
             cursor.start(cx);
         });
+        if let Some(on_focus) = &self.on_focus {
+            on_focus(window, cx);
+        }
     }
 
-    fn on_blur(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        self.history.prevent_merge();
+    fn handle_blur(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.history.commit();
+        self.suggestions.dismiss();
+        self.run_validation(cx);
         self.cursor.update(cx, |cursor, cx| {
             cursor.stop(cx);
-        })
+        });
+        if let Some(on_blur) = &self.on_blur {
+            on_blur(window, cx);
+        }
     }
 
     fn pause_cursor_blink(&mut self, cx: &mut Context<Self>) {
@@ -280,11 +839,14 @@ impl InputState {
 
     /// Move cursor left by one grapheme cluster
     pub(super) fn left(&mut self, _: &Left, _: &mut Window, cx: &mut Context<Self>) {
+        let rtl = self.is_rtl();
         if self.selected_range.is_empty() {
             self.move_to(
-                TextOps::previous_boundary(&self.value, self.cursor_offset()),
+                TextOps::left_boundary(&self.value, self.cursor_offset(), rtl),
                 cx,
             );
+        } else if rtl {
+            self.move_to(self.selected_range.end, cx);
         } else {
             self.move_to(self.selected_range.start, cx);
         }
@@ -292,11 +854,14 @@ impl InputState {
 
     /// Move cursor right by one grapheme cluster
     pub(super) fn right(&mut self, _: &Right, _: &mut Window, cx: &mut Context<Self>) {
+        let rtl = self.is_rtl();
         if self.selected_range.is_empty() {
             self.move_to(
-                TextOps::next_boundary(&self.value, self.selected_range.end),
+                TextOps::right_boundary(&self.value, self.cursor_offset(), rtl),
                 cx,
             );
+        } else if rtl {
+            self.move_to(self.selected_range.start, cx);
         } else {
             self.move_to(self.selected_range.end, cx);
         }
@@ -304,12 +869,30 @@ impl InputState {
 
     /// Move cursor left by one word
     pub(super) fn word_left(&mut self, _: &WordLeft, _: &mut Window, cx: &mut Context<Self>) {
-        let new_offset = TextOps::previous_word_boundary(&self.value, self.cursor_offset());
-        self.move_to(new_offset, cx);
+        if self.is_rtl() {
+            self.move_word_right(cx);
+        } else {
+            self.move_word_left(cx);
+        }
     }
 
     /// Move cursor right by one word
     pub(super) fn word_right(&mut self, _: &WordRight, _: &mut Window, cx: &mut Context<Self>) {
+        if self.is_rtl() {
+            self.move_word_left(cx);
+        } else {
+            self.move_word_right(cx);
+        }
+    }
+
+    /// Move the caret to the start of the word before it
+    pub fn move_word_left(&mut self, cx: &mut Context<Self>) {
+        let new_offset = TextOps::previous_word_boundary(&self.value, self.cursor_offset());
+        self.move_to(new_offset, cx);
+    }
+
+    /// Move the caret to the end of the word after it
+    pub fn move_word_right(&mut self, cx: &mut Context<Self>) {
         let new_offset = TextOps::next_word_boundary(&self.value, self.cursor_offset());
         self.move_to(new_offset, cx);
     }
@@ -324,16 +907,32 @@ impl InputState {
         self.move_to(self.value.len(), cx);
     }
 
+    /// Move cursor to the same column on the line above
+    pub(super) fn up(&mut self, _: &Up, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.multiline {
+            return cx.propagate();
+        }
+        self.move_to(TextOps::line_above(&self.value, self.cursor_offset()), cx);
+    }
+
+    /// Move cursor to the same column on the line below
+    pub(super) fn down(&mut self, _: &Down, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.multiline {
+            return cx.propagate();
+        }
+        self.move_to(TextOps::line_below(&self.value, self.cursor_offset()), cx);
+    }
+
     /// Move cursor to a specific offset
     pub(super) fn move_to(&mut self, offset: usize, cx: &mut Context<Self>) {
         self.pause_cursor_blink(cx);
         let offset = offset.clamp(0, self.value.len());
-        if offset == self.cursor_offset() {
+        if self.selected_range == (offset..offset) {
             return;
         }
         self.selected_range = offset..offset;
         self.should_auto_scroll = true;
-        self.history.prevent_merge();
+        self.history.commit();
         cx.notify();
     }
 
@@ -344,7 +943,7 @@ impl InputState {
     /// Extend selection left by one grapheme cluster
     pub(super) fn select_left(&mut self, _: &SelectLeft, _: &mut Window, cx: &mut Context<Self>) {
         self.select_to(
-            TextOps::previous_boundary(&self.value, self.cursor_offset()),
+            TextOps::left_boundary(&self.value, self.cursor_offset(), self.is_rtl()),
             cx,
         );
     }
@@ -352,7 +951,7 @@ impl InputState {
     /// Extend selection right by one grapheme cluster
     pub(super) fn select_right(&mut self, _: &SelectRight, _: &mut Window, cx: &mut Context<Self>) {
         self.select_to(
-            TextOps::next_boundary(&self.value, self.cursor_offset()),
+            TextOps::right_boundary(&self.value, self.cursor_offset(), self.is_rtl()),
             cx,
         );
     }
@@ -364,8 +963,12 @@ impl InputState {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let new_offset = TextOps::previous_word_boundary(&self.value, self.cursor_offset());
-        self.history.prevent_merge();
+        let new_offset = if self.is_rtl() {
+            TextOps::next_word_boundary(&self.value, self.cursor_offset())
+        } else {
+            TextOps::previous_word_boundary(&self.value, self.cursor_offset())
+        };
+        self.history.commit();
         self.select_to(new_offset, cx);
     }
 
@@ -376,8 +979,12 @@ impl InputState {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let new_offset = TextOps::next_word_boundary(&self.value, self.cursor_offset());
-        self.history.prevent_merge();
+        let new_offset = if self.is_rtl() {
+            TextOps::previous_word_boundary(&self.value, self.cursor_offset())
+        } else {
+            TextOps::next_word_boundary(&self.value, self.cursor_offset())
+        };
+        self.history.commit();
         self.select_to(new_offset, cx);
     }
 
@@ -401,10 +1008,73 @@ impl InputState {
         self.select_to(self.value.len(), cx);
     }
 
-    /// Select all text in the input
-    pub(super) fn select_all(&mut self, _: &SelectAll, _: &mut Window, cx: &mut Context<Self>) {
-        self.move_to(0, cx);
-        self.select_to(self.value.len(), cx);
+    /// Extend selection to the same column on the line above
+    pub(super) fn select_up(&mut self, _: &SelectUp, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.multiline {
+            return cx.propagate();
+        }
+        self.select_to(TextOps::line_above(&self.value, self.cursor_offset()), cx);
+    }
+
+    /// Extend selection to the same column on the line below
+    pub(super) fn select_down(&mut self, _: &SelectDown, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.multiline {
+            return cx.propagate();
+        }
+        self.select_to(TextOps::line_below(&self.value, self.cursor_offset()), cx);
+    }
+
+    /// Select all text in the input, so that typing or pasting replaces it as one undo step
+    pub fn select_all(&mut self, cx: &mut Context<Self>) {
+        self.selected_range = 0..self.value.len();
+        self.selection_reversed = false;
+        self.should_auto_scroll = true;
+        self.history.commit();
+        cx.notify();
+    }
+
+    /// The byte offset of the caret, at the moving end of any selection
+    pub fn caret_offset(&self) -> usize {
+        self.cursor_offset()
+    }
+
+    /// Move the caret to `offset`, collapsing any selection
+    ///
+    /// Offsets are snapped like in [`set_selection`](Self::set_selection).
+    pub fn set_caret_offset(&mut self, offset: usize, cx: &mut Context<Self>) {
+        let offset = TextOps::snap_to_boundary(&self.value, offset);
+        self.move_to(offset, cx);
+    }
+
+    /// The selected byte range, which is empty at the caret
+    pub fn selection(&self) -> Range<usize> {
+        self.selected_range.clone()
+    }
+
+    /// Select `range`, with the caret at its end
+    ///
+    /// Offsets past the end are clamped and offsets inside a character snap to the
+    /// nearest grapheme boundary. Selecting doesn't change the history, but seals the
+    /// current undo step like moving the caret does.
+    pub fn set_selection(&mut self, range: Range<usize>, cx: &mut Context<Self>) {
+        self.select_range(range.start, range.end, cx);
+    }
+
+    /// Select from `anchor` to `head`, with the caret at `head`, which may come first
+    ///
+    /// Offsets are snapped like in [`set_selection`](Self::set_selection).
+    pub fn select_range(&mut self, anchor: usize, head: usize, cx: &mut Context<Self>) {
+        let (range, reversed) = TextOps::snap_selection(&self.value, anchor, head);
+        self.selected_range = range;
+        self.selection_reversed = reversed;
+        self.should_auto_scroll = true;
+        self.history.commit();
+        cx.notify();
+    }
+
+    /// Handle the select all action
+    pub(super) fn on_select_all(&mut self, _: &SelectAll, _: &mut Window, cx: &mut Context<Self>) {
+        self.select_all(cx);
     }
 
     /// Extend selection to a specific offset
@@ -424,12 +1094,24 @@ impl InputState {
         cx.notify();
     }
 
-    /// Select the word at the given offset
-    fn select_word(&mut self, offset: usize, cx: &mut Context<Self>) {
-        let start = TextOps::previous_word_boundary(&self.value, offset);
-        let end = TextOps::next_word_boundary(&self.value, offset);
-        self.selected_range = start..end;
+    /// Select the unit of text around the given offset, and keep it as the anchor that
+    /// dragging extends by whole units
+    fn select_unit(
+        &mut self,
+        offset: usize,
+        unit: fn(&str, usize) -> Range<usize>,
+        cx: &mut Context<Self>,
+    ) {
+        // Word boundaries would reveal the structure of masked text
+        let range = if self.masked {
+            0..self.value.len()
+        } else {
+            unit(&self.value, offset)
+        };
+        self.selected_range = range.clone();
         self.selection_reversed = false;
+        self.drag_anchor = Some((range, unit));
+        self.history.commit();
         cx.notify();
     }
 
@@ -439,33 +1121,69 @@ impl InputState {
 
     /// Delete character before cursor
     pub(super) fn backspace(&mut self, _: &Backspace, window: &mut Window, cx: &mut Context<Self>) {
-        if self.selected_range.is_empty() {
-            self.select_to(
-                TextOps::previous_boundary(&self.value, self.cursor_offset()),
-                cx,
-            );
-        }
-        self.replace_text_in_range(None, "", window, cx);
+        let range = if self.selected_range.is_empty() {
+            let Some(change) = Change::delete_grapheme_before(&self.value, self.cursor_offset())
+            else {
+                return;
+            };
+            Some(change.range())
+        } else {
+            None
+        };
+        self.delete_range(range, window, cx);
     }
 
     /// Delete character after cursor
     pub(super) fn delete(&mut self, _: &Delete, window: &mut Window, cx: &mut Context<Self>) {
-        if self.selected_range.is_empty() {
-            self.select_to(
-                TextOps::next_boundary(&self.value, self.cursor_offset()),
-                cx,
-            );
-        }
-        self.replace_text_in_range(None, "", window, cx);
+        let range = if self.selected_range.is_empty() {
+            let Some(change) = Change::delete_grapheme_after(&self.value, self.cursor_offset())
+            else {
+                return;
+            };
+            Some(change.range())
+        } else {
+            None
+        };
+        self.delete_range(range, window, cx);
+    }
+
+    /// Delete the given range, or the selection when `None`, keeping the caret as the
+    /// selection to restore on undo
+    fn delete_range(
+        &mut self,
+        range: Option<Range<usize>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let range_utf16 = range.map(|range| TextOps::range_to_utf16(&self.value, &range));
+        self.replace_text_in_range(range_utf16, "", window, cx);
     }
 
-    /// Paste text from clipboard
+    /// Paste text from clipboard over the selection, as an undo step of its own
     pub(super) fn paste(&mut self, _: &Paste, window: &mut Window, cx: &mut Context<Self>) {
         if let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) {
-            self.history.prevent_merge();
-            // Replace newlines with spaces for single-line input
-            self.replace_text_in_range(None, &text.replace('\n', " "), window, cx);
+            self.history.commit();
+            // Keep any composition as typed, so the paste isn't recorded as part of it
+            self.marked_range = None;
+            self.history_origin = ChangeOrigin::Paste;
+            let separator = match self.paste_newlines {
+                _ if self.multiline => "\n",
+                PasteNewlines::Space => " ",
+                PasteNewlines::Strip => "",
+            };
+            let text = TextOps::join_lines(&text, separator);
+            self.replace_text_in_range(None, &text, window, cx);
+            self.history_origin = ChangeOrigin::UserInput;
+            self.history.commit();
+        }
+    }
+
+    /// Insert a line break in a multiline input, letting enter through otherwise
+    pub(super) fn newline(&mut self, _: &Newline, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.multiline {
+            return cx.propagate();
         }
+        self.replace_text_in_range(None, "\n", window, cx);
     }
 
     /// Copy selected text to clipboard
@@ -476,77 +1194,115 @@ impl InputState {
         }
     }
 
-    /// Cut selected text to clipboard
+    /// Cut selected text to clipboard, as an undo step of its own
     pub(super) fn cut(&mut self, _: &Cut, window: &mut Window, cx: &mut Context<Self>) {
-        if !self.selected_range.is_empty() {
+        if !self.read_only && !self.selected_range.is_empty() {
             let selected_text = self.value[self.selected_range.clone()].to_string();
             cx.write_to_clipboard(ClipboardItem::new_string(selected_text));
-            self.history.prevent_merge();
+            self.history.commit();
             self.replace_text_in_range(None, "", window, cx);
+            self.history.commit();
         }
     }
 
+    /// Undo the last edit, restoring the selection from before it
+    ///
+    /// Ignored while an IME composition is in progress, since the input method still
+    /// owns the marked text.
     pub(super) fn undo(&mut self, _: &Undo, window: &mut Window, cx: &mut Context<Self>) {
-        self.ignore_history = true;
-        if let Some(change) = self.history.undo() {
-            self.replace_text_in_range(
-                Some(TextOps::range_to_utf16(&self.value, &change.range())),
-                &change.text(),
-                window,
-                cx,
-            );
-            self.selected_range = change.selection_range();
+        if self.read_only || self.marked_range.is_some() {
+            return;
+        }
+        if let Some(step) = self.history.undo() {
+            self.apply_change(&step.change, window, cx);
+            self.selected_range = step.selection;
         }
-        self.ignore_history = false;
     }
 
+    /// Redo the last undone edit, placing the caret after it
     pub(super) fn redo(&mut self, _: &Redo, window: &mut Window, cx: &mut Context<Self>) {
-        self.ignore_history = true;
-        if let Some(change) = self.history.redo() {
-            self.replace_text_in_range(
-                Some(TextOps::range_to_utf16(&self.value, &change.range())),
-                &change.text(),
-                window,
-                cx,
-            );
+        if self.read_only || self.marked_range.is_some() {
+            return;
+        }
+        if let Some(step) = self.history.redo() {
+            self.apply_change(&step.change, window, cx);
+            self.selected_range = step.selection;
         }
-        self.ignore_history = false;
     }
 
-    fn push_history(&mut self, new_text: &str, range: &Range<usize>) {
-        if self.ignore_history {
-            return;
-        }
+    /// Apply a change returned by the history to the buffer, without recording it
+    fn apply_change(&mut self, change: &Change, window: &mut Window, cx: &mut Context<Self>) {
+        self.pause_cursor_blink(cx);
+        let mut value = self.value.to_string();
+        let caret = change.apply(&mut value);
+
+        let previous = std::mem::replace(&mut self.value, value.into());
+        self.selected_range = caret..caret;
+        self.selection_reversed = false;
+        self.marked_range = None;
+        self.should_auto_scroll = true;
+        self.last_layout = None;
+        self.last_bounds = None;
+
+        self.update_scroll_offset(None, cx);
+        self.schedule_validation(cx);
+        self.suggestions.dismiss();
+        self.notify_change(&previous, window, cx);
+    }
 
+    fn push_history(&mut self, new_text: &str, range: &Range<usize>) {
         if range.start == 0 && range.end == 0 && new_text.is_empty() {
             return;
         }
 
         let marked = self.marked_range.is_some();
 
-        if range.start == range.end {
-            self.history.push(Change::Insert {
+        let change = if range.start == range.end {
+            Change::Insert {
                 range: range.clone(),
                 text: new_text.to_string().into(),
-            });
+            }
         } else if new_text.is_empty() {
-            self.history.push(Change::Delete {
+            Change::Delete {
                 range: range.clone(),
                 text: self.value[range.start..range.end].to_string().into(),
-            })
+            }
         } else {
-            self.history.push(Change::Replace {
+            Change::Replace {
                 range: range.clone(),
                 new_text: new_text.to_string().into(),
                 old_text: self.value[range.start..range.end].to_string().into(),
                 marked,
-            });
+            }
+        };
+
+        // Committing composed text replaces the marked range, so it stays with the rest
+        // of the composition.
+        let origin = if marked {
+            ChangeOrigin::Ime
+        } else {
+            self.history_origin
+        };
+        let caret = range.start + new_text.len();
+        let redo_discarded = self.history.push_entry(HistoryEntry {
+            origin,
+            selection_before: Some(self.selected_range.clone()),
+            selection_after: Some(caret..caret),
+            ..HistoryEntry::new(change)
+        });
+        // Not every push reaches the history's own callback, e.g. one made inside a
+        // transaction, so a discarded redo stack is reported on its own
+        if redo_discarded {
+            self.history_changed.set(true);
         }
     }
 
     /// Clear all text and reset state
-    pub(super) fn clear(&mut self, _: &Clear, _: &mut Window, cx: &mut Context<Self>) {
-        self.value = "".into();
+    pub(super) fn clear(&mut self, _: &Clear, window: &mut Window, cx: &mut Context<Self>) {
+        if self.read_only {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.value, "".into());
         self.selected_range = 0..0;
         self.selection_reversed = false;
         self.marked_range = None;
@@ -555,38 +1311,109 @@ impl InputState {
         self.selecting = false;
         self.should_auto_scroll = true;
         self.scroll_handle.set_offset(point(px(0.0), px(0.0)));
+        self.history.clear();
+        self.schedule_validation(cx);
+        self.notify_change(&previous, window, cx);
         cx.notify();
     }
 
-    /// Delete word to the left of cursor
+    /// Delete word to the left of cursor, as an undo step of its own
     pub(super) fn delete_word_left(
         &mut self,
         _: &DeleteWordLeft,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.selected_range.is_empty() {
-            let cursor_pos = self.cursor_offset();
-            let word_start = TextOps::previous_word_boundary(&self.value, cursor_pos);
-            self.selected_range = word_start..cursor_pos;
-        }
-        self.replace_text_in_range(None, "", window, cx);
+        let range = if self.selected_range.is_empty() {
+            let cursor = self.cursor_offset();
+            Some(TextOps::previous_word_boundary(&self.value, cursor)..cursor)
+        } else {
+            None
+        };
+        self.delete_word(range, window, cx);
     }
 
-    /// Delete word to the right of cursor
+    /// Delete word to the right of cursor, as an undo step of its own
     pub(super) fn delete_word_right(
         &mut self,
         _: &DeleteWordRight,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.selected_range.is_empty() {
-            let cursor_pos = self.cursor_offset();
-            let word_end = TextOps::next_word_boundary(&self.value, cursor_pos);
-            self.selected_range = cursor_pos..word_end;
+        let range = if self.selected_range.is_empty() {
+            let cursor = self.cursor_offset();
+            Some(cursor..TextOps::next_word_boundary(&self.value, cursor))
+        } else {
+            None
+        };
+        self.delete_word(range, window, cx);
+    }
+
+    /// Delete a word, or any other range, without merging it with the edits on either side
+    fn delete_word(
+        &mut self,
+        range: Option<Range<usize>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.history.commit();
+        self.delete_range(range, window, cx);
+        self.history.commit();
+    }
+
+    /// Delete all of the text as a single undo step, keeping the input focused
+    pub fn clear_value(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        window.focus(&self.focus_handle);
+        self.delete_word(Some(0..self.value.len()), window, cx);
+    }
+
+    /// Replace all of the text as an undo step of its own, made by the application
+    ///
+    /// The text goes through the input mask, filter and length limit like typed text,
+    /// and `on_change` is called.
+    pub fn replace_value(&mut self, value: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.history.commit();
+        self.marked_range = None;
+        self.history_origin = ChangeOrigin::Programmatic;
+        let range_utf16 = TextOps::range_to_utf16(&self.value, &(0..self.value.len()));
+        self.replace_text_in_range(Some(range_utf16), value, window, cx);
+        self.history_origin = ChangeOrigin::UserInput;
+        self.history.commit();
+    }
+
+    /// Replace the value without recording an undo step or calling `on_change`
+    ///
+    /// Like [`value`](Self::value), the history is cleared and the value is formatted by
+    /// the input mask. The caret moves to the end.
+    pub fn set_value(&mut self, value: impl Into<SharedString>, cx: &mut Context<Self>) {
+        self.value = value.into();
+        if let Some(input_mask) = &self.input_mask {
+            self.value = input_mask.format(&self.value).into();
         }
-        self.history.prevent_merge();
-        self.replace_text_in_range(None, "", window, cx);
+        self.history.clear();
+        self.selected_range = self.value.len()..self.value.len();
+        self.selection_reversed = false;
+        self.marked_range = None;
+        self.composing_from = None;
+        self.should_auto_scroll = true;
+        self.last_layout = None;
+        self.last_bounds = None;
+        cx.notify();
+    }
+
+    /// Restore an undo history taken with [`History::snapshot`], e.g. from a previous session
+    ///
+    /// The recorded changes apply to the text the snapshot was taken with, so restore that
+    /// value with [`set_value`](Self::set_value) first.
+    pub fn restore_history(
+        &mut self,
+        snapshot: HistorySnapshot,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.history.restore(snapshot);
+        self.notify_history(window, cx);
+        cx.notify();
     }
 
     /// Delete from cursor to beginning of input
@@ -617,6 +1444,166 @@ impl InputState {
         self.replace_text_in_range(None, "", window, cx);
     }
 
+    // ============================================================================
+    // Suggestions
+    // ============================================================================
+
+    /// The range a suggestion replaces: the word before the caret or the whole value
+    fn completion_range(&self) -> Range<usize> {
+        if self.complete_word {
+            TextOps::word_before(&self.value, self.cursor_offset())
+        } else {
+            0..self.value.len()
+        }
+    }
+
+    /// Refresh the suggestions for the text being completed
+    fn update_suggestions(&mut self, window: &Window) {
+        let Some(suggest) = &self.suggest else {
+            return;
+        };
+        let query = &self.value[self.completion_range()];
+        if query.is_empty() || !self.focus_handle.is_focused(window) {
+            self.suggestions.dismiss();
+        } else {
+            self.suggestions.set(suggest(query));
+        }
+    }
+
+    /// Accept the suggestion at `ix`, as an undo step of its own
+    pub(super) fn accept_suggestion(
+        &mut self,
+        ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.suggestions.highlight(ix);
+        let Some(suggestion) = self.suggestions.accept() else {
+            return;
+        };
+        let range = TextOps::range_to_utf16(&self.value, &self.completion_range());
+        self.history.commit();
+        self.replace_text_in_range(Some(range), &suggestion, window, cx);
+        self.history.commit();
+        self.suggestions.dismiss();
+        cx.notify();
+    }
+
+    pub(super) fn select_next_suggestion(
+        &mut self,
+        _: &SelectNextSuggestion,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.suggestions.is_open() {
+            return cx.propagate();
+        }
+        self.suggestions.next();
+        cx.notify();
+    }
+
+    pub(super) fn select_previous_suggestion(
+        &mut self,
+        _: &SelectPreviousSuggestion,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.suggestions.is_open() {
+            return cx.propagate();
+        }
+        self.suggestions.previous();
+        cx.notify();
+    }
+
+    /// Accept the highlighted suggestion, letting enter through when there is none
+    pub(super) fn on_accept_suggestion(
+        &mut self,
+        _: &AcceptSuggestion,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match self.suggestions.highlighted() {
+            Some(ix) => self.accept_suggestion(ix, window, cx),
+            None => cx.propagate(),
+        }
+    }
+
+    /// Accept the highlighted suggestion, or the first one, letting the key through while
+    /// the list is closed
+    pub(super) fn complete_suggestion(
+        &mut self,
+        _: &CompleteSuggestion,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.suggestions.is_open() {
+            return cx.propagate();
+        }
+        let ix = self.suggestions.highlighted().unwrap_or(0);
+        self.accept_suggestion(ix, window, cx);
+    }
+
+    pub(super) fn dismiss_suggestions(
+        &mut self,
+        _: &DismissSuggestions,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.suggestions.is_open() {
+            return cx.propagate();
+        }
+        self.suggestions.dismiss();
+        cx.notify();
+    }
+
+    // ============================================================================
+    // Tab Handling
+    // ============================================================================
+
+    /// The text tab inserts, or `None` when it moves focus
+    fn tab_text(&self) -> Option<String> {
+        match self.tab_behavior {
+            TabBehavior::MoveFocus => None,
+            TabBehavior::InsertTab => Some("\t".to_string()),
+            TabBehavior::InsertSpaces(count) => Some(" ".repeat(count)),
+        }
+    }
+
+    /// Insert indentation or move focus forward
+    pub(super) fn tab(&mut self, _: &Tab, window: &mut Window, cx: &mut Context<Self>) {
+        match self.tab_text() {
+            Some(text) => {
+                self.history.commit();
+                self.replace_text_in_range(None, &text, window, cx);
+                self.history.commit();
+            }
+            None => window.focus_next(),
+        }
+    }
+
+    /// Remove one level of indentation from the caret's line or move focus back
+    pub(super) fn backtab(&mut self, _: &Backtab, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(text) = self.tab_text() else {
+            return window.focus_prev();
+        };
+        let cursor = self.cursor_offset();
+        let line = TextOps::line_range(&self.value, cursor);
+        let indent = TextOps::outdent_range(&self.value[line.clone()], &text);
+        let range = (line.start + indent.start)..(line.start + indent.end);
+        if range.is_empty() || self.read_only {
+            return;
+        }
+        // Keep the caret where it was in the text that remains
+        let cursor = if cursor >= range.end {
+            cursor - range.len()
+        } else {
+            range.start
+        };
+        self.delete_word(Some(range), window, cx);
+        self.selected_range = cursor..cursor;
+        cx.notify();
+    }
+
     // ============================================================================
     // Mouse Event Handlers
     // ============================================================================
@@ -625,25 +1612,27 @@ impl InputState {
     pub(super) fn on_mouse_down(
         &mut self,
         event: &MouseDownEvent,
-        window: &mut Window,
+        _: &mut Window,
         cx: &mut Context<Self>,
     ) {
         self.selecting = true;
+        self.drag_position = Some(event.position);
+
+        self.drag_anchor = None;
+        let mouse_offset = self.index_for_mouse_position(event.position);
 
-        // Handle multi-click selection
+        // Handle multi-click selection: double-click selects a word, triple-click the line
         if event.click_count > 1 {
-            if event.click_count % 2 == 0 {
-                // Double-click: select word
-                self.select_word(self.index_for_mouse_position(event.position), cx);
+            let unit: fn(&str, usize) -> Range<usize> = if event.click_count == 2 {
+                TextOps::word_range
             } else {
-                // Triple-click: select all
-                self.select_all(&SelectAll, window, cx);
-            }
+                TextOps::line_range
+            };
+            self.select_unit(mouse_offset, unit, cx);
             return;
         }
 
         // Single click: position cursor or extend selection
-        let mouse_offset = self.index_for_mouse_position(event.position);
         if event.modifiers.shift {
             self.select_to(mouse_offset, cx);
         } else {
@@ -654,6 +1643,7 @@ impl InputState {
     /// Handle mouse up events
     pub(super) fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut Window, _: &mut Context<Self>) {
         self.selecting = false;
+        self.drag_position = None;
     }
 
     /// Handle mouse move events for drag selection
@@ -663,9 +1653,81 @@ impl InputState {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.selecting {
-            self.select_to(self.index_for_mouse_position(event.position), cx);
+        if !self.selecting {
+            return;
         }
+
+        self.drag_position = Some(event.position);
+        self.drag_to(event.position, cx);
+        self.schedule_drag_scroll(cx);
+    }
+
+    /// Extend the selection being dragged to the text under `position`, or to the edge of
+    /// the visible text while the drag is past it
+    fn drag_to(&mut self, mut position: Point<Pixels>, cx: &mut Context<Self>) {
+        if let Some(bounds) = self.last_bounds {
+            position.x = position.x.max(bounds.left()).min(bounds.right());
+        }
+        let mouse_offset = self.index_for_mouse_position(position);
+        match &self.drag_anchor {
+            Some((anchor, unit)) => {
+                let target = if self.masked {
+                    mouse_offset..mouse_offset
+                } else {
+                    unit(&self.value, mouse_offset)
+                };
+                let (range, reversed) = TextOps::extend_selection(anchor, &target);
+                self.selected_range = range;
+                self.selection_reversed = reversed;
+                self.should_auto_scroll = true;
+                cx.notify();
+            }
+            None => self.select_to(mouse_offset, cx),
+        }
+    }
+
+    /// Keep scrolling while a drag is held past either edge of the text
+    fn schedule_drag_scroll(&mut self, cx: &mut Context<Self>) {
+        if self.drag_scrolling || self.drag_scroll_step() == 0.0 {
+            return;
+        }
+
+        self.drag_scrolling = true;
+        cx.spawn(async move |this, cx| {
+            loop {
+                Timer::after(DRAG_SCROLL_INTERVAL).await;
+                let Ok(true) = this.update(cx, |this, cx| this.drag_scroll(cx)) else {
+                    break;
+                };
+            }
+        })
+        .detach();
+    }
+
+    /// Scroll one step towards the edge the drag is past, returning whether to continue
+    fn drag_scroll(&mut self, cx: &mut Context<Self>) -> bool {
+        let step = self.drag_scroll_step();
+        let Some(position) = self.drag_position.filter(|_| self.selecting && step != 0.0) else {
+            self.drag_scrolling = false;
+            return false;
+        };
+
+        let offset = self.scroll_handle.offset();
+        self.update_scroll_offset(Some(point(offset.x + px(step), offset.y)), cx);
+        self.drag_to(position, cx);
+        true
+    }
+
+    fn drag_scroll_step(&self) -> f32 {
+        let (Some(position), Some(bounds)) = (self.drag_position, self.last_bounds) else {
+            return 0.0;
+        };
+        TextOps::edge_scroll(
+            f32::from(position.x),
+            f32::from(bounds.left()),
+            f32::from(bounds.right()),
+            DRAG_SCROLL_MAX_STEP,
+        )
     }
 
     // ============================================================================
@@ -704,10 +1766,10 @@ impl InputState {
     fn update_scroll_offset(&mut self, offset: Option<Point<Pixels>>, cx: &mut Context<Self>) {
         let mut offset = offset.unwrap_or(self.scroll_handle.offset());
 
-        // Constrain horizontal scrolling
+        // Constrain horizontal scrolling, and vertical scrolling of the lines that don't fit
         if let (Some(layout), Some(bounds)) = (self.last_layout.as_ref(), self.last_bounds.as_ref())
         {
-            let text_width = layout.width;
+            let text_width = layout.width();
             let visible_width = bounds.size.width - px(CURSOR_WIDTH);
 
             offset.x = offset.x.max(px(0.0));
@@ -717,54 +1779,62 @@ impl InputState {
             } else {
                 offset.x = px(0.0);
             }
+
+            offset.y = offset
+                .y
+                .min(layout.height() - bounds.size.height)
+                .max(px(0.0));
         } else {
             offset.x = offset.x.max(px(0.0));
+            offset.y = offset.y.max(px(0.0));
         }
 
         // Disable vertical scrolling for single-line input
-        offset.y = px(0.0);
+        if !self.multiline {
+            offset.y = px(0.0);
+        }
 
         self.scroll_handle.set_offset(offset);
         cx.notify();
     }
 
+    /// Scroll the text so the caret is visible on the next frame
+    pub fn scroll_to_caret(&mut self, cx: &mut Context<Self>) {
+        self.should_auto_scroll = true;
+        cx.notify();
+    }
+
     /// Automatically scroll to keep cursor visible
-    pub(super) fn auto_scroll_to_cursor(&mut self, layout: &ShapedLine, bounds: Bounds<Pixels>) {
+    pub(super) fn auto_scroll_to_cursor(&mut self, layout: &TextLayout, bounds: Bounds<Pixels>) {
         if !self.should_auto_scroll {
             return;
         }
 
         self.should_auto_scroll = false;
 
-        let cursor_offset = self.display_cursor_offset();
-        let cursor_x = layout.x_for_index(cursor_offset);
+        let cursor = layout.position_for_index(self.display_cursor_offset());
         let current_scroll = self.scroll_handle.offset();
         let visible_width = bounds.size.width - px(CURSOR_WIDTH);
-        let text_width = layout.width;
-        let visible_left = current_scroll.x;
-        let visible_right = current_scroll.x + visible_width;
-
-        let mut new_scroll_x = current_scroll.x;
-
-        if cursor_x < visible_left {
-            new_scroll_x = cursor_x.max(px(0.0));
-        } else if cursor_x >= visible_right {
-            new_scroll_x = cursor_x - visible_width;
-        }
-
-        // Ensure no blank space is shown when text fits or becomes shorter
-        if text_width <= visible_width {
-            // Text fits entirely, show from beginning
-            new_scroll_x = px(0.0);
+        let new_scroll_x = px(TextOps::scroll_to_reveal(
+            f32::from(cursor.x),
+            f32::from(current_scroll.x),
+            f32::from(visible_width),
+            f32::from(layout.width()),
+        ));
+        let new_scroll_y = if self.multiline {
+            px(TextOps::scroll_to_reveal_line(
+                f32::from(cursor.y),
+                f32::from(cursor.y + layout.line_height()),
+                f32::from(current_scroll.y),
+                f32::from(bounds.size.height),
+                f32::from(layout.height()),
+            ))
         } else {
-            // Text is longer than visible area
-            // Ensure we don't scroll past the end, leaving blank space
-            let max_scroll = text_width - visible_width;
-            new_scroll_x = new_scroll_x.min(max_scroll).max(px(0.0));
-        }
+            current_scroll.y
+        };
 
-        if new_scroll_x != current_scroll.x {
-            let new_offset = point(new_scroll_x, current_scroll.y);
+        let new_offset = point(new_scroll_x, new_scroll_y);
+        if new_offset != current_scroll {
             self.scroll_handle.set_offset(new_offset);
         }
     }
@@ -787,6 +1857,17 @@ impl InputState {
         self.actual_to_display_offset(self.cursor_offset())
     }
 
+    /// Get the selection background for the current focus state
+    pub(super) fn selection_background(&self, focused: bool) -> Hsla {
+        if focused {
+            return self.selection_color;
+        }
+        self.inactive_selection_color.unwrap_or(Hsla {
+            a: self.selection_color.a * 0.5,
+            ..self.selection_color
+        })
+    }
+
     /// Convert actual text range to display text range for masked inputs
     pub(super) fn display_selection_range(&self) -> std::ops::Range<usize> {
         let start = self.actual_to_display_offset(self.selected_range.start);
@@ -794,36 +1875,26 @@ impl InputState {
         start..end
     }
 
+    /// Convert the marked range to display text coordinates, where composed text is
+    /// shown as typed even in masked inputs
+    pub(super) fn display_marked_range(&self) -> Option<Range<usize>> {
+        self.marked_range.as_ref().map(|range| {
+            self.actual_to_display_offset(range.start)..self.actual_to_display_offset(range.end)
+        })
+    }
+
     /// Convert actual text offset to display text offset
     fn actual_to_display_offset(&self, actual_offset: usize) -> usize {
         if !self.masked {
             return actual_offset;
         }
 
-        if let Some(marked_range) = &self.marked_range {
-            if actual_offset <= marked_range.start {
-                // Before marked range: count graphemes and multiply by mask length
-                let grapheme_count = self.value[..actual_offset].graphemes(true).count();
-                grapheme_count * self.mask.len()
-            } else if actual_offset <= marked_range.end {
-                // Inside marked range: masked graphemes before + unmarked bytes within
-                let before_graphemes = self.value[..marked_range.start].graphemes(true).count();
-                before_graphemes * self.mask.len() + (actual_offset - marked_range.start)
-            } else {
-                // After marked range: before masked + marked bytes + after masked
-                let before_graphemes = self.value[..marked_range.start].graphemes(true).count();
-                let after_graphemes = self.value[marked_range.end..actual_offset]
-                    .graphemes(true)
-                    .count();
-                before_graphemes * self.mask.len()
-                    + (marked_range.end - marked_range.start)
-                    + after_graphemes * self.mask.len()
-            }
-        } else {
-            // No marked text: count graphemes and multiply by mask length
-            let grapheme_count = self.value[..actual_offset].graphemes(true).count();
-            grapheme_count * self.mask.len()
-        }
+        TextOps::offset_to_masked(
+            &self.value,
+            actual_offset,
+            self.mask.len(),
+            self.marked_range.as_ref(),
+        )
     }
 
     /// Calculate text index for mouse position
@@ -832,71 +1903,46 @@ impl InputState {
             return 0;
         }
 
-        let (Some(bounds), Some(line)) = (self.last_bounds.as_ref(), self.last_layout.as_ref())
+        let (Some(bounds), Some(layout)) = (self.last_bounds.as_ref(), self.last_layout.as_ref())
         else {
             return 0;
         };
 
-        if position.y < bounds.top() {
+        // Past a multiline input's edges the caret goes to the nearest line instead
+        if !self.multiline && position.y < bounds.top() {
             return 0;
         }
-        if position.y > bounds.bottom() {
+        if !self.multiline && position.y > bounds.bottom() {
             return self.value.len();
         }
 
-        let scroll_offset = self.scroll_handle.offset();
-        let display_index = line.closest_index_for_x(position.x - bounds.left() + scroll_offset.x);
-        self.display_to_actual_offset(display_index)
+        let position = position - bounds.origin + self.text_offset(layout, *bounds);
+        self.display_to_actual_offset(layout.index_for_position(position))
+    }
+
+    /// How far the text is shifted left of the bounds: the scroll offset, less the space
+    /// right-to-left text is aligned over while it fits
+    pub(super) fn text_offset(&self, layout: &TextLayout, bounds: Bounds<Pixels>) -> Point<Pixels> {
+        let align = TextOps::align_offset(
+            self.is_rtl(),
+            f32::from(layout.width()),
+            f32::from(bounds.size.width - px(CURSOR_WIDTH)),
+        );
+        self.scroll_handle.offset() - point(px(align), px(0.0))
     }
 
     /// Convert display text offset back to actual text offset
     fn display_to_actual_offset(&self, display_offset: usize) -> usize {
-        if !self.masked || self.mask.is_empty() {
+        if !self.masked {
             return display_offset;
         }
 
-        let mask_len = self.mask.len();
-
-        if let Some(marked_range) = &self.marked_range {
-            let before_graphemes = self.value[..marked_range.start].graphemes(true).count();
-            let masked_before_end = before_graphemes * mask_len;
-            let marked_end = masked_before_end + (marked_range.end - marked_range.start);
-
-            if display_offset <= masked_before_end {
-                // In masked text before marked range - find grapheme boundary
-                let target_grapheme = display_offset / mask_len;
-                TextOps::grapheme_offset_to_byte_offset(
-                    &self.value,
-                    target_grapheme.min(before_graphemes),
-                )
-            } else if display_offset <= marked_end {
-                // In unmarked marked range
-                marked_range.start + (display_offset - masked_before_end)
-            } else {
-                // In masked text after marked range - find grapheme boundary
-                let after_display = display_offset - marked_end;
-                let target_after_grapheme = after_display / mask_len;
-                let after_graphemes = self.value[marked_range.end..].graphemes(true).count();
-                let actual_after_grapheme = target_after_grapheme.min(after_graphemes);
-
-                // Convert grapheme index to byte offset from marked_range.end
-                let after_byte_offset = self.value[marked_range.end..]
-                    .grapheme_indices(true)
-                    .nth(actual_after_grapheme)
-                    .map(|(i, _)| i)
-                    .unwrap_or(self.value.len() - marked_range.end);
-
-                marked_range.end + after_byte_offset
-            }
-        } else {
-            // No marked text: find grapheme boundary
-            let target_grapheme = display_offset / mask_len;
-            let total_graphemes = self.value.graphemes(true).count();
-            TextOps::grapheme_offset_to_byte_offset(
-                &self.value,
-                target_grapheme.min(total_graphemes),
-            )
-        }
+        TextOps::offset_from_masked(
+            &self.value,
+            display_offset,
+            self.mask.len(),
+            self.marked_range.as_ref(),
+        )
     }
 }
 
@@ -931,17 +1977,25 @@ impl EntityInputHandler for InputState {
             .map(|range| TextOps::range_to_utf16(&self.value, range))
     }
 
-    fn unmark_text(&mut self, _: &mut Window, _: &mut Context<Self>) {
+    fn unmark_text(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         self.marked_range = None;
+        // The composition is committed as composed
+        if let Some(composing_from) = self.composing_from.clone() {
+            self.notify_change(&composing_from, window, cx);
+        }
     }
 
     fn replace_text_in_range(
         &mut self,
         range_utf16: Option<Range<usize>>,
         new_text: &str,
-        _: &mut Window,
+        window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if self.read_only {
+            return;
+        }
+
         self.pause_cursor_blink(cx);
         let range = range_utf16
             .as_ref()
@@ -949,7 +2003,36 @@ impl EntityInputHandler for InputState {
             .or(self.marked_range.clone())
             .unwrap_or(self.selected_range.clone());
 
-        self.push_history(new_text, &range);
+        let (range, new_text, new_cursor_pos) = if let Some(input_mask) = &self.input_mask {
+            // The separators the edit adds or removes arrive as part of the same change
+            let Some(edit) = input_mask.edit(&self.value, range, new_text) else {
+                return;
+            };
+            (edit.range, edit.text, edit.cursor)
+        } else {
+            let filtered = match &self.allow {
+                Some(allow) => TextOps::filter(new_text, allow),
+                None => new_text.into(),
+            };
+            // Typing or pasting only rejected characters leaves the selection in place, and
+            // committing them removes the composed text
+            if !new_text.is_empty() && filtered.is_empty() && self.marked_range.is_none() {
+                return;
+            }
+            let new_text = match self.max_length {
+                Some(max_length) => {
+                    TextOps::truncate_to_fit(&self.value, &range, &filtered, max_length)
+                }
+                None => &filtered,
+            };
+            if new_text.is_empty() && range.is_empty() && self.marked_range.is_none() {
+                return;
+            }
+            let new_cursor_pos = range.start + new_text.len();
+            (range, new_text.to_string(), new_cursor_pos)
+        };
+
+        self.push_history(&new_text, &range);
 
         let new_value = format!(
             "{}{}{}",
@@ -958,8 +2041,7 @@ impl EntityInputHandler for InputState {
             &self.value[range.end..]
         );
 
-        self.value = new_value.into();
-        let new_cursor_pos = range.start + new_text.len();
+        let previous = std::mem::replace(&mut self.value, new_value.into());
         self.selected_range = new_cursor_pos..new_cursor_pos;
         self.marked_range = None;
         self.should_auto_scroll = true;
@@ -967,6 +2049,10 @@ impl EntityInputHandler for InputState {
         self.last_bounds = None;
 
         self.update_scroll_offset(None, cx);
+        self.schedule_validation(cx);
+        self.schedule_history_flush(cx);
+        self.update_suggestions(window);
+        self.notify_change(&previous, window, cx);
     }
 
     fn replace_and_mark_text_in_range(
@@ -974,16 +2060,26 @@ impl EntityInputHandler for InputState {
         range_utf16: Option<Range<usize>>,
         new_text: &str,
         new_selected_range_utf16: Option<Range<usize>>,
-        _: &mut Window,
+        window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if self.read_only {
+            return;
+        }
+        // Composed text can't be fitted to a mask until it is committed, so commit it now
+        if self.input_mask.is_some() {
+            return self.replace_text_in_range(range_utf16, new_text, window, cx);
+        }
+
         let range = range_utf16
             .as_ref()
             .map(|range_utf16| TextOps::range_from_utf16(&self.value, range_utf16))
             .or(self.marked_range.clone())
             .unwrap_or(self.selected_range.clone());
 
+        self.history_origin = ChangeOrigin::Ime;
         self.push_history(new_text, &range);
+        self.history_origin = ChangeOrigin::UserInput;
 
         let new_value = format!(
             "{}{}{}",
@@ -992,7 +2088,7 @@ impl EntityInputHandler for InputState {
             &self.value[range.end..]
         );
 
-        self.value = new_value.into();
+        let previous = std::mem::replace(&mut self.value, new_value.into());
 
         if !new_text.is_empty() {
             self.marked_range = Some(range.start..range.start + new_text.len());
@@ -1009,7 +2105,10 @@ impl EntityInputHandler for InputState {
                 new_pos..new_pos
             });
 
+        // The composition is reported once it is committed
+        self.composing_from.get_or_insert(previous);
         self.should_auto_scroll = true;
+        self.notify_history(window, cx);
         cx.notify();
     }
 
@@ -1022,16 +2121,12 @@ impl EntityInputHandler for InputState {
     ) -> Option<Bounds<Pixels>> {
         let last_layout = self.last_layout.as_ref()?;
         let range = TextOps::range_from_utf16(&self.value, &range_utf16);
+        let start = last_layout.position_for_index(range.start);
+        let end = last_layout.position_for_index(range.end);
 
         Some(Bounds::from_corners(
-            point(
-                bounds.left() + last_layout.x_for_index(range.start),
-                bounds.top(),
-            ),
-            point(
-                bounds.left() + last_layout.x_for_index(range.end),
-                bounds.bottom(),
-            ),
+            bounds.origin + start,
+            bounds.origin + end + point(px(0.0), last_layout.line_height()),
         ))
     }
 
@@ -1041,10 +2136,12 @@ impl EntityInputHandler for InputState {
         _: &mut Window,
         _: &mut Context<Self>,
     ) -> Option<usize> {
-        let line_point = self.last_bounds?.localize(&point)?;
+        let last_bounds = self.last_bounds?;
+        let line_point = last_bounds.localize(&point)?;
         let last_layout = self.last_layout.as_ref()?;
 
-        let utf8_index = last_layout.index_for_x(point.x - line_point.x)?;
+        let utf8_index =
+            last_layout.index_for_position(line_point + self.text_offset(last_layout, last_bounds));
         Some(TextOps::offset_to_utf16(&self.value, utf8_index))
     }
 }
@@ -1062,6 +2159,7 @@ impl Render for InputState {
             .flex_1()
             .flex_grow()
             .overflow_x_hidden()
+            .when(self.multiline, |this| this.overflow_hidden())
             .child(TextElement::new(cx.entity().clone()))
     }
 }
@@ -0,0 +1,77 @@
+use gpui::SharedString;
+
+/// The autocomplete list shown below an input and the item highlighted in it
+///
+/// Navigation wraps around at either end. The list is closed while it has no items, and
+/// stays closed after being dismissed until new items are set.
+#[derive(Default)]
+pub struct Suggestions {
+    items: Vec<SharedString>,
+    highlighted: Option<usize>,
+    open: bool,
+}
+
+impl Suggestions {
+    /// Replace the items, opening the list when there are any and clearing the highlight
+    pub fn set(&mut self, items: Vec<SharedString>) {
+        self.open = !items.is_empty();
+        self.items = items;
+        self.highlighted = None;
+    }
+
+    /// Close the list without accepting anything
+    pub fn dismiss(&mut self) {
+        self.open = false;
+        self.highlighted = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn items(&self) -> &[SharedString] {
+        if self.open { &self.items } else { &[] }
+    }
+
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted.filter(|_| self.open)
+    }
+
+    /// Highlight the next item, or the first one when nothing is highlighted
+    pub fn next(&mut self) {
+        if !self.open {
+            return;
+        }
+        self.highlighted = Some(match self.highlighted {
+            Some(ix) => (ix + 1) % self.items.len(),
+            None => 0,
+        });
+    }
+
+    /// Highlight the previous item, or the last one when nothing is highlighted
+    pub fn previous(&mut self) {
+        if !self.open {
+            return;
+        }
+        self.highlighted = Some(match self.highlighted {
+            Some(0) | None => self.items.len() - 1,
+            Some(ix) => ix - 1,
+        });
+    }
+
+    /// Highlight the item at `ix`, ignoring indices past the end
+    pub fn highlight(&mut self, ix: usize) {
+        if self.open && ix < self.items.len() {
+            self.highlighted = Some(ix);
+        }
+    }
+
+    /// Close the list and return the highlighted item, if any
+    pub fn accept(&mut self) -> Option<SharedString> {
+        let item = self.highlighted().map(|ix| self.items[ix].clone());
+        if item.is_some() {
+            self.dismiss();
+        }
+        item
+    }
+}
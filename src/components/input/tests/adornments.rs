@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod adornments {
+    use crate::components::input::tests::support::text_input;
+    use gpui::{
+        Bounds, InteractiveElement, IntoElement, Modifiers, ParentElement, Pixels, Styled,
+        TestAppContext, VisualTestContext, div, point, px,
+    };
+
+    fn adornment(selector: &'static str, width: f32) -> impl IntoElement {
+        div()
+            .debug_selector(move || selector.into())
+            .w(px(width))
+            .h(px(16.0))
+            .child(selector)
+    }
+
+    fn bounds(cx: &mut VisualTestContext, selector: &'static str) -> Bounds<Pixels> {
+        cx.debug_bounds(selector).expect("adornment is rendered")
+    }
+
+    #[gpui::test]
+    fn text_is_inset_by_the_adornments(cx: &mut TestAppContext) {
+        let (state, cx) = text_input(
+            cx,
+            |state| state.value("100"),
+            |input| {
+                input
+                    .w(px(300.0))
+                    .prefix(adornment("prefix", 30.0))
+                    .suffix(adornment("suffix", 20.0))
+            },
+        );
+        let text = state.read_with(cx, |state, _| state.last_bounds.unwrap());
+        assert_eq!(text.left(), bounds(cx, "prefix").right());
+        assert_eq!(text.right(), bounds(cx, "suffix").left());
+        assert_eq!(text.size.width, px(250.0));
+
+        // Offsets start at the text, not at the prefix
+        cx.simulate_click(point(text.left(), text.center().y), Modifiers::none());
+        assert_eq!(state.read_with(cx, |state, _| state.caret_offset()), 0);
+        cx.simulate_click(
+            point(text.right() - px(1.0), text.center().y),
+            Modifiers::none(),
+        );
+        assert_eq!(state.read_with(cx, |state, _| state.caret_offset()), 3);
+    }
+
+    #[gpui::test]
+    fn clicking_an_adornment_focuses_the_text(cx: &mut TestAppContext) {
+        let (state, cx) = text_input(
+            cx,
+            |state| state,
+            |input| input.prefix(adornment("prefix", 30.0)),
+        );
+        cx.update(|window, _| window.blur());
+
+        cx.simulate_click(bounds(cx, "prefix").center(), Modifiers::none());
+        assert!(cx.update(|window, cx| state.read(cx).focus_handle.is_focused(window)));
+    }
+
+    #[gpui::test]
+    fn adornments_can_keep_their_clicks(cx: &mut TestAppContext) {
+        let (state, cx) = text_input(
+            cx,
+            |state| state,
+            |input| {
+                input
+                    .prefix(adornment("prefix", 30.0))
+                    .adornments_focus(false)
+            },
+        );
+        cx.update(|window, _| window.blur());
+
+        cx.simulate_click(bounds(cx, "prefix").center(), Modifiers::none());
+        assert!(!cx.update(|window, cx| state.read(cx).focus_handle.is_focused(window)));
+    }
+}
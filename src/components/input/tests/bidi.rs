@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod bidi {
+    use crate::components::input::text_ops::TextOps;
+
+    /// Press an arrow key repeatedly from `offset`, collecting the caret offsets
+    fn walk(text: &str, mut offset: usize, rtl: bool, rightwards: bool) -> Vec<usize> {
+        let mut offsets = vec![offset];
+        loop {
+            let next = if rightwards {
+                TextOps::right_boundary(text, offset, rtl)
+            } else {
+                TextOps::left_boundary(text, offset, rtl)
+            };
+            if next == offset {
+                return offsets;
+            }
+            offset = next;
+            offsets.push(offset);
+        }
+    }
+
+    #[test]
+    fn detects_base_direction() {
+        assert!(!TextOps::is_rtl("hello"));
+        assert!(TextOps::is_rtl("שלום"));
+        assert!(TextOps::is_rtl("مرحبا"));
+        assert!(!TextOps::is_rtl("abc שלום"));
+        assert!(TextOps::is_rtl("שלום abc"));
+        assert!(TextOps::is_rtl("123, שלום"));
+        assert!(!TextOps::is_rtl("123"));
+        assert!(!TextOps::is_rtl(""));
+    }
+
+    #[test]
+    fn right_arrow_walks_mixed_ltr_text_in_logical_order() {
+        // Each Hebrew letter is two bytes
+        let text = "ab שלום c";
+        assert_eq!(
+            walk(text, 0, false, true),
+            vec![0, 1, 2, 3, 5, 7, 9, 11, 12, 13]
+        );
+        assert_eq!(walk(text, 13, false, false).last(), Some(&0));
+    }
+
+    #[test]
+    fn left_arrow_walks_mixed_rtl_text_in_logical_order() {
+        let text = "שלום ab";
+        assert_eq!(walk(text, 0, true, false), vec![0, 2, 4, 6, 8, 9, 10, 11]);
+        assert_eq!(walk(text, 11, true, true), vec![11, 10, 9, 8, 6, 4, 2, 0]);
+    }
+
+    #[test]
+    fn rtl_movement_keeps_graphemes_whole() {
+        // Hebrew letter with a vowel point, then a plain letter
+        let text = "\u{05D1}\u{05B0}\u{05D2}";
+        assert_eq!(walk(text, 0, true, false), vec![0, 4, 6]);
+    }
+
+    #[test]
+    fn rtl_text_aligns_right_while_it_fits() {
+        assert_eq!(TextOps::align_offset(true, 40.0, 100.0), 60.0);
+        assert_eq!(TextOps::align_offset(true, 140.0, 100.0), 0.0);
+        assert_eq!(TextOps::align_offset(false, 40.0, 100.0), 0.0);
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod blink {
+    use crate::components::input::blink::Blink;
+    use std::time::Duration;
+
+    const INTERVAL: Option<Duration> = Some(Duration::from_millis(500));
+
+    #[test]
+    fn ticks_toggle_the_caret() {
+        let mut blink = Blink::new(INTERVAL);
+        let epoch = blink.start().unwrap();
+        assert!(blink.visible());
+
+        let epoch = blink.tick(epoch).unwrap();
+        assert!(!blink.visible());
+        blink.tick(epoch).unwrap();
+        assert!(blink.visible());
+    }
+
+    #[test]
+    fn stale_ticks_are_ignored() {
+        let mut blink = Blink::new(INTERVAL);
+        let first = blink.start().unwrap();
+        let second = blink.start().unwrap();
+
+        assert_eq!(blink.tick(first), None);
+        assert!(blink.visible());
+        assert!(blink.tick(second).is_some());
+
+        let epoch = blink.start().unwrap();
+        blink.stop();
+        assert_eq!(blink.tick(epoch), None);
+        assert!(!blink.visible());
+    }
+
+    #[test]
+    fn typing_keeps_the_caret_solid() {
+        let mut blink = Blink::new(INTERVAL);
+        let epoch = blink.start().unwrap();
+        let epoch = blink.tick(epoch).unwrap();
+        assert!(!blink.visible());
+
+        // Each keystroke pauses, and only the last pause resumes
+        let first = blink.pause();
+        assert!(blink.visible());
+        assert_eq!(blink.tick(epoch), None);
+        let second = blink.pause();
+        assert_eq!(blink.resume(first), None);
+        assert!(blink.visible());
+
+        let epoch = blink.resume(second).unwrap();
+        assert!(blink.visible());
+        blink.tick(epoch).unwrap();
+        assert!(!blink.visible());
+    }
+
+    #[test]
+    fn no_interval_keeps_the_caret_solid() {
+        let mut blink = Blink::new(None);
+        assert_eq!(blink.start(), None);
+        assert!(blink.visible());
+        assert_eq!(blink.tick(1), None);
+
+        let pause = blink.pause();
+        assert_eq!(blink.resume(pause), None);
+        assert!(blink.visible());
+    }
+
+    #[test]
+    fn changing_the_interval_restarts_the_blink() {
+        let mut blink = Blink::new(INTERVAL);
+        let epoch = blink.start().unwrap();
+
+        assert_eq!(blink.set_interval(None), None);
+        assert_eq!(blink.tick(epoch), None);
+        assert!(blink.visible());
+
+        let epoch = blink.set_interval(INTERVAL).unwrap();
+        blink.tick(epoch).unwrap();
+        assert!(!blink.visible());
+
+        // Not focused, so nothing to schedule until it starts
+        let mut blink = Blink::new(None);
+        assert_eq!(blink.set_interval(INTERVAL), None);
+    }
+}
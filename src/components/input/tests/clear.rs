@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod clear {
+    use crate::components::input::tests::support::{text_input, value};
+    use gpui::{Modifiers, TestAppContext};
+
+    #[gpui::test]
+    fn clear_button_empties_in_one_step(cx: &mut TestAppContext) {
+        let (state, cx) = text_input(cx, |state| state, |input| input.clearable(true));
+        cx.simulate_input("hello world");
+
+        let bounds = cx.debug_bounds("clear").expect("clear button is shown");
+        cx.simulate_click(bounds.center(), Modifiers::none());
+        assert_eq!(value(&state, cx), "");
+        assert!(cx.update(|window, cx| state.read(cx).focus_handle.is_focused(window)));
+
+        // Nothing left to clear
+        assert!(cx.debug_bounds("clear").is_none());
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "hello world");
+    }
+}
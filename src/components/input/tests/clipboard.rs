@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod clipboard {
+    use crate::components::input::tests::support::{input, selection, value};
+    use gpui::{ClipboardItem, TestAppContext};
+
+    fn clipboard_text(cx: &TestAppContext) -> Option<String> {
+        cx.read_from_clipboard().and_then(|item| item.text())
+    }
+
+    #[gpui::test]
+    fn copy_leaves_the_value_alone(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("hello world"));
+        state.update(cx, |state, cx| state.set_selection(6..11, cx));
+
+        cx.simulate_keystrokes("cmd-c");
+        assert_eq!(clipboard_text(cx).as_deref(), Some("world"));
+        assert_eq!(value(&state, cx), "hello world");
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+    }
+
+    #[gpui::test]
+    fn cut_is_one_step_apart_from_typing(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        cx.simulate_input("hello world");
+        state.update(cx, |state, cx| state.set_selection(5..11, cx));
+
+        cx.simulate_keystrokes("cmd-x");
+        assert_eq!(clipboard_text(cx).as_deref(), Some(" world"));
+        assert_eq!(value(&state, cx), "hello");
+        cx.simulate_input("!");
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "hello");
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "hello world");
+        assert_eq!(selection(&state, cx), 5..11);
+    }
+
+    #[gpui::test]
+    fn paste_inserts_at_the_caret(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        cx.simulate_input("ab");
+        cx.write_to_clipboard(ClipboardItem::new_string("xyz".into()));
+
+        cx.simulate_keystrokes("cmd-v");
+        assert_eq!(value(&state, cx), "abxyz");
+        assert_eq!(selection(&state, cx), 5..5);
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "ab");
+    }
+
+    #[gpui::test]
+    fn paste_replaces_the_selection_in_one_step(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("hello world"));
+        state.update(cx, |state, cx| state.set_selection(0..5, cx));
+        cx.write_to_clipboard(ClipboardItem::new_string("goodbye".into()));
+
+        cx.simulate_keystrokes("cmd-v");
+        assert_eq!(value(&state, cx), "goodbye world");
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "hello world");
+        assert_eq!(selection(&state, cx), 0..5);
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+    }
+
+    #[gpui::test]
+    fn paste_never_merges_with_typing(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        cx.write_to_clipboard(ClipboardItem::new_string("pasted".into()));
+
+        cx.simulate_input("ab");
+        cx.simulate_keystrokes("cmd-v");
+        cx.simulate_input("cd");
+        assert_eq!(value(&state, cx), "abpastedcd");
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "abpasted");
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "ab");
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "");
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod drag {
+    use crate::components::input::text_ops::TextOps;
+
+    /// Measure text as if every byte were 10px wide
+    fn monospace(index: usize) -> f32 {
+        index as f32 * 10.0
+    }
+
+    #[test]
+    fn x_maps_to_nearest_boundary() {
+        let text = "hello";
+        assert_eq!(TextOps::offset_for_x(text, 0.0, monospace), 0);
+        assert_eq!(TextOps::offset_for_x(text, 4.0, monospace), 0);
+        assert_eq!(TextOps::offset_for_x(text, 6.0, monospace), 1);
+        assert_eq!(TextOps::offset_for_x(text, 23.0, monospace), 2);
+        assert_eq!(TextOps::offset_for_x(text, 48.0, monospace), 5);
+    }
+
+    #[test]
+    fn x_past_the_text_clamps() {
+        assert_eq!(TextOps::offset_for_x("hello", -15.0, monospace), 0);
+        assert_eq!(TextOps::offset_for_x("hello", 500.0, monospace), 5);
+        assert_eq!(TextOps::offset_for_x("", 30.0, monospace), 0);
+    }
+
+    #[test]
+    fn x_never_splits_a_grapheme() {
+        // "e" and a combining acute accent, 3 bytes drawn as one 10px glyph
+        let text = "ae\u{301}b";
+        let x_for_index = |index: usize| match index {
+            0 => 0.0,
+            1 => 10.0,
+            4 => 20.0,
+            _ => 30.0,
+        };
+        assert_eq!(TextOps::offset_for_x(text, 14.0, x_for_index), 1);
+        assert_eq!(TextOps::offset_for_x(text, 16.0, x_for_index), 4);
+        assert_eq!(TextOps::offset_for_x(text, 29.0, x_for_index), 5);
+    }
+
+    #[test]
+    fn drag_selects_between_press_and_release() {
+        let text = "select this text";
+        let anchor = TextOps::offset_for_x(text, 72.0, monospace);
+        let anchor = anchor..anchor;
+
+        let target = TextOps::offset_for_x(text, 109.0, monospace);
+        assert_eq!(
+            TextOps::extend_selection(&anchor, &(target..target)),
+            (7..11, false)
+        );
+
+        let target = TextOps::offset_for_x(text, 21.0, monospace);
+        assert_eq!(
+            TextOps::extend_selection(&anchor, &(target..target)),
+            (2..7, true)
+        );
+    }
+
+    #[test]
+    fn edge_scroll_past_either_side() {
+        assert_eq!(TextOps::edge_scroll(50.0, 0.0, 100.0, 20.0), 0.0);
+        assert_eq!(TextOps::edge_scroll(105.0, 0.0, 100.0, 20.0), 5.0);
+        assert_eq!(TextOps::edge_scroll(180.0, 0.0, 100.0, 20.0), 20.0);
+        assert_eq!(TextOps::edge_scroll(-3.0, 0.0, 100.0, 20.0), -3.0);
+        assert_eq!(TextOps::edge_scroll(-90.0, 0.0, 100.0, 20.0), -20.0);
+    }
+}
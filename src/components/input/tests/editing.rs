@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod editing {
+    use crate::components::input::tests::support::{input, selection, value};
+    use gpui::{EntityInputHandler, TestAppContext};
+
+    #[gpui::test]
+    fn typing_over_select_all_is_one_step(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("hello world"));
+
+        cx.simulate_keystrokes("cmd-a");
+        assert_eq!(selection(&state, cx), 0..11);
+        cx.simulate_input("x");
+        assert_eq!(value(&state, cx), "x");
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "hello world");
+        assert_eq!(selection(&state, cx), 0..11);
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+    }
+
+    #[gpui::test]
+    fn deleting_a_word_is_one_step(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("the quick brown fox"));
+        state.update(cx, |state, cx| state.set_caret_offset(9, cx));
+
+        cx.simulate_keystrokes("alt-backspace");
+        assert_eq!(value(&state, cx), "the  brown fox");
+        cx.simulate_keystrokes("alt-delete");
+        assert_eq!(value(&state, cx), "the  fox");
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "the  brown fox");
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "the quick brown fox");
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+    }
+
+    #[gpui::test]
+    fn deleting_a_word_keeps_apart_from_typing(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        cx.simulate_input("quick brown");
+        cx.simulate_keystrokes("alt-backspace");
+        assert_eq!(value(&state, cx), "quick ");
+
+        // Only the word comes back, the typing before it is still there
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "quick brown");
+        assert_eq!(selection(&state, cx), 11..11);
+    }
+
+    #[gpui::test]
+    fn backspace_removes_whole_graphemes(cx: &mut TestAppContext) {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("cafe\u{301} {family}");
+        let (state, cx) = input(cx, |state| state.value(text.clone()));
+        state.update(cx, |state, cx| state.set_caret_offset(text.len(), cx));
+
+        cx.simulate_keystrokes("backspace");
+        assert_eq!(value(&state, cx), "cafe\u{301} ");
+        cx.simulate_keystrokes("backspace backspace");
+        assert_eq!(value(&state, cx), "caf");
+
+        // The removed clusters come back intact
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), text);
+    }
+
+    #[gpui::test]
+    fn delete_removes_a_whole_flag(cx: &mut TestAppContext) {
+        let flags = "\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}";
+        let (state, cx) = input(cx, |state| state.value(flags));
+        state.update(cx, |state, cx| state.set_caret_offset(0, cx));
+
+        cx.simulate_keystrokes("delete");
+        assert_eq!(value(&state, cx), "\u{1F1E9}\u{1F1EA}");
+        assert_eq!(selection(&state, cx), 0..0);
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), flags);
+    }
+
+    #[gpui::test]
+    fn typing_over_a_selection_is_one_replace(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("hello world"));
+        state.update(cx, |state, cx| state.set_selection(6..11, cx));
+
+        state.update_in(cx, |state, window, cx| {
+            state.replace_text_in_range(None, "t", window, cx);
+        });
+        cx.simulate_input("here");
+        assert_eq!(value(&state, cx), "hello there");
+        assert_eq!(selection(&state, cx), 11..11);
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "hello world");
+        assert_eq!(selection(&state, cx), 6..11);
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+
+        cx.simulate_keystrokes("cmd-shift-z");
+        assert_eq!(value(&state, cx), "hello there");
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod events {
+    use crate::components::input::{
+        InputState, Undo,
+        tests::support::{input, value},
+    };
+    use gpui::{ClipboardItem, EntityInputHandler, TestAppContext};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[gpui::test]
+    fn history_change_reports_discarded_redo(cx: &mut TestAppContext) {
+        let can_redo = Rc::new(RefCell::new(Vec::new()));
+        let (state, cx) = input(cx, |state| {
+            let can_redo = can_redo.clone();
+            state.on_history_change(move |history, _, _| {
+                can_redo.borrow_mut().push(history.can_redo())
+            })
+        });
+
+        cx.simulate_input("a");
+        cx.dispatch_action(Undo);
+        assert_eq!(value(&state, cx), "");
+        assert_eq!(*can_redo.borrow(), [false, true]);
+
+        // Typing after undoing drops the undone edit
+        cx.simulate_input("b");
+        assert_eq!(value(&state, cx), "b");
+        assert_eq!(*can_redo.borrow(), [false, true, false]);
+    }
+
+    /// Record every value `on_change` reports
+    fn recording(changes: &Rc<RefCell<Vec<String>>>, state: InputState) -> InputState {
+        let changes = changes.clone();
+        state.on_change(move |value, _, _| changes.borrow_mut().push(value.into()))
+    }
+
+    #[gpui::test]
+    fn change_reports_each_edit(cx: &mut TestAppContext) {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let (state, cx) = input(cx, |state| recording(&changes, state));
+        cx.write_to_clipboard(ClipboardItem::new_string("cd".into()));
+
+        cx.simulate_input("ab");
+        cx.simulate_keystrokes("cmd-v");
+        cx.dispatch_action(Undo);
+        state.update_in(cx, |state, window, cx| state.clear_value(window, cx));
+        assert_eq!(*changes.borrow(), ["a", "ab", "abcd", "ab", ""]);
+
+        // Nothing to clear, nothing to report
+        state.update_in(cx, |state, window, cx| state.clear_value(window, cx));
+        cx.simulate_keystrokes("backspace");
+        assert_eq!(changes.borrow().len(), 5);
+    }
+
+    #[gpui::test]
+    fn change_reports_composition_once_committed(cx: &mut TestAppContext) {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let (state, cx) = input(cx, |state| recording(&changes, state));
+
+        state.update_in(cx, |state, window, cx| {
+            state.replace_and_mark_text_in_range(None, "k", None, window, cx);
+            state.replace_and_mark_text_in_range(None, "か", None, window, cx);
+        });
+        assert!(changes.borrow().is_empty());
+
+        state.update_in(cx, |state, window, cx| {
+            state.replace_text_in_range(None, "か", window, cx)
+        });
+        assert_eq!(value(&state, cx), "か");
+        assert_eq!(*changes.borrow(), ["か"]);
+    }
+
+    #[gpui::test]
+    fn focus_and_blur_follow_focus_transitions(cx: &mut TestAppContext) {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let (state, cx) = input(cx, |state| {
+            let (on_focus, on_blur) = (events.clone(), events.clone());
+            state
+                .on_focus(move |_, _| on_focus.borrow_mut().push("focus"))
+                .on_blur(move |_, _| on_blur.borrow_mut().push("blur"))
+        });
+        assert_eq!(*events.borrow(), ["focus"]);
+
+        cx.simulate_input("ab");
+        cx.update(|window, _| window.blur());
+        cx.run_until_parked();
+        assert_eq!(*events.borrow(), ["focus", "blur"]);
+
+        // Blurring again, or focusing the focused input, isn't a transition
+        cx.update(|window, _| window.blur());
+        state.update_in(cx, |state, window, _| state.focus_handle.focus(window));
+        state.update_in(cx, |state, window, _| state.focus_handle.focus(window));
+        cx.run_until_parked();
+        assert_eq!(*events.borrow(), ["focus", "blur", "focus"]);
+
+        // Blurring ended the undo step, so typing after it is undone on its own
+        cx.simulate_input("c");
+        cx.dispatch_action(Undo);
+        assert_eq!(value(&state, cx), "ab");
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod filter {
+    use crate::components::input::{
+        Paste,
+        tests::support::{input, value},
+        text_ops::TextOps,
+    };
+    use gpui::{ClipboardItem, EntityInputHandler, TestAppContext};
+
+    #[test]
+    fn numeric_paste() {
+        assert_eq!(TextOps::filter("12a3.4x", TextOps::is_numeric), "123.4");
+        assert_eq!(TextOps::filter("-1,000", TextOps::is_numeric), "-1000");
+        assert_eq!(TextOps::filter("abc", TextOps::is_numeric), "");
+    }
+
+    #[test]
+    fn allowed_text_is_borrowed() {
+        assert!(matches!(
+            TextOps::filter("42", TextOps::is_numeric),
+            std::borrow::Cow::Borrowed("42")
+        ));
+    }
+
+    #[test]
+    fn custom_filter() {
+        assert_eq!(
+            TextOps::filter("héllo wörld", |ch| ch.is_ascii_alphabetic()),
+            "hllowrld"
+        );
+        assert_eq!(TextOps::filter("a b\tc", |ch| !ch.is_whitespace()), "abc");
+    }
+
+    #[gpui::test]
+    fn rejected_composition_is_removed_on_commit(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.numeric().value("12"));
+
+        state.update_in(cx, |state, window, cx| {
+            state.replace_and_mark_text_in_range(None, "k", None, window, cx);
+            state.replace_and_mark_text_in_range(None, "か", None, window, cx);
+        });
+        assert_eq!(value(&state, cx), "12か");
+
+        state.update_in(cx, |state, window, cx| {
+            state.replace_text_in_range(None, "か", window, cx)
+        });
+        assert_eq!(value(&state, cx), "12");
+        assert_eq!(
+            state.update_in(cx, |state, window, cx| state.marked_text_range(window, cx)),
+            None
+        );
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+    }
+
+    #[gpui::test]
+    fn rejected_text_keeps_the_selection(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.numeric().value("1234"));
+        state.update(cx, |state, cx| state.set_selection(1..3, cx));
+
+        cx.simulate_input("x");
+        cx.write_to_clipboard(ClipboardItem::new_string("abc".into()));
+        cx.dispatch_action(Paste);
+        assert_eq!(value(&state, cx), "1234");
+        assert_eq!(state.read_with(cx, |state, _| state.selection()), 1..3);
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+
+        // Allowed characters still replace it
+        cx.simulate_input("9");
+        assert_eq!(value(&state, cx), "194");
+    }
+}
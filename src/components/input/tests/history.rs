@@ -1,7 +1,14 @@
 #[cfg(test)]
 mod history {
-    use crate::components::input::history::{Change, History};
-    use std::ops::Range;
+    use crate::components::input::history::{
+        Change, ChangeOrigin, History, HistoryEntry, HistoryMode, is_word_boundary,
+    };
+    use std::{
+        cell::Cell,
+        ops::Range,
+        rc::Rc,
+        time::{Duration, Instant},
+    };
 
     fn insert_text(history: &mut History, text: &str) {
         for (i, ch) in text.char_indices() {
@@ -12,6 +19,7 @@ mod history {
         }
     }
 
+    #[allow(deprecated)]
     fn cut_text(history: &mut History, text: &str, range: Range<usize>) {
         history.prevent_merge();
         history.push(Change::Delete {
@@ -20,6 +28,7 @@ mod history {
         });
     }
 
+    #[allow(deprecated)]
     fn paste_text(history: &mut History, text: &str, range: Range<usize>) {
         history.prevent_merge();
         history.push(Change::Insert {
@@ -33,7 +42,7 @@ mod history {
         let mut history = History::new();
         insert_text(&mut history, "Hello World!");
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -43,7 +52,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -69,7 +78,7 @@ mod history {
             range: 3..3,
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Replace {
@@ -81,7 +90,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 2..4);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -91,7 +100,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -100,7 +109,7 @@ mod history {
             }
         );
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Replace {
@@ -119,7 +128,7 @@ mod history {
         cut_text(&mut history, "brown ", 6..12);
         paste_text(&mut history, "brown ", 0..0);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -129,7 +138,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Insert {
@@ -139,7 +148,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 6..12);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -149,7 +158,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -158,7 +167,7 @@ mod history {
             }
         );
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Delete {
@@ -167,7 +176,7 @@ mod history {
             }
         );
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -189,7 +198,7 @@ mod history {
             marked: false,
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Replace {
@@ -201,7 +210,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 6..11);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -211,7 +220,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -227,7 +236,7 @@ mod history {
         insert_text(&mut history, "Hello World!");
         cut_text(&mut history, "Hello", 0..5);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Insert {
@@ -237,7 +246,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..5);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Delete {
@@ -246,7 +255,7 @@ mod history {
             }
         );
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Insert {
@@ -267,7 +276,7 @@ mod history {
             range: 1..2,
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Insert {
@@ -320,7 +329,7 @@ mod history {
             text: "e".into(),
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Replace {
@@ -332,7 +341,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 3..5);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -342,7 +351,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -351,7 +360,7 @@ mod history {
             }
         );
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Replace {
@@ -400,7 +409,7 @@ mod history {
             text: "D".into(),
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Replace {
@@ -412,7 +421,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..14);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -422,7 +431,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -431,7 +440,7 @@ mod history {
             }
         );
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Replace {
@@ -455,7 +464,7 @@ mod history {
             marked: false,
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Replace {
@@ -467,7 +476,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 6..10);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -477,7 +486,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -486,7 +495,7 @@ mod history {
             }
         );
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Replace {
@@ -514,7 +523,7 @@ mod history {
             marked: true,
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -524,7 +533,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -550,7 +559,7 @@ mod history {
             text: "rld".into(),
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -560,7 +569,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -592,7 +601,7 @@ mod history {
             text: "wesome".into(),
         });
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Replace {
@@ -604,7 +613,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 6..13);
 
-        let undo = history.undo().unwrap();
+        let undo = history.undo().unwrap().change;
         assert_eq!(
             undo,
             Change::Delete {
@@ -614,7 +623,7 @@ mod history {
         );
         assert_eq!(undo.selection_range(), 0..0);
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Insert {
@@ -623,7 +632,7 @@ mod history {
             }
         );
 
-        let redo = history.redo().unwrap();
+        let redo = history.redo().unwrap().change;
         assert_eq!(
             redo,
             Change::Replace {
@@ -634,4 +643,2299 @@ mod history {
             }
         );
     }
+
+    #[test]
+    fn single_char_inserts_coalesce() {
+        let mut history = History::new();
+        insert_text(&mut history, "abc");
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..3
+            }
+        );
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn multibyte_inserts_coalesce() {
+        let mut history = History::new();
+        insert_text(&mut history, "añ👋");
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..7
+            }
+        );
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn non_adjacent_inserts_stay_separate() {
+        let mut history = History::new();
+        insert_text(&mut history, "abc");
+        history.push(Change::Insert {
+            text: "x".into(),
+            range: 1..1,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 1..2
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..3
+            }
+        );
+    }
+
+    #[test]
+    fn contiguous_replaces_merge() {
+        let mut history = History::new();
+        insert_text(&mut history, "abcdef");
+        history.commit();
+
+        history.push(Change::Replace {
+            range: 0..2,
+            old_text: "ab".into(),
+            new_text: "X".into(),
+            marked: false,
+        });
+        history.push(Change::Replace {
+            range: 1..3,
+            old_text: "cd".into(),
+            new_text: "Y".into(),
+            marked: false,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Replace {
+                range: 0..2,
+                old_text: "XY".into(),
+                new_text: "abcd".into(),
+                marked: false,
+            }
+        );
+        assert_eq!(undo.selection_range(), 0..4);
+
+        let redo = history.redo().unwrap().change;
+        assert_eq!(
+            redo,
+            Change::Replace {
+                range: 0..4,
+                old_text: "abcd".into(),
+                new_text: "XY".into(),
+                marked: false,
+            }
+        );
+    }
+
+    #[test]
+    fn overlapping_replace_merges() {
+        let mut history = History::new();
+        insert_text(&mut history, "abcdef");
+        history.commit();
+
+        history.push(Change::Replace {
+            range: 2..4,
+            old_text: "cd".into(),
+            new_text: "XY".into(),
+            marked: false,
+        });
+        history.push(Change::Replace {
+            range: 2..4,
+            old_text: "XY".into(),
+            new_text: "Z".into(),
+            marked: false,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Replace {
+                range: 2..3,
+                old_text: "Z".into(),
+                new_text: "cd".into(),
+                marked: false,
+            }
+        );
+        assert_eq!(undo.selection_range(), 2..4);
+
+        let redo = history.redo().unwrap().change;
+        assert_eq!(
+            redo,
+            Change::Replace {
+                range: 2..4,
+                old_text: "cd".into(),
+                new_text: "Z".into(),
+                marked: false,
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(undo.selection_range(), 2..4);
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..6
+            }
+        );
+    }
+
+    #[test]
+    fn distant_replaces_stay_separate() {
+        let mut history = History::new();
+        insert_text(&mut history, "abcdef");
+        history.commit();
+
+        history.push(Change::Replace {
+            range: 0..1,
+            old_text: "a".into(),
+            new_text: "X".into(),
+            marked: false,
+        });
+        history.push(Change::Replace {
+            range: 4..5,
+            old_text: "e".into(),
+            new_text: "Y".into(),
+            marked: false,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Replace {
+                range: 4..5,
+                old_text: "Y".into(),
+                new_text: "e".into(),
+                marked: false,
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Replace {
+                range: 0..1,
+                old_text: "X".into(),
+                new_text: "a".into(),
+                marked: false,
+            }
+        );
+    }
+
+    #[test]
+    fn replace_then_distant_insert_stays_separate() {
+        let mut history = History::new();
+        insert_text(&mut history, "abcdef");
+        history.commit();
+
+        history.push(Change::Replace {
+            range: 0..2,
+            old_text: "ab".into(),
+            new_text: "X".into(),
+            marked: false,
+        });
+        history.push(Change::Insert {
+            text: "Y".into(),
+            range: 5..5,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 5..6
+            }
+        );
+    }
+
+    #[test]
+    fn coalesce_within_timeout() {
+        let mut history = History::new().with_coalesce_timeout(Duration::from_millis(500));
+        let start = Instant::now();
+
+        history.push_at(
+            Change::Insert {
+                text: "a".into(),
+                range: 0..0,
+            },
+            start,
+        );
+        history.push_at(
+            Change::Insert {
+                text: "b".into(),
+                range: 1..1,
+            },
+            start + Duration::from_millis(400),
+        );
+        history.push_at(
+            Change::Insert {
+                text: "c".into(),
+                range: 2..2,
+            },
+            start + Duration::from_millis(800),
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..3
+            }
+        );
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn no_coalesce_after_timeout() {
+        let mut history = History::new().with_coalesce_timeout(Duration::from_millis(500));
+        let start = Instant::now();
+
+        history.push_at(
+            Change::Insert {
+                text: "a".into(),
+                range: 0..0,
+            },
+            start,
+        );
+        history.push_at(
+            Change::Insert {
+                text: "b".into(),
+                range: 1..1,
+            },
+            start + Duration::from_secs(300),
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 1..2
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..1
+            }
+        );
+    }
+
+    #[test]
+    fn word_boundaries_split_entries() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        insert_text(&mut history, "hello world foo");
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 12..15
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 6..12
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..6
+            }
+        );
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn undo_keeps_the_separator_before_the_next_word() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        let mut buffer = String::new();
+        for ch in "foo bar".chars() {
+            let change = Change::insert(ch.to_string(), buffer.len());
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "foo ");
+    }
+
+    #[test]
+    fn punctuation_splits_entries() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        insert_text(&mut history, "foo.bar");
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 4..7
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..4
+            }
+        );
+    }
+
+    #[test]
+    fn custom_word_boundary() {
+        fn whitespace_only(text: &str) -> bool {
+            text.ends_with(char::is_whitespace)
+        }
+
+        let mut history = History::new().with_word_boundary(whitespace_only);
+        insert_text(&mut history, "foo.bar baz");
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 8..11
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..8
+            }
+        );
+    }
+
+    #[test]
+    fn insert_then_delete_cancels() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        history.commit();
+
+        history.push(Change::Insert {
+            text: "x".into(),
+            range: 5..5,
+        });
+        history.push(Change::Delete {
+            text: "x".into(),
+            range: 5..6,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..5
+            }
+        );
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn typed_then_deleted_leaves_no_entry() {
+        let mut history = History::new();
+        history.push(Change::Insert {
+            text: "x".into(),
+            range: 0..0,
+        });
+        history.push(Change::Delete {
+            text: "x".into(),
+            range: 0..1,
+        });
+
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn delete_past_insert_becomes_replace() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        history.commit();
+
+        history.push(Change::Insert {
+            text: "XY".into(),
+            range: 5..5,
+        });
+        history.push(Change::Delete {
+            text: "oX".into(),
+            range: 4..6,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Replace {
+                range: 4..5,
+                old_text: "Y".into(),
+                new_text: "o".into(),
+                marked: false,
+            }
+        );
+
+        let redo = history.redo().unwrap().change;
+        assert_eq!(
+            redo,
+            Change::Replace {
+                range: 4..5,
+                old_text: "o".into(),
+                new_text: "Y".into(),
+                marked: false,
+            }
+        );
+    }
+
+    #[test]
+    fn delete_covering_insert_becomes_delete() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        history.commit();
+
+        history.push(Change::Insert {
+            text: "XY".into(),
+            range: 5..5,
+        });
+        history.push(Change::Delete {
+            text: "loXY".into(),
+            range: 3..7,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Insert {
+                text: "lo".into(),
+                range: 3..3
+            }
+        );
+        assert_eq!(undo.selection_range(), 3..5);
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..5
+            }
+        );
+    }
+
+    #[test]
+    fn restores_selection_around_delete() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        history.commit();
+
+        history.push_with_selection(
+            Change::Delete {
+                text: "o".into(),
+                range: 4..5,
+            },
+            5..5,
+            4..4,
+        );
+
+        let undo = history.undo().unwrap();
+        assert_eq!(
+            undo.change,
+            Change::Insert {
+                text: "o".into(),
+                range: 4..4
+            }
+        );
+        assert_eq!(undo.selection, 5..5);
+
+        let redo = history.redo().unwrap();
+        assert_eq!(
+            redo.change,
+            Change::Delete {
+                text: "o".into(),
+                range: 4..5
+            }
+        );
+        assert_eq!(redo.selection, 4..4);
+    }
+
+    #[test]
+    fn merged_entries_keep_first_selection() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        history.commit();
+
+        history.push_with_selection(
+            Change::Delete {
+                text: "o".into(),
+                range: 4..5,
+            },
+            5..5,
+            4..4,
+        );
+        history.push_with_selection(
+            Change::Delete {
+                text: "l".into(),
+                range: 3..4,
+            },
+            4..4,
+            3..3,
+        );
+
+        let undo = history.undo().unwrap();
+        assert_eq!(
+            undo.change,
+            Change::Insert {
+                text: "lo".into(),
+                range: 3..3
+            }
+        );
+        assert_eq!(undo.selection, 5..5);
+
+        let redo = history.redo().unwrap();
+        assert_eq!(redo.selection, 3..3);
+    }
+
+    #[test]
+    fn default_selection_follows_change() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        cut_text(&mut history, "ell", 1..4);
+
+        let undo = history.undo().unwrap();
+        assert_eq!(undo.selection, 1..4);
+
+        let redo = history.redo().unwrap();
+        assert_eq!(redo.selection, 1..1);
+    }
+
+    #[test]
+    fn transaction_groups_changes() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello world");
+
+        history.begin_transaction();
+        history.push(Change::Delete {
+            text: "hello".into(),
+            range: 0..5,
+        });
+        history.push(Change::Insert {
+            text: "bye".into(),
+            range: 0..0,
+        });
+        history.push(Change::Replace {
+            range: 4..9,
+            old_text: "world".into(),
+            new_text: "moon".into(),
+            marked: false,
+        });
+        history.end_transaction();
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Batch {
+                changes: vec![
+                    Change::Replace {
+                        range: 4..8,
+                        old_text: "moon".into(),
+                        new_text: "world".into(),
+                        marked: false,
+                    },
+                    Change::Delete {
+                        text: "".into(),
+                        range: 0..3
+                    },
+                    Change::Insert {
+                        text: "hello".into(),
+                        range: 0..0
+                    },
+                ]
+            }
+        );
+
+        let redo = history.redo().unwrap().change;
+        assert_eq!(
+            redo,
+            Change::Batch {
+                changes: vec![
+                    Change::Delete {
+                        text: "hello".into(),
+                        range: 0..5
+                    },
+                    Change::Insert {
+                        text: "bye".into(),
+                        range: 0..0
+                    },
+                    Change::Replace {
+                        range: 4..9,
+                        old_text: "world".into(),
+                        new_text: "moon".into(),
+                        marked: false,
+                    },
+                ]
+            }
+        );
+
+        history.undo();
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 0..11
+            }
+        );
+    }
+
+    #[test]
+    fn transaction_does_not_merge_with_typing() {
+        let mut history = History::new();
+        insert_text(&mut history, "ab");
+
+        history.transaction(|history| {
+            history.push(Change::Insert {
+                text: "c".into(),
+                range: 2..2,
+            });
+        });
+        history.push(Change::Insert {
+            text: "d".into(),
+            range: 3..3,
+        });
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 3..4
+            }
+        );
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 2..3
+            }
+        );
+    }
+
+    #[test]
+    fn nested_transactions() {
+        let mut history = History::new();
+
+        history.begin_transaction();
+        history.push(Change::Insert {
+            text: "a".into(),
+            range: 0..0,
+        });
+        history.begin_transaction();
+        history.push(Change::Insert {
+            text: "b".into(),
+            range: 1..1,
+        });
+        history.end_transaction();
+        history.push(Change::Insert {
+            text: "c".into(),
+            range: 2..2,
+        });
+        history.end_transaction();
+
+        let undo = history.undo().unwrap().change;
+        assert!(matches!(undo, Change::Batch { changes } if changes.len() == 3));
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn empty_transaction() {
+        let mut history = History::new();
+        history.transaction(|_| {});
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn can_undo_and_redo() {
+        let mut history = History::new();
+        assert!(!history.can_undo());
+        assert!(!history.can_redo());
+
+        insert_text(&mut history, "abc");
+        assert!(history.can_undo());
+        assert!(!history.can_redo());
+
+        history.undo();
+        assert!(!history.can_undo());
+        assert!(history.can_redo());
+
+        history.redo();
+        assert!(history.can_undo());
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn stack_depths() {
+        let mut history = History::new();
+        assert!(history.is_empty());
+        assert_eq!(history.undo_len(), 0);
+        assert_eq!(history.redo_len(), 0);
+
+        insert_text(&mut history, "hello");
+        assert!(!history.is_empty());
+        assert_eq!(history.undo_len(), 1);
+
+        cut_text(&mut history, "ell", 1..4);
+        assert_eq!(history.undo_len(), 2);
+
+        history.undo();
+        assert_eq!(history.undo_len(), 1);
+        assert_eq!(history.redo_len(), 1);
+
+        history.undo();
+        assert_eq!(history.undo_len(), 0);
+        assert_eq!(history.redo_len(), 2);
+        assert!(!history.is_empty());
+
+        history.clear();
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn max_bytes_evicts_oldest() {
+        let mut history = History::new().with_max_bytes(10);
+        paste_text(&mut history, "abcd", 0..0);
+        paste_text(&mut history, "efgh", 4..4);
+        assert_eq!(history.undo_bytes(), 8);
+
+        paste_text(&mut history, "ijk", 8..8);
+        assert_eq!(history.undo_len(), 2);
+        assert_eq!(history.undo_bytes(), 7);
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 8..11
+            }
+        );
+        assert_eq!(history.undo_bytes(), 4);
+
+        let undo = history.undo().unwrap().change;
+        assert_eq!(
+            undo,
+            Change::Delete {
+                text: "".into(),
+                range: 4..8
+            }
+        );
+        assert!(history.undo().is_none());
+        assert_eq!(history.undo_bytes(), 0);
+
+        history.redo();
+        history.redo();
+        assert_eq!(history.undo_bytes(), 7);
+    }
+
+    #[test]
+    fn max_bytes_counts_merged_entries() {
+        let mut history = History::new().with_max_bytes(6);
+        paste_text(&mut history, "big", 0..0);
+        history.commit();
+        insert_text(&mut history, "hello");
+
+        assert_eq!(history.undo_len(), 1);
+        assert_eq!(history.undo_bytes(), 5);
+    }
+
+    #[test]
+    fn max_bytes_oversized_change_clears_history() {
+        let mut history = History::new().with_max_bytes(4);
+        paste_text(&mut history, "ab", 0..0);
+        paste_text(&mut history, "cd", 2..2);
+        history.undo();
+
+        assert!(history.push(Change::insert("too large", 2)));
+        assert!(!history.can_undo());
+        assert!(!history.can_redo());
+        assert_eq!(history.undo_bytes(), 0);
+
+        paste_text(&mut history, "ef", 11..11);
+        assert_eq!(history.undo_len(), 1);
+        assert_eq!(history.undo_bytes(), 2);
+    }
+
+    #[test]
+    fn max_bytes_evicts_oversized_transaction_whole() {
+        let mut history = History::new().with_max_bytes(6);
+        paste_text(&mut history, "ab", 0..0);
+
+        history.push_batch(vec![
+            Change::insert("cd", 2),
+            Change::insert("too large", 4),
+            Change::insert("ef", 13),
+        ]);
+        assert!(!history.can_undo());
+        assert_eq!(history.undo_bytes(), 0);
+    }
+
+    #[test]
+    fn restore_snapshot() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        cut_text(&mut history, "ell", 1..4);
+        history.undo();
+
+        let mut restored = History::new();
+        restored.restore(history.snapshot());
+        assert_eq!(restored.undo_len(), 1);
+        assert_eq!(restored.redo_len(), 1);
+
+        paste_text(&mut history, " world", 5..5);
+        insert_text(&mut restored, "!");
+        assert_eq!(restored.undo_len(), 2);
+
+        let mut restored = History::new();
+        restored.restore(history.snapshot());
+        assert_eq!(restored.undo().unwrap(), history.undo().unwrap());
+        assert_eq!(restored.undo().unwrap(), history.undo().unwrap());
+        assert!(restored.undo().is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_round_trip() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        history.push_with_selection(
+            Change::Replace {
+                range: 0..5,
+                old_text: "hello".into(),
+                new_text: "bye".into(),
+                marked: false,
+            },
+            0..5,
+            3..3,
+        );
+        history.commit();
+        cut_text(&mut history, "b", 0..1);
+        history.undo();
+
+        let json = serde_json::to_string(&history.snapshot()).unwrap();
+        let mut restored = History::new();
+        restored.restore(serde_json::from_str(&json).unwrap());
+
+        assert_eq!(restored.redo_len(), 1);
+        assert_eq!(restored.redo().unwrap(), history.redo().unwrap());
+        while let Some(step) = history.undo() {
+            assert_eq!(restored.undo(), Some(step));
+        }
+        assert!(restored.undo().is_none());
+    }
+
+    #[test]
+    fn peek_undo_and_redo() {
+        let mut history = History::new();
+        assert!(history.peek_undo().is_none());
+        assert!(history.peek_redo().is_none());
+
+        insert_text(&mut history, "hello");
+        cut_text(&mut history, "ell", 1..4);
+        assert_eq!(
+            history.peek_undo(),
+            Some(&Change::Delete {
+                text: "ell".into(),
+                range: 1..4
+            })
+        );
+
+        let peeked = history.peek_undo().cloned().unwrap();
+        assert_eq!(history.undo().unwrap().change, peeked.inverse());
+        assert_eq!(history.undo_len(), 1);
+
+        let peeked = history.peek_redo().cloned().unwrap();
+        assert_eq!(history.redo().unwrap().change, peeked);
+        assert!(history.peek_redo().is_none());
+    }
+
+    #[test]
+    fn on_change_notifications() {
+        let count = Rc::new(Cell::new(0));
+        let mut history = History::new();
+        history.set_on_change({
+            let count = count.clone();
+            move |_| count.set(count.get() + 1)
+        });
+
+        insert_text(&mut history, "abc");
+        assert_eq!(count.get(), 3);
+
+        history.undo();
+        history.redo();
+        assert_eq!(count.get(), 5);
+
+        history.redo();
+        assert_eq!(count.get(), 5);
+
+        history.transaction(|history| {
+            paste_text(history, "x", 3..3);
+            paste_text(history, "y", 4..4);
+        });
+        assert_eq!(count.get(), 6);
+
+        history.clear();
+        assert_eq!(count.get(), 7);
+    }
+
+    #[test]
+    fn on_change_sees_updated_history() {
+        let can_undo = Rc::new(Cell::new(false));
+        let mut history = History::new();
+        history.set_on_change({
+            let can_undo = can_undo.clone();
+            move |history| can_undo.set(history.can_undo())
+        });
+
+        insert_text(&mut history, "a");
+        assert!(can_undo.get());
+        history.undo();
+        assert!(!can_undo.get());
+    }
+
+    #[test]
+    fn push_reports_discarded_redo() {
+        let mut history = History::new();
+        assert!(!history.push(Change::insert("a", 0)));
+        assert!(!history.push(Change::insert("b", 1)));
+
+        // Undoing alone keeps the redo stack
+        history.undo();
+        assert!(history.can_redo());
+
+        assert!(history.push(Change::insert("c", 1)));
+        assert!(!history.can_redo());
+        assert!(!history.push(Change::insert("d", 2)));
+    }
+
+    #[test]
+    fn push_reports_discarded_redo_after_redoing_everything() {
+        let mut history = History::new();
+        paste_text(&mut history, "a", 0..0);
+        history.undo();
+        history.redo();
+        assert!(!history.push_with_origin(Change::insert("b", 1), ChangeOrigin::Paste));
+    }
+
+    #[test]
+    fn push_reports_discarded_redo_once_per_transaction() {
+        let mut history = History::new();
+        paste_text(&mut history, "a", 0..0);
+        history.undo();
+
+        let discarded = history.transaction(|history| {
+            [
+                history.push(Change::insert("x", 0)),
+                history.push(Change::insert("y", 1)),
+            ]
+        });
+        assert_eq!(discarded, [true, false]);
+
+        history.undo();
+        assert!(history.push_batch(vec![Change::insert("z", 0), Change::insert("w", 1)]));
+        assert!(!history.push_batch(vec![Change::insert("v", 2)]));
+    }
+
+    #[test]
+    fn push_reports_redo_stashed_as_branch() {
+        let mut history = History::new().with_mode(HistoryMode::Tree);
+        paste_text(&mut history, "a", 0..0);
+        history.undo();
+
+        assert!(history.push(Change::insert("b", 0)));
+        assert!(!history.can_redo());
+        assert_eq!(history.branches_at_current().len(), 0);
+        history.undo();
+        assert_eq!(history.branches_at_current().len(), 1);
+    }
+
+    #[test]
+    fn commit_seals_entry() {
+        let mut history = History::new();
+        insert_text(&mut history, "ab");
+        history.commit();
+        history.push(Change::Insert {
+            text: "c".into(),
+            range: 2..2,
+        });
+        assert_eq!(history.undo_len(), 2);
+
+        history.push(Change::Insert {
+            text: "d".into(),
+            range: 3..3,
+        });
+        assert_eq!(history.undo_len(), 2);
+
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 2..4
+            }
+        );
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 0..2
+            }
+        );
+    }
+
+    #[test]
+    fn commit_on_empty_history() {
+        let mut history = History::new();
+        history.commit();
+        insert_text(&mut history, "ab");
+        assert_eq!(history.undo_len(), 1);
+    }
+
+    #[test]
+    fn evicts_oldest_in_order() {
+        let mut history = History::with_max_size(10);
+        for i in 0..1000 {
+            paste_text(&mut history, "x", i..i);
+        }
+        assert_eq!(history.undo_len(), 10);
+
+        for i in (990..1000).rev() {
+            assert_eq!(
+                history.undo().unwrap().change,
+                Change::Delete {
+                    text: "".into(),
+                    range: i..i + 1
+                }
+            );
+        }
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn savepoint_tracks_dirty_state() {
+        let mut history = History::new();
+        assert!(!history.is_dirty());
+
+        insert_text(&mut history, "hello");
+        assert!(history.is_dirty());
+        history.save();
+        assert!(!history.is_dirty());
+        assert_eq!(history.savepoint(), Some(1));
+
+        insert_text(&mut history, " world");
+        assert_eq!(history.undo_len(), 2);
+        assert!(history.is_dirty());
+
+        history.undo();
+        assert!(!history.is_dirty());
+        history.undo();
+        assert!(history.is_dirty());
+        history.redo();
+        assert!(!history.is_dirty());
+    }
+
+    #[test]
+    fn savepoint_unreachable_after_new_change() {
+        let mut history = History::new();
+        paste_text(&mut history, "a", 0..0);
+        paste_text(&mut history, "b", 1..1);
+        history.save();
+
+        history.undo();
+        paste_text(&mut history, "c", 1..1);
+        assert_eq!(history.savepoint(), None);
+        assert!(history.is_dirty());
+        history.undo();
+        assert!(history.is_dirty());
+    }
+
+    #[test]
+    fn savepoint_eviction_and_clear() {
+        let mut history = History::with_max_size(2);
+        paste_text(&mut history, "a", 0..0);
+        paste_text(&mut history, "b", 1..1);
+        history.save();
+        paste_text(&mut history, "c", 2..2);
+        assert_eq!(history.savepoint(), Some(1));
+
+        paste_text(&mut history, "d", 3..3);
+        assert_eq!(history.savepoint(), Some(0));
+        paste_text(&mut history, "e", 4..4);
+        assert_eq!(history.savepoint(), None);
+
+        history.clear();
+        assert!(history.is_dirty());
+        history.save();
+        history.clear();
+        assert!(!history.is_dirty());
+    }
+
+    /// Applies the changes since the savepoint to `saved`
+    fn replay_since_savepoint(history: &History, saved: &str) -> String {
+        let mut buffer = saved.to_string();
+        for change in history.changes_since_savepoint() {
+            change.apply(&mut buffer);
+        }
+        buffer
+    }
+
+    #[test]
+    fn changes_since_savepoint_after_pushes() {
+        let mut history = History::new();
+        assert!(history.changes_since_savepoint().is_empty());
+
+        insert_text(&mut history, "hello");
+        history.save();
+        assert!(history.changes_since_savepoint().is_empty());
+
+        paste_text(&mut history, " world", 5..5);
+        cut_text(&mut history, "hello", 0..5);
+        assert_eq!(
+            history.changes_since_savepoint(),
+            vec![Change::insert(" world", 5), Change::delete("hello", 0..5)]
+        );
+        assert_eq!(replay_since_savepoint(&history, "hello"), " world");
+    }
+
+    #[test]
+    fn changes_since_savepoint_across_undo_and_redo() {
+        let mut history = History::new();
+        paste_text(&mut history, "a", 0..0);
+        paste_text(&mut history, "b", 1..1);
+        paste_text(&mut history, "c", 2..2);
+        history.save();
+
+        // Undoing past the savepoint reports the undone changes as their inverses
+        history.undo();
+        history.undo();
+        assert_eq!(
+            history.changes_since_savepoint(),
+            vec![
+                Change::insert("c", 2).inverse(),
+                Change::insert("b", 1).inverse()
+            ]
+        );
+        assert_eq!(replay_since_savepoint(&history, "abc"), "a");
+
+        history.redo();
+        assert_eq!(replay_since_savepoint(&history, "abc"), "ab");
+        history.redo();
+        assert!(history.changes_since_savepoint().is_empty());
+
+        // Redoing past it reports the redone changes as they are
+        history.undo();
+        history.undo();
+        history.save();
+        history.redo();
+        history.redo();
+        assert_eq!(
+            history.changes_since_savepoint(),
+            vec![Change::insert("b", 1), Change::insert("c", 2)]
+        );
+        assert_eq!(replay_since_savepoint(&history, "a"), "abc");
+    }
+
+    #[test]
+    fn changes_since_unreachable_savepoint() {
+        let mut history = History::new();
+        paste_text(&mut history, "a", 0..0);
+        paste_text(&mut history, "b", 1..1);
+        history.save();
+
+        history.undo();
+        paste_text(&mut history, "c", 1..1);
+        assert!(history.changes_since_savepoint().is_empty());
+        assert!(history.is_dirty());
+    }
+
+    #[test]
+    fn shrink_max_size() {
+        let mut history = History::new();
+        for i in 0..5 {
+            paste_text(&mut history, "x", i..i);
+        }
+        history.undo();
+
+        history.set_max_size(2);
+        assert_eq!(history.undo_len(), 2);
+        assert_eq!(history.redo_len(), 1);
+
+        history.redo();
+        assert_eq!(history.undo_len(), 2);
+        paste_text(&mut history, "y", 5..5);
+        assert_eq!(history.undo_len(), 2);
+
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 5..6
+            }
+        );
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 4..5
+            }
+        );
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn grow_max_size() {
+        let mut history = History::with_max_size(2);
+        for i in 0..3 {
+            paste_text(&mut history, "x", i..i);
+        }
+        history.set_max_size(4);
+        for i in 3..6 {
+            paste_text(&mut history, "x", i..i);
+        }
+        assert_eq!(history.undo_len(), 4);
+    }
+
+    #[test]
+    fn push_batch_of_mixed_changes() {
+        let mut history = History::new();
+        insert_text(&mut history, "foo bar");
+
+        let insert = Change::Insert {
+            text: "(".into(),
+            range: 0..0,
+        };
+        let delete = Change::Delete {
+            text: " ".into(),
+            range: 4..5,
+        };
+        let replace = Change::Replace {
+            range: 4..7,
+            old_text: "bar".into(),
+            new_text: "baz)".into(),
+            marked: false,
+        };
+        history.push_batch(vec![insert.clone(), delete.clone(), replace.clone()]);
+        assert_eq!(history.undo_len(), 2);
+
+        insert_text(&mut history, "!");
+        assert_eq!(history.undo_len(), 3);
+        history.undo();
+
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Batch {
+                changes: vec![
+                    replace.clone().inverse(),
+                    delete.clone().inverse(),
+                    insert.clone().inverse(),
+                ]
+            }
+        );
+        assert_eq!(
+            history.redo().unwrap().change,
+            Change::Batch {
+                changes: vec![insert, delete, replace]
+            }
+        );
+    }
+
+    #[test]
+    fn push_empty_batch() {
+        let mut history = History::new();
+        history.push_batch(Vec::new());
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn delete_grapheme_before_clusters() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("a{family}");
+        assert_eq!(
+            Change::delete_grapheme_before(&text, text.len()),
+            Some(Change::Delete {
+                text: family.into(),
+                range: 1..text.len()
+            })
+        );
+
+        let text = "cafe\u{301}!";
+        assert_eq!(
+            Change::delete_grapheme_before(text, 6),
+            Some(Change::Delete {
+                text: "e\u{301}".into(),
+                range: 3..6
+            })
+        );
+        assert_eq!(
+            Change::delete_grapheme_before(text, 5),
+            Some(Change::Delete {
+                text: "e\u{301}".into(),
+                range: 3..6
+            })
+        );
+        assert_eq!(Change::delete_grapheme_before(text, 0), None);
+    }
+
+    #[test]
+    fn multibyte_ranges_are_bytes() {
+        let mut history = History::new();
+        history.push(Change::Insert {
+            text: "é".into(),
+            range: 0..0,
+        });
+        history.push(Change::Insert {
+            text: "🙂".into(),
+            range: 2..2,
+        });
+        history.push(Change::delete_grapheme_before("é🙂", 6).unwrap());
+
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Insert {
+                text: "🙂".into(),
+                range: 2..2
+            }
+        );
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 0..6
+            }
+        );
+    }
+
+    #[test]
+    fn checked_constructors() {
+        assert_eq!(
+            Change::insert("ab", 3),
+            Change::Insert {
+                text: "ab".into(),
+                range: 3..3
+            }
+        );
+        assert_eq!(
+            Change::delete("ab", 3..5),
+            Change::Delete {
+                text: "ab".into(),
+                range: 3..5
+            }
+        );
+        assert_eq!(
+            Change::replace("ab", "xyz", 3..5),
+            Change::Replace {
+                range: 3..5,
+                old_text: "ab".into(),
+                new_text: "xyz".into(),
+                marked: false,
+            }
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    #[should_panic(expected = "delete range 5..3 is reversed")]
+    fn delete_reversed_range() {
+        Change::delete("ab", 5..3);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "delete range 3..6 spans 3 bytes but the text is 2 bytes long")]
+    fn delete_length_mismatch() {
+        Change::delete("ab", 3..6);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    #[should_panic(expected = "replace range 5..3 is reversed")]
+    fn replace_reversed_range() {
+        Change::replace("ab", "c", 5..3);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "replace range 0..1 spans 1 bytes but the text is 2 bytes long")]
+    fn replace_length_mismatch() {
+        Change::replace("ab", "c", 0..1);
+    }
+
+    #[test]
+    fn paste_never_merges_with_typing() {
+        let mut history = History::new();
+        insert_text(&mut history, "ab");
+        history.push_with_origin(Change::insert("cd", 2), ChangeOrigin::Paste);
+        history.push(Change::insert("e", 4));
+        assert_eq!(history.undo_len(), 3);
+
+        let undo = history.undo().unwrap();
+        assert_eq!(undo.origin, ChangeOrigin::UserInput);
+        assert_eq!(
+            undo.change,
+            Change::Delete {
+                text: "".into(),
+                range: 4..5
+            }
+        );
+
+        let undo = history.undo().unwrap();
+        assert_eq!(undo.origin, ChangeOrigin::Paste);
+        assert_eq!(
+            undo.change,
+            Change::Delete {
+                text: "".into(),
+                range: 2..4
+            }
+        );
+
+        let redo = history.redo().unwrap();
+        assert_eq!(redo.origin, ChangeOrigin::Paste);
+        assert_eq!(redo.change, Change::insert("cd", 2));
+    }
+
+    #[test]
+    fn same_origin_merges() {
+        let mut history = History::new();
+        history.push_with_origin(Change::insert("a", 0), ChangeOrigin::Collaboration);
+        history.push_with_origin(Change::insert("b", 1), ChangeOrigin::Collaboration);
+        history.push(Change::insert("c", 2));
+        assert_eq!(history.undo_len(), 2);
+
+        history.undo();
+        let undo = history.undo().unwrap();
+        assert_eq!(undo.origin, ChangeOrigin::Collaboration);
+        assert_eq!(
+            undo.change,
+            Change::Delete {
+                text: "".into(),
+                range: 0..2
+            }
+        );
+    }
+
+    #[test]
+    fn merge_forward_adjacent_inserts() {
+        let mut history = History::new();
+        history.push(Change::insert("x", 5));
+        history.push(Change::insert("y", 6));
+        history.push(Change::insert("z", 7));
+        assert_eq!(history.peek_undo(), Some(&Change::insert("xyz", 5)));
+    }
+
+    #[test]
+    fn merge_backward_adjacent_inserts() {
+        let mut history = History::new();
+        history.push(Change::insert("c", 5));
+        history.push(Change::insert("b", 5));
+        history.push(Change::insert("a", 5));
+        assert_eq!(history.undo_len(), 1);
+        assert_eq!(history.peek_undo(), Some(&Change::insert("abc", 5)));
+
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 5..8
+            }
+        );
+    }
+
+    fn assert_round_trip(text: &str, change: Change, expected: &str, caret: usize) {
+        let mut buffer = text.to_string();
+        assert_eq!(change.apply(&mut buffer), caret);
+        assert_eq!(buffer, expected);
+
+        change.inverse().apply(&mut buffer);
+        assert_eq!(buffer.as_bytes(), text.as_bytes());
+    }
+
+    #[test]
+    fn apply_and_inverse() {
+        assert_round_trip("héllo", Change::insert("🙂", 3), "hé🙂llo", 7);
+        assert_round_trip("héllo", Change::delete("él", 1..4), "hlo", 1);
+        assert_round_trip("héllo", Change::replace("llo", "y", 3..6), "héy", 4);
+        assert_round_trip(
+            "abc",
+            Change::Batch {
+                changes: vec![
+                    Change::insert("(", 0),
+                    Change::delete("b", 2..3),
+                    Change::insert(")", 3),
+                ],
+            },
+            "(ac)",
+            4,
+        );
+    }
+
+    #[test]
+    fn apply_undo_steps() {
+        let mut buffer = String::new();
+        let mut history = History::new();
+        for (i, ch) in "hello world".char_indices() {
+            let change = Change::insert(ch.to_string(), i);
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+        let change = Change::delete("hello ", 0..6);
+        change.apply(&mut buffer);
+        history.push(change);
+        assert_eq!(buffer, "world");
+
+        while let Some(step) = history.undo() {
+            step.change.apply(&mut buffer);
+        }
+        assert_eq!(buffer, "");
+        while let Some(step) = history.redo() {
+            step.change.apply(&mut buffer);
+        }
+        assert_eq!(buffer, "world");
+    }
+
+    #[test]
+    fn linear_mode_discards_redo() {
+        let mut history = History::new();
+        paste_text(&mut history, "a", 0..0);
+        history.undo();
+        paste_text(&mut history, "b", 0..0);
+
+        assert!(history.branches_at_current().is_empty());
+        history.undo();
+        assert!(history.branches_at_current().is_empty());
+        assert_eq!(history.redo_len(), 1);
+    }
+
+    #[test]
+    fn tree_mode_keeps_branches() {
+        let mut history = History::new().with_mode(HistoryMode::Tree);
+        paste_text(&mut history, "a", 0..0);
+        paste_text(&mut history, "b", 1..1);
+        history.undo();
+        paste_text(&mut history, "c", 1..1);
+        assert!(!history.can_redo());
+
+        // The undone "b" forks off after "a".
+        assert!(history.branches_at_current().is_empty());
+        history.undo();
+        let branches = history.branches_at_current();
+        assert_eq!(branches.len(), 1);
+        assert_eq!(history.peek_redo(), Some(&Change::insert("c", 1)));
+
+        assert!(history.switch_branch(branches[0]));
+        assert_eq!(history.redo().unwrap().change, Change::insert("b", 1));
+
+        // Switching kept "c" around as another branch at the same position.
+        history.undo();
+        let branches = history.branches_at_current();
+        assert_eq!(branches.len(), 1);
+        assert!(history.switch_branch(branches[0]));
+        assert_eq!(history.redo().unwrap().change, Change::insert("c", 1));
+    }
+
+    #[test]
+    fn tree_mode_nested_branches() {
+        let mut history = History::new().with_mode(HistoryMode::Tree);
+        paste_text(&mut history, "a", 0..0);
+        paste_text(&mut history, "b", 1..1);
+        paste_text(&mut history, "c", 2..2);
+        history.undo();
+        paste_text(&mut history, "d", 2..2);
+        history.undo();
+        history.undo();
+        paste_text(&mut history, "e", 1..1);
+        history.undo();
+
+        // Only the branch forking after "a" is visible from here.
+        let branches = history.branches_at_current();
+        assert_eq!(branches.len(), 1);
+        assert!(history.switch_branch(branches[0]));
+        assert_eq!(history.redo().unwrap().change, Change::insert("b", 1));
+
+        // The "c" branch forking after "b" came back with it.
+        assert_eq!(history.peek_redo(), Some(&Change::insert("d", 2)));
+        let branches = history.branches_at_current();
+        assert_eq!(branches.len(), 1);
+        assert!(history.switch_branch(branches[0]));
+        assert_eq!(history.redo().unwrap().change, Change::insert("c", 2));
+    }
+
+    #[test]
+    fn switching_branch_leaves_savepoint_behind() {
+        let mut history = History::new().with_mode(HistoryMode::Tree);
+        paste_text(&mut history, "a", 0..0);
+        paste_text(&mut history, "b", 1..1);
+        paste_text(&mut history, "c", 2..2);
+        history.undo();
+        history.undo();
+        paste_text(&mut history, "d", 1..1);
+        paste_text(&mut history, "e", 2..2);
+        history.save();
+
+        history.undo();
+        history.undo();
+        let branches = history.branches_at_current();
+        assert!(history.switch_branch(branches[0]));
+        assert_eq!(history.savepoint(), None);
+
+        history.redo();
+        history.redo();
+        assert_eq!(history.undo_len(), 3);
+        assert!(history.is_dirty());
+    }
+
+    #[test]
+    fn switch_to_unknown_branch() {
+        let mut history = History::new().with_mode(HistoryMode::Tree);
+        paste_text(&mut history, "a", 0..0);
+        history.undo();
+        paste_text(&mut history, "b", 0..0);
+        let branch = {
+            history.undo();
+            history.branches_at_current()[0]
+        };
+        history.redo();
+        assert!(!history.switch_branch(branch));
+        assert!(!history.switch_branch(42));
+    }
+
+    #[test]
+    fn tree_mode_eviction_drops_old_branches() {
+        let mut history = History::with_max_size(2).with_mode(HistoryMode::Tree);
+        paste_text(&mut history, "a", 0..0);
+        history.undo();
+        paste_text(&mut history, "b", 0..0);
+        paste_text(&mut history, "c", 1..1);
+        paste_text(&mut history, "d", 2..2);
+
+        history.undo();
+        history.undo();
+        assert!(history.branches_at_current().is_empty());
+    }
+
+    #[test]
+    fn newline_ends_entry() {
+        let mut history = History::new();
+        insert_text(&mut history, "one\ntwo\nthree");
+        assert_eq!(history.undo_len(), 3);
+
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 8..13
+            }
+        );
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 4..8
+            }
+        );
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 0..4
+            }
+        );
+    }
+
+    #[test]
+    fn backspace_across_newlines() {
+        let mut history = History::new();
+        paste_text(&mut history, "ab\ncd\n", 0..0);
+        history.commit();
+        for (i, ch) in "ab\ncd\n".char_indices().rev() {
+            history.push(Change::delete(ch.to_string(), i..i + 1));
+        }
+        assert_eq!(history.undo_len(), 2);
+        assert_eq!(history.peek_undo(), Some(&Change::delete("ab\ncd\n", 0..6)));
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::insert("ab\ncd\n", 0)
+        );
+    }
+
+    #[test]
+    fn cut_then_type_stays_separate() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        cut_text(&mut history, "ell", 1..4);
+        history.commit();
+        history.push(Change::insert("a", 1));
+        assert_eq!(history.undo_len(), 3);
+
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 1..2
+            }
+        );
+        assert_eq!(history.undo().unwrap().change, Change::insert("ell", 1));
+    }
+
+    #[test]
+    fn paste_over_selection_undoes_in_one_step() {
+        let mut history = History::new();
+        insert_text(&mut history, "hello");
+        history.commit();
+        history.push_with_origin(Change::replace("ell", "ipp", 1..4), ChangeOrigin::Paste);
+        history.commit();
+        history.push(Change::insert("o", 4));
+        assert_eq!(history.undo_len(), 3);
+
+        history.undo();
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::replace("ipp", "ell", 1..4)
+        );
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::Delete {
+                text: "".into(),
+                range: 0..5
+            }
+        );
+    }
+
+    #[test]
+    fn typing_over_select_all_is_one_step() {
+        let mut history = History::new();
+        let mut buffer = String::new();
+        for (i, ch) in "hello world".char_indices() {
+            let change = Change::insert(ch.to_string(), i);
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+
+        // Selecting all commits the typing before it
+        history.commit();
+        let change = Change::replace(buffer.clone(), "x", 0..buffer.len());
+        change.apply(&mut buffer);
+        history.push(change);
+        assert_eq!(buffer, "x");
+        assert_eq!(history.undo_len(), 2);
+
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "hello world");
+        assert_eq!(step.selection, 0..11);
+    }
+
+    /// Types `text` over `selection` the way the input records it, returning the caret
+    fn type_over(
+        history: &mut History,
+        buffer: &mut String,
+        selection: Range<usize>,
+        text: &str,
+    ) -> usize {
+        let mut caret = selection.start;
+        let mut selection = selection;
+        for ch in text.chars() {
+            let change = if selection.is_empty() {
+                Change::insert(ch.to_string(), caret)
+            } else {
+                Change::replace(
+                    buffer[selection.clone()].to_string(),
+                    ch.to_string(),
+                    selection.clone(),
+                )
+            };
+            change.apply(buffer);
+            caret += ch.len_utf8();
+            history.push_entry(HistoryEntry {
+                selection_before: Some(selection),
+                selection_after: Some(caret..caret),
+                ..HistoryEntry::new(change)
+            });
+            selection = caret..caret;
+        }
+        caret
+    }
+
+    #[test]
+    fn idle_flush_separates_adjacent_inserts() {
+        let mut history = History::new();
+        let start = Instant::now();
+        history.push_at(Change::insert("a", 0), start);
+
+        // The idle timer fires well within the coalescing timeout
+        history.flush();
+        history.push_at(Change::insert("b", 1), start + Duration::from_millis(100));
+        assert_eq!(history.undo_len(), 2);
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::insert("b", 1).inverse()
+        );
+    }
+
+    #[test]
+    fn typing_after_idle_flush_coalesces_again() {
+        let mut history = History::new();
+        insert_text(&mut history, "ab");
+        history.flush();
+        history.push(Change::insert("c", 2));
+        history.push(Change::insert("d", 3));
+        assert_eq!(history.undo_len(), 2);
+
+        history.flush();
+        history.push(Change::insert("e", 4));
+        assert_eq!(history.undo_len(), 3);
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::insert("e", 4).inverse()
+        );
+        assert_eq!(
+            history.undo().unwrap().change,
+            Change::insert("cd", 2).inverse()
+        );
+    }
+
+    #[test]
+    fn typing_over_selection_is_one_step() {
+        let mut history = History::new();
+        let mut buffer = String::from("hello world");
+        history.commit();
+
+        type_over(&mut history, &mut buffer, 1..5, "ipp");
+        assert_eq!(buffer, "hipp world");
+        assert_eq!(history.undo_len(), 1);
+
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "hello world");
+        assert_eq!(step.selection, 1..5);
+        assert!(!history.can_undo());
+
+        let step = history.redo().unwrap();
+        assert_eq!(step.change, Change::replace("ello", "ipp", 1..5));
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "hipp world");
+    }
+
+    #[test]
+    fn typing_over_selection_keeps_coalescing_with_word_boundaries() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        let mut buffer = String::from("the quick fox");
+        history.commit();
+
+        // The typed word and its separator extend the replace, the next word starts a step
+        // of its own
+        let caret = type_over(&mut history, &mut buffer, 4..9, "slow");
+        type_over(&mut history, &mut buffer, caret..caret, " red");
+        assert_eq!(buffer, "the slow red fox");
+        assert_eq!(history.undo_len(), 2);
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "the slow  fox");
+
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "the quick fox");
+        assert_eq!(step.selection, 4..9);
+    }
+
+    #[test]
+    fn typing_over_selection_stops_coalescing_elsewhere() {
+        let mut history = History::new();
+        let mut buffer = String::from("hello world");
+        history.commit();
+
+        type_over(&mut history, &mut buffer, 0..5, "yo");
+        // Moving the caret away before typing again
+        let end = buffer.len();
+        type_over(&mut history, &mut buffer, end..end, "!");
+        assert_eq!(buffer, "yo world!");
+        assert_eq!(history.undo_len(), 2);
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "hello world");
+        assert_eq!(step.selection, 0..5);
+    }
+
+    #[test]
+    fn typing_over_selection_after_timeout_is_separate() {
+        let mut history = History::new();
+        let mut buffer = String::from("hello");
+        let start = Instant::now();
+        history.commit();
+
+        let change = Change::replace("ell", "a", 1..4);
+        change.apply(&mut buffer);
+        history.push_at(change, start);
+        let change = Change::insert("b", 2);
+        change.apply(&mut buffer);
+        history.push_at(change, start + Duration::from_secs(5));
+        assert_eq!(buffer, "habo");
+        assert_eq!(history.undo_len(), 2);
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "hao");
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "hello");
+    }
+
+    #[test]
+    fn word_delete_is_one_step() {
+        let mut history = History::new();
+        let mut buffer = String::new();
+        for (i, ch) in "the quick brown fox".char_indices() {
+            let change = Change::insert(ch.to_string(), i);
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+        let change = Change::delete_grapheme_before(&buffer, buffer.len()).unwrap();
+        change.apply(&mut buffer);
+        history.push(change);
+
+        // Word deletion commits on both sides
+        history.commit();
+        let change = Change::delete("quick ", 4..10);
+        change.apply(&mut buffer);
+        history.push(change);
+        history.commit();
+        assert_eq!(buffer, "the brown fo");
+
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "the quick brown fo");
+        assert_eq!(step.selection, 4..10);
+
+        // The backspace before it was not merged into the word
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "the quick brown fox");
+    }
+
+    fn compose(history: &mut History, change: Change, timestamp: Instant) {
+        history.push_entry(HistoryEntry {
+            origin: ChangeOrigin::Ime,
+            timestamp,
+            ..HistoryEntry::new(change)
+        });
+    }
+
+    #[test]
+    fn composition_is_one_step() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        let mut buffer = String::new();
+        let start = Instant::now();
+
+        let change = Change::insert("a", 0);
+        change.apply(&mut buffer);
+        history.push_at(change, start);
+
+        // Picking a candidate can take much longer than the coalescing timeout
+        let steps = [
+            Change::insert("ni", 1),
+            Change::Replace {
+                range: 1..3,
+                old_text: "ni".into(),
+                new_text: "ni h".into(),
+                marked: true,
+            },
+            Change::Replace {
+                range: 1..5,
+                old_text: "ni h".into(),
+                new_text: "你好".into(),
+                marked: true,
+            },
+        ];
+        for (i, change) in steps.into_iter().enumerate() {
+            change.apply(&mut buffer);
+            compose(
+                &mut history,
+                change,
+                start + Duration::from_secs(10 * i as u64),
+            );
+        }
+        assert_eq!(buffer, "a你好");
+        assert_eq!(history.undo_len(), 2);
+
+        let step = history.undo().unwrap();
+        assert_eq!(step.origin, ChangeOrigin::Ime);
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "a");
+    }
+
+    #[test]
+    fn cancelled_composition_leaves_no_entry() {
+        let mut history = History::new();
+        insert_text(&mut history, "a");
+
+        let now = Instant::now();
+        compose(&mut history, Change::insert("k", 1), now);
+        compose(
+            &mut history,
+            Change::Replace {
+                range: 1..2,
+                old_text: "k".into(),
+                new_text: "".into(),
+                marked: true,
+            },
+            now,
+        );
+        assert_eq!(history.undo_len(), 1);
+        assert_eq!(history.undo().unwrap().origin, ChangeOrigin::UserInput);
+    }
+
+    #[test]
+    fn type_undo_redo_type() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        let mut buffer = String::new();
+        let type_text = |history: &mut History, buffer: &mut String, text: &str| {
+            for ch in text.chars() {
+                let change = Change::insert(ch.to_string(), buffer.len());
+                change.apply(buffer);
+                history.push(change);
+            }
+        };
+
+        type_text(&mut history, &mut buffer, "hello world");
+        assert_eq!(history.undo_len(), 2);
+
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "hello ");
+        assert_eq!(step.selection, 6..6);
+
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "");
+        assert_eq!(step.selection, 0..0);
+        assert!(history.undo().is_none());
+
+        let step = history.redo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "hello ");
+        assert_eq!(step.selection, 6..6);
+
+        type_text(&mut history, &mut buffer, "there");
+        assert_eq!(buffer, "hello there");
+        assert!(history.redo().is_none());
+
+        let step = history.undo().unwrap();
+        step.change.apply(&mut buffer);
+        assert_eq!(buffer, "hello ");
+        assert_eq!(step.selection, 6..6);
+    }
+
+    #[test]
+    fn clearing_is_one_step() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        let mut buffer = String::new();
+        for ch in "some text here".chars() {
+            let change = Change::insert(ch.to_string(), buffer.len());
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+
+        history.commit();
+        let change = Change::delete(buffer.clone(), 0..buffer.len());
+        change.apply(&mut buffer);
+        history.push(change);
+        history.commit();
+        assert_eq!(buffer, "");
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "some text here");
+    }
+
+    #[test]
+    fn accepting_a_suggestion_is_one_step() {
+        let mut history = History::new().with_word_boundary(is_word_boundary);
+        let mut buffer = String::new();
+        for ch in "ap".chars() {
+            let change = Change::insert(ch.to_string(), buffer.len());
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+
+        history.commit();
+        let change = Change::replace(buffer.clone(), "apricot", 0..buffer.len());
+        change.apply(&mut buffer);
+        history.push(change);
+        history.commit();
+        assert_eq!(buffer, "apricot");
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "ap");
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "");
+    }
+
+    #[test]
+    fn delete_grapheme_after_clusters() {
+        let text = "e\u{301}x";
+        assert_eq!(
+            Change::delete_grapheme_after(text, 0),
+            Some(Change::Delete {
+                text: "e\u{301}".into(),
+                range: 0..3
+            })
+        );
+        assert_eq!(
+            Change::delete_grapheme_after(text, 1),
+            Some(Change::Delete {
+                text: "e\u{301}".into(),
+                range: 0..3
+            })
+        );
+
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("{family}a");
+        assert_eq!(
+            Change::delete_grapheme_after(&text, 0),
+            Some(Change::Delete {
+                text: family.into(),
+                range: 0..family.len()
+            })
+        );
+        assert_eq!(Change::delete_grapheme_after(&text, text.len()), None);
+    }
+
+    #[test]
+    fn flags_delete_as_one_grapheme() {
+        // Two regional indicators each, for Japan and France
+        let text = "\u{1F1EF}\u{1F1F5}\u{1F1EB}\u{1F1F7}";
+        assert_eq!(
+            Change::delete_grapheme_before(text, text.len()),
+            Some(Change::Delete {
+                text: "\u{1F1EB}\u{1F1F7}".into(),
+                range: 8..16
+            })
+        );
+        assert_eq!(
+            Change::delete_grapheme_after(text, 0),
+            Some(Change::Delete {
+                text: "\u{1F1EF}\u{1F1F5}".into(),
+                range: 0..8
+            })
+        );
+    }
+
+    #[test]
+    fn undoing_a_cluster_delete_restores_it_intact() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        for original in ["cafe\u{301}", family, "\u{1F1EF}\u{1F1F5}"] {
+            let mut history = History::new();
+            let mut buffer = original.to_string();
+
+            let change = Change::delete_grapheme_before(&buffer, buffer.len()).unwrap();
+            change.apply(&mut buffer);
+            history.push(change);
+            assert!(
+                original.starts_with(&buffer),
+                "'{buffer}' left a broken glyph"
+            );
+
+            history.undo().unwrap().change.apply(&mut buffer);
+            assert_eq!(buffer, original);
+
+            let change = Change::delete_grapheme_after(&buffer, 0).unwrap();
+            change.apply(&mut buffer);
+            history.push(change);
+            history.undo().unwrap().change.apply(&mut buffer);
+            assert_eq!(buffer, original);
+        }
+    }
+
+    #[test]
+    fn type_paste_type_undoes_in_three_steps() {
+        let mut history = History::new();
+        let mut buffer = String::new();
+        for ch in "ab".chars() {
+            let change = Change::insert(ch.to_string(), buffer.len());
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+
+        let change = Change::insert("PASTED", buffer.len());
+        change.apply(&mut buffer);
+        history.push_with_origin(change, ChangeOrigin::Paste);
+
+        for ch in "cd".chars() {
+            let change = Change::insert(ch.to_string(), buffer.len());
+            change.apply(&mut buffer);
+            history.push(change);
+        }
+        assert_eq!(buffer, "abPASTEDcd");
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "abPASTED");
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "ab");
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "");
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn consecutive_pastes_stay_separate() {
+        let mut history = History::new();
+        let mut buffer = String::new();
+        for text in ["one", "two"] {
+            let change = Change::insert(text, buffer.len());
+            change.apply(&mut buffer);
+            history.push_with_origin(change, ChangeOrigin::Paste);
+        }
+        assert_eq!(history.undo_len(), 2);
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "one");
+    }
 }
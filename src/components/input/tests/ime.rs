@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod ime {
+    use crate::components::input::tests::support::{input, selection, value};
+    use gpui::{EntityInputHandler, TestAppContext};
+
+    #[gpui::test]
+    fn committed_composition_is_one_undo_step(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        cx.simulate_input("a ");
+
+        state.update_in(cx, |state, window, cx| {
+            state.replace_and_mark_text_in_range(None, "k", None, window, cx);
+            state.replace_and_mark_text_in_range(None, "か", None, window, cx);
+            state.replace_and_mark_text_in_range(None, "かん", None, window, cx);
+        });
+        assert_eq!(value(&state, cx), "a かん");
+        assert_eq!(
+            state.update_in(cx, |state, window, cx| state.marked_text_range(window, cx)),
+            Some(2..4)
+        );
+
+        // Undo waits for the composition to end
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "a かん");
+
+        state.update_in(cx, |state, window, cx| {
+            state.replace_text_in_range(None, "漢", window, cx);
+        });
+        assert_eq!(value(&state, cx), "a 漢");
+        assert_eq!(
+            state.update_in(cx, |state, window, cx| state.marked_text_range(window, cx)),
+            None
+        );
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "a ");
+        assert_eq!(selection(&state, cx), 2..2);
+
+        cx.simulate_keystrokes("cmd-shift-z");
+        assert_eq!(value(&state, cx), "a 漢");
+    }
+}
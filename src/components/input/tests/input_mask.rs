@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod input_mask {
+    use crate::components::input::{
+        history::{Change, History},
+        input_mask::{InputMask, MaskEdit},
+    };
+    use std::ops::Range;
+
+    const PHONE: &str = "(###) ###-####";
+
+    /// Apply an edit to `text`, recording it in `history` as its own step
+    fn apply(
+        mask: &InputMask,
+        text: &mut String,
+        history: &mut History,
+        range: Range<usize>,
+        new_text: &str,
+    ) -> usize {
+        let Some(MaskEdit {
+            range,
+            text: replacement,
+            cursor,
+        }) = mask.edit(text, range, new_text)
+        else {
+            return text.len();
+        };
+        let old_text = text[range.clone()].to_string();
+        let change = if range.is_empty() {
+            Change::insert(replacement, range.start)
+        } else if replacement.is_empty() {
+            Change::delete(old_text, range)
+        } else {
+            Change::replace(old_text, replacement, range)
+        };
+        change.apply(text);
+        history.push(change);
+        history.commit();
+        cursor
+    }
+
+    fn type_text(mask: &InputMask, text: &mut String, history: &mut History, typed: &str) {
+        for ch in typed.chars() {
+            let cursor = text.len();
+            apply(mask, text, history, cursor..cursor, &ch.to_string());
+        }
+    }
+
+    #[test]
+    fn typing_digits_into_a_phone_mask() {
+        let mask = InputMask::new(PHONE);
+        let mut text = String::new();
+        let mut history = History::new();
+
+        type_text(&mask, &mut text, &mut history, "555");
+        assert_eq!(text, "(555");
+        type_text(&mask, &mut text, &mut history, "1234567");
+        assert_eq!(text, "(555) 123-4567");
+        assert_eq!(mask.unformat(&text), "5551234567");
+    }
+
+    #[test]
+    fn rejects_characters_that_dont_fit() {
+        let mask = InputMask::new(PHONE);
+        let mut text = String::new();
+        let mut history = History::new();
+
+        type_text(&mask, &mut text, &mut history, "5a5-5 1");
+        assert_eq!(text, "(555) 1");
+        type_text(&mask, &mut text, &mut history, "234567890");
+        assert_eq!(text, "(555) 123-4567");
+    }
+
+    #[test]
+    fn separators_undo_with_their_digit() {
+        let mask = InputMask::new(PHONE);
+        let mut text = String::new();
+        let mut history = History::new();
+        type_text(&mask, &mut text, &mut history, "5551234");
+        assert_eq!(text, "(555) 123-4");
+
+        history.undo().unwrap().change.apply(&mut text);
+        assert_eq!(text, "(555) 123");
+        history.undo().unwrap().change.apply(&mut text);
+        assert_eq!(text, "(555) 12");
+        for _ in 0..3 {
+            history.undo().unwrap().change.apply(&mut text);
+        }
+        assert_eq!(text, "(55");
+        history.undo().unwrap().change.apply(&mut text);
+        history.undo().unwrap().change.apply(&mut text);
+        assert_eq!(text, "");
+        assert!(history.undo().is_none());
+
+        history.redo().unwrap().change.apply(&mut text);
+        assert_eq!(text, "(5");
+    }
+
+    #[test]
+    fn backspace_over_a_separator_deletes_the_digit_before_it() {
+        let mask = InputMask::new(PHONE);
+        let mut text = String::new();
+        let mut history = History::new();
+        type_text(&mask, &mut text, &mut history, "5551");
+
+        // The caret sits after "(555) " and backspace removes the space
+        let cursor = apply(&mask, &mut text, &mut history, 5..6, "");
+        assert_eq!(text, "(551");
+        assert_eq!(cursor, 3);
+    }
+
+    #[test]
+    fn editing_in_the_middle_reflows() {
+        let mask = InputMask::new(PHONE);
+        let mut text = String::new();
+        let mut history = History::new();
+        type_text(&mask, &mut text, &mut history, "5551234");
+
+        let cursor = apply(&mask, &mut text, &mut history, 1..1, "9");
+        assert_eq!(text, "(955) 512-34");
+        assert_eq!(cursor, 2);
+        assert_eq!(mask.unformat(&text), "95551234");
+
+        let cursor = apply(&mask, &mut text, &mut history, 1..2, "");
+        assert_eq!(text, "(555) 123-4");
+        assert_eq!(cursor, 1);
+    }
+
+    #[test]
+    fn pasting_formatted_text() {
+        let mask = InputMask::new(PHONE);
+        assert_eq!(mask.format("(555) 123-4567"), "(555) 123-4567");
+        assert_eq!(mask.format("555.123.4567"), "(555) 123-4567");
+    }
+
+    #[test]
+    fn date_mask() {
+        let mask = InputMask::new("##/##/####");
+        let mut text = String::new();
+        let mut history = History::new();
+        type_text(&mask, &mut text, &mut history, "12");
+        assert_eq!(text, "12");
+        type_text(&mask, &mut text, &mut history, "3");
+        assert_eq!(text, "12/3");
+        type_text(&mask, &mut text, &mut history, "1202499");
+        assert_eq!(text, "12/31/2024");
+        assert_eq!(mask.unformat(&text), "12312024");
+    }
+
+    #[test]
+    fn unchanged_edits_are_skipped() {
+        let mask = InputMask::new(PHONE);
+        assert_eq!(mask.edit("(555", 4..4, "x"), None);
+        assert_eq!(mask.edit("", 0..0, ""), None);
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod lines {
+    use crate::components::input::text_ops::TextOps;
+
+    #[test]
+    fn line_ranges() {
+        let text = "one\ntwo\n\nfour";
+        assert_eq!(TextOps::line_range(text, 0), 0..3);
+        assert_eq!(TextOps::line_range(text, 3), 0..3);
+        assert_eq!(TextOps::line_range(text, 4), 4..7);
+        assert_eq!(TextOps::line_range(text, 8), 8..8);
+        assert_eq!(TextOps::line_range(text, text.len()), 9..13);
+        assert_eq!(TextOps::line_range("", 0), 0..0);
+    }
+
+    #[test]
+    fn move_between_lines() {
+        let text = "hello\nhi\n\nworld";
+        assert_eq!(TextOps::line_below(text, 4), 8);
+        assert_eq!(TextOps::line_below(text, 8), 9);
+        assert_eq!(TextOps::line_below(text, 9), 10);
+        assert_eq!(TextOps::line_below(text, 12), text.len());
+
+        assert_eq!(TextOps::line_above(text, 14), 9);
+        assert_eq!(TextOps::line_above(text, 9), 6);
+        assert_eq!(TextOps::line_above(text, 7), 1);
+        assert_eq!(TextOps::line_above(text, 3), 0);
+    }
+
+    #[test]
+    fn columns_count_graphemes() {
+        let text = "e\u{301}\u{1F642}x\nabcd";
+        let below = TextOps::line_below(text, text.find('x').unwrap());
+        assert_eq!(&text[below..], "cd");
+        assert_eq!(TextOps::line_above(text, below), text.find('x').unwrap());
+    }
+
+    #[test]
+    fn line_counts() {
+        assert_eq!(TextOps::line_count(""), 1);
+        assert_eq!(TextOps::line_count("one"), 1);
+        assert_eq!(TextOps::line_count("one\ntwo"), 2);
+        assert_eq!(TextOps::line_count("one\n"), 2);
+        assert_eq!(TextOps::line_count("\n\n\n"), 4);
+    }
+
+    #[test]
+    fn auto_grow_between_limits() {
+        let heights: Vec<usize> = (0..8).map(|n| TextOps::visible_lines(n, 2, 5)).collect();
+        assert_eq!(heights, [2, 2, 2, 3, 4, 5, 5, 5]);
+
+        // Deleting text shrinks it again
+        let mut text = String::from("a\nb\nc\nd\ne\nf");
+        assert_eq!(TextOps::visible_lines(TextOps::line_count(&text), 1, 4), 4);
+        text.truncate(3);
+        assert_eq!(TextOps::visible_lines(TextOps::line_count(&text), 1, 4), 2);
+
+        // The minimum wins over a smaller maximum
+        assert_eq!(TextOps::visible_lines(1, 3, 2), 3);
+        assert_eq!(TextOps::visible_lines(6, 3, 2), 3);
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod masking {
+    use crate::components::input::text_ops::TextOps;
+
+    #[test]
+    fn masks_every_grapheme() {
+        assert_eq!(TextOps::mask_text("hunter2", "•", None), "•••••••");
+        assert_eq!(TextOps::mask_text("cafe\u{301}", "•", None), "••••");
+        assert_eq!(TextOps::mask_text("ab", "**", None), "****");
+        assert_eq!(TextOps::mask_text("ab", "", None), "");
+        assert_eq!(TextOps::mask_text("", "•", None), "");
+    }
+
+    #[test]
+    fn marked_text_is_shown() {
+        let marked = 2..5;
+        assert_eq!(TextOps::mask_text("abにc", "*", Some(&marked)), "**に*");
+    }
+
+    #[test]
+    fn offsets_round_trip() {
+        let text = "pä🙂ss";
+        let mask = "•";
+        let display = TextOps::mask_text(text, mask, None);
+        assert_eq!(display, "•••••");
+
+        for (i, (offset, _)) in text.char_indices().enumerate() {
+            let display_offset = TextOps::offset_to_masked(text, offset, mask.len(), None);
+            assert_eq!(display_offset, i * mask.len());
+            assert_eq!(
+                TextOps::offset_from_masked(text, display_offset, mask.len(), None),
+                offset
+            );
+        }
+        assert_eq!(
+            TextOps::offset_to_masked(text, text.len(), mask.len(), None),
+            display.len()
+        );
+    }
+
+    #[test]
+    fn display_offsets_snap_to_graphemes() {
+        // "•" is three bytes, so offsets inside it belong to the grapheme it masks
+        assert_eq!(TextOps::offset_from_masked("abc", 4, 3, None), 1);
+        assert_eq!(TextOps::offset_from_masked("abc", 100, 3, None), 3);
+    }
+
+    #[test]
+    fn offsets_around_marked_text() {
+        let text = "abにc";
+        let marked = 2..5;
+        let display = TextOps::mask_text(text, "*", Some(&marked));
+
+        for offset in [0, 1, 2, 5, 6] {
+            let display_offset = TextOps::offset_to_masked(text, offset, 1, Some(&marked));
+            assert_eq!(
+                TextOps::offset_from_masked(text, display_offset, 1, Some(&marked)),
+                offset
+            );
+        }
+        assert_eq!(
+            TextOps::offset_to_masked(text, text.len(), 1, Some(&marked)),
+            display.len()
+        );
+    }
+}
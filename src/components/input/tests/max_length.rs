@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod max_length {
+    use crate::components::input::text_ops::TextOps;
+
+    #[test]
+    fn fits_within_limit() {
+        assert_eq!(TextOps::truncate_to_fit("abc", &(3..3), "de", 5), "de");
+        assert_eq!(TextOps::truncate_to_fit("", &(0..0), "hello", 10), "hello");
+    }
+
+    #[test]
+    fn truncates_paste() {
+        assert_eq!(TextOps::truncate_to_fit("abc", &(3..3), "defgh", 5), "de");
+        assert_eq!(TextOps::truncate_to_fit("abc", &(1..1), "xyz", 4), "x");
+        assert_eq!(TextOps::truncate_to_fit("abcde", &(5..5), "f", 5), "");
+    }
+
+    #[test]
+    fn replaced_text_frees_room() {
+        assert_eq!(TextOps::truncate_to_fit("abcde", &(1..4), "wxyz", 5), "wxy");
+        assert_eq!(
+            TextOps::truncate_to_fit("abcde", &(0..5), "vwxyz!", 5),
+            "vwxyz"
+        );
+    }
+
+    #[test]
+    fn counts_grapheme_clusters() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("{family}e\u{301}");
+        assert_eq!(
+            TextOps::truncate_to_fit(&text, &(text.len()..text.len()), &format!("{family}x"), 3),
+            family
+        );
+    }
+
+    #[test]
+    fn already_over_limit() {
+        assert_eq!(TextOps::truncate_to_fit("abcdef", &(6..6), "g", 3), "");
+    }
+
+    #[test]
+    fn counter_counts_grapheme_clusters() {
+        let mut text = String::from("ab");
+        assert_eq!(TextOps::grapheme_count(&text), 2);
+
+        text.push_str("e\u{301}");
+        text.push_str("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}");
+        assert_eq!(TextOps::grapheme_count(&text), 4);
+        assert!(text.len() > 4);
+
+        text.truncate(2);
+        assert_eq!(TextOps::grapheme_count(&text), 2);
+        assert_eq!(TextOps::grapheme_count(""), 0);
+    }
+
+    #[test]
+    fn counter_warns_near_limit() {
+        assert!(!TextOps::near_limit(125, 140));
+        assert!(TextOps::near_limit(126, 140));
+        assert!(TextOps::near_limit(140, 140));
+        assert!(!TextOps::near_limit(8, 10));
+        assert!(TextOps::near_limit(9, 10));
+        assert!(TextOps::near_limit(0, 0));
+    }
+}
@@ -1,2 +1,27 @@
+mod adornments;
+mod bidi;
+mod blink;
+mod clear;
+mod clipboard;
+mod drag;
+mod editing;
+mod events;
+mod filter;
 mod history;
+mod ime;
+mod input_mask;
+mod lines;
+mod masking;
+mod max_length;
+mod multiline;
+mod paste;
+mod placeholder;
+mod read_only;
+mod runs;
+mod scroll;
+mod selection;
+mod suggestions;
+mod support;
+mod tab;
+mod undo;
 mod word_boundaries;
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod multiline {
+    use crate::components::input::{
+        Paste, Undo,
+        tests::support::{input, value},
+    };
+    use gpui::{ClipboardItem, Modifiers, TestAppContext, VisualTestContext, point, px};
+
+    #[gpui::test]
+    fn enter_inserts_newline_and_lines_undo_separately(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.multiline(true));
+        cx.simulate_input("one");
+        cx.simulate_keystrokes("enter");
+        cx.simulate_input("two");
+        assert_eq!(value(&state, cx), "one\ntwo");
+
+        cx.dispatch_action(Undo);
+        assert_eq!(value(&state, cx), "one\n");
+        cx.dispatch_action(Undo);
+        assert_eq!(value(&state, cx), "one");
+    }
+
+    #[gpui::test]
+    fn enter_leaves_single_line_input_alone(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("one"));
+        cx.simulate_keystrokes("enter");
+        assert_eq!(value(&state, cx), "one");
+    }
+
+    #[gpui::test]
+    fn up_and_down_keep_the_column(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.multiline(true).value("abcd\nef\nghij"));
+        state.update(cx, |state, cx| state.set_caret_offset(3, cx));
+
+        // The short middle line holds the caret at its end
+        cx.simulate_keystrokes("down");
+        assert_eq!(state.read_with(cx, |state, _| state.caret_offset()), 7);
+        cx.simulate_keystrokes("down");
+        assert_eq!(state.read_with(cx, |state, _| state.caret_offset()), 10);
+        cx.simulate_keystrokes("up up");
+        assert_eq!(state.read_with(cx, |state, _| state.caret_offset()), 2);
+
+        cx.simulate_keystrokes("shift-down");
+        assert_eq!(state.read_with(cx, |state, _| state.selection()), 2..7);
+    }
+
+    #[gpui::test]
+    fn paste_keeps_line_breaks(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.multiline(true));
+        cx.write_to_clipboard(ClipboardItem::new_string("a\r\nb\rc".into()));
+        cx.dispatch_action(Paste);
+        assert_eq!(value(&state, cx), "a\nb\nc");
+    }
+
+    #[gpui::test]
+    fn lays_out_one_row_per_line(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.multiline(true).value("a\nb\nc"));
+        let line_height = cx.update(|window, _| window.line_height());
+        let bounds = state.read_with(cx, |state, _| state.last_bounds.unwrap());
+        assert_eq!(bounds.size.height, line_height * 3.0);
+
+        // Clicking the last row puts the caret on the last line
+        let position = bounds.origin + point(px(0.0), line_height * 2.5);
+        cx.simulate_click(position, Modifiers::none());
+        assert_eq!(state.read_with(cx, |state, _| state.caret_offset()), 4);
+    }
+
+    #[gpui::test]
+    fn auto_grow_follows_the_line_count(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.auto_grow(2, 4));
+        let line_height = cx.update(|window, _| window.line_height());
+        let height = |cx: &mut VisualTestContext| {
+            state.read_with(cx, |state, _| state.last_bounds.unwrap().size.height)
+        };
+        assert_eq!(height(cx), line_height * 2.0);
+
+        cx.simulate_input("a");
+        cx.simulate_keystrokes("enter enter");
+        assert_eq!(height(cx), line_height * 3.0);
+
+        // Stops growing at the max, scrolling the rest
+        cx.simulate_keystrokes("enter enter enter");
+        assert_eq!(value(&state, cx), "a\n\n\n\n\n");
+        assert_eq!(height(cx), line_height * 4.0);
+
+        cx.simulate_keystrokes("backspace backspace backspace");
+        assert_eq!(height(cx), line_height * 3.0);
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(height(cx), line_height * 4.0);
+
+        cx.simulate_keystrokes("cmd-a backspace");
+        assert_eq!(height(cx), line_height * 2.0);
+    }
+}
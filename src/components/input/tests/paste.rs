@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod paste {
+    use crate::components::input::text_ops::TextOps;
+
+    #[test]
+    fn newlines_become_spaces() {
+        assert_eq!(TextOps::join_lines("a\nb\r\nc", " "), "a b c");
+        assert_eq!(TextOps::join_lines("a\rb", " "), "a b");
+        assert_eq!(TextOps::join_lines("a\n\nb\n", " "), "a  b ");
+    }
+
+    #[test]
+    fn newlines_are_stripped() {
+        assert_eq!(TextOps::join_lines("a\nb\r\nc", ""), "abc");
+        assert_eq!(TextOps::join_lines("\r\nline\r\n", ""), "line");
+    }
+
+    #[test]
+    fn single_line_text_is_borrowed() {
+        assert!(matches!(
+            TextOps::join_lines("one line", " "),
+            std::borrow::Cow::Borrowed("one line")
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod placeholder {
+    use crate::components::input::placeholder::display_highlight;
+    use gpui::{FontStyle, HighlightStyle, hsla};
+
+    #[test]
+    fn placeholder_uses_its_style() {
+        let text_color = hsla(0., 0., 0., 1.);
+        let style = HighlightStyle {
+            color: Some(hsla(0.6, 0.5, 0.5, 1.)),
+            font_style: Some(FontStyle::Italic),
+            fade_out: Some(0.2),
+            ..Default::default()
+        };
+        assert_eq!(
+            display_highlight(true, text_color, None, Some(style)),
+            style
+        );
+    }
+
+    #[test]
+    fn real_text_keeps_the_text_style() {
+        let text_color = hsla(0., 0., 0., 1.);
+        let style = HighlightStyle {
+            color: Some(hsla(0.6, 0.5, 0.5, 1.)),
+            font_style: Some(FontStyle::Italic),
+            ..Default::default()
+        };
+        let highlight =
+            display_highlight(false, text_color, Some(hsla(0.3, 1., 0.5, 1.)), Some(style));
+        assert_eq!(
+            highlight,
+            HighlightStyle {
+                color: Some(text_color),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn placeholder_color_fills_in_for_the_style() {
+        let text_color = hsla(0., 0., 0., 1.);
+        let color = hsla(0.3, 1., 0.5, 1.);
+        let style = HighlightStyle {
+            font_style: Some(FontStyle::Italic),
+            ..Default::default()
+        };
+        let highlight = display_highlight(true, text_color, Some(color), Some(style));
+        assert_eq!(highlight.color, Some(color));
+        assert_eq!(highlight.font_style, Some(FontStyle::Italic));
+    }
+
+    #[test]
+    fn placeholder_defaults_to_dimmed_text() {
+        let text_color = hsla(0.1, 0.2, 0.3, 0.8);
+        assert_eq!(
+            display_highlight(true, text_color, None, None),
+            HighlightStyle {
+                color: Some(hsla(0.1, 0.2, 0.3, 0.4)),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod read_only {
+    use crate::components::input::tests::support::{input, selection, value};
+    use gpui::{ClipboardItem, EntityInputHandler, TestAppContext};
+
+    #[gpui::test]
+    fn keys_never_change_the_value(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("locked").read_only(true));
+        cx.write_to_clipboard(ClipboardItem::new_string("pasted".into()));
+
+        cx.simulate_input("typed");
+        cx.simulate_keystrokes("backspace left delete alt-backspace enter cmd-v");
+        cx.simulate_keystrokes("cmd-a cmd-x backspace cmd-z");
+        state.update_in(cx, |state, window, cx| {
+            state.replace_and_mark_text_in_range(None, "か", None, window, cx);
+        });
+
+        assert_eq!(value(&state, cx), "locked");
+        assert!(!state.read_with(cx, |state, _| state.can_undo()));
+    }
+
+    #[gpui::test]
+    fn selection_can_still_be_copied(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state.value("locked").read_only(true));
+
+        cx.simulate_keystrokes("cmd-a");
+        assert_eq!(selection(&state, cx), 0..6);
+        cx.simulate_keystrokes("cmd-c");
+        assert_eq!(
+            cx.read_from_clipboard()
+                .and_then(|item| item.text())
+                .as_deref(),
+            Some("locked")
+        );
+
+        cx.simulate_keystrokes("left shift-right");
+        assert_eq!(selection(&state, cx), 0..1);
+    }
+}
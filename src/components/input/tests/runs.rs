@@ -0,0 +1,32 @@
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod runs {
+    use crate::components::input::text_ops::TextOps;
+
+    #[test]
+    fn no_ranges_is_one_segment() {
+        assert_eq!(TextOps::split_at_ranges(5, &[]), [0..5]);
+        assert!(TextOps::split_at_ranges(0, &[]).is_empty());
+    }
+
+    #[test]
+    fn selection_splits_text() {
+        assert_eq!(TextOps::split_at_ranges(10, &[2..6]), [0..2, 2..6, 6..10]);
+        assert_eq!(TextOps::split_at_ranges(10, &[0..10]), [0..10]);
+        assert_eq!(TextOps::split_at_ranges(10, &[0..4]), [0..4, 4..10]);
+    }
+
+    #[test]
+    fn overlapping_marked_and_selected_ranges() {
+        assert_eq!(
+            TextOps::split_at_ranges(10, &[1..5, 3..8]),
+            [0..1, 1..3, 3..5, 5..8, 8..10]
+        );
+        assert_eq!(TextOps::split_at_ranges(10, &[4..4]), [0..4, 4..10]);
+    }
+
+    #[test]
+    fn bounds_past_the_text_are_dropped() {
+        assert_eq!(TextOps::split_at_ranges(4, &[2..9]), [0..2, 2..4]);
+    }
+}
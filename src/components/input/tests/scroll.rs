@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod scroll {
+    use crate::components::input::text_ops::TextOps;
+
+    #[test]
+    fn text_that_fits_never_scrolls() {
+        assert_eq!(TextOps::scroll_to_reveal(80.0, 0.0, 100.0, 90.0), 0.0);
+        assert_eq!(TextOps::scroll_to_reveal(80.0, 40.0, 100.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn caret_inside_view_keeps_scroll() {
+        assert_eq!(TextOps::scroll_to_reveal(50.0, 0.0, 100.0, 300.0), 0.0);
+        assert_eq!(TextOps::scroll_to_reveal(150.0, 120.0, 100.0, 300.0), 120.0);
+    }
+
+    #[test]
+    fn caret_past_right_edge_scrolls_right() {
+        assert_eq!(TextOps::scroll_to_reveal(101.0, 0.0, 100.0, 300.0), 1.0);
+        assert_eq!(TextOps::scroll_to_reveal(180.0, 0.0, 100.0, 300.0), 80.0);
+        assert_eq!(TextOps::scroll_to_reveal(300.0, 50.0, 100.0, 300.0), 200.0);
+    }
+
+    #[test]
+    fn caret_past_left_edge_scrolls_left() {
+        assert_eq!(TextOps::scroll_to_reveal(30.0, 120.0, 100.0, 300.0), 30.0);
+        assert_eq!(TextOps::scroll_to_reveal(0.0, 120.0, 100.0, 300.0), 0.0);
+    }
+
+    #[test]
+    fn scroll_stays_within_content() {
+        // Text shrank under a scrolled view
+        assert_eq!(TextOps::scroll_to_reveal(140.0, 150.0, 100.0, 150.0), 50.0);
+        assert_eq!(TextOps::scroll_to_reveal(-10.0, 20.0, 100.0, 300.0), 0.0);
+    }
+
+    #[test]
+    fn line_below_view_scrolls_down_to_its_bottom() {
+        // 20px lines, three visible out of six
+        assert_eq!(TextOps::scroll_to_reveal_line(20.0, 40.0, 0.0, 60.0, 120.0), 0.0);
+        assert_eq!(TextOps::scroll_to_reveal_line(60.0, 80.0, 0.0, 60.0, 120.0), 20.0);
+        assert_eq!(TextOps::scroll_to_reveal_line(100.0, 120.0, 0.0, 60.0, 120.0), 60.0);
+    }
+
+    #[test]
+    fn line_above_view_scrolls_up_to_its_top() {
+        assert_eq!(TextOps::scroll_to_reveal_line(20.0, 40.0, 60.0, 60.0, 120.0), 20.0);
+        assert_eq!(TextOps::scroll_to_reveal_line(0.0, 20.0, 30.0, 60.0, 120.0), 0.0);
+        // Lines that all fit never scroll
+        assert_eq!(TextOps::scroll_to_reveal_line(40.0, 60.0, 20.0, 60.0, 60.0), 0.0);
+    }
+}
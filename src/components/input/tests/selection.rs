@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod selection {
+    use crate::components::input::text_ops::TextOps;
+
+    #[test]
+    fn double_click_selects_word() {
+        let text = "foo, bar_baz 漢字";
+        let cjk = text.find('漢').unwrap();
+
+        assert_eq!(TextOps::word_range(text, 0), 0..3);
+        assert_eq!(TextOps::word_range(text, 1), 0..3);
+        assert_eq!(TextOps::word_range(text, 3), 3..4);
+        assert_eq!(TextOps::word_range(text, 4), 4..5);
+        assert_eq!(TextOps::word_range(text, 5), 5..12);
+        assert_eq!(TextOps::word_range(text, 8), 5..12);
+        assert_eq!(TextOps::word_range(text, cjk), cjk..text.len());
+        assert_eq!(TextOps::word_range(text, text.len()), cjk..text.len());
+    }
+
+    #[test]
+    fn double_click_selects_whitespace_run() {
+        assert_eq!(TextOps::word_range("one   two", 4), 3..6);
+        assert_eq!(TextOps::word_range("", 0), 0..0);
+    }
+
+    #[test]
+    fn double_click_keeps_decimals_together() {
+        assert_eq!(TextOps::word_range("pi is 3.14", 7), 6..10);
+        assert_eq!(TextOps::word_range("end.", 3), 3..4);
+    }
+
+    #[test]
+    fn triple_click_selects_line() {
+        assert_eq!(TextOps::line_range("single line", 3), 0..11);
+        assert_eq!(TextOps::line_range("one\ntwo\nthree", 5), 4..7);
+    }
+
+    #[test]
+    fn drag_extends_by_unit() {
+        let text = "alpha beta gamma";
+        let anchor = TextOps::word_range(text, 7);
+        assert_eq!(anchor, 6..10);
+
+        let forward = TextOps::word_range(text, 13);
+        assert_eq!(TextOps::extend_selection(&anchor, &forward), (6..16, false));
+
+        let backward = TextOps::word_range(text, 2);
+        assert_eq!(TextOps::extend_selection(&anchor, &backward), (0..10, true));
+
+        // Dragging within the anchor keeps it whole
+        let inside = TextOps::word_range(text, 8);
+        assert_eq!(TextOps::extend_selection(&anchor, &inside), (6..10, false));
+    }
+
+    #[test]
+    fn set_selection_at_boundaries() {
+        let text = "héllo wörld";
+        assert_eq!(TextOps::snap_selection(text, 0, 6), (0..6, false));
+        assert_eq!(TextOps::snap_selection(text, 7, 12), (7..12, false));
+        assert_eq!(TextOps::snap_selection(text, 0, text.len()), (0..13, false));
+        assert_eq!(TextOps::snap_selection(text, 3, 3), (3..3, false));
+    }
+
+    #[test]
+    fn set_selection_clamps_out_of_range() {
+        let text = "hello";
+        assert_eq!(TextOps::snap_selection(text, 2, 99), (2..5, false));
+        assert_eq!(TextOps::snap_selection(text, 99, 100), (5..5, false));
+        assert_eq!(TextOps::snap_selection("", 3, 7), (0..0, false));
+    }
+
+    #[test]
+    fn set_selection_snaps_inside_characters() {
+        // "é" spans 1..3, so 2 sits inside it
+        let text = "héllo";
+        assert_eq!(TextOps::snap_selection(text, 2, 4), (1..4, false));
+
+        // A family emoji spans 0..18 and splits towards the nearer end
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(TextOps::snap_selection(family, 4, 15), (0..18, false));
+    }
+
+    #[test]
+    fn select_range_reverses_when_head_comes_first() {
+        assert_eq!(TextOps::snap_selection("hello", 4, 1), (1..4, true));
+        assert_eq!(TextOps::snap_selection("héllo", 2, 0), (0..1, true));
+    }
+
+    #[test]
+    fn caret_snaps_to_valid_offsets() {
+        let text = "añb";
+        assert_eq!(TextOps::snap_to_boundary(text, 0), 0);
+        assert_eq!(TextOps::snap_to_boundary(text, 1), 1);
+        assert_eq!(TextOps::snap_to_boundary(text, 3), 3);
+        assert_eq!(TextOps::snap_to_boundary(text, 4), 4);
+    }
+
+    #[test]
+    fn caret_past_the_end_clamps() {
+        assert_eq!(TextOps::snap_to_boundary("añb", 5), 4);
+        assert_eq!(TextOps::snap_to_boundary("añb", usize::MAX), 4);
+        assert_eq!(TextOps::snap_to_boundary("", 1), 0);
+    }
+
+    #[test]
+    fn caret_inside_a_multibyte_character_snaps_to_the_nearest_boundary() {
+        // "ñ" spans 1..3 and "🙂" spans 3..7
+        let text = "añ🙂";
+        assert_eq!(TextOps::snap_to_boundary(text, 2), 1);
+        assert_eq!(TextOps::snap_to_boundary(text, 4), 3);
+        assert_eq!(TextOps::snap_to_boundary(text, 5), 3);
+        assert_eq!(TextOps::snap_to_boundary(text, 6), 7);
+
+        // Inside a combining sequence the caret leaves the whole cluster intact
+        assert_eq!(TextOps::snap_to_boundary("e\u{301}", 1), 0);
+        assert_eq!(TextOps::snap_to_boundary("e\u{301}", 2), 3);
+    }
+}
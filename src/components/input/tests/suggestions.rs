@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod suggestions {
+    use crate::components::input::{
+        InputState, TabBehavior,
+        suggestions::Suggestions,
+        tests::support::{input, value},
+    };
+    use gpui::{SharedString, TestAppContext};
+
+    fn open(items: &[&'static str]) -> Suggestions {
+        let mut suggestions = Suggestions::default();
+        suggestions.set(items.iter().map(|&item| SharedString::from(item)).collect());
+        suggestions
+    }
+
+    #[test]
+    fn opens_with_items() {
+        let suggestions = open(&["apple", "apricot"]);
+        assert!(suggestions.is_open());
+        assert_eq!(suggestions.items().len(), 2);
+        assert_eq!(suggestions.highlighted(), None);
+    }
+
+    #[test]
+    fn stays_closed_without_items() {
+        let suggestions = open(&[]);
+        assert!(!suggestions.is_open());
+        assert!(suggestions.items().is_empty());
+    }
+
+    #[test]
+    fn next_wraps_around() {
+        let mut suggestions = open(&["a", "b", "c"]);
+        suggestions.next();
+        assert_eq!(suggestions.highlighted(), Some(0));
+        suggestions.next();
+        suggestions.next();
+        assert_eq!(suggestions.highlighted(), Some(2));
+        suggestions.next();
+        assert_eq!(suggestions.highlighted(), Some(0));
+    }
+
+    #[test]
+    fn previous_wraps_around() {
+        let mut suggestions = open(&["a", "b", "c"]);
+        suggestions.previous();
+        assert_eq!(suggestions.highlighted(), Some(2));
+        suggestions.previous();
+        suggestions.previous();
+        assert_eq!(suggestions.highlighted(), Some(0));
+        suggestions.previous();
+        assert_eq!(suggestions.highlighted(), Some(2));
+    }
+
+    #[test]
+    fn new_items_reset_the_highlight() {
+        let mut suggestions = open(&["a", "b"]);
+        suggestions.next();
+        suggestions.set(vec!["c".into()]);
+        assert_eq!(suggestions.highlighted(), None);
+    }
+
+    #[test]
+    fn highlight_ignores_out_of_range() {
+        let mut suggestions = open(&["a", "b"]);
+        suggestions.highlight(1);
+        suggestions.highlight(5);
+        assert_eq!(suggestions.highlighted(), Some(1));
+    }
+
+    #[test]
+    fn dismiss_closes_until_new_items() {
+        let mut suggestions = open(&["a", "b"]);
+        suggestions.next();
+        suggestions.dismiss();
+        assert!(!suggestions.is_open());
+        assert_eq!(suggestions.highlighted(), None);
+
+        suggestions.next();
+        assert_eq!(suggestions.highlighted(), None);
+        assert_eq!(suggestions.accept(), None);
+
+        suggestions.set(vec!["c".into()]);
+        assert!(suggestions.is_open());
+    }
+
+    #[test]
+    fn accept_returns_highlighted_and_closes() {
+        let mut suggestions = open(&["apple", "apricot"]);
+        assert_eq!(suggestions.accept(), None);
+        assert!(suggestions.is_open());
+
+        suggestions.next();
+        suggestions.next();
+        assert_eq!(suggestions.accept(), Some("apricot".into()));
+        assert!(!suggestions.is_open());
+    }
+
+    fn fruit(state: InputState) -> InputState {
+        state.suggestions(|query| {
+            ["apple", "apricot", "banana"]
+                .into_iter()
+                .filter(|fruit| fruit.starts_with(query))
+                .map(SharedString::from)
+                .collect()
+        })
+    }
+
+    #[gpui::test]
+    fn tab_accepts_the_first_suggestion(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, fruit);
+        cx.simulate_input("ap");
+        assert!(state.read_with(cx, |state, _| state.suggestions.is_open()));
+
+        cx.simulate_keystrokes("tab");
+        assert_eq!(value(&state, cx), "apple");
+        assert!(!state.read_with(cx, |state, _| state.suggestions.is_open()));
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "ap");
+    }
+
+    #[gpui::test]
+    fn enter_accepts_the_highlighted_suggestion(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, fruit);
+        cx.simulate_input("ap");
+
+        cx.simulate_keystrokes("down down enter");
+        assert_eq!(value(&state, cx), "apricot");
+    }
+
+    #[gpui::test]
+    fn tab_indents_once_the_list_is_closed(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| {
+            fruit(state).tab_behavior(TabBehavior::InsertSpaces(2))
+        });
+        cx.simulate_input("ap");
+
+        cx.simulate_keystrokes("escape tab");
+        assert_eq!(value(&state, cx), "ap  ");
+    }
+}
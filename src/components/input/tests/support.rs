@@ -0,0 +1,53 @@
+use crate::components::input::{InputState, TextInput, init};
+use gpui::*;
+use std::ops::Range;
+
+/// A window rendering a single [`TextInput`]
+pub(super) struct InputView {
+    pub(super) state: Entity<InputState>,
+    decorate: Box<dyn Fn(TextInput) -> TextInput>,
+}
+
+impl Render for InputView {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .size_full()
+            .child((self.decorate)(TextInput::new(self.state.clone())))
+    }
+}
+
+/// Open a window with a focused input built by `build`
+pub(super) fn input(
+    cx: &mut TestAppContext,
+    build: impl FnOnce(InputState) -> InputState,
+) -> (Entity<InputState>, &mut VisualTestContext) {
+    text_input(cx, build, |input| input)
+}
+
+/// Open a window with a focused input built by `build`, rendered by the component `decorate`
+/// returns
+pub(super) fn text_input(
+    cx: &mut TestAppContext,
+    build: impl FnOnce(InputState) -> InputState,
+    decorate: impl Fn(TextInput) -> TextInput + 'static,
+) -> (Entity<InputState>, &mut VisualTestContext) {
+    cx.update(init);
+    let (view, cx) = cx.add_window_view(|window, cx| InputView {
+        state: cx.new(|cx| build(InputState::new(window, cx))),
+        decorate: Box::new(decorate),
+    });
+    let state = view.read_with(cx, |view, _| view.state.clone());
+    state.update_in(cx, |state, window, _| state.focus_handle.focus(window));
+    cx.run_until_parked();
+    (state, cx)
+}
+
+/// The current value of the input
+pub(super) fn value(state: &Entity<InputState>, cx: &mut VisualTestContext) -> String {
+    state.read_with(cx, |state, _| state.value.to_string())
+}
+
+/// The selected range of the input, which is empty at the caret
+pub(super) fn selection(state: &Entity<InputState>, cx: &mut VisualTestContext) -> Range<usize> {
+    state.read_with(cx, |state, _| state.selection())
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tab {
+    use crate::components::input::{
+        InputState, TabBehavior, TextInput,
+        history::{Change, History},
+        init,
+        tests::support::{input, selection, value},
+        text_ops::TextOps,
+    };
+    use gpui::*;
+
+    #[test]
+    fn outdent_removes_a_leading_tab() {
+        assert_eq!(TextOps::outdent_range("\tvalue", "\t"), 0..1);
+        assert_eq!(TextOps::outdent_range("\t\tvalue", "    "), 0..1);
+    }
+
+    #[test]
+    fn outdent_removes_up_to_one_indent_of_spaces() {
+        assert_eq!(TextOps::outdent_range("      value", "    "), 0..4);
+        assert_eq!(TextOps::outdent_range("  value", "    "), 0..2);
+        assert_eq!(TextOps::outdent_range("  value", "\t"), 0..1);
+    }
+
+    #[test]
+    fn outdent_without_indentation_is_empty() {
+        assert!(TextOps::outdent_range("value", "    ").is_empty());
+        assert!(TextOps::outdent_range("", "\t").is_empty());
+        assert!(TextOps::outdent_range("value\t", "\t").is_empty());
+    }
+
+    #[test]
+    fn inserted_indentation_is_one_step() {
+        let mut history = History::new();
+        let mut buffer = "ab".to_string();
+
+        history.commit();
+        let change = Change::insert("    ", 1);
+        change.apply(&mut buffer);
+        history.push(change);
+        history.commit();
+        assert_eq!(buffer, "a    b");
+
+        history.undo().unwrap().change.apply(&mut buffer);
+        assert_eq!(buffer, "ab");
+        assert!(history.undo().is_none());
+    }
+
+    #[gpui::test]
+    fn backtab_outdents_the_caret_line(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| {
+            state
+                .multiline(true)
+                .tab_behavior(TabBehavior::InsertSpaces(2))
+                .value("  a\n    b")
+        });
+        state.update(cx, |state, cx| state.set_caret_offset(9, cx));
+
+        cx.simulate_keystrokes("shift-tab");
+        assert_eq!(value(&state, cx), "  a\n  b");
+        assert_eq!(selection(&state, cx), 7..7);
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "  a\n    b");
+    }
+
+    /// A window with two inputs, to move focus between
+    struct TwoInputs {
+        first: Entity<InputState>,
+        second: Entity<InputState>,
+    }
+
+    impl Render for TwoInputs {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .child(TextInput::new(self.first.clone()))
+                .child(TextInput::new(self.second.clone()))
+        }
+    }
+
+    #[gpui::test]
+    fn tab_moves_focus_between_inputs(cx: &mut TestAppContext) {
+        cx.update(init);
+        let (view, cx) = cx.add_window_view(|window, cx| TwoInputs {
+            first: cx.new(|cx| InputState::new(window, cx)),
+            second: cx.new(|cx| InputState::new(window, cx)),
+        });
+        let (first, second) =
+            view.read_with(cx, |view, _| (view.first.clone(), view.second.clone()));
+        let focused = |input: &Entity<InputState>, cx: &mut VisualTestContext| {
+            cx.update(|window, cx| input.read(cx).focus_handle.is_focused(window))
+        };
+        second.update_in(cx, |state, window, _| state.focus_handle.focus(window));
+        cx.run_until_parked();
+
+        cx.simulate_keystrokes("shift-tab");
+        assert!(focused(&first, cx));
+        cx.simulate_keystrokes("tab");
+        assert!(focused(&second, cx));
+    }
+}
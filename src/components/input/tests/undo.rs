@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod undo {
+    use crate::components::input::{
+        Redo, Undo,
+        tests::support::{input, selection, value},
+    };
+    use gpui::TestAppContext;
+
+    #[gpui::test]
+    fn undoing_past_save_is_dirty(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        assert!(!state.read_with(cx, |state, _| state.is_dirty()));
+
+        cx.simulate_input("draft");
+        state.update_in(cx, |state, window, cx| state.save(window, cx));
+        assert!(!state.read_with(cx, |state, _| state.is_dirty()));
+
+        cx.simulate_input(" two");
+        assert!(state.read_with(cx, |state, _| state.is_dirty()));
+
+        cx.dispatch_action(Undo);
+        assert_eq!(value(&state, cx), "draft");
+        assert!(!state.read_with(cx, |state, _| state.is_dirty()));
+
+        cx.dispatch_action(Undo);
+        assert_eq!(value(&state, cx), "");
+        assert!(state.read_with(cx, |state, _| state.is_dirty()));
+
+        cx.dispatch_action(Redo);
+        assert!(!state.read_with(cx, |state, _| state.is_dirty()));
+    }
+
+    #[gpui::test]
+    fn restored_history_undoes_previous_session(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        cx.simulate_input("hello world");
+        let snapshot = state.read_with(cx, |state, _| state.history().snapshot());
+
+        state.update_in(cx, |state, window, cx| {
+            state.set_value("hello world", cx);
+            assert!(!state.can_undo());
+            state.restore_history(snapshot, window, cx);
+        });
+        cx.dispatch_action(Undo);
+        assert_eq!(value(&state, cx), "hello");
+    }
+
+    #[gpui::test]
+    fn typing_after_undo_drops_redo(cx: &mut TestAppContext) {
+        let (state, cx) = input(cx, |state| state);
+        cx.simulate_input("one two");
+
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "one");
+        assert_eq!(selection(&state, cx), 3..3);
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "");
+        assert_eq!(selection(&state, cx), 0..0);
+
+        cx.simulate_keystrokes("cmd-shift-z");
+        assert_eq!(value(&state, cx), "one");
+        assert_eq!(selection(&state, cx), 3..3);
+
+        cx.simulate_input("!");
+        assert_eq!(value(&state, cx), "one!");
+        assert_eq!(selection(&state, cx), 4..4);
+        assert!(!state.read_with(cx, |state, _| state.can_redo()));
+
+        cx.simulate_keystrokes("cmd-shift-z");
+        assert_eq!(value(&state, cx), "one!");
+        cx.simulate_keystrokes("cmd-z");
+        assert_eq!(value(&state, cx), "one");
+        assert_eq!(selection(&state, cx), 3..3);
+    }
+}
@@ -82,4 +82,50 @@ mod word_boundaries {
         test_boundaries("the quick-brown_fox42 jumps!", 21, 10, 27);
         test_boundaries("the quick-brown_fox42 jumps!", 27, 22, 28);
     }
+
+    #[test]
+    fn mixed_text() {
+        let text = "foo, bar_baz 漢字";
+        let cjk = text.find('漢').unwrap();
+
+        test_boundaries(text, 0, 0, 3);
+        test_boundaries(text, 3, 0, 4);
+        test_boundaries(text, 4, 3, 12);
+        test_boundaries(text, 5, 3, 12);
+        test_boundaries(text, 9, 5, 12);
+        test_boundaries(text, 12, 5, text.len());
+        test_boundaries(text, cjk, 5, text.len());
+        test_boundaries(text, text.len(), cjk, text.len());
+    }
+
+    #[test]
+    fn word_movement_walks_mixed_text() {
+        let text = "foo, bar_baz 漢字";
+
+        let mut stops = vec![0];
+        while *stops.last().unwrap() < text.len() {
+            stops.push(TextOps::next_word_boundary(text, *stops.last().unwrap()));
+        }
+        assert_eq!(stops, [0, 3, 4, 12, text.len()]);
+
+        let mut stops = vec![text.len()];
+        while *stops.last().unwrap() > 0 {
+            stops.push(TextOps::previous_word_boundary(
+                text,
+                *stops.last().unwrap(),
+            ));
+        }
+        assert_eq!(stops, [text.len(), 13, 5, 3, 0]);
+    }
+
+    #[test]
+    fn word_before_caret() {
+        assert_eq!(TextOps::word_before("hello wor", 9), 6..9);
+        assert_eq!(TextOps::word_before("hello wor", 7), 6..7);
+        assert_eq!(TextOps::word_before("hello ", 6), 6..6);
+        assert_eq!(TextOps::word_before("snake_ca", 8), 0..8);
+        assert_eq!(TextOps::word_before("a.b", 2), 2..2);
+        assert_eq!(TextOps::word_before("", 0), 0..0);
+        assert_eq!(TextOps::word_before("café", 5), 0..5);
+    }
 }
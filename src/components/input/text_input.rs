@@ -1,14 +1,29 @@
 use crate::{
-    components::input::state::{CONTEXT, InputState},
-    primitives::h_flex_center,
+    Disableable,
+    components::input::{
+        state::{CONTEXT, InputState},
+        text_ops::TextOps,
+    },
+    primitives::{h_flex_center, v_flex},
 };
 use gpui::{prelude::FluentBuilder, *};
 
+const GROUP: &str = "text-input";
+
 #[derive(IntoElement)]
 pub struct TextInput {
     base: Div,
     state: Entity<InputState>,
-    leading: Option<AnyElement>,
+    prefix: Option<AnyElement>,
+    suffix: Option<AnyElement>,
+    adornments_focus: bool,
+    clearable: bool,
+    always_show_clear: bool,
+    show_counter: bool,
+    counter_warning_color: Hsla,
+    suggestion_highlight_color: Hsla,
+    disabled: bool,
+    when_invalid_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
 }
 
 impl TextInput {
@@ -16,13 +31,22 @@ impl TextInput {
         Self {
             base: h_flex_center().cursor(CursorStyle::IBeam),
             state,
-            leading: None,
+            prefix: None,
+            suffix: None,
+            adornments_focus: true,
+            clearable: false,
+            always_show_clear: false,
+            show_counter: false,
+            counter_warning_color: rgb(0xf59e0b).into(),
+            suggestion_highlight_color: rgba(0x3390FF40).into(),
+            disabled: false,
+            when_invalid_handler: None,
         }
     }
 
     pub fn set_placeholder_color(self, color: impl Into<Hsla>, cx: &mut impl AppContext) -> Self {
         self.state.update(cx, |this, cx| {
-            this.placeholder_color = color.into();
+            this.placeholder_color = Some(color.into());
             cx.notify();
         });
         self
@@ -36,8 +60,157 @@ impl TextInput {
         self
     }
 
-    pub fn leading(mut self, element: impl IntoElement) -> Self {
-        self.leading = Some(element.into_any_element());
+    /// Renders an element inside the input, before the text.
+    ///
+    /// The text area shrinks to make room for it, so caret offsets and history are
+    /// unaffected.
+    pub fn prefix(mut self, element: impl IntoElement) -> Self {
+        self.prefix = Some(element.into_any_element());
+        self
+    }
+
+    /// Renders an element inside the input, after the text.
+    pub fn suffix(mut self, element: impl IntoElement) -> Self {
+        self.suffix = Some(element.into_any_element());
+        self
+    }
+
+    /// Sets whether clicking the prefix or suffix focuses the text, which is the default.
+    ///
+    /// Turn this off for adornments with their own click behavior.
+    pub fn adornments_focus(mut self, focus: bool) -> Self {
+        self.adornments_focus = focus;
+        self
+    }
+
+    /// Shows a "×" button after the text that clears it as a single undo step.
+    ///
+    /// The button only appears while the input is non-empty, and by default only while
+    /// it is hovered or focused.
+    pub fn clearable(mut self, clearable: bool) -> Self {
+        self.clearable = clearable;
+        self
+    }
+
+    /// Sets whether the clear button stays visible when the input is neither hovered nor
+    /// focused.
+    pub fn always_show_clear(mut self, always: bool) -> Self {
+        self.always_show_clear = always;
+        self
+    }
+
+    /// Shows a live character count after the text, as "27/140" when the state has a
+    /// [`max_length`](InputState::max_length).
+    pub fn show_counter(mut self, show: bool) -> Self {
+        self.show_counter = show;
+        self
+    }
+
+    /// Sets the counter color once the count is within a tenth of the limit.
+    pub fn counter_warning_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.counter_warning_color = color.into();
+        self
+    }
+
+    fn counter(&self, state: &InputState) -> Div {
+        let count = state.length();
+        let (label, near_limit) = match state.max_length {
+            Some(max_length) => (
+                format!("{count}/{max_length}"),
+                TextOps::near_limit(count, max_length),
+            ),
+            None => (count.to_string(), false),
+        };
+        div().flex_none().child(label).when(near_limit, |this| {
+            this.text_color(self.counter_warning_color)
+        })
+    }
+
+    /// Sets the background of the highlighted entry in the
+    /// [suggestion list](InputState::suggestions).
+    pub fn suggestion_highlight_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.suggestion_highlight_color = color.into();
+        self
+    }
+
+    fn suggestion_list(&self, state: &InputState, window: &mut Window) -> Deferred {
+        let highlighted = state.suggestions.highlighted();
+        let items = state
+            .suggestions
+            .items()
+            .iter()
+            .enumerate()
+            .map(|(ix, item)| {
+                div()
+                    .id(ix)
+                    .cursor_pointer()
+                    .child(item.clone())
+                    .when(highlighted == Some(ix), |this| {
+                        this.bg(self.suggestion_highlight_color)
+                    })
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        window.listener_for(&self.state, move |state, _, window, cx| {
+                            window.prevent_default();
+                            cx.stop_propagation();
+                            state.accept_suggestion(ix, window, cx);
+                        }),
+                    )
+            });
+        deferred(
+            div()
+                .absolute()
+                .top_full()
+                .left_0()
+                .w_full()
+                .child(v_flex().children(items)),
+        )
+    }
+
+    fn clear_button(&self, focused: bool, window: &mut Window) -> Stateful<Div> {
+        div()
+            .id("clear")
+            .debug_selector(|| "clear".into())
+            .flex_none()
+            .cursor_pointer()
+            .child("×")
+            .when(!self.always_show_clear && !focused, |this| {
+                this.invisible().group_hover(GROUP, |style| style.visible())
+            })
+            .on_mouse_down(MouseButton::Left, |_, window, app| {
+                window.prevent_default();
+                app.stop_propagation();
+            })
+            .on_click(window.listener_for(&self.state, |state, _, window, cx| {
+                state.clear_value(window, cx);
+            }))
+    }
+
+    fn adornment(element: AnyElement, focus: bool) -> Div {
+        div().flex_none().child(element).when(!focus, |this| {
+            this.on_mouse_down(MouseButton::Left, |_, window, app| {
+                window.prevent_default();
+                app.stop_propagation();
+            })
+        })
+    }
+
+    /// Applies styles when the value failed validation, see [`InputState::validate`].
+    pub fn when_invalid(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_invalid_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+/// A disabled input can't be focused or selected and ignores all input, unlike a
+/// [read-only](InputState::read_only) one.
+impl Disableable for TextInput {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
         self
     }
 }
@@ -55,8 +228,38 @@ impl InteractiveElement for TextInput {
 }
 
 impl RenderOnce for TextInput {
-    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if !self.state.read(app).is_valid()
+            && let Some(handler) = self.when_invalid_handler.take()
+        {
+            self = handler(self);
+        }
+
+        // Without focus tracking, actions or mouse handlers the text can't be reached
+        if self.disabled {
+            return self
+                .base
+                .cursor_default()
+                .when_some(self.prefix, |this, prefix| {
+                    this.child(Self::adornment(prefix, true))
+                })
+                .child(self.state.clone())
+                .when_some(self.suffix, |this, suffix| {
+                    this.child(Self::adornment(suffix, true))
+                });
+        }
+
+        let adornments_focus = self.adornments_focus;
+        let state = self.state.read(app);
+        let clear_button = (self.clearable && !state.read_only && !state.value.is_empty())
+            .then(|| self.clear_button(state.focus_handle.is_focused(window), window));
+        let counter = self.show_counter.then(|| self.counter(state));
+        let suggestion_list = (state.suggestions.is_open()
+            && state.focus_handle.is_focused(window))
+        .then(|| self.suggestion_list(state, window));
         self.base
+            .relative()
+            .group(GROUP)
             .key_context(CONTEXT)
             .track_focus(&self.state.focus_handle(app))
             .on_action(window.listener_for(&self.state, InputState::backspace))
@@ -65,9 +268,14 @@ impl RenderOnce for TextInput {
             .on_action(window.listener_for(&self.state, InputState::right))
             .on_action(window.listener_for(&self.state, InputState::select_left))
             .on_action(window.listener_for(&self.state, InputState::select_right))
-            .on_action(window.listener_for(&self.state, InputState::select_all))
+            .on_action(window.listener_for(&self.state, InputState::on_select_all))
             .on_action(window.listener_for(&self.state, InputState::home))
             .on_action(window.listener_for(&self.state, InputState::end))
+            .on_action(window.listener_for(&self.state, InputState::up))
+            .on_action(window.listener_for(&self.state, InputState::down))
+            .on_action(window.listener_for(&self.state, InputState::select_up))
+            .on_action(window.listener_for(&self.state, InputState::select_down))
+            .on_action(window.listener_for(&self.state, InputState::newline))
             .on_action(window.listener_for(&self.state, InputState::show_character_palette))
             .on_action(window.listener_for(&self.state, InputState::paste))
             .on_action(window.listener_for(&self.state, InputState::cut))
@@ -84,6 +292,13 @@ impl RenderOnce for TextInput {
             .on_action(window.listener_for(&self.state, InputState::select_to_end))
             .on_action(window.listener_for(&self.state, InputState::undo))
             .on_action(window.listener_for(&self.state, InputState::redo))
+            .on_action(window.listener_for(&self.state, InputState::select_next_suggestion))
+            .on_action(window.listener_for(&self.state, InputState::select_previous_suggestion))
+            .on_action(window.listener_for(&self.state, InputState::on_accept_suggestion))
+            .on_action(window.listener_for(&self.state, InputState::complete_suggestion))
+            .on_action(window.listener_for(&self.state, InputState::dismiss_suggestions))
+            .on_action(window.listener_for(&self.state, InputState::tab))
+            .on_action(window.listener_for(&self.state, InputState::backtab))
             .on_mouse_down(
                 MouseButton::Left,
                 window.listener_for(&self.state, InputState::on_mouse_down),
@@ -98,7 +313,15 @@ impl RenderOnce for TextInput {
             )
             .on_mouse_move(window.listener_for(&self.state, InputState::on_mouse_move))
             .on_scroll_wheel(window.listener_for(&self.state, InputState::on_scroll_wheel))
-            .when_some(self.leading, |this, leading| this.child(leading))
+            .when_some(self.prefix, |this, prefix| {
+                this.child(Self::adornment(prefix, adornments_focus))
+            })
             .child(self.state.clone())
+            .children(clear_button)
+            .children(counter)
+            .when_some(self.suffix, |this, suffix| {
+                this.child(Self::adornment(suffix, adornments_focus))
+            })
+            .children(suggestion_list)
     }
 }
@@ -3,7 +3,7 @@
 //! This module provides utilities for working with text boundaries, cursor positioning,
 //! and text manipulation operations like word boundaries and grapheme clusters.
 
-use std::ops::Range;
+use std::{borrow::Cow, ops::Range};
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Character type for word boundary detection
@@ -38,6 +38,79 @@ impl TextOps {
             .unwrap_or(text.len())
     }
 
+    /// Clamp the offset to the text and snap it to the nearest grapheme boundary,
+    /// preferring the earlier one when both are as near
+    pub fn snap_to_boundary(text: &str, offset: usize) -> usize {
+        let offset = offset.min(text.len());
+        let next = Self::next_boundary(text, offset);
+        let previous = Self::previous_boundary(text, next);
+        if offset - previous <= next - offset {
+            previous
+        } else {
+            next
+        }
+    }
+
+    /// Snap a selection from `anchor` to `head`, returning it in order with whether the
+    /// head comes first
+    pub fn snap_selection(text: &str, anchor: usize, head: usize) -> (Range<usize>, bool) {
+        let anchor = Self::snap_to_boundary(text, anchor);
+        let head = Self::snap_to_boundary(text, head);
+        if head < anchor {
+            (head..anchor, true)
+        } else {
+            (anchor..head, false)
+        }
+    }
+
+    /// Get the grapheme boundary one step to the left of the given offset, which is the
+    /// next one in right-to-left text
+    pub fn left_boundary(text: &str, offset: usize, rtl: bool) -> usize {
+        if rtl {
+            Self::next_boundary(text, offset)
+        } else {
+            Self::previous_boundary(text, offset)
+        }
+    }
+
+    /// Get the grapheme boundary one step to the right of the given offset, which is the
+    /// previous one in right-to-left text
+    pub fn right_boundary(text: &str, offset: usize, rtl: bool) -> usize {
+        if rtl {
+            Self::previous_boundary(text, offset)
+        } else {
+            Self::next_boundary(text, offset)
+        }
+    }
+
+    /// Whether the character belongs to a right-to-left script, such as Hebrew or Arabic
+    pub fn is_rtl_char(ch: char) -> bool {
+        matches!(ch,
+            '\u{0590}'..='\u{08FF}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}')
+    }
+
+    /// Whether the first character with a strong direction is right-to-left, skipping
+    /// digits, punctuation and whitespace
+    pub fn is_rtl(text: &str) -> bool {
+        text.chars()
+            .find(|&ch| Self::is_rtl_char(ch) || ch.is_alphabetic())
+            .is_some_and(Self::is_rtl_char)
+    }
+
+    /// How far to shift text right so that right-to-left text that fits ends at the right
+    /// edge
+    pub fn align_offset(rtl: bool, content_width: f32, visible_width: f32) -> f32 {
+        if rtl {
+            (visible_width - content_width).max(0.0)
+        } else {
+            0.0
+        }
+    }
+
     /// Get the previous word boundary from the given offset
     pub fn previous_word_boundary(text: &str, offset: usize) -> usize {
         if offset == 0 {
@@ -122,6 +195,59 @@ impl TextOps {
         text.len()
     }
 
+    /// Get the range of the word, whitespace run or punctuation run containing the given
+    /// offset, classified the same way as word movement
+    pub fn word_range(text: &str, offset: usize) -> Range<usize> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        if chars.is_empty() {
+            return 0..0;
+        }
+
+        let types: Vec<CharType> = (0..chars.len())
+            .map(|i| {
+                let prev = i.checked_sub(1).map(|i| chars[i].1);
+                let next = chars.get(i + 1).map(|&(_, c)| c);
+                Self::char_type(chars[i].1, next, prev)
+            })
+            .collect();
+
+        let index = chars
+            .partition_point(|&(i, _)| i <= offset)
+            .saturating_sub(1);
+        let mut start = index;
+        while start > 0 && types[start - 1] == types[index] {
+            start -= 1;
+        }
+        let mut end = index + 1;
+        while end < chars.len() && types[end] == types[index] {
+            end += 1;
+        }
+
+        chars[start].0..chars.get(end).map_or(text.len(), |&(i, _)| i)
+    }
+
+    /// Get the range of the word characters directly before the given offset, which is
+    /// empty when the offset follows whitespace or punctuation
+    pub fn word_before(text: &str, offset: usize) -> Range<usize> {
+        let start = text[..offset]
+            .char_indices()
+            .rev()
+            .take_while(|&(_, ch)| ch.is_alphanumeric() || ch == '_')
+            .last()
+            .map_or(offset, |(i, _)| i);
+        start..offset
+    }
+
+    /// Extend an anchored selection to cover `target`, returning the selection and whether
+    /// the caret is at its start
+    pub fn extend_selection(anchor: &Range<usize>, target: &Range<usize>) -> (Range<usize>, bool) {
+        if target.start < anchor.start {
+            (target.start..anchor.end, true)
+        } else {
+            (anchor.start..target.end.max(anchor.end), false)
+        }
+    }
+
     /// Determine the character type for word boundary detection
     fn char_type(ch: char, next: Option<char>, prev: Option<char>) -> CharType {
         if ch.is_whitespace() {
@@ -138,6 +264,48 @@ impl TextOps {
         }
     }
 
+    /// Get the range of the line containing the given offset, excluding its newline
+    pub fn line_range(text: &str, offset: usize) -> Range<usize> {
+        let start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+        start..end
+    }
+
+    /// Get the offset in the previous line with the same grapheme column, or the start
+    /// of the text when already on the first line
+    pub fn line_above(text: &str, offset: usize) -> usize {
+        let line = Self::line_range(text, offset);
+        if line.start == 0 {
+            return 0;
+        }
+        let column = text[line.start..offset].graphemes(true).count();
+        let above = Self::line_range(text, line.start - 1);
+        above.start + Self::grapheme_offset_to_byte_offset(&text[above], column)
+    }
+
+    /// Get the offset in the next line with the same grapheme column, or the end of the
+    /// text when already on the last line
+    pub fn line_below(text: &str, offset: usize) -> usize {
+        let line = Self::line_range(text, offset);
+        if line.end == text.len() {
+            return text.len();
+        }
+        let column = text[line.start..offset].graphemes(true).count();
+        let below = Self::line_range(text, line.end + 1);
+        below.start + Self::grapheme_offset_to_byte_offset(&text[below], column)
+    }
+
+    /// Get the number of logical lines in the text, which is one more than its newlines
+    pub fn line_count(text: &str) -> usize {
+        text.matches('\n').count() + 1
+    }
+
+    /// Get the number of lines an auto-growing input shows for `line_count` lines of
+    /// content, scrolling the rest once it stops growing at `max_lines`
+    pub fn visible_lines(line_count: usize, min_lines: usize, max_lines: usize) -> usize {
+        line_count.clamp(min_lines, max_lines.max(min_lines))
+    }
+
     /// Convert a grapheme offset to a byte offset
     pub fn grapheme_offset_to_byte_offset(text: &str, grapheme_offset: usize) -> usize {
         text.grapheme_indices(true)
@@ -187,4 +355,211 @@ impl TextOps {
     pub fn range_from_utf16(text: &str, range: &Range<usize>) -> Range<usize> {
         Self::offset_from_utf16(text, range.start)..Self::offset_from_utf16(text, range.end)
     }
+
+    /// Split `0..len` into consecutive segments at the bounds of each of `ranges`, so that
+    /// every segment lies either entirely inside or entirely outside each range
+    pub fn split_at_ranges(len: usize, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        let mut bounds: Vec<usize> = ranges
+            .iter()
+            .flat_map(|range| [range.start, range.end])
+            .filter(|&bound| bound < len)
+            .chain([0, len])
+            .collect();
+        bounds.sort_unstable();
+        bounds.dedup();
+        bounds.windows(2).map(|pair| pair[0]..pair[1]).collect()
+    }
+
+    /// Get the horizontal scroll offset that brings `x` into view, moving as little as
+    /// possible and never scrolling past either end of the content
+    pub fn scroll_to_reveal(x: f32, scroll: f32, visible_width: f32, content_width: f32) -> f32 {
+        if content_width <= visible_width {
+            return 0.0;
+        }
+
+        let scroll = if x < scroll {
+            x
+        } else if x >= scroll + visible_width {
+            x - visible_width
+        } else {
+            scroll
+        };
+        scroll.clamp(0.0, content_width - visible_width)
+    }
+
+    /// Get the vertical scroll offset that brings the line spanning `top..bottom` into view,
+    /// moving as little as possible and never scrolling past either end of the content
+    pub fn scroll_to_reveal_line(
+        top: f32,
+        bottom: f32,
+        scroll: f32,
+        visible_height: f32,
+        content_height: f32,
+    ) -> f32 {
+        if content_height <= visible_height {
+            return 0.0;
+        }
+
+        let scroll = if top < scroll {
+            top
+        } else if bottom > scroll + visible_height {
+            bottom - visible_height
+        } else {
+            scroll
+        };
+        scroll.clamp(0.0, content_height - visible_height)
+    }
+
+    /// Get the grapheme boundary closest to `x`, measuring each boundary with `x_for_index`
+    pub fn offset_for_x(text: &str, x: f32, x_for_index: impl Fn(usize) -> f32) -> usize {
+        text.grapheme_indices(true)
+            .map(|(i, _)| i)
+            .chain([text.len()])
+            .min_by(|&a, &b| {
+                (x_for_index(a) - x)
+                    .abs()
+                    .total_cmp(&(x_for_index(b) - x).abs())
+            })
+            .unwrap_or(0)
+    }
+
+    /// How far to scroll while dragging at `x`, by the distance past the nearer edge of
+    /// `left..right` up to `max_step`, negative to the left and zero inside
+    pub fn edge_scroll(x: f32, left: f32, right: f32, max_step: f32) -> f32 {
+        if x < left {
+            (x - left).max(-max_step)
+        } else if x > right {
+            (x - right).min(max_step)
+        } else {
+            0.0
+        }
+    }
+
+    /// Get the range of one level of indentation at the start of `text`: a leading tab, or
+    /// up to as many leading spaces as `indent` is wide
+    pub fn outdent_range(text: &str, indent: &str) -> Range<usize> {
+        if text.starts_with('\t') {
+            return 0..1;
+        }
+        let spaces = text.bytes().take_while(|&b| b == b' ').count();
+        0..spaces.min(indent.chars().count().max(1))
+    }
+
+    /// Remove the characters `allow` rejects from `text`
+    pub fn filter(text: &str, allow: impl Fn(char) -> bool) -> Cow<'_, str> {
+        if text.chars().all(&allow) {
+            Cow::Borrowed(text)
+        } else {
+            Cow::Owned(text.chars().filter(|&ch| allow(ch)).collect())
+        }
+    }
+
+    /// Join the lines of `text` with `separator`, treating `\r\n` as one line break
+    pub fn join_lines<'a>(text: &'a str, separator: &str) -> Cow<'a, str> {
+        if !text.contains(['\n', '\r']) {
+            return Cow::Borrowed(text);
+        }
+        Cow::Owned(
+            text.split("\r\n")
+                .flat_map(|line| line.split(['\n', '\r']))
+                .collect::<Vec<_>>()
+                .join(separator),
+        )
+    }
+
+    /// Whether the character can appear in a number: digits, a sign or a decimal point
+    pub fn is_numeric(ch: char) -> bool {
+        ch.is_ascii_digit() || matches!(ch, '-' | '+' | '.')
+    }
+
+    /// Count the grapheme clusters in the text, the unit `max_length` is measured in
+    pub fn grapheme_count(text: &str) -> usize {
+        text.graphemes(true).count()
+    }
+
+    /// Whether `count` is within a tenth of `max_length`, where a counter should warn
+    pub fn near_limit(count: usize, max_length: usize) -> bool {
+        count * 10 >= max_length * 9
+    }
+
+    /// Truncate `new_text` so that replacing `range` of `text` with it leaves at most
+    /// `max_length` graphemes
+    pub fn truncate_to_fit<'a>(
+        text: &str,
+        range: &Range<usize>,
+        new_text: &'a str,
+        max_length: usize,
+    ) -> &'a str {
+        let kept =
+            Self::grapheme_count(&text[..range.start]) + Self::grapheme_count(&text[range.end..]);
+        let available = max_length.saturating_sub(kept);
+        &new_text[..Self::grapheme_offset_to_byte_offset(new_text, available)]
+    }
+
+    /// Build the text displayed for a masked input
+    ///
+    /// Every grapheme is replaced by `mask`, except for the text in `marked_range`, which
+    /// is shown as typed while it is being composed
+    pub fn mask_text(text: &str, mask: &str, marked_range: Option<&Range<usize>>) -> String {
+        let masked = |text: &str| mask.repeat(text.graphemes(true).count());
+        match marked_range {
+            Some(marked_range) => format!(
+                "{}{}{}",
+                masked(&text[..marked_range.start]),
+                &text[marked_range.clone()],
+                masked(&text[marked_range.end..])
+            ),
+            None => masked(text),
+        }
+    }
+
+    /// Convert an offset in the text to an offset in its masked display text
+    pub fn offset_to_masked(
+        text: &str,
+        offset: usize,
+        mask_len: usize,
+        marked_range: Option<&Range<usize>>,
+    ) -> usize {
+        let masked_len = |text: &str| text.graphemes(true).count() * mask_len;
+        let Some(marked_range) = marked_range else {
+            return masked_len(&text[..offset]);
+        };
+
+        if offset <= marked_range.start {
+            masked_len(&text[..offset])
+        } else if offset <= marked_range.end {
+            masked_len(&text[..marked_range.start]) + (offset - marked_range.start)
+        } else {
+            masked_len(&text[..marked_range.start])
+                + marked_range.len()
+                + masked_len(&text[marked_range.end..offset])
+        }
+    }
+
+    /// Convert an offset in masked display text back to the text, snapping to the start of
+    /// the grapheme it falls in
+    pub fn offset_from_masked(
+        text: &str,
+        display_offset: usize,
+        mask_len: usize,
+        marked_range: Option<&Range<usize>>,
+    ) -> usize {
+        let unmasked = |text: &str, display_offset: usize| {
+            let grapheme = display_offset.checked_div(mask_len).unwrap_or(0);
+            Self::grapheme_offset_to_byte_offset(text, grapheme)
+        };
+        let Some(marked_range) = marked_range else {
+            return unmasked(text, display_offset);
+        };
+
+        let marked_start = Self::offset_to_masked(text, marked_range.start, mask_len, None);
+        let marked_end = marked_start + marked_range.len();
+        if display_offset <= marked_start {
+            unmasked(&text[..marked_range.start], display_offset)
+        } else if display_offset <= marked_end {
+            marked_range.start + (display_offset - marked_start)
+        } else {
+            marked_range.end + unmasked(&text[marked_range.end..], display_offset - marked_end)
+        }
+    }
 }
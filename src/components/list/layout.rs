@@ -0,0 +1,186 @@
+use std::ops::Range;
+
+/// How many items past either edge of the viewport are laid out, so scrolling a little
+/// doesn't show a gap before the next render
+pub const OVERSCAN: usize = 4;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Heights {
+    Fixed(f32),
+    /// The heights of the items laid out so far, with `estimate` standing in for the
+    /// others, and the offset of the top of each item
+    Measured {
+        estimate: f32,
+        heights: Vec<Option<f32>>,
+        offsets: Vec<f32>,
+    },
+}
+
+/// Where the items of a [`List`](super::List) are, so a list of thousands of items only
+/// builds the few in view.
+///
+/// Items are either all the same fixed height, or measured as they are laid out, with an
+/// estimated height for those that haven't been yet. Scroll positions count up from 0
+/// with the first item at the top of the viewport.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListLayout {
+    count: usize,
+    heights: Heights,
+}
+
+impl ListLayout {
+    /// `count` items, each `item_height` tall
+    pub fn fixed(item_height: f32, count: usize) -> Self {
+        Self {
+            count,
+            heights: Heights::Fixed(item_height),
+        }
+    }
+
+    /// `count` items of their own heights, counted as `estimate` tall until measured
+    pub fn measured(estimate: f32, count: usize) -> Self {
+        let mut layout = Self {
+            count: 0,
+            heights: Heights::Measured {
+                estimate,
+                heights: Vec::new(),
+                offsets: vec![0.],
+            },
+        };
+        layout.set_count(count);
+        layout
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_measured(&self) -> bool {
+        matches!(self.heights, Heights::Measured { .. })
+    }
+
+    /// Change how many items there are, keeping the heights measured for the items that
+    /// remain
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+        if let Heights::Measured { heights, .. } = &mut self.heights {
+            heights.resize(count, None);
+            self.update_offsets(0);
+        }
+    }
+
+    /// Record that the item at `ix` is `height` tall, returning whether that moved the
+    /// items after it
+    ///
+    /// Items of a fixed height aren't measured.
+    pub fn measure(&mut self, ix: usize, height: f32) -> bool {
+        let Heights::Measured { heights, .. } = &mut self.heights else {
+            return false;
+        };
+        match heights.get_mut(ix) {
+            Some(measured) if *measured != Some(height) => {
+                *measured = Some(height);
+                self.update_offsets(ix);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn update_offsets(&mut self, from: usize) {
+        if let Heights::Measured {
+            estimate,
+            heights,
+            offsets,
+        } = &mut self.heights
+        {
+            offsets.resize(heights.len() + 1, 0.);
+            for ix in from..heights.len() {
+                offsets[ix + 1] = offsets[ix] + heights[ix].unwrap_or(*estimate);
+            }
+        }
+    }
+
+    /// How tall the item at `ix` is, or is estimated to be
+    pub fn height(&self, ix: usize) -> f32 {
+        match &self.heights {
+            Heights::Fixed(height) => *height,
+            Heights::Measured {
+                estimate, heights, ..
+            } => heights.get(ix).copied().flatten().unwrap_or(*estimate),
+        }
+    }
+
+    /// How far the top of the item at `ix` is from the top of the first item, which for
+    /// `ix` past the last item is the height of all of them
+    pub fn offset(&self, ix: usize) -> f32 {
+        let ix = ix.min(self.count);
+        match &self.heights {
+            Heights::Fixed(height) => ix as f32 * height,
+            Heights::Measured { offsets, .. } => offsets[ix],
+        }
+    }
+
+    /// The height of all of the items
+    pub fn content_height(&self) -> f32 {
+        self.offset(self.count)
+    }
+
+    /// How far a viewport `height` tall scrolls, or 0 when every item fits
+    pub fn max_scroll(&self, height: f32) -> f32 {
+        (self.content_height() - height).max(0.)
+    }
+
+    /// The index of the item at `y` from the top of the first item
+    fn item_at(&self, y: f32) -> usize {
+        let ix = match &self.heights {
+            Heights::Fixed(height) if *height > 0. => (y / height).floor() as usize,
+            Heights::Fixed(_) => 0,
+            Heights::Measured { offsets, .. } => offsets[1..].partition_point(|&end| end <= y),
+        };
+        ix.min(self.count.saturating_sub(1))
+    }
+
+    /// How many items start above `y` from the top of the first item
+    fn items_above(&self, y: f32) -> usize {
+        let count = match &self.heights {
+            Heights::Fixed(height) if *height > 0. => (y / height).ceil() as usize,
+            Heights::Fixed(_) => self.count,
+            Heights::Measured { offsets, .. } => {
+                offsets[..self.count].partition_point(|&top| top < y)
+            }
+        };
+        count.min(self.count)
+    }
+
+    /// The items at least partly in a viewport `height` tall at `scroll`, and the
+    /// [`OVERSCAN`] items before and after them
+    pub fn visible_range(&self, scroll: f32, height: f32) -> Range<usize> {
+        if self.count == 0 {
+            return 0..0;
+        }
+        let scroll = scroll.clamp(0., self.max_scroll(height));
+        let first = self.item_at(scroll);
+        let end = self.items_above(scroll + height).max(first + 1);
+        first.saturating_sub(OVERSCAN)..(end + OVERSCAN).min(self.count)
+    }
+
+    /// The scroll that brings the item at `ix` into a viewport `height` tall from
+    /// `scroll`, moving as little as possible, or `scroll` itself when the item is
+    /// already in view
+    ///
+    /// An item taller than the viewport is scrolled to its top.
+    pub fn scroll_to_item(&self, scroll: f32, height: f32, ix: usize) -> f32 {
+        let ix = ix.min(self.count.saturating_sub(1));
+        let top = self.offset(ix);
+        let bottom = top + self.height(ix);
+        let scroll = if top < scroll {
+            top
+        } else if bottom > scroll + height {
+            (bottom - height).min(top)
+        } else {
+            scroll
+        };
+        scroll.clamp(0., self.max_scroll(height))
+    }
+}
@@ -0,0 +1,150 @@
+use crate::primitives::v_flex;
+use gpui::{prelude::FluentBuilder, *};
+
+mod layout;
+mod selection;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use state::*;
+
+/// A scrolled list that builds only the items in view, so it stays quick with thousands
+/// of items.
+///
+/// The list calls `render_item` for the items in view and a few on either side, out of
+/// the [`item_count`](ListState::item_count) of its state, and stands in for the others
+/// with space so it scrolls smoothly by the pixel as if every item was built. Items are
+/// all the same [`item_height`](ListState::item_height), or with
+/// [`measured`](ListState::measured) heights each as tall as it lays out.
+/// [`ListState::scroll_to_item`] scrolls an item into view.
+///
+/// In a [`selectable`](ListState::selectable) list, pressing an item selects it, with Ctrl
+/// or Cmd adds it to the selection or removes it, and with Shift selects the items from
+/// the last one pressed to it. Up and Down move the selection, and with Shift extend it.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     ListState::new(cx)
+///         .item_count(names.len())
+///         .item_height(px(24))
+///         .selectable(true)
+///         .on_selection_change(|selected, _window, _cx| println!("Selected: {selected:?}"))
+/// });
+///
+/// let selection = state.clone();
+/// List::new(state.clone(), move |ix, _window, cx| {
+///     div()
+///         .px_2()
+///         .when(selection.read(cx).is_selected(ix), |item| item.bg(rgb(0xdbeafe)))
+///         .child(names[ix].clone())
+///         .into_any_element()
+/// })
+/// .h(px(320))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct List {
+    base: Div,
+    state: Entity<ListState>,
+    render_item: Box<dyn Fn(usize, &mut Window, &mut App) -> AnyElement>,
+}
+
+impl List {
+    /// Creates a list for the given state, built by `render_item` from the index of
+    /// each item.
+    pub fn new(
+        state: Entity<ListState>,
+        render_item: impl Fn(usize, &mut Window, &mut App) -> AnyElement + 'static,
+    ) -> Self {
+        Self {
+            base: div(),
+            state,
+            render_item: Box::new(render_item),
+        }
+    }
+}
+
+impl Styled for List {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for List {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = self.state.read(app);
+        let range = state.visible_range();
+        let layout = &state.layout;
+        let fixed_height = (!layout.is_measured()).then(|| px(layout.height(0)));
+        let space_above = px(layout.offset(range.start));
+        let space_below = px(layout.content_height() - layout.offset(range.end));
+        let scroll_handle = state.scroll_handle().clone();
+        let selectable = state.is_selectable();
+        let focus_handle = state.focus_handle().clone();
+
+        let items: Vec<_> = range
+            .clone()
+            .map(|ix| {
+                let item = div()
+                    .debug_selector(|| format!("list-item-{ix}"))
+                    .flex_none()
+                    .when(selectable, |item| {
+                        let state = self.state.clone();
+                        item.on_mouse_down(MouseButton::Left, move |event, window, app| {
+                            state.update(app, |state, cx| {
+                                state.on_item_mouse_down(ix, event, window, cx)
+                            })
+                        })
+                    })
+                    .child((self.render_item)(ix, window, app));
+                if let Some(height) = fixed_height {
+                    item.h(height).overflow_hidden()
+                } else {
+                    let state = self.state.clone();
+                    let measure = canvas(
+                        move |bounds, _, app| {
+                            state.update(app, |state, cx| state.measure(ix, bounds.size.height, cx))
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .absolute()
+                    .size_full();
+                    item.relative().child(measure)
+                }
+            })
+            .collect();
+
+        let tracked = self.state.clone();
+        let viewport = canvas(
+            move |bounds, _, app| {
+                tracked.update(app, |state, cx| {
+                    state.set_viewport_height(bounds.size.height, cx)
+                })
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        // Stand in for the items out of view with space, so the list scrolls as if every
+        // item was laid out
+        self.base.relative().child(viewport).child(
+            v_flex()
+                .id(self.state.entity_id())
+                .debug_selector(|| "list".into())
+                .size_full()
+                .overflow_y_scroll()
+                .track_scroll(&scroll_handle)
+                .when(selectable, |list| {
+                    list.track_focus(&focus_handle)
+                        .on_key_down(window.listener_for(&self.state, ListState::on_key_down))
+                })
+                .child(div().flex_none().h(space_above))
+                .children(items)
+                .child(div().flex_none().h(space_below)),
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeSet;
+
+/// The selected items of a [`List`](super::List), changed by clicks and the arrow keys.
+///
+/// A click selects only the item clicked, and a toggling click adds it to the selection
+/// or removes it. Both move the anchor to the item, and an extending click selects the
+/// items from the anchor to the one clicked. The arrow keys move the cursor, the item the
+/// keyboard is on, and select it, or extend the selection from the anchor to it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListSelection {
+    selected: BTreeSet<usize>,
+    /// Where extending the selection starts from
+    anchor: Option<usize>,
+    cursor: Option<usize>,
+}
+
+impl ListSelection {
+    /// The indices of the selected items
+    pub fn selected(&self) -> &BTreeSet<usize> {
+        &self.selected
+    }
+
+    pub fn anchor(&self) -> Option<usize> {
+        self.anchor
+    }
+
+    /// The item the keyboard is on, which is the last one clicked
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Select the item at `ix` alone, or add it to the selection or remove it when
+    /// `toggle`, or select the items from the anchor to it when `extend`
+    ///
+    /// Extending while toggling adds the items from the anchor to the selection instead
+    /// of replacing it. Extending without an anchor is an ordinary click.
+    pub fn click(&mut self, ix: usize, toggle: bool, extend: bool) {
+        match self.anchor.filter(|_| extend) {
+            Some(anchor) => {
+                let range = anchor.min(ix)..=anchor.max(ix);
+                if !toggle {
+                    self.selected.clear();
+                }
+                self.selected.extend(range);
+            }
+            None => {
+                if !toggle {
+                    self.selected.clear();
+                    self.selected.insert(ix);
+                } else if !self.selected.remove(&ix) {
+                    self.selected.insert(ix);
+                }
+                self.anchor = Some(ix);
+            }
+        }
+        self.cursor = Some(ix);
+    }
+
+    /// Move the cursor to the next of `count` items, or the previous one when not
+    /// `forward`, and select it alone, or the items from the anchor to it when `extend`
+    ///
+    /// The cursor stops at either end. Without a cursor, moving forward starts at the
+    /// first item and moving back at the last.
+    pub fn step(&mut self, forward: bool, extend: bool, count: usize) {
+        let Some(last) = count.checked_sub(1) else {
+            return;
+        };
+        let cursor = match (self.cursor, forward) {
+            (Some(cursor), true) => (cursor + 1).min(last),
+            (Some(cursor), false) => cursor.saturating_sub(1).min(last),
+            (None, true) => 0,
+            (None, false) => last,
+        };
+        self.cursor = Some(cursor);
+        let anchor = match self.anchor.filter(|_| extend) {
+            Some(anchor) => anchor,
+            None => {
+                self.anchor = Some(cursor);
+                cursor
+            }
+        };
+        self.selected = (anchor.min(cursor)..=anchor.max(cursor)).collect();
+    }
+
+    /// Forget the items from `count` on, once the list has only `count` items
+    pub fn truncate(&mut self, count: usize) {
+        self.selected.retain(|&ix| ix < count);
+        self.anchor = self.anchor.filter(|&ix| ix < count);
+        self.cursor = self.cursor.filter(|&ix| ix < count);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::components::list::{layout::ListLayout, selection::ListSelection};
+use gpui::*;
+use std::{collections::BTreeSet, ops::Range};
+
+/// The state of a [`List`](super::List)
+///
+/// Keeps how many items there are, how tall they are and how far the list is scrolled.
+/// Items are all [`item_height`](Self::item_height) tall, or with
+/// [`measured`](Self::measured) heights each as tall as it lays out, with an estimate for
+/// the items not laid out yet.
+///
+/// A [`selectable`](Self::selectable) list selects the item clicked, and with Ctrl or Cmd
+/// toggles it, or with Shift selects the items from the last one clicked to it. Up and
+/// Down move the selection, and with Shift extend it.
+#[allow(clippy::type_complexity)]
+pub struct ListState {
+    pub(super) layout: ListLayout,
+    selectable: bool,
+    selection: ListSelection,
+    focus_handle: FocusHandle,
+    scroll_handle: ScrollHandle,
+    /// The visible height of the list, as last laid out
+    viewport_height: f32,
+    /// The item [`scroll_to_item`](Self::scroll_to_item) scrolled to and the scroll it
+    /// set, to scroll to it again while measuring the items moves it
+    revealing: Option<(usize, f32)>,
+    on_selection_change: Option<Box<dyn Fn(&BTreeSet<usize>, &mut Window, &mut Context<Self>)>>,
+}
+
+impl ListState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new ListState without items
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            layout: ListLayout::fixed(28., 0),
+            selectable: false,
+            selection: ListSelection::default(),
+            focus_handle: cx.focus_handle(),
+            scroll_handle: ScrollHandle::new(),
+            viewport_height: 0.,
+            revealing: None,
+            on_selection_change: None,
+        }
+    }
+
+    /// Set how many items the list has
+    pub fn item_count(mut self, count: usize) -> Self {
+        self.layout.set_count(count);
+        self
+    }
+
+    /// Make every item `item_height` tall, 28px by default
+    ///
+    /// The list needs it to lay out only the items in view, so items keep this height.
+    pub fn item_height(mut self, item_height: impl Into<Pixels>) -> Self {
+        self.layout = ListLayout::fixed(item_height.into().into(), self.layout.count());
+        self
+    }
+
+    /// Let every item be as tall as it lays out, counting the items not laid out yet as
+    /// `estimate` tall
+    pub fn measured(mut self, estimate: impl Into<Pixels>) -> Self {
+        self.layout = ListLayout::measured(estimate.into().into(), self.layout.count());
+        self
+    }
+
+    /// Let clicks and the arrow keys select items
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self.focus_handle = self.focus_handle.tab_stop(selectable);
+        self
+    }
+
+    /// Call `on_selection_change` with the indices of the selected items whenever they
+    /// change
+    pub fn on_selection_change(
+        mut self,
+        on_selection_change: impl Fn(&BTreeSet<usize>, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Box::new(on_selection_change));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn current_item_count(&self) -> usize {
+        self.layout.count()
+    }
+
+    /// Change how many items the list has, keeping the heights measured for the items
+    /// that remain
+    pub fn set_item_count(&mut self, count: usize, cx: &mut Context<Self>) {
+        if self.layout.count() != count {
+            self.layout.set_count(count);
+            self.selection.truncate(count);
+            cx.notify();
+        }
+    }
+
+    pub fn is_selectable(&self) -> bool {
+        self.selectable
+    }
+
+    /// The indices of the selected items
+    pub fn selected(&self) -> &BTreeSet<usize> {
+        self.selection.selected()
+    }
+
+    pub fn is_selected(&self, ix: usize) -> bool {
+        self.selection.selected().contains(&ix)
+    }
+
+    pub(super) fn focus_handle(&self) -> &FocusHandle {
+        &self.focus_handle
+    }
+
+    /// Change the selection with `change`, telling
+    /// [`on_selection_change`](Self::on_selection_change) when it did
+    fn update_selection(
+        &mut self,
+        change: impl FnOnce(&mut ListSelection),
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let selected = self.selection.selected().clone();
+        change(&mut self.selection);
+        if *self.selection.selected() != selected
+            && let Some(on_selection_change) = &self.on_selection_change
+        {
+            on_selection_change(self.selection.selected(), window, cx);
+        }
+        cx.notify();
+    }
+
+    pub(super) fn scroll_handle(&self) -> &ScrollHandle {
+        &self.scroll_handle
+    }
+
+    /// How far the list is scrolled
+    pub fn scroll(&self) -> f32 {
+        -f32::from(self.scroll_handle.offset().y)
+    }
+
+    fn scroll_to(&self, scroll: f32) {
+        self.scroll_handle.set_offset(point(px(0.), px(-scroll)));
+    }
+
+    /// The items laid out at the current scroll, those in view and a few on either side
+    pub fn visible_range(&self) -> Range<usize> {
+        self.layout
+            .visible_range(self.scroll(), self.viewport_height)
+    }
+
+    /// Scroll as little as needed to show the item at `ix` in full, or its top when it is
+    /// taller than the list
+    ///
+    /// With [`measured`](Self::measured) heights, the list scrolls to the item again as
+    /// the items before it are measured, until it is scrolled some other way.
+    pub fn scroll_to_item(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if self.layout.count() == 0 {
+            return;
+        }
+        let scroll = self
+            .layout
+            .scroll_to_item(self.scroll(), self.viewport_height, ix);
+        self.scroll_to(scroll);
+        self.revealing = self.layout.is_measured().then_some((ix, scroll));
+        cx.notify();
+    }
+
+    pub(super) fn set_viewport_height(&mut self, height: Pixels, cx: &mut Context<Self>) {
+        let height = f32::from(height);
+        if self.viewport_height != height {
+            self.viewport_height = height;
+            self.reveal_again();
+            cx.notify();
+        }
+    }
+
+    /// Record the height the item at `ix` laid out at
+    pub(super) fn measure(&mut self, ix: usize, height: Pixels, cx: &mut Context<Self>) {
+        if self.layout.measure(ix, f32::from(height)) {
+            self.reveal_again();
+            cx.notify();
+        }
+    }
+
+    /// Scroll to the item [`scroll_to_item`](Self::scroll_to_item) scrolled to once more,
+    /// unless the list was scrolled since
+    fn reveal_again(&mut self) {
+        let Some((ix, scroll)) = self.revealing.take() else {
+            return;
+        };
+        if self.scroll() == scroll {
+            let scroll = self.layout.scroll_to_item(scroll, self.viewport_height, ix);
+            self.scroll_to(scroll);
+            self.revealing = Some((ix, scroll));
+        }
+    }
+
+    // ============================================================================
+    // Keyboard Handling
+    // ============================================================================
+
+    /// Move the selection with Up and Down, extending it with Shift
+    pub(super) fn on_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let forward = match event.keystroke.key.as_str() {
+            "up" => false,
+            "down" => true,
+            _ => return,
+        };
+        let modifiers = event.keystroke.modifiers;
+        if !self.selectable || modifiers.control || modifiers.alt || modifiers.platform {
+            return;
+        }
+        let count = self.layout.count();
+        self.update_selection(
+            |selection| selection.step(forward, modifiers.shift, count),
+            window,
+            cx,
+        );
+        if let Some(cursor) = self.selection.cursor() {
+            self.scroll_to_item(cursor, cx);
+        }
+        cx.stop_propagation();
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// Select the item at `ix` as it is pressed, toggling it with Ctrl or Cmd and
+    /// extending the selection to it with Shift
+    pub(super) fn on_item_mouse_down(
+        &mut self,
+        ix: usize,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.selectable {
+            return;
+        }
+        let modifiers = event.modifiers;
+        let toggle = modifiers.control || modifiers.platform;
+        self.focus_handle.focus(window);
+        self.update_selection(
+            |selection| selection.click(ix, toggle, modifiers.shift),
+            window,
+            cx,
+        );
+    }
+}
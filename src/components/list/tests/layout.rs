@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod layout {
+    use crate::components::list::layout::{ListLayout, OVERSCAN};
+
+    /// Items whose heights cycle through 10, 20 and 30, all measured
+    fn measured(count: usize) -> ListLayout {
+        let mut layout = ListLayout::measured(20., count);
+        for ix in 0..count {
+            layout.measure(ix, [10., 20., 30.][ix % 3]);
+        }
+        layout
+    }
+
+    #[test]
+    fn fixed_items_in_view_and_overscan() {
+        let layout = ListLayout::fixed(20., 1000);
+
+        assert_eq!(layout.visible_range(0., 100.), 0..5 + OVERSCAN);
+        // Scrolled 10px into item 10, so item 15 shows at the bottom
+        assert_eq!(
+            layout.visible_range(210., 100.),
+            10 - OVERSCAN..16 + OVERSCAN
+        );
+        assert_eq!(layout.visible_range(19_900., 100.), 995 - OVERSCAN..1000);
+    }
+
+    #[test]
+    fn scroll_is_clamped_to_the_content() {
+        let layout = ListLayout::fixed(20., 1000);
+
+        assert_eq!(layout.max_scroll(100.), 19_900.);
+        assert_eq!(
+            layout.visible_range(-50., 100.),
+            layout.visible_range(0., 100.)
+        );
+        assert_eq!(
+            layout.visible_range(50_000., 100.),
+            layout.visible_range(19_900., 100.)
+        );
+    }
+
+    #[test]
+    fn fewer_items_than_fit_are_all_visible() {
+        let layout = ListLayout::fixed(20., 3);
+
+        assert_eq!(layout.max_scroll(100.), 0.);
+        assert_eq!(layout.visible_range(0., 100.), 0..3);
+        assert_eq!(ListLayout::fixed(20., 0).visible_range(0., 100.), 0..0);
+    }
+
+    #[test]
+    fn measured_offsets_add_up_the_heights() {
+        let layout = measured(6);
+
+        assert_eq!(layout.offset(0), 0.);
+        assert_eq!(layout.offset(3), 60.);
+        assert_eq!(layout.offset(5), 90.);
+        assert_eq!(layout.content_height(), 120.);
+        assert_eq!(layout.offset(10), 120.);
+    }
+
+    #[test]
+    fn unmeasured_items_count_as_the_estimate() {
+        let mut layout = ListLayout::measured(25., 4);
+        assert_eq!(layout.content_height(), 100.);
+
+        assert!(layout.measure(1, 40.));
+        assert_eq!(layout.offset(2), 65.);
+        assert_eq!(layout.content_height(), 115.);
+        // Measuring the same height again moves nothing
+        assert!(!layout.measure(1, 40.));
+        assert!(!layout.measure(9, 40.));
+    }
+
+    #[test]
+    fn measured_items_in_view() {
+        let layout = measured(300);
+
+        // Items 0 to 2 end at 10, 30 and 60, so at 35 item 2 is at the top, and item 5,
+        // from 90 to 120, at the bottom edge
+        assert_eq!(layout.visible_range(35., 70.), 0..5 + 1 + OVERSCAN);
+        assert_eq!(
+            layout.visible_range(600., 60.),
+            30 - OVERSCAN..33 + OVERSCAN
+        );
+    }
+
+    #[test]
+    fn an_item_ending_at_the_top_edge_is_out_of_view() {
+        let layout = ListLayout::fixed(20., 1000);
+        assert_eq!(layout.visible_range(200., 100.).start, 10 - OVERSCAN);
+
+        let layout = measured(300);
+        // Item 5 ends at 120
+        assert_eq!(layout.visible_range(120., 30.).start, 6 - OVERSCAN);
+    }
+
+    #[test]
+    fn changing_the_count_keeps_the_measured_heights() {
+        let mut layout = measured(6);
+        layout.set_count(3);
+        assert_eq!(layout.content_height(), 60.);
+
+        layout.set_count(5);
+        assert_eq!(layout.content_height(), 100.);
+        assert_eq!(layout.height(4), 20.);
+    }
+
+    #[test]
+    fn fixed_items_are_not_measured() {
+        let mut layout = ListLayout::fixed(20., 10);
+        assert!(!layout.measure(0, 50.));
+        assert_eq!(layout.height(0), 20.);
+    }
+
+    #[test]
+    fn scroll_to_item_moves_as_little_as_possible() {
+        let layout = ListLayout::fixed(20., 1000);
+
+        // Below the viewport, the item ends at the bottom edge
+        assert_eq!(layout.scroll_to_item(0., 100., 10), 120.);
+        // Above it, the item starts at the top edge
+        assert_eq!(layout.scroll_to_item(500., 100., 10), 200.);
+        // In view, nothing moves
+        assert_eq!(layout.scroll_to_item(190., 100., 12), 190.);
+    }
+
+    #[test]
+    fn scroll_to_item_keeps_the_item_visible() {
+        let height = 100.;
+        for layout in [ListLayout::fixed(20., 500), measured(500)] {
+            for (scroll, ix) in [(0., 499), (5_000., 0), (300., 250), (1_000., 3)] {
+                let scroll = layout.scroll_to_item(scroll, height, ix);
+                let top = layout.offset(ix) - scroll;
+                let bottom = top + layout.height(ix);
+                assert!(
+                    top >= 0. && bottom <= height,
+                    "item {ix} at {top}..{bottom}"
+                );
+                assert!(layout.visible_range(scroll, height).contains(&ix));
+            }
+        }
+    }
+
+    #[test]
+    fn scroll_to_item_clamps_to_the_content() {
+        let layout = ListLayout::fixed(20., 1000);
+
+        assert_eq!(layout.scroll_to_item(0., 100., 999), 19_900.);
+        assert_eq!(layout.scroll_to_item(0., 100., 5_000), 19_900.);
+        assert_eq!(ListLayout::fixed(20., 3).scroll_to_item(0., 100., 2), 0.);
+    }
+
+    #[test]
+    fn scroll_to_an_item_taller_than_the_viewport_shows_its_top() {
+        let mut layout = ListLayout::measured(20., 10);
+        layout.measure(5, 300.);
+
+        assert_eq!(layout.scroll_to_item(0., 100., 5), 100.);
+        assert_eq!(layout.scroll_to_item(1_000., 100., 5), 100.);
+    }
+}
@@ -0,0 +1,4 @@
+mod layout;
+mod scrolling;
+mod selecting;
+mod selection;
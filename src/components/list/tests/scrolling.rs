@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod scrolling {
+    use crate::components::list::{List, ListState};
+    use gpui::*;
+
+    struct ListView {
+        state: Entity<ListState>,
+    }
+
+    impl Render for ListView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div().size_full().child(
+                List::new(self.state.clone(), |ix, _, _| {
+                    // Every third item is twice as tall, which only measured lists see
+                    div()
+                        .h(px(if ix % 3 == 0 { 40. } else { 20. }))
+                        .child(format!("Item {ix}"))
+                        .into_any_element()
+                })
+                .h(px(100.)),
+            )
+        }
+    }
+
+    /// Open a window with a 100px tall list of the state built by `build`
+    fn list(
+        cx: &mut TestAppContext,
+        build: impl FnOnce(ListState) -> ListState,
+    ) -> (Entity<ListState>, &mut VisualTestContext) {
+        let (view, cx) = cx.add_window_view(|_, cx| ListView {
+            state: cx.new(|cx| build(ListState::new(cx))),
+        });
+        let state = view.read_with(cx, |view, _| view.state.clone());
+        cx.run_until_parked();
+        (state, cx)
+    }
+
+    /// Whether the element with the debug selector `item` lies fully within the list
+    fn in_view(cx: &mut VisualTestContext, item: &'static str) -> bool {
+        let list = cx.debug_bounds("list").unwrap();
+        cx.debug_bounds(item)
+            .is_some_and(|item| item.top() >= list.top() && item.bottom() <= list.bottom())
+    }
+
+    #[gpui::test]
+    fn builds_only_the_items_in_view(cx: &mut TestAppContext) {
+        let (state, cx) = list(cx, |state| state.item_count(10_000).item_height(px(20.)));
+
+        assert!(cx.debug_bounds("list-item-0").is_some());
+        assert!(cx.debug_bounds("list-item-50").is_none());
+        let visible = state.read_with(cx, |state, _| state.visible_range());
+        assert!(visible.len() < 20, "{visible:?}");
+    }
+
+    #[gpui::test]
+    fn scroll_to_item_brings_a_fixed_item_into_view(cx: &mut TestAppContext) {
+        let (state, cx) = list(cx, |state| state.item_count(10_000).item_height(px(20.)));
+
+        state.update(cx, |state, cx| state.scroll_to_item(5_000, cx));
+        cx.run_until_parked();
+
+        assert_eq!(state.read_with(cx, |state, _| state.scroll()), 99_920.);
+        assert!(in_view(cx, "list-item-5000"));
+        assert!(cx.debug_bounds("list-item-0").is_none());
+    }
+
+    #[gpui::test]
+    fn scroll_to_item_brings_a_measured_item_into_view(cx: &mut TestAppContext) {
+        let (state, cx) = list(cx, |state| state.item_count(600).measured(px(20.)));
+
+        // Measuring the items around it moves it, and the list follows
+        state.update(cx, |state, cx| state.scroll_to_item(300, cx));
+        cx.run_until_parked();
+
+        assert!(in_view(cx, "list-item-300"));
+    }
+
+    #[gpui::test]
+    fn measured_items_keep_their_own_heights(cx: &mut TestAppContext) {
+        let (_, cx) = list(cx, |state| state.item_count(600).measured(px(20.)));
+
+        let first = cx.debug_bounds("list-item-0").unwrap();
+        let second = cx.debug_bounds("list-item-1").unwrap();
+        assert_eq!(first.size.height, px(40.));
+        assert_eq!(second.size.height, px(20.));
+        assert_eq!(second.top(), first.bottom());
+    }
+}
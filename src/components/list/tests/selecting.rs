@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod selecting {
+    use crate::components::list::{List, ListState};
+    use gpui::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    struct ListView {
+        state: Entity<ListState>,
+    }
+
+    impl Render for ListView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div().size_full().child(
+                List::new(self.state.clone(), |ix, _, _| {
+                    format!("Item {ix}").into_any_element()
+                })
+                .h(px(200.)),
+            )
+        }
+    }
+
+    /// Open a window with a list of 100 items, 20px tall, recording every selection it
+    /// reports
+    fn list(
+        cx: &mut TestAppContext,
+        selectable: bool,
+    ) -> (
+        Entity<ListState>,
+        Rc<RefCell<Vec<Vec<usize>>>>,
+        &mut VisualTestContext,
+    ) {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let recorded = changes.clone();
+        let (view, cx) = cx.add_window_view(|_, cx| ListView {
+            state: cx.new(|cx| {
+                ListState::new(cx)
+                    .item_count(100)
+                    .item_height(px(20.))
+                    .selectable(selectable)
+                    .on_selection_change(move |selected, _, _| {
+                        recorded
+                            .borrow_mut()
+                            .push(selected.iter().copied().collect())
+                    })
+            }),
+        });
+        let state = view.read_with(cx, |view, _| view.state.clone());
+        cx.run_until_parked();
+        (state, changes, cx)
+    }
+
+    fn click(cx: &mut VisualTestContext, item: &'static str, modifiers: Modifiers) {
+        let bounds = cx.debug_bounds(item).unwrap();
+        cx.simulate_click(bounds.center(), modifiers);
+    }
+
+    fn selected(state: &Entity<ListState>, cx: &mut VisualTestContext) -> Vec<usize> {
+        state.read_with(cx, |state, _| state.selected().iter().copied().collect())
+    }
+
+    #[gpui::test]
+    fn a_click_selects_the_item(cx: &mut TestAppContext) {
+        let (state, changes, cx) = list(cx, true);
+
+        click(cx, "list-item-2", Modifiers::none());
+        click(cx, "list-item-4", Modifiers::none());
+        assert_eq!(selected(&state, cx), [4]);
+        assert_eq!(*changes.borrow(), [vec![2], vec![4]]);
+
+        // Clicking the selected item again changes nothing
+        click(cx, "list-item-4", Modifiers::none());
+        assert_eq!(changes.borrow().len(), 2);
+    }
+
+    #[gpui::test]
+    fn ctrl_and_cmd_clicks_toggle_the_item(cx: &mut TestAppContext) {
+        let (state, _, cx) = list(cx, true);
+
+        click(cx, "list-item-1", Modifiers::none());
+        click(cx, "list-item-3", Modifiers::control());
+        click(cx, "list-item-5", Modifiers::command());
+        assert_eq!(selected(&state, cx), [1, 3, 5]);
+
+        click(cx, "list-item-3", Modifiers::control());
+        assert_eq!(selected(&state, cx), [1, 5]);
+    }
+
+    #[gpui::test]
+    fn a_shift_click_selects_the_range_from_the_anchor(cx: &mut TestAppContext) {
+        let (state, changes, cx) = list(cx, true);
+
+        click(cx, "list-item-2", Modifiers::none());
+        click(cx, "list-item-5", Modifiers::shift());
+        assert_eq!(selected(&state, cx), [2, 3, 4, 5]);
+        click(cx, "list-item-0", Modifiers::shift());
+        assert_eq!(selected(&state, cx), [0, 1, 2]);
+        assert_eq!(changes.borrow().last().unwrap(), &vec![0, 1, 2]);
+    }
+
+    #[gpui::test]
+    fn a_ctrl_shift_click_adds_the_range(cx: &mut TestAppContext) {
+        let (state, _, cx) = list(cx, true);
+
+        click(cx, "list-item-0", Modifiers::none());
+        click(cx, "list-item-4", Modifiers::control());
+        click(
+            cx,
+            "list-item-6",
+            Modifiers {
+                control: true,
+                shift: true,
+                ..Modifiers::default()
+            },
+        );
+        assert_eq!(selected(&state, cx), [0, 4, 5, 6]);
+    }
+
+    #[gpui::test]
+    fn arrows_move_the_selection(cx: &mut TestAppContext) {
+        let (state, _, cx) = list(cx, true);
+
+        click(cx, "list-item-3", Modifiers::none());
+        cx.simulate_keystrokes("down down");
+        assert_eq!(selected(&state, cx), [5]);
+        cx.simulate_keystrokes("up");
+        assert_eq!(selected(&state, cx), [4]);
+    }
+
+    #[gpui::test]
+    fn shift_arrows_extend_the_selection(cx: &mut TestAppContext) {
+        let (state, changes, cx) = list(cx, true);
+
+        click(cx, "list-item-3", Modifiers::none());
+        cx.simulate_keystrokes("shift-down shift-down");
+        assert_eq!(selected(&state, cx), [3, 4, 5]);
+        cx.simulate_keystrokes("shift-up shift-up shift-up");
+        assert_eq!(selected(&state, cx), [2, 3]);
+        assert_eq!(
+            changes.borrow().iter().map(Vec::len).collect::<Vec<_>>(),
+            [1, 2, 3, 2, 1, 2]
+        );
+    }
+
+    #[gpui::test]
+    fn arrows_scroll_the_selection_into_view(cx: &mut TestAppContext) {
+        let (state, _, cx) = list(cx, true);
+
+        click(cx, "list-item-8", Modifiers::none());
+        cx.simulate_keystrokes("down down down");
+        assert_eq!(selected(&state, cx), [11]);
+        // Item 11 ends 240px down, 40px past the 200px of the list
+        assert_eq!(state.read_with(cx, |state, _| state.scroll()), 40.);
+    }
+
+    #[gpui::test]
+    fn items_are_not_selected_unless_selectable(cx: &mut TestAppContext) {
+        let (state, changes, cx) = list(cx, false);
+
+        click(cx, "list-item-2", Modifiers::none());
+        cx.simulate_keystrokes("down");
+        assert!(selected(&state, cx).is_empty());
+        assert!(changes.borrow().is_empty());
+    }
+}
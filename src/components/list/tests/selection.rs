@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod selection {
+    use crate::components::list::selection::ListSelection;
+
+    fn selected(selection: &ListSelection) -> Vec<usize> {
+        selection.selected().iter().copied().collect()
+    }
+
+    /// A selection after clicking each of `clicks` as `(ix, toggle, extend)`
+    fn clicked(clicks: &[(usize, bool, bool)]) -> ListSelection {
+        let mut selection = ListSelection::default();
+        for &(ix, toggle, extend) in clicks {
+            selection.click(ix, toggle, extend);
+        }
+        selection
+    }
+
+    #[test]
+    fn a_click_selects_only_the_item() {
+        let selection = clicked(&[(2, false, false), (5, false, false)]);
+        assert_eq!(selected(&selection), [5]);
+        assert_eq!(selection.anchor(), Some(5));
+        assert_eq!(selection.cursor(), Some(5));
+    }
+
+    #[test]
+    fn a_toggling_click_adds_and_removes_the_item() {
+        let selection = clicked(&[(2, false, false), (5, true, false), (7, true, false)]);
+        assert_eq!(selected(&selection), [2, 5, 7]);
+
+        let selection = clicked(&[(2, false, false), (5, true, false), (2, true, false)]);
+        assert_eq!(selected(&selection), [5]);
+        assert_eq!(selection.anchor(), Some(2));
+    }
+
+    #[test]
+    fn an_extending_click_selects_from_the_anchor() {
+        let selection = clicked(&[(3, false, false), (6, false, true)]);
+        assert_eq!(selected(&selection), [3, 4, 5, 6]);
+        assert_eq!(selection.anchor(), Some(3));
+        assert_eq!(selection.cursor(), Some(6));
+
+        // Extending again starts from the same anchor, in either direction
+        let selection = clicked(&[(3, false, false), (6, false, true), (1, false, true)]);
+        assert_eq!(selected(&selection), [1, 2, 3]);
+    }
+
+    #[test]
+    fn an_extending_click_replaces_toggled_items() {
+        let selection = clicked(&[(0, false, false), (8, true, false), (6, false, true)]);
+        // The toggling click moved the anchor to 8
+        assert_eq!(selected(&selection), [6, 7, 8]);
+    }
+
+    #[test]
+    fn an_extending_toggling_click_adds_the_range() {
+        let selection = clicked(&[(0, false, false), (5, true, false), (7, true, true)]);
+        assert_eq!(selected(&selection), [0, 5, 6, 7]);
+    }
+
+    #[test]
+    fn an_extending_click_without_an_anchor_selects_the_item() {
+        let selection = clicked(&[(4, false, true)]);
+        assert_eq!(selected(&selection), [4]);
+        assert_eq!(selection.anchor(), Some(4));
+    }
+
+    #[test]
+    fn arrows_move_the_selection_and_stop_at_the_ends() {
+        let mut selection = ListSelection::default();
+        selection.step(true, false, 3);
+        assert_eq!(selected(&selection), [0]);
+        selection.step(true, false, 3);
+        selection.step(true, false, 3);
+        selection.step(true, false, 3);
+        assert_eq!(selected(&selection), [2]);
+        selection.step(false, false, 3);
+        assert_eq!(selected(&selection), [1]);
+        assert_eq!(selection.anchor(), Some(1));
+    }
+
+    #[test]
+    fn moving_back_without_a_cursor_starts_at_the_last_item() {
+        let mut selection = ListSelection::default();
+        selection.step(false, false, 4);
+        assert_eq!(selected(&selection), [3]);
+    }
+
+    #[test]
+    fn arrows_move_on_from_the_last_click() {
+        let mut selection = clicked(&[(1, false, false), (4, true, false)]);
+        selection.step(true, false, 10);
+        assert_eq!(selected(&selection), [5]);
+    }
+
+    #[test]
+    fn shift_arrows_extend_the_selection_from_the_anchor() {
+        let mut selection = clicked(&[(4, false, false)]);
+        selection.step(true, true, 10);
+        selection.step(true, true, 10);
+        assert_eq!(selected(&selection), [4, 5, 6]);
+
+        // Going back past the anchor extends the other way
+        for _ in 0..4 {
+            selection.step(false, true, 10);
+        }
+        assert_eq!(selected(&selection), [2, 3, 4]);
+        assert_eq!(selection.anchor(), Some(4));
+    }
+
+    #[test]
+    fn shift_arrows_continue_an_extending_click() {
+        let mut selection = clicked(&[(2, false, false), (4, false, true)]);
+        selection.step(true, true, 10);
+        assert_eq!(selected(&selection), [2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn arrows_do_nothing_without_items() {
+        let mut selection = ListSelection::default();
+        selection.step(true, true, 0);
+        assert_eq!(selection, ListSelection::default());
+    }
+
+    #[test]
+    fn removing_items_drops_them_from_the_selection() {
+        let mut selection = clicked(&[(1, false, false), (6, false, true)]);
+        selection.truncate(4);
+        assert_eq!(selected(&selection), [1, 2, 3]);
+        assert_eq!(selection.anchor(), Some(1));
+        assert_eq!(selection.cursor(), None);
+    }
+}
@@ -0,0 +1,84 @@
+use gpui::{App, SharedString, Window};
+use std::rc::Rc;
+
+#[derive(Clone)]
+enum MenuItemKind {
+    Action(Option<Rc<dyn Fn(&mut Window, &mut App)>>),
+    Submenu(Vec<MenuItem>),
+    Separator,
+}
+
+/// An item of a [`Menu`](super::Menu): an action, a submenu of further items, or a
+/// separator between groups of them
+#[derive(Clone)]
+pub struct MenuItem {
+    /// What the menu shows for the item
+    pub label: SharedString,
+    pub disabled: bool,
+    kind: MenuItemKind,
+}
+
+impl MenuItem {
+    /// Create an enabled action showing `label`, which does nothing until given
+    /// [`on_select`](Self::on_select)
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            disabled: false,
+            kind: MenuItemKind::Action(None),
+        }
+    }
+
+    /// Create an item showing `label` that opens a submenu of `items`
+    pub fn submenu(label: impl Into<SharedString>, items: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            label: label.into(),
+            disabled: false,
+            kind: MenuItemKind::Submenu(items.into_iter().collect()),
+        }
+    }
+
+    /// Create a line between groups of items, which can't be highlighted
+    pub fn separator() -> Self {
+        Self {
+            label: SharedString::default(),
+            disabled: true,
+            kind: MenuItemKind::Separator,
+        }
+    }
+
+    /// Set whether the item can't be highlighted or picked, though it is still shown
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled || self.is_separator();
+        self
+    }
+
+    /// Call `on_select` when the action is picked, once the menu has closed
+    ///
+    /// Submenus and separators ignore it.
+    pub fn on_select(mut self, on_select: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        if let MenuItemKind::Action(action) = &mut self.kind {
+            *action = Some(Rc::new(on_select));
+        }
+        self
+    }
+
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, MenuItemKind::Separator)
+    }
+
+    /// The items of the submenu the item opens, if it does
+    pub fn submenu_items(&self) -> Option<&[MenuItem]> {
+        match &self.kind {
+            MenuItemKind::Submenu(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub(super) fn action(&self) -> Option<Rc<dyn Fn(&mut Window, &mut App)>> {
+        match &self.kind {
+            MenuItemKind::Action(action) => action.clone(),
+            _ => None,
+        }
+    }
+}
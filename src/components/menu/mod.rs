@@ -0,0 +1,264 @@
+use crate::{
+    components::Separator,
+    primitives::{h_flex, v_flex},
+};
+use gpui::{prelude::FluentBuilder, *};
+use smallvec::SmallVec;
+use std::{rc::Rc, time::Duration};
+
+mod item;
+mod navigation;
+mod path;
+mod placement;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use item::MenuItem;
+pub use state::*;
+
+/// How long the pointer rests on an item before its submenu opens
+pub const DEFAULT_SUBMENU_DELAY: Duration = Duration::from_millis(200);
+
+/// What an item of an open menu is, handed to [`Menu::item`] to style its row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuRow {
+    pub label: SharedString,
+    /// Whether the pointer or the keyboard is on the item
+    pub highlighted: bool,
+    pub disabled: bool,
+    /// Whether the item opens a submenu
+    pub submenu: bool,
+    /// Whether the submenu of the item is open
+    pub open: bool,
+}
+
+/// An element that opens a context menu of items where the pointer right clicks it.
+///
+/// Clicking an action closes the menu and calls its [`on_select`](MenuItem::on_select),
+/// and pressing outside the menu closes it. An item with a submenu opens it once the
+/// pointer rests on the item for the [`submenu_delay`](MenuState::submenu_delay), or
+/// right away when clicked, and moving to a sibling item closes it again.
+///
+/// The open menu has focus. The arrow keys, Home and End move the highlight over the
+/// items of the deepest open level, skipping separators and disabled items, and typing
+/// highlights the next item starting with the letters typed. The right arrow opens the
+/// submenu of the highlighted item and the left arrow closes it, Enter and Space pick the
+/// highlighted item, and Escape closes the menu.
+///
+/// The menu shows below and to the right of the pointer, and each submenu to the right
+/// of its item. Where the window has no room for them they flip to the other side, and
+/// they shift to stay within the window.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     MenuState::new(cx).items([
+///         MenuItem::new("Copy").on_select(|_window, _cx| println!("Copy")),
+///         MenuItem::new("Paste").disabled(true),
+///         MenuItem::separator(),
+///         MenuItem::submenu("Share", [MenuItem::new("Email"), MenuItem::new("Messages")]),
+///     ])
+/// });
+///
+/// Menu::new(state)
+///     .panel(|panel| panel.p_1().min_w(px(160)).bg(rgb(0xffffff)).border_1().rounded_md())
+///     .item(|row, item| row.px_2().when(item.highlighted, |row| row.bg(rgb(0xe5e7eb))))
+///     .child(canvas_area)
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Menu {
+    base: Div,
+    state: Entity<MenuState>,
+    children: SmallVec<[AnyElement; 1]>,
+    panel: Option<Rc<dyn Fn(Div) -> Div>>,
+    item: Option<Rc<dyn Fn(Stateful<Div>, &MenuRow) -> Stateful<Div>>>,
+}
+
+impl Menu {
+    /// Creates an element opening the menu of the given state.
+    pub fn new(state: Entity<MenuState>) -> Self {
+        Self {
+            base: div(),
+            state,
+            children: SmallVec::new(),
+            panel: None,
+            item: None,
+        }
+    }
+
+    /// Customizes the box of the menu and of each of its submenus.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns a box, applying modifications
+    pub fn panel(mut self, handler: impl Fn(Div) -> Div + 'static) -> Self {
+        self.panel = Some(Rc::new(handler));
+        self
+    }
+
+    /// Styles the row of each item.
+    ///
+    /// Without it, the highlighted row is shaded and disabled ones are faded.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes a row and what its item is, and returns the
+    ///   styled row
+    pub fn item(
+        mut self,
+        handler: impl Fn(Stateful<Div>, &MenuRow) -> Stateful<Div> + 'static,
+    ) -> Self {
+        self.item = Some(Rc::new(handler));
+        self
+    }
+
+    fn default_item(row: Stateful<Div>, item: &MenuRow) -> Stateful<Div> {
+        row.when(item.highlighted, |row| row.bg(rgb(0xe5e7eb)))
+            .when(item.disabled, |row| row.opacity(0.5))
+    }
+
+    /// The panel of the open `level`, with a row for each of its items
+    fn render_panel(&self, level: usize, state: &MenuState, window: &mut Window) -> Div {
+        let rows = state.items_at(level).iter().enumerate().map(|(ix, item)| {
+            if item.is_separator() {
+                return Separator::new()
+                    .h(px(1.))
+                    .my_1()
+                    .bg(rgb(0xe5e7eb))
+                    .into_any_element();
+            }
+            let info = MenuRow {
+                label: item.label.clone(),
+                highlighted: state.highlighted(level) == Some(ix),
+                disabled: item.disabled,
+                submenu: item.submenu_items().is_some(),
+                open: state.is_submenu_open(level, ix),
+            };
+            // The bounds of the item place its open submenu next to it
+            let anchor = info.open.then(|| {
+                let tracked = self.state.clone();
+                canvas(
+                    move |bounds, _, app| {
+                        tracked.update(app, |state, cx| {
+                            state.set_anchor_bounds(level + 1, bounds, cx)
+                        })
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            });
+            let row = h_flex()
+                .id(("menu-item", ix))
+                .debug_selector(move || format!("menu-item-{level}-{ix}"))
+                .relative()
+                .justify_between()
+                .gap_4()
+                .children(anchor)
+                .child(item.label.clone())
+                .when(info.submenu, |this| this.child("▸"))
+                .on_mouse_move(
+                    window.listener_for(&self.state, move |state, _: &MouseMoveEvent, _, cx| {
+                        state.hover(level, ix, cx)
+                    }),
+                )
+                .when(!info.disabled, |this| {
+                    this.cursor_pointer().on_click(window.listener_for(
+                        &self.state,
+                        move |state, _: &ClickEvent, window, cx| {
+                            state.select(level, ix, window, cx)
+                        },
+                    ))
+                });
+            let row = match &self.item {
+                Some(item) => item(row, &info),
+                None => Self::default_item(row, &info),
+            };
+            row.into_any_element()
+        });
+
+        let tracked = self.state.clone();
+        let bounds = canvas(
+            move |bounds, _, app| {
+                tracked.update(app, |state, cx| state.set_panel_bounds(level, bounds, cx))
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+        let panel = v_flex()
+            .debug_selector(move || format!("menu-panel-{level}"))
+            .relative()
+            .occlude()
+            .child(bounds)
+            .children(rows.collect::<Vec<_>>());
+        match &self.panel {
+            Some(panel_handler) => panel_handler(panel),
+            None => panel
+                .bg(rgb(0xffffff))
+                .border_1()
+                .border_color(rgb(0xe5e7eb)),
+        }
+    }
+}
+
+impl ParentElement for Menu {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Menu {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Menu {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = self.state.read(app);
+        let viewport = window.viewport_size();
+        let panels: Vec<_> = if state.is_open() {
+            (0..state.levels())
+                .map(|level| {
+                    // A panel is laid out out of sight until its size is known, then placed
+                    let origin = state.panel_origin(level, viewport);
+                    let panel = self
+                        .render_panel(level, state, window)
+                        .when(origin.is_none(), |this| this.invisible());
+                    let panel = if level == 0 {
+                        panel
+                            .track_focus(state.focus_handle())
+                            .on_key_down(window.listener_for(&self.state, MenuState::on_key_down))
+                            .on_mouse_down_out(
+                                window.listener_for(&self.state, MenuState::on_mouse_down_out),
+                            )
+                    } else {
+                        panel
+                    };
+                    deferred(
+                        anchored()
+                            .position(origin.unwrap_or(state.position()))
+                            .child(panel),
+                    )
+                    .with_priority(level)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        self.base
+            .on_mouse_down(
+                MouseButton::Right,
+                window.listener_for(&self.state, |state, event: &MouseDownEvent, window, cx| {
+                    state.open_at(event.position, window, cx)
+                }),
+            )
+            .children(self.children)
+            .children(panels)
+    }
+}
@@ -0,0 +1,107 @@
+use gpui::SharedString;
+use std::time::{Duration, Instant};
+
+/// How long after the last letter typed the next one starts a new type-ahead search
+pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Which item of a menu the keyboard moves to, skipping separators and disabled items,
+/// which have no label here
+pub struct MenuNavigation {
+    labels: Vec<Option<SharedString>>,
+}
+
+impl MenuNavigation {
+    pub fn new(labels: Vec<Option<SharedString>>) -> Self {
+        Self { labels }
+    }
+
+    fn is_enabled(&self, ix: usize) -> bool {
+        self.labels[ix].is_some()
+    }
+
+    /// The next enabled item after `from`, or before it when not `forward`, wrapping
+    /// around at either end
+    ///
+    /// Without a highlighted item, moving forward starts at the first item and moving back
+    /// at the last.
+    pub fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let len = self.labels.len();
+        let start = match (from, forward) {
+            (Some(ix), _) => ix.min(len.checked_sub(1)?),
+            (None, true) => len.checked_sub(1)?,
+            (None, false) => 0,
+        };
+        (1..=len)
+            .map(|offset| {
+                if forward {
+                    (start + offset) % len
+                } else {
+                    (start + len - offset % len) % len
+                }
+            })
+            .find(|&ix| self.is_enabled(ix))
+    }
+
+    /// The first enabled item
+    pub fn first(&self) -> Option<usize> {
+        (0..self.labels.len()).find(|&ix| self.is_enabled(ix))
+    }
+
+    /// The last enabled item
+    pub fn last(&self) -> Option<usize> {
+        (0..self.labels.len()).rev().find(|&ix| self.is_enabled(ix))
+    }
+
+    /// The enabled item whose label starts with `query` regardless of case, looking from
+    /// `from` on and wrapping around
+    ///
+    /// The item at `from` stays highlighted while it still matches as the query grows. A
+    /// query of one letter, or of the same letter typed again, moves on to the next item
+    /// starting with it, so typing it over and over cycles through them.
+    pub fn find(&self, query: &str, from: Option<usize>) -> Option<usize> {
+        let query = query.to_lowercase();
+        let mut chars = query.chars();
+        let first = chars.next()?;
+        let (query, skip) = if chars.all(|ch| ch == first) {
+            (first.to_string(), 1)
+        } else {
+            (query, 0)
+        };
+        let len = self.labels.len();
+        let start = from.map_or(0, |ix| ix + skip);
+        (0..len).map(|offset| (start + offset) % len).find(|&ix| {
+            self.labels[ix]
+                .as_ref()
+                .is_some_and(|label| label.to_lowercase().starts_with(&query))
+        })
+    }
+}
+
+/// The letters typed in quick succession, which type-ahead looks for
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeAhead {
+    query: String,
+    typed_at: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Add `text` typed at `now`, starting over once the [`TYPE_AHEAD_TIMEOUT`] passed
+    /// since the last letter, and return the query
+    pub fn push(&mut self, text: &str, now: Instant) -> &str {
+        if self
+            .typed_at
+            .is_none_or(|typed_at| now.duration_since(typed_at) >= TYPE_AHEAD_TIMEOUT)
+        {
+            self.query.clear();
+        }
+        self.query.push_str(text);
+        self.typed_at = Some(now);
+        &self.query
+    }
+
+    /// Forget the letters typed, like when the menu closes
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.typed_at = None;
+    }
+}
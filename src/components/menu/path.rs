@@ -0,0 +1,79 @@
+/// The highlighted item of each open level of a menu, from the menu itself to the
+/// deepest open submenu.
+///
+/// Level `n + 1` is the submenu of the item highlighted at level `n`, so highlighting
+/// another item of a level closes the submenus below it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuPath {
+    highlights: Vec<Option<usize>>,
+}
+
+impl Default for MenuPath {
+    fn default() -> Self {
+        Self {
+            highlights: vec![None],
+        }
+    }
+}
+
+impl MenuPath {
+    /// How many levels are open, the menu itself included
+    pub fn levels(&self) -> usize {
+        self.highlights.len()
+    }
+
+    /// How many submenus are open
+    pub fn depth(&self) -> usize {
+        self.highlights.len() - 1
+    }
+
+    /// The highlighted item of `level`, if it is open
+    pub fn highlighted(&self, level: usize) -> Option<usize> {
+        self.highlights.get(level).copied().flatten()
+    }
+
+    /// The highlighted item of the deepest open level, where the keyboard is
+    pub fn current(&self) -> Option<usize> {
+        self.highlighted(self.depth())
+    }
+
+    /// Whether the submenu of `item` at `level` is open
+    pub fn is_open(&self, level: usize, item: usize) -> bool {
+        self.depth() > level && self.highlighted(level) == Some(item)
+    }
+
+    /// Highlight `item` of `level`, or nothing, returning whether that changed the path
+    ///
+    /// The submenus below the level close, unless `item` is the one they opened from.
+    /// Levels that aren't open are left alone.
+    pub fn highlight(&mut self, level: usize, item: Option<usize>) -> bool {
+        if level >= self.levels() || (self.highlighted(level) == item && item.is_some()) {
+            return false;
+        }
+        let changed = self.highlights[level] != item || self.depth() > level;
+        self.highlights.truncate(level + 1);
+        self.highlights[level] = item;
+        changed
+    }
+
+    /// Open the submenu of `item` at `level`, highlighting it and `first` in the submenu,
+    /// in place of any submenus open below the level
+    pub fn open(&mut self, level: usize, item: usize, first: Option<usize>) {
+        if level >= self.levels() {
+            return;
+        }
+        self.highlights.truncate(level + 1);
+        self.highlights[level] = Some(item);
+        self.highlights.push(first);
+    }
+
+    /// Close the deepest open submenu, leaving the item it opened from highlighted, or
+    /// return false when no submenu is open
+    pub fn close(&mut self) -> bool {
+        if self.depth() == 0 {
+            return false;
+        }
+        self.highlights.pop();
+        true
+    }
+}
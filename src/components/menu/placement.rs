@@ -0,0 +1,65 @@
+/// A rectangle in window coordinates
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// How a menu panel of `width` by `height` fits in a window of `window_width` by
+/// `window_height`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MenuFit {
+    pub width: f32,
+    pub height: f32,
+    pub window_width: f32,
+    pub window_height: f32,
+}
+
+impl MenuFit {
+    /// The origin of a menu opened at `x`, `y`, like where the pointer pressed
+    ///
+    /// The menu shows below and to the right of the point, or above or to the left of it
+    /// on the axis where only that side has room, or more of it. It then shifts to stay
+    /// within the window.
+    pub fn at(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            next_to(x, x, self.width, self.window_width),
+            next_to(y, y, self.height, self.window_height),
+        )
+    }
+
+    /// The origin of a submenu opened from `item`, the bounds of its row
+    ///
+    /// The submenu shows to the right of the row with its top at the top of the row, or
+    /// to the left of it when only that side has room, or more of it. It then shifts up
+    /// to stay within the window.
+    pub fn beside(&self, item: Rect) -> (f32, f32) {
+        (
+            next_to(item.x, item.x + item.width, self.width, self.window_width),
+            clamp_into(item.y, self.height, self.window_height),
+        )
+    }
+}
+
+/// The start of a span of `length` next to `before..after` on an axis of the window
+/// `0..window`, after it when it fits there, else before it when it fits there or has
+/// more room, and shifted to stay within the window
+fn next_to(before: f32, after: f32, length: f32, window: f32) -> f32 {
+    let room_after = window - after;
+    let start = if room_after >= length {
+        after
+    } else if before >= length || before > room_after {
+        before - length
+    } else {
+        after
+    };
+    clamp_into(start, length, window)
+}
+
+/// Move a span of `length` starting at `start` into `0..window`, keeping the start in the
+/// window when the span is longer than it
+fn clamp_into(start: f32, length: f32, window: f32) -> f32 {
+    start.min(window - length).max(0.)
+}
@@ -0,0 +1,460 @@
+use crate::components::menu::{
+    DEFAULT_SUBMENU_DELAY, MenuItem,
+    navigation::{MenuNavigation, TypeAhead},
+    path::MenuPath,
+    placement::{MenuFit, Rect},
+};
+use gpui::*;
+use std::time::{Duration, Instant};
+
+/// The state of a [`Menu`](super::Menu)
+///
+/// Owns the items and which of them are highlighted, from the menu itself down to the
+/// deepest open submenu. Resting the pointer on an item with a submenu opens the submenu
+/// after the [`submenu_delay`](Self::submenu_delay), and moving to a sibling item closes
+/// it.
+///
+/// The keyboard works on the deepest open level. The arrow keys, Home and End move the
+/// highlight, skipping separators and disabled items, and typing letters highlights the
+/// next item whose label starts with them. The right arrow opens the submenu of the
+/// highlighted item, highlighting its first item, and the left arrow closes the deepest
+/// submenu. Enter and Space pick the highlighted item, and Escape closes the menu.
+pub struct MenuState {
+    items: Vec<MenuItem>,
+    open: bool,
+    /// Where the menu opened, like where the pointer pressed
+    position: Point<Pixels>,
+    path: MenuPath,
+    submenu_delay: Duration,
+    focus_handle: FocusHandle,
+    /// What had focus when the menu opened
+    return_focus: Option<FocusHandle>,
+    /// The bounds of each open level as last laid out, where a press doesn't close the
+    /// menu
+    panels: Vec<Option<Bounds<Pixels>>>,
+    /// The bounds of the item each open submenu opened from, by the level of the submenu
+    anchors: Vec<Option<Bounds<Pixels>>>,
+    /// The submenu waiting for the pointer to rest on its item, by its level and item
+    pending_submenu: Option<((usize, usize), Task<()>)>,
+    type_ahead: TypeAhead,
+}
+
+impl MenuState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new closed MenuState without items
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            items: Vec::new(),
+            open: false,
+            position: Point::default(),
+            path: MenuPath::default(),
+            submenu_delay: DEFAULT_SUBMENU_DELAY,
+            focus_handle: cx.focus_handle(),
+            return_focus: None,
+            panels: Vec::new(),
+            anchors: Vec::new(),
+            pending_submenu: None,
+            type_ahead: TypeAhead::default(),
+        }
+    }
+
+    /// Set the items of the menu
+    pub fn items(mut self, items: impl IntoIterator<Item = MenuItem>) -> Self {
+        self.items = items.into_iter().collect();
+        self
+    }
+
+    /// Set how long the pointer rests on an item before its submenu opens, 200ms by
+    /// default
+    pub fn submenu_delay(mut self, submenu_delay: Duration) -> Self {
+        self.submenu_delay = submenu_delay;
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn current_items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    /// Replace the items, closing the menu if it is open
+    pub fn set_items(&mut self, items: Vec<MenuItem>, window: &mut Window, cx: &mut Context<Self>) {
+        self.close(window, cx);
+        self.items = items;
+        cx.notify();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// How many submenus are open
+    pub fn open_submenus(&self) -> usize {
+        self.path.depth()
+    }
+
+    /// The highlighted item of `level`, where level 0 is the menu itself and level `n` is
+    /// the `n`th open submenu
+    pub fn highlighted(&self, level: usize) -> Option<usize> {
+        self.path.highlighted(level)
+    }
+
+    /// Open the menu at `position` in the window, with nothing highlighted, and focus it
+    pub fn open_at(
+        &mut self,
+        position: Point<Pixels>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.open {
+            self.return_focus = window.focused(cx);
+        }
+        self.open = true;
+        self.position = position;
+        self.path = MenuPath::default();
+        self.pending_submenu = None;
+        self.type_ahead.clear();
+        self.panels.clear();
+        self.anchors.clear();
+        self.focus_handle.focus(window);
+        cx.notify();
+    }
+
+    /// Close the menu with its submenus, giving focus back to what had it before
+    pub fn close(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            return;
+        }
+        self.open = false;
+        self.path = MenuPath::default();
+        self.pending_submenu = None;
+        if let Some(return_focus) = self.return_focus.take()
+            && (self.focus_handle.is_focused(window) || window.focused(cx).is_none())
+        {
+            return_focus.focus(window);
+        }
+        cx.notify();
+    }
+
+    pub(super) fn focus_handle(&self) -> &FocusHandle {
+        &self.focus_handle
+    }
+
+    /// The items shown at `level`, or none when it isn't open
+    pub(super) fn items_at(&self, level: usize) -> &[MenuItem] {
+        if level >= self.path.levels() {
+            return &[];
+        }
+        let mut items = self.items.as_slice();
+        for level in 0..level {
+            match self
+                .path
+                .highlighted(level)
+                .and_then(|ix| items.get(ix))
+                .and_then(MenuItem::submenu_items)
+            {
+                Some(submenu) => items = submenu,
+                None => return &[],
+            }
+        }
+        items
+    }
+
+    /// How many levels are open, the menu itself included
+    pub(super) fn levels(&self) -> usize {
+        self.path.levels()
+    }
+
+    pub(super) fn is_submenu_open(&self, level: usize, item: usize) -> bool {
+        self.path.is_open(level, item)
+    }
+
+    /// Keep the bounds of the panel of `level` as laid out, placing it again when its size
+    /// changed
+    pub(super) fn set_panel_bounds(
+        &mut self,
+        level: usize,
+        bounds: Bounds<Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        if set_level(&mut self.panels, level, bounds) {
+            cx.notify();
+        }
+    }
+
+    /// Keep the bounds of the item the submenu of `level` opened from, placing the submenu
+    /// again when they changed
+    pub(super) fn set_anchor_bounds(
+        &mut self,
+        level: usize,
+        bounds: Bounds<Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        if set_level(&mut self.anchors, level, bounds) {
+            cx.notify();
+        }
+    }
+
+    /// Where the panel of `level` shows in a window of `viewport`, once it was laid out
+    ///
+    /// The menu shows below and to the right of where it opened, and each submenu to the
+    /// right of its item, each flipping to the other side where the window has no room.
+    pub(super) fn panel_origin(
+        &self,
+        level: usize,
+        viewport: Size<Pixels>,
+    ) -> Option<Point<Pixels>> {
+        let size = self.panels.get(level).copied().flatten()?.size;
+        let fit = MenuFit {
+            width: size.width.into(),
+            height: size.height.into(),
+            window_width: viewport.width.into(),
+            window_height: viewport.height.into(),
+        };
+        let (x, y) = if level == 0 {
+            fit.at(self.position.x.into(), self.position.y.into())
+        } else {
+            let anchor = self.anchors.get(level).copied().flatten()?;
+            fit.beside(Rect {
+                x: anchor.origin.x.into(),
+                y: anchor.origin.y.into(),
+                width: anchor.size.width.into(),
+                height: anchor.size.height.into(),
+            })
+        };
+        Some(point(px(x), px(y)))
+    }
+
+    pub(super) fn position(&self) -> Point<Pixels> {
+        self.position
+    }
+
+    /// Which item of `level` the keyboard moves to
+    fn navigation(&self, level: usize) -> MenuNavigation {
+        navigation_of(self.items_at(level))
+    }
+
+    /// Highlight `item` of the deepest open `level`, if there is one to highlight
+    fn highlight_from_keyboard(&mut self, level: usize, item: Option<usize>) {
+        if item.is_some() {
+            self.pending_submenu = None;
+            self.path.highlight(level, item);
+        }
+    }
+
+    /// Forget the layout of the levels that closed, so they are placed anew when they
+    /// open again
+    fn forget_closed_levels(&mut self) {
+        self.panels.truncate(self.path.levels());
+        self.anchors.truncate(self.path.levels());
+    }
+
+    /// Open the submenu of `item` at `level`, highlighting its first enabled item when
+    /// `highlight_first`, or return false when the item has no submenu or is disabled
+    fn open_submenu(
+        &mut self,
+        level: usize,
+        item: usize,
+        highlight_first: bool,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(submenu) = self
+            .items_at(level)
+            .get(item)
+            .filter(|item| !item.disabled)
+            .and_then(MenuItem::submenu_items)
+        else {
+            return false;
+        };
+        let first = highlight_first
+            .then(|| navigation_of(submenu).first())
+            .flatten();
+        self.pending_submenu = None;
+        self.path.highlight(level, Some(item));
+        self.forget_closed_levels();
+        self.path.open(level, item, first);
+        cx.notify();
+        true
+    }
+
+    /// Pick the item at `ix` of `level`, unless it is disabled
+    ///
+    /// An item with a submenu opens it right away, and an action closes the menu before
+    /// calling its [`on_select`](MenuItem::on_select).
+    pub fn select(&mut self, level: usize, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(item) = self.items_at(level).get(ix).filter(|item| !item.disabled) else {
+            return;
+        };
+        if item.submenu_items().is_some() {
+            if !self.path.is_open(level, ix) {
+                self.open_submenu(level, ix, false, cx);
+            }
+            return;
+        }
+        let action = item.action();
+        self.close(window, cx);
+        if let Some(action) = action {
+            action(window, cx);
+        }
+    }
+
+    // ============================================================================
+    // Keyboard Handling
+    // ============================================================================
+
+    /// Move the highlight over the items of the deepest open level with the arrow keys,
+    /// Home and End, pick it with Enter or Space, and close the menu with Escape
+    ///
+    /// The right arrow opens the submenu of the highlighted item and the left arrow closes
+    /// the deepest submenu. Typing letters highlights the next item whose label starts
+    /// with them.
+    pub(super) fn on_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let level = self.path.depth();
+        let current = self.path.current();
+        let navigation = self.navigation(level);
+        let keystroke = &event.keystroke;
+        let modifiers = keystroke.modifiers;
+        let typed = keystroke
+            .key_char
+            .as_deref()
+            .filter(|text| !text.trim().is_empty())
+            .filter(|_| !(modifiers.control || modifiers.alt || modifiers.platform));
+        // Any other key ends the letters typed in a row
+        if typed.is_none() {
+            self.type_ahead.clear();
+        }
+        match keystroke.key.as_str() {
+            "up" => self.highlight_from_keyboard(level, navigation.step(current, false)),
+            "down" => self.highlight_from_keyboard(level, navigation.step(current, true)),
+            "home" => self.highlight_from_keyboard(level, navigation.first()),
+            "end" => self.highlight_from_keyboard(level, navigation.last()),
+            "enter" | "space" => {
+                let Some(item) = current else {
+                    return;
+                };
+                if !self.open_submenu(level, item, true, cx) {
+                    self.select(level, item, window, cx);
+                }
+            }
+            "escape" => self.close(window, cx),
+            "right" => {
+                let Some(item) = current else {
+                    return;
+                };
+                if !self.open_submenu(level, item, true, cx) {
+                    return;
+                }
+            }
+            "left" => {
+                if !self.path.close() {
+                    return;
+                }
+                self.pending_submenu = None;
+                self.forget_closed_levels();
+            }
+            _ => {
+                let Some(text) = typed else {
+                    return;
+                };
+                let query = self.type_ahead.push(text, Instant::now());
+                let found = navigation.find(query, current);
+                self.highlight_from_keyboard(level, found);
+            }
+        }
+        cx.stop_propagation();
+        cx.notify();
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// Highlight the item under the pointer, closing the submenus of its siblings, and
+    /// open its own submenu once the pointer rests on it for the submenu delay
+    pub(super) fn hover(&mut self, level: usize, ix: usize, cx: &mut Context<Self>) {
+        let Some(item) = self.items_at(level).get(ix).filter(|item| !item.disabled) else {
+            return;
+        };
+        let has_submenu = item.submenu_items().is_some();
+        if self.path.highlight(level, Some(ix)) {
+            self.forget_closed_levels();
+            cx.notify();
+        }
+        if !has_submenu {
+            self.pending_submenu = None;
+            return;
+        }
+        let waiting = self
+            .pending_submenu
+            .as_ref()
+            .is_some_and(|(pending, _)| *pending == (level, ix));
+        if waiting || self.path.is_open(level, ix) {
+            return;
+        }
+        if self.submenu_delay.is_zero() {
+            self.open_submenu(level, ix, false, cx);
+            return;
+        }
+        let delay = self.submenu_delay;
+        let task = cx.spawn(async move |this, cx| {
+            Timer::after(delay).await;
+            this.update(cx, |this, cx| {
+                if this.path.highlighted(level) == Some(ix) {
+                    this.open_submenu(level, ix, false, cx);
+                }
+            })
+            .ok();
+        });
+        self.pending_submenu = Some(((level, ix), task));
+    }
+
+    /// Close the menu on a press outside of it and its open submenus
+    pub(super) fn on_mouse_down_out(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let inside = self
+            .panels
+            .iter()
+            .flatten()
+            .any(|bounds| bounds.contains(&event.position));
+        if !inside {
+            self.close(window, cx);
+        }
+    }
+}
+
+fn navigation_of(items: &[MenuItem]) -> MenuNavigation {
+    MenuNavigation::new(
+        items
+            .iter()
+            .map(|item| (!item.disabled).then(|| item.label.clone()))
+            .collect(),
+    )
+}
+
+/// Set the bounds of `level`, returning whether they changed
+fn set_level(
+    levels: &mut Vec<Option<Bounds<Pixels>>>,
+    level: usize,
+    bounds: Bounds<Pixels>,
+) -> bool {
+    if levels.len() <= level {
+        levels.resize(level + 1, None);
+    }
+    let changed = levels[level] != Some(bounds);
+    levels[level] = Some(bounds);
+    changed
+}
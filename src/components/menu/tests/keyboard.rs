@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod keyboard {
+    use crate::components::menu::{
+        MenuState,
+        tests::support::{highlighted, hover, menu, open_at, open_submenus},
+    };
+    use gpui::*;
+
+    fn is_open(state: &Entity<MenuState>, cx: &mut VisualTestContext) -> bool {
+        state.read_with(cx, |state, _| state.is_open())
+    }
+
+    #[gpui::test]
+    fn the_arrow_keys_skip_separators_and_disabled_items(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        cx.simulate_keystrokes("down");
+        assert_eq!(highlighted(&state, 0, cx), Some(0));
+        // Past the disabled Paste and the separator
+        cx.simulate_keystrokes("down");
+        assert_eq!(highlighted(&state, 0, cx), Some(3));
+        cx.simulate_keystrokes("down");
+        assert_eq!(highlighted(&state, 0, cx), Some(4));
+        cx.simulate_keystrokes("down");
+        assert_eq!(highlighted(&state, 0, cx), Some(0));
+
+        cx.simulate_keystrokes("up");
+        assert_eq!(highlighted(&state, 0, cx), Some(4));
+        cx.simulate_keystrokes("up up");
+        assert_eq!(highlighted(&state, 0, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn home_and_end_move_to_either_end(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        cx.simulate_keystrokes("end");
+        assert_eq!(highlighted(&state, 0, cx), Some(4));
+        cx.simulate_keystrokes("home");
+        assert_eq!(highlighted(&state, 0, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn the_arrow_keys_move_within_the_deepest_submenu(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        cx.simulate_keystrokes("down down right down");
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert_eq!(highlighted(&state, 0, cx), Some(3));
+        assert_eq!(highlighted(&state, 1, cx), Some(1));
+    }
+
+    #[gpui::test]
+    fn enter_and_space_pick_the_highlighted_item(cx: &mut TestAppContext) {
+        let (state, picks, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        cx.simulate_keystrokes("down enter");
+        assert!(!is_open(&state, cx));
+        assert_eq!(*picks.borrow(), ["Copy"]);
+
+        open_at(cx, 10., 10.);
+        cx.simulate_keystrokes("end space");
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert_eq!(highlighted(&state, 1, cx), Some(0));
+        cx.simulate_keystrokes("space");
+        assert!(!is_open(&state, cx));
+        assert_eq!(*picks.borrow(), ["Copy", "Editor"]);
+    }
+
+    #[gpui::test]
+    fn escape_closes_the_menu(cx: &mut TestAppContext) {
+        let (state, picks, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+        hover(cx, "menu-item-0-3");
+        cx.simulate_keystrokes("right");
+
+        cx.simulate_keystrokes("escape");
+        assert!(!is_open(&state, cx));
+        assert!(cx.debug_bounds("menu-panel-0").is_none());
+        assert!(picks.borrow().is_empty());
+    }
+
+    #[gpui::test]
+    fn typing_highlights_the_item_starting_with_the_letters(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        cx.simulate_keystrokes("o");
+        assert_eq!(highlighted(&state, 0, cx), Some(4));
+        cx.simulate_keystrokes("home c");
+        assert_eq!(highlighted(&state, 0, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn typing_skips_disabled_items(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        cx.simulate_keystrokes("p");
+        assert_eq!(highlighted(&state, 0, cx), None);
+    }
+
+    #[gpui::test]
+    fn typing_matches_within_the_deepest_submenu(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        cx.simulate_keystrokes("s right m");
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert_eq!(highlighted(&state, 1, cx), Some(1));
+    }
+}
@@ -0,0 +1,6 @@
+mod keyboard;
+mod navigation;
+mod path;
+mod placement;
+mod submenus;
+mod support;
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod navigation {
+    use crate::components::menu::navigation::{MenuNavigation, TYPE_AHEAD_TIMEOUT, TypeAhead};
+    use std::time::{Duration, Instant};
+
+    /// Copy, a disabled Paste, a separator, Share, Save As and Open, the way the keyboard
+    /// sees them
+    fn navigation() -> MenuNavigation {
+        MenuNavigation::new(vec![
+            Some("Copy".into()),
+            None,
+            None,
+            Some("Share".into()),
+            Some("Save As".into()),
+            Some("Open".into()),
+        ])
+    }
+
+    #[test]
+    fn stepping_skips_separators_and_disabled_items() {
+        let navigation = navigation();
+
+        assert_eq!(navigation.step(Some(0), true), Some(3));
+        assert_eq!(navigation.step(Some(3), false), Some(0));
+    }
+
+    #[test]
+    fn stepping_wraps_around() {
+        let navigation = navigation();
+
+        assert_eq!(navigation.step(Some(5), true), Some(0));
+        assert_eq!(navigation.step(Some(0), false), Some(5));
+    }
+
+    #[test]
+    fn stepping_without_a_highlight_starts_at_either_end() {
+        let navigation = navigation();
+
+        assert_eq!(navigation.step(None, true), Some(0));
+        assert_eq!(navigation.step(None, false), Some(5));
+    }
+
+    #[test]
+    fn first_and_last_skip_separators_and_disabled_items() {
+        let navigation = MenuNavigation::new(vec![None, Some("Copy".into()), None]);
+
+        assert_eq!(navigation.first(), Some(1));
+        assert_eq!(navigation.last(), Some(1));
+        assert_eq!(navigation.step(Some(1), true), Some(1));
+    }
+
+    #[test]
+    fn nothing_is_reachable_in_an_empty_menu() {
+        let navigation = MenuNavigation::new(vec![None, None]);
+
+        assert_eq!(navigation.step(None, true), None);
+        assert_eq!(navigation.first(), None);
+        assert_eq!(navigation.find("c", None), None);
+        assert_eq!(MenuNavigation::new(Vec::new()).step(Some(0), true), None);
+    }
+
+    #[test]
+    fn type_ahead_matches_the_start_of_labels_regardless_of_case() {
+        let navigation = navigation();
+
+        assert_eq!(navigation.find("o", None), Some(5));
+        assert_eq!(navigation.find("SAV", None), Some(4));
+        assert_eq!(navigation.find("hare", None), None);
+    }
+
+    #[test]
+    fn type_ahead_skips_disabled_items() {
+        assert_eq!(navigation().find("p", None), None);
+    }
+
+    #[test]
+    fn type_ahead_keeps_the_highlight_while_it_matches() {
+        let navigation = navigation();
+
+        assert_eq!(navigation.find("sa", Some(4)), Some(4));
+        assert_eq!(navigation.find("sh", Some(4)), Some(3));
+    }
+
+    #[test]
+    fn the_same_letter_again_cycles_through_the_items_with_it() {
+        let navigation = navigation();
+
+        assert_eq!(navigation.find("s", None), Some(3));
+        assert_eq!(navigation.find("s", Some(3)), Some(4));
+        assert_eq!(navigation.find("ss", Some(4)), Some(3));
+        assert_eq!(navigation.find("sss", Some(3)), Some(4));
+    }
+
+    #[test]
+    fn typed_letters_add_up_until_the_timeout() {
+        let start = Instant::now();
+        let mut type_ahead = TypeAhead::default();
+
+        assert_eq!(type_ahead.push("s", start), "s");
+        assert_eq!(
+            type_ahead.push("a", start + Duration::from_millis(300)),
+            "sa"
+        );
+        assert_eq!(
+            type_ahead.push("o", start + Duration::from_millis(300) + TYPE_AHEAD_TIMEOUT),
+            "o"
+        );
+    }
+
+    #[test]
+    fn clearing_starts_a_new_query() {
+        let start = Instant::now();
+        let mut type_ahead = TypeAhead::default();
+        type_ahead.push("s", start);
+        type_ahead.clear();
+
+        assert_eq!(type_ahead.push("o", start), "o");
+    }
+}
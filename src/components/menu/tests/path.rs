@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod path {
+    use crate::components::menu::path::MenuPath;
+
+    #[test]
+    fn only_the_menu_is_open_at_first() {
+        let path = MenuPath::default();
+
+        assert_eq!(path.levels(), 1);
+        assert_eq!(path.depth(), 0);
+        assert_eq!(path.current(), None);
+    }
+
+    #[test]
+    fn opening_a_submenu_highlights_its_item() {
+        let mut path = MenuPath::default();
+        path.open(0, 2, Some(0));
+
+        assert_eq!(path.depth(), 1);
+        assert_eq!(path.highlighted(0), Some(2));
+        assert_eq!(path.current(), Some(0));
+        assert!(path.is_open(0, 2));
+        assert!(!path.is_open(0, 1));
+    }
+
+    #[test]
+    fn highlighting_a_sibling_closes_the_submenus_below() {
+        let mut path = MenuPath::default();
+        path.open(0, 2, None);
+        path.open(1, 1, None);
+
+        assert!(path.highlight(0, Some(3)));
+        assert_eq!(path.depth(), 0);
+        assert_eq!(path.current(), Some(3));
+    }
+
+    #[test]
+    fn highlighting_the_item_of_the_open_submenu_keeps_it_open() {
+        let mut path = MenuPath::default();
+        path.open(0, 2, Some(1));
+
+        assert!(!path.highlight(0, Some(2)));
+        assert_eq!(path.depth(), 1);
+        assert_eq!(path.current(), Some(1));
+    }
+
+    #[test]
+    fn highlighting_within_a_submenu_keeps_its_parents() {
+        let mut path = MenuPath::default();
+        path.open(0, 2, None);
+        path.open(1, 0, None);
+
+        assert!(path.highlight(1, Some(3)));
+        assert_eq!(path.depth(), 1);
+        assert_eq!(path.highlighted(0), Some(2));
+        assert_eq!(path.current(), Some(3));
+    }
+
+    #[test]
+    fn levels_that_arent_open_are_left_alone() {
+        let mut path = MenuPath::default();
+
+        assert!(!path.highlight(1, Some(0)));
+        path.open(1, 0, None);
+        assert_eq!(path, MenuPath::default());
+    }
+
+    #[test]
+    fn closing_returns_to_the_item_the_submenu_opened_from() {
+        let mut path = MenuPath::default();
+        path.open(0, 2, Some(0));
+        path.open(1, 0, Some(1));
+
+        assert!(path.close());
+        assert_eq!(path.depth(), 1);
+        assert_eq!(path.current(), Some(0));
+        assert!(path.close());
+        assert_eq!(path.current(), Some(2));
+        assert!(!path.close());
+        assert_eq!(path.current(), Some(2));
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod placement {
+    use crate::components::menu::placement::{MenuFit, Rect};
+
+    /// A 100 by 80 panel in an 800 by 600 window
+    const FIT: MenuFit = MenuFit {
+        width: 100.,
+        height: 80.,
+        window_width: 800.,
+        window_height: 600.,
+    };
+
+    /// An item row of a panel from 300 to 400 across, 28 tall
+    const ITEM: Rect = Rect {
+        x: 300.,
+        y: 200.,
+        width: 100.,
+        height: 28.,
+    };
+
+    #[test]
+    fn a_menu_opens_below_and_right_of_the_point() {
+        assert_eq!(FIT.at(200., 150.), (200., 150.));
+    }
+
+    #[test]
+    fn a_menu_flips_left_at_the_right_edge() {
+        assert_eq!(FIT.at(750., 150.), (650., 150.));
+    }
+
+    #[test]
+    fn a_menu_flips_up_at_the_bottom_edge() {
+        assert_eq!(FIT.at(200., 560.), (200., 480.));
+    }
+
+    #[test]
+    fn a_menu_shifts_into_a_window_too_small_on_either_side() {
+        let fit = MenuFit {
+            window_width: 150.,
+            ..FIT
+        };
+
+        assert_eq!(fit.at(80., 150.), (0., 150.));
+    }
+
+    #[test]
+    fn a_menu_larger_than_the_window_starts_at_its_edge() {
+        let fit = MenuFit {
+            height: 900.,
+            ..FIT
+        };
+
+        assert_eq!(fit.at(200., 150.), (200., 0.));
+    }
+
+    #[test]
+    fn a_submenu_opens_right_of_its_item() {
+        assert_eq!(FIT.beside(ITEM), (400., 200.));
+    }
+
+    #[test]
+    fn a_submenu_flips_left_at_the_right_edge() {
+        let item = Rect { x: 650., ..ITEM };
+
+        assert_eq!(FIT.beside(item), (550., 200.));
+    }
+
+    #[test]
+    fn a_submenu_stays_right_when_the_left_has_less_room() {
+        let fit = MenuFit {
+            window_width: 480.,
+            width: 120.,
+            ..FIT
+        };
+        let item = Rect {
+            x: 40.,
+            width: 340.,
+            ..ITEM
+        };
+
+        assert_eq!(fit.beside(item), (360., 200.));
+    }
+
+    #[test]
+    fn a_submenu_shifts_up_at_the_bottom_edge() {
+        let item = Rect { y: 560., ..ITEM };
+
+        assert_eq!(FIT.beside(item), (400., 520.));
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod submenus {
+    use crate::components::menu::{
+        DEFAULT_SUBMENU_DELAY,
+        tests::support::{click, highlighted, hover, menu, open_at, open_submenus},
+    };
+    use gpui::*;
+    use std::time::Duration;
+
+    fn wait(cx: &mut VisualTestContext, delay: Duration) {
+        cx.executor().advance_clock(delay);
+        cx.run_until_parked();
+    }
+
+    #[gpui::test]
+    fn resting_on_an_item_opens_its_submenu_after_the_delay(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        hover(cx, "menu-item-0-3");
+        assert_eq!(highlighted(&state, 0, cx), Some(3));
+        assert_eq!(open_submenus(&state, cx), 0);
+        assert!(cx.debug_bounds("menu-panel-1").is_none());
+
+        wait(cx, DEFAULT_SUBMENU_DELAY);
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert!(cx.debug_bounds("menu-item-1-0").is_some());
+    }
+
+    #[gpui::test]
+    fn leaving_the_item_before_the_delay_keeps_the_submenu_closed(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        hover(cx, "menu-item-0-3");
+        wait(cx, DEFAULT_SUBMENU_DELAY / 2);
+        hover(cx, "menu-item-0-0");
+        wait(cx, DEFAULT_SUBMENU_DELAY);
+        assert_eq!(open_submenus(&state, cx), 0);
+    }
+
+    #[gpui::test]
+    fn moving_to_a_sibling_closes_the_submenu(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+        hover(cx, "menu-item-0-3");
+        wait(cx, DEFAULT_SUBMENU_DELAY);
+
+        hover(cx, "menu-item-0-0");
+        assert_eq!(open_submenus(&state, cx), 0);
+        assert_eq!(highlighted(&state, 0, cx), Some(0));
+        assert!(cx.debug_bounds("menu-panel-1").is_none());
+    }
+
+    #[gpui::test]
+    fn moving_to_a_sibling_with_a_submenu_swaps_them(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+        hover(cx, "menu-item-0-3");
+        wait(cx, DEFAULT_SUBMENU_DELAY);
+
+        hover(cx, "menu-item-0-4");
+        assert_eq!(open_submenus(&state, cx), 0);
+        wait(cx, DEFAULT_SUBMENU_DELAY);
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert_eq!(highlighted(&state, 0, cx), Some(4));
+    }
+
+    #[gpui::test]
+    fn moving_into_the_submenu_keeps_it_open(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+        hover(cx, "menu-item-0-3");
+        wait(cx, DEFAULT_SUBMENU_DELAY);
+
+        hover(cx, "menu-item-1-1");
+        wait(cx, DEFAULT_SUBMENU_DELAY);
+        assert_eq!(open_submenus(&state, cx), 2);
+        assert_eq!(highlighted(&state, 0, cx), Some(3));
+
+        hover(cx, "menu-item-1-0");
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert_eq!(highlighted(&state, 1, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn clicking_an_item_opens_its_submenu_right_away(cx: &mut TestAppContext) {
+        let (state, picks, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+
+        click(cx, "menu-item-0-3");
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert!(state.read_with(cx, |state, _| state.is_open()));
+
+        click(cx, "menu-item-1-0");
+        assert!(!state.read_with(cx, |state, _| state.is_open()));
+        assert_eq!(*picks.borrow(), ["Email"]);
+    }
+
+    #[gpui::test]
+    fn the_arrow_keys_descend_and_ascend_the_submenus(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+        hover(cx, "menu-item-0-3");
+
+        cx.simulate_keystrokes("right");
+        assert_eq!(open_submenus(&state, cx), 1);
+        assert_eq!(highlighted(&state, 1, cx), Some(0));
+
+        // Email has no submenu to open
+        cx.simulate_keystrokes("right");
+        assert_eq!(open_submenus(&state, cx), 1);
+
+        cx.simulate_keystrokes("left");
+        assert_eq!(open_submenus(&state, cx), 0);
+        assert_eq!(highlighted(&state, 0, cx), Some(3));
+        assert!(cx.debug_bounds("menu-panel-1").is_none());
+
+        // The menu itself stays open
+        cx.simulate_keystrokes("left");
+        assert!(state.read_with(cx, |state, _| state.is_open()));
+    }
+
+    #[gpui::test]
+    fn a_submenu_opens_right_of_its_item(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+        click(cx, "menu-item-0-3");
+        assert_eq!(open_submenus(&state, cx), 1);
+
+        let item = cx.debug_bounds("menu-item-0-3").unwrap();
+        let submenu = cx.debug_bounds("menu-panel-1").unwrap();
+        assert_eq!(submenu.origin, point(item.right(), item.top()));
+    }
+
+    #[gpui::test]
+    fn menus_flip_left_at_the_right_edge_of_the_window(cx: &mut TestAppContext) {
+        let (state, _, cx) = menu(cx);
+        let width = cx.update(|window, _| f32::from(window.viewport_size().width));
+        open_at(cx, width - 50., 10.);
+
+        let panel = cx.debug_bounds("menu-panel-0").unwrap();
+        assert_eq!(panel.origin, point(px(width - 170.), px(10.)));
+
+        click(cx, "menu-item-0-3");
+        assert_eq!(open_submenus(&state, cx), 1);
+        let item = cx.debug_bounds("menu-item-0-3").unwrap();
+        let submenu = cx.debug_bounds("menu-panel-1").unwrap();
+        assert_eq!(submenu.origin, point(item.left() - px(120.), item.top()));
+    }
+
+    #[gpui::test]
+    fn pressing_outside_closes_the_menu(cx: &mut TestAppContext) {
+        let (state, picks, cx) = menu(cx);
+        open_at(cx, 10., 10.);
+        click(cx, "menu-item-0-3");
+
+        cx.simulate_click(point(px(400.), px(300.)), Modifiers::none());
+        assert!(!state.read_with(cx, |state, _| state.is_open()));
+        assert!(cx.debug_bounds("menu-panel-0").is_none());
+        assert!(picks.borrow().is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::components::menu::{Menu, MenuItem, MenuState};
+use gpui::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// The labels of the actions picked from the menu
+pub(super) type Picks = Rc<RefCell<Vec<&'static str>>>;
+
+/// A window covered by a menu of Copy, a disabled Paste, a separator, a "Share" submenu
+/// of Email and a "More" submenu of Print, and an "Open With" submenu of Editor
+///
+/// Each panel is 120px wide and each item row 20px tall.
+pub(super) struct MenuView {
+    pub(super) state: Entity<MenuState>,
+}
+
+impl Render for MenuView {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Menu::new(self.state.clone())
+            .size_full()
+            .panel(|panel| panel.w(px(120.)))
+            .item(|row, _| row.h(px(20.)))
+            .child("Page")
+    }
+}
+
+fn action(label: &'static str, picks: &Picks) -> MenuItem {
+    let picks = picks.clone();
+    MenuItem::new(label).on_select(move |_, _| picks.borrow_mut().push(label))
+}
+
+/// Open a window with the menu closed, recording the actions picked from it
+pub(super) fn menu(cx: &mut TestAppContext) -> (Entity<MenuState>, Picks, &mut VisualTestContext) {
+    let picks = Picks::default();
+    let items = vec![
+        action("Copy", &picks),
+        action("Paste", &picks).disabled(true),
+        MenuItem::separator(),
+        MenuItem::submenu(
+            "Share",
+            [
+                action("Email", &picks),
+                MenuItem::submenu("More", [action("Print", &picks)]),
+            ],
+        ),
+        MenuItem::submenu("Open With", [action("Editor", &picks)]),
+    ];
+    let (view, cx) = cx.add_window_view(|_, cx| MenuView {
+        state: cx.new(|cx| MenuState::new(cx).items(items)),
+    });
+    let state = view.read_with(cx, |view, _| view.state.clone());
+    cx.run_until_parked();
+    (state, picks, cx)
+}
+
+/// Right click the window at `x`, `y`, opening the menu there
+pub(super) fn open_at(cx: &mut VisualTestContext, x: f32, y: f32) {
+    let position = point(px(x), px(y));
+    cx.simulate_mouse_down(position, MouseButton::Right, Modifiers::none());
+    cx.simulate_mouse_up(position, MouseButton::Right, Modifiers::none());
+    cx.run_until_parked();
+}
+
+/// Move the pointer onto the item with the debug selector `item`
+pub(super) fn hover(cx: &mut VisualTestContext, item: &'static str) {
+    let center = cx.debug_bounds(item).unwrap().center();
+    cx.simulate_mouse_move(center, None, Modifiers::none());
+    cx.run_until_parked();
+}
+
+pub(super) fn click(cx: &mut VisualTestContext, item: &'static str) {
+    let center = cx.debug_bounds(item).unwrap().center();
+    cx.simulate_click(center, Modifiers::none());
+    cx.run_until_parked();
+}
+
+pub(super) fn open_submenus(state: &Entity<MenuState>, cx: &mut VisualTestContext) -> usize {
+    state.read_with(cx, |state, _| state.open_submenus())
+}
+
+pub(super) fn highlighted(
+    state: &Entity<MenuState>,
+    level: usize,
+    cx: &mut VisualTestContext,
+) -> Option<usize> {
+    state.read_with(cx, |state, _| state.highlighted(level))
+}
@@ -1,12 +1,37 @@
+pub mod accordion;
+mod avatar;
+mod badge;
+pub mod breadcrumb;
 mod button;
 mod checkbox;
+mod chip;
+pub mod color_picker;
+pub mod date_picker;
 pub mod input;
+pub mod list;
+pub mod menu;
+pub mod modal;
+pub mod pagination;
 pub mod progress;
+mod radio;
+pub mod scrollbar;
+pub mod select;
 mod separator;
+pub mod slider;
+mod spinner;
+pub mod split_pane;
 mod switch;
+pub mod table;
 pub mod tabs;
+pub mod toast;
+pub mod tooltip;
 
-pub use button::Button;
-pub use checkbox::Checkbox;
+pub use avatar::{Avatar, AvatarShape};
+pub use badge::{Badge, BadgePosition, BadgeVariant};
+pub use button::*;
+pub use checkbox::*;
+pub use chip::{Chip, ChipVariant};
+pub use radio::*;
 pub use separator::Separator;
+pub use spinner::Spinner;
 pub use switch::Switch;
@@ -0,0 +1,14 @@
+/// Which of the `len` focusable elements of a modal Tab moves focus to from `current`, or
+/// Shift+Tab when `backward`, wrapping around at either end so focus never leaves the
+/// modal
+///
+/// Without a focused element, Tab starts at the first and Shift+Tab at the last.
+pub fn cycle(current: Option<usize>, len: usize, backward: bool) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    Some(match (current.filter(|&ix| ix <= last), backward) {
+        (Some(ix), false) if ix < last => ix + 1,
+        (Some(ix), true) if ix > 0 => ix - 1,
+        (Some(_), false) | (None, false) => 0,
+        (Some(_), true) | (None, true) => last,
+    })
+}
@@ -0,0 +1,146 @@
+use gpui::*;
+use smallvec::SmallVec;
+
+mod focus_trap;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use state::*;
+
+/// A dialog shown over the page, on a backdrop covering the window, while its
+/// [`ModalState`] is open.
+///
+/// While the modal is open, Tab and Shift+Tab move focus through its
+/// [`focus_handles`](Self::focus_handles) and wrap around at either end, so focus doesn't
+/// reach the page behind it. Opening it focuses the first handle, and closing it gives
+/// focus back to the element that opened it.
+///
+/// Escape and a press on the backdrop close the modal, and presses inside it don't. When
+/// a modal opens over another, it shows above it and only it closes on Escape or a press
+/// on its backdrop, see [`ModalState`].
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     ModalState::new(cx)
+///         .close_on_backdrop_click(false)
+///         .on_close(|_window, _cx| println!("Closed"))
+/// });
+///
+/// Modal::new(state.clone())
+///     .focus_handles([name.focus_handle(cx), save.clone()])
+///     .w(px(400))
+///     .p_4()
+///     .bg(rgb(0xffffff))
+///     .rounded_lg()
+///     .backdrop(|backdrop| backdrop.bg(hsla(0., 0., 0., 0.5)))
+///     .child(TextInput::new(name.clone()))
+///     .child(Button::new("save").track_focus(&save).child("Save"))
+/// ```
+#[derive(IntoElement)]
+pub struct Modal {
+    base: Div,
+    state: Entity<ModalState>,
+    backdrop: Div,
+    focus_handles: Vec<FocusHandle>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Modal {
+    /// Creates a modal for the given state.
+    pub fn new(state: Entity<ModalState>) -> Self {
+        Self {
+            base: div(),
+            state,
+            backdrop: div(),
+            focus_handles: Vec::new(),
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets the focusable elements of the modal, in the order Tab moves through them.
+    pub fn focus_handles(mut self, focus_handles: impl IntoIterator<Item = FocusHandle>) -> Self {
+        self.focus_handles = focus_handles.into_iter().collect();
+        self
+    }
+
+    /// Customizes the backdrop covering the window behind the modal.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the backdrop, applying modifications
+    pub fn backdrop(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.backdrop = handler(self.backdrop);
+        self
+    }
+}
+
+impl ParentElement for Modal {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Modal {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Modal {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let focus_handles = self.focus_handles;
+        self.state
+            .update(app, |state, _| state.focus_handles = focus_handles);
+        let state = self.state.read(app);
+        if !state.is_open() {
+            return None;
+        }
+        let focus_handle = state.focus_handle().clone();
+        let viewport = window.viewport_size();
+        let depth = ModalStack::depth(self.state.entity_id(), app);
+
+        let tracked = self.state.clone();
+        // Keep the bounds of the modal, so pressing inside it doesn't close it
+        let bounds = canvas(
+            move |bounds, _, app| tracked.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        Some(
+            deferred(
+                anchored().position(Point::default()).child(
+                    self.backdrop
+                        .debug_selector(|| "modal-backdrop".into())
+                        .occlude()
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            window.listener_for(&self.state, ModalState::on_backdrop_mouse_down),
+                        )
+                        .w(viewport.width)
+                        .h(viewport.height)
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .child(
+                            self.base
+                                .debug_selector(|| "modal".into())
+                                .relative()
+                                .track_focus(&focus_handle)
+                                .on_key_down(
+                                    window.listener_for(&self.state, ModalState::on_key_down),
+                                )
+                                .child(bounds)
+                                .children(self.children),
+                        ),
+                ),
+            )
+            // Modals opened later show above
+            .with_priority(depth),
+        )
+    }
+}
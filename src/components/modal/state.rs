@@ -0,0 +1,225 @@
+use crate::components::modal::focus_trap;
+use gpui::*;
+
+/// The open modals of the app, from the first opened to the topmost
+#[derive(Default)]
+pub(super) struct ModalStack(Vec<EntityId>);
+
+impl Global for ModalStack {}
+
+impl ModalStack {
+    /// How many modals opened before the one with `id` are still open
+    pub(super) fn depth(id: EntityId, app: &App) -> usize {
+        app.try_global::<Self>()
+            .and_then(|stack| stack.0.iter().position(|open| *open == id))
+            .unwrap_or(0)
+    }
+
+    fn is_topmost(id: EntityId, app: &App) -> bool {
+        app.try_global::<Self>()
+            .is_some_and(|stack| stack.0.last() == Some(&id))
+    }
+
+    fn remove(id: EntityId, app: &mut App) {
+        app.default_global::<Self>().0.retain(|open| *open != id);
+    }
+}
+
+/// The state of a [`Modal`](super::Modal)
+///
+/// While the modal is open, Tab and Shift+Tab move focus through its
+/// [`focus_handles`](super::Modal::focus_handles) in order, wrapping around at either end,
+/// so focus stays in the modal rather than reaching the page behind it. Opening the modal
+/// focuses the first of them, and closing it gives focus back to the element that had it
+/// before.
+///
+/// Escape and a press on the backdrop close the modal, unless turned off with
+/// [`close_on_escape`](Self::close_on_escape) and
+/// [`close_on_backdrop_click`](Self::close_on_backdrop_click). When modals are stacked,
+/// only the last one opened handles Tab, Escape and the backdrop.
+#[allow(clippy::type_complexity)]
+pub struct ModalState {
+    open: bool,
+    close_on_escape: bool,
+    close_on_backdrop_click: bool,
+    focus_handle: FocusHandle,
+    /// The handles Tab moves through, as last rendered
+    pub(super) focus_handles: Vec<FocusHandle>,
+    /// What had focus when the modal opened
+    return_focus: Option<FocusHandle>,
+    /// The bounds of the modal, where a press doesn't count as one on the backdrop
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    on_close: Option<Box<dyn Fn(&mut Window, &mut Context<Self>)>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ModalState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new closed ModalState
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let this = cx.entity().downgrade();
+        let id = cx.entity_id();
+        let _subscriptions = vec![
+            cx.intercept_keystrokes(move |event, window, cx| {
+                this.update(cx, |this, cx| this.trap_tab(&event.keystroke, window, cx))
+                    .ok();
+            }),
+            cx.on_release(move |_, cx| ModalStack::remove(id, cx)),
+        ];
+
+        Self {
+            open: false,
+            close_on_escape: true,
+            close_on_backdrop_click: true,
+            focus_handle: cx.focus_handle(),
+            focus_handles: Vec::new(),
+            return_focus: None,
+            last_bounds: None,
+            on_close: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set whether Escape closes the modal, which it does by default
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    /// Set whether a press on the backdrop around the modal closes it, which it does by
+    /// default
+    pub fn close_on_backdrop_click(mut self, close_on_backdrop_click: bool) -> Self {
+        self.close_on_backdrop_click = close_on_backdrop_click;
+        self
+    }
+
+    /// Call `on_close` when Escape or a press on the backdrop closes the modal
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(&mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show or hide the modal
+    ///
+    /// Opening it focuses the first of its focus handles, or the modal itself without
+    /// any, and closing it focuses what had focus before it opened.
+    pub fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if self.open == open {
+            return;
+        }
+        self.open = open;
+        let id = cx.entity_id();
+        ModalStack::remove(id, cx);
+        if open {
+            cx.default_global::<ModalStack>().0.push(id);
+            self.return_focus = window.focused(cx);
+            self.focus_handles
+                .first()
+                .unwrap_or(&self.focus_handle)
+                .focus(window);
+        } else if let Some(return_focus) = self.return_focus.take()
+            && (self.focus_handle.contains_focused(window, cx) || window.focused(cx).is_none())
+        {
+            return_focus.focus(window);
+        }
+        cx.notify();
+    }
+
+    pub(super) fn focus_handle(&self) -> &FocusHandle {
+        &self.focus_handle
+    }
+
+    /// Whether the modal is the last open one, which alone handles the keyboard and the
+    /// backdrop
+    fn is_topmost(&self, cx: &Context<Self>) -> bool {
+        ModalStack::is_topmost(cx.entity_id(), cx)
+    }
+
+    /// Close the modal on behalf of the user and tell [`on_close`](Self::on_close)
+    fn dismiss(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_open(false, window, cx);
+        if let Some(on_close) = &self.on_close {
+            on_close(window, cx);
+        }
+    }
+
+    // ============================================================================
+    // Keyboard Handling
+    // ============================================================================
+
+    /// Move focus to the next of the focus handles on Tab, or the previous one on
+    /// Shift+Tab, while focus is in the open modal
+    ///
+    /// This runs before the key bindings, so Tab moves focus even from inputs that would
+    /// otherwise handle it themselves.
+    fn trap_tab(&mut self, keystroke: &Keystroke, window: &mut Window, cx: &mut Context<Self>) {
+        let modifiers = keystroke.modifiers;
+        if !self.open
+            || !self.is_topmost(cx)
+            || keystroke.key != "tab"
+            || modifiers.control
+            || modifiers.alt
+            || modifiers.platform
+            || !self.focus_handle.contains_focused(window, cx)
+        {
+            return;
+        }
+        let current = self
+            .focus_handles
+            .iter()
+            .position(|handle| handle.contains_focused(window, cx));
+        if let Some(next) = focus_trap::cycle(current, self.focus_handles.len(), modifiers.shift) {
+            self.focus_handles[next].focus(window);
+        }
+        cx.stop_propagation();
+    }
+
+    /// Close the topmost modal with Escape
+    pub(super) fn on_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if event.keystroke.key != "escape" || !self.close_on_escape || !self.is_topmost(cx) {
+            return;
+        }
+        self.dismiss(window, cx);
+        cx.stop_propagation();
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// Close the topmost modal on a press on the backdrop, outside of the modal
+    pub(super) fn on_backdrop_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.close_on_backdrop_click
+            && self.is_topmost(cx)
+            && self
+                .last_bounds
+                .is_none_or(|bounds| !bounds.contains(&event.position))
+        {
+            self.dismiss(window, cx);
+        }
+    }
+}
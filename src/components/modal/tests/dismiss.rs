@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod dismiss {
+    use crate::components::modal::{
+        ModalState,
+        tests::support::{is_open, show, stacked},
+    };
+    use gpui::{Context, Modifiers, TestAppContext, VisualTestContext, Window, point, px};
+    use std::{cell::Cell, rc::Rc};
+
+    /// A counter of the calls to `on_close`, and the callback that counts them
+    fn closes() -> (
+        Rc<Cell<usize>>,
+        impl Fn(&mut Window, &mut Context<ModalState>),
+    ) {
+        let count = Rc::new(Cell::new(0));
+        let counted = count.clone();
+        let on_close =
+            move |_: &mut Window, _: &mut Context<ModalState>| counted.set(counted.get() + 1);
+        (count, on_close)
+    }
+
+    /// Press the backdrop in the corner of the window, away from the modals
+    fn click_backdrop(cx: &mut VisualTestContext) {
+        cx.simulate_click(point(px(2.), px(2.)), Modifiers::none());
+    }
+
+    /// Press the middle of the window, where the modals are
+    fn click_content(cx: &mut VisualTestContext) {
+        let modal = cx.debug_bounds("modal").unwrap();
+        cx.simulate_click(modal.center(), Modifiers::none());
+    }
+
+    #[gpui::test]
+    fn escape_closes_the_modal(cx: &mut TestAppContext) {
+        let (count, on_close) = closes();
+        let (view, cx) = stacked(cx, |state| state.on_close(on_close), |state| state);
+        let first = view.read_with(cx, |view, _| view.first.clone());
+        show(&first, cx);
+
+        cx.simulate_keystrokes("escape");
+        assert!(!is_open(&first, cx));
+        assert_eq!(count.get(), 1);
+        assert!(cx.debug_bounds("modal").is_none());
+    }
+
+    #[gpui::test]
+    fn escape_can_be_turned_off(cx: &mut TestAppContext) {
+        let (count, on_close) = closes();
+        let (view, cx) = stacked(
+            cx,
+            |state| state.close_on_escape(false).on_close(on_close),
+            |state| state,
+        );
+        let first = view.read_with(cx, |view, _| view.first.clone());
+        show(&first, cx);
+
+        cx.simulate_keystrokes("escape");
+        assert!(is_open(&first, cx));
+        assert_eq!(count.get(), 0);
+    }
+
+    #[gpui::test]
+    fn escape_closes_only_the_topmost_of_stacked_modals(cx: &mut TestAppContext) {
+        let (first_count, first_close) = closes();
+        let (second_count, second_close) = closes();
+        let (view, cx) = stacked(
+            cx,
+            |state| state.on_close(first_close),
+            |state| state.on_close(second_close),
+        );
+        let (first, second) =
+            view.read_with(cx, |view, _| (view.first.clone(), view.second.clone()));
+        show(&first, cx);
+        show(&second, cx);
+
+        cx.simulate_keystrokes("escape");
+        assert!(is_open(&first, cx));
+        assert!(!is_open(&second, cx));
+        assert_eq!((first_count.get(), second_count.get()), (0, 1));
+
+        // Focus went back to the first modal, which closes on the next Escape
+        cx.simulate_keystrokes("escape");
+        assert!(!is_open(&first, cx));
+        assert_eq!((first_count.get(), second_count.get()), (1, 1));
+    }
+
+    #[gpui::test]
+    fn escape_on_a_modal_that_keeps_open_leaves_the_one_below(cx: &mut TestAppContext) {
+        let (view, cx) = stacked(cx, |state| state, |state| state.close_on_escape(false));
+        let (first, second) =
+            view.read_with(cx, |view, _| (view.first.clone(), view.second.clone()));
+        show(&first, cx);
+        show(&second, cx);
+
+        cx.simulate_keystrokes("escape");
+        assert!(is_open(&first, cx));
+        assert!(is_open(&second, cx));
+    }
+
+    #[gpui::test]
+    fn a_backdrop_click_closes_the_modal(cx: &mut TestAppContext) {
+        let (count, on_close) = closes();
+        let (view, cx) = stacked(cx, |state| state.on_close(on_close), |state| state);
+        let first = view.read_with(cx, |view, _| view.first.clone());
+        show(&first, cx);
+
+        click_backdrop(cx);
+        assert!(!is_open(&first, cx));
+        assert_eq!(count.get(), 1);
+    }
+
+    #[gpui::test]
+    fn a_content_click_keeps_the_modal_open(cx: &mut TestAppContext) {
+        let (count, on_close) = closes();
+        let (view, cx) = stacked(cx, |state| state.on_close(on_close), |state| state);
+        let first = view.read_with(cx, |view, _| view.first.clone());
+        show(&first, cx);
+
+        click_content(cx);
+        assert!(is_open(&first, cx));
+        assert_eq!(count.get(), 0);
+    }
+
+    #[gpui::test]
+    fn backdrop_clicks_can_be_turned_off(cx: &mut TestAppContext) {
+        let (view, cx) = stacked(
+            cx,
+            |state| state.close_on_backdrop_click(false),
+            |state| state,
+        );
+        let first = view.read_with(cx, |view, _| view.first.clone());
+        show(&first, cx);
+
+        click_backdrop(cx);
+        assert!(is_open(&first, cx));
+    }
+
+    #[gpui::test]
+    fn a_backdrop_click_closes_only_the_topmost_modal(cx: &mut TestAppContext) {
+        let (view, cx) = stacked(cx, |state| state, |state| state);
+        let (first, second) =
+            view.read_with(cx, |view, _| (view.first.clone(), view.second.clone()));
+        show(&first, cx);
+        show(&second, cx);
+
+        click_content(cx);
+        assert!(is_open(&second, cx));
+
+        click_backdrop(cx);
+        assert!(is_open(&first, cx));
+        assert!(!is_open(&second, cx));
+
+        click_backdrop(cx);
+        assert!(!is_open(&first, cx));
+    }
+}
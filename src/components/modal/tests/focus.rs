@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod focus {
+    use crate::components::modal::tests::support::{focused, modal, open};
+    use gpui::{TestAppContext, VisualTestContext};
+
+    #[gpui::test]
+    fn opening_focuses_the_first_element(cx: &mut TestAppContext) {
+        let (view, cx) = modal(cx);
+
+        open(&view, cx);
+        assert_eq!(focused(&view, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn tab_wraps_from_the_last_element_to_the_first(cx: &mut TestAppContext) {
+        let (view, cx) = modal(cx);
+        open(&view, cx);
+
+        cx.simulate_keystrokes("tab");
+        assert_eq!(focused(&view, cx), Some(1));
+        // The input would move focus on to the next tab stop of the window by itself
+        cx.simulate_keystrokes("tab");
+        assert_eq!(focused(&view, cx), Some(2));
+        cx.simulate_keystrokes("tab");
+        assert_eq!(focused(&view, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn shift_tab_wraps_from_the_first_element_to_the_last(cx: &mut TestAppContext) {
+        let (view, cx) = modal(cx);
+        open(&view, cx);
+
+        cx.simulate_keystrokes("shift-tab");
+        assert_eq!(focused(&view, cx), Some(2));
+        cx.simulate_keystrokes("shift-tab shift-tab");
+        assert_eq!(focused(&view, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn focus_never_reaches_the_page(cx: &mut TestAppContext) {
+        let (view, cx) = modal(cx);
+        open(&view, cx);
+
+        for keystroke in ["tab", "shift-tab"] {
+            for _ in 0..7 {
+                cx.simulate_keystrokes(keystroke);
+                assert!(focused(&view, cx).is_some());
+                assert!(!view.update_in(cx, |view, window, _| view.trigger.is_focused(window)));
+            }
+        }
+    }
+
+    #[gpui::test]
+    fn tab_on_the_modal_itself_starts_at_either_end(cx: &mut TestAppContext) {
+        let (view, cx) = modal(cx);
+        open(&view, cx);
+        let state = view.read_with(cx, |view, _| view.state.clone());
+        let focus_modal = |cx: &mut VisualTestContext| {
+            state.update_in(cx, |state, window, _| state.focus_handle().focus(window));
+            cx.run_until_parked();
+        };
+
+        focus_modal(cx);
+        cx.simulate_keystrokes("tab");
+        assert_eq!(focused(&view, cx), Some(0));
+        focus_modal(cx);
+        cx.simulate_keystrokes("shift-tab");
+        assert_eq!(focused(&view, cx), Some(2));
+    }
+
+    #[gpui::test]
+    fn closing_focuses_the_trigger_again(cx: &mut TestAppContext) {
+        let (view, cx) = modal(cx);
+        open(&view, cx);
+        cx.simulate_keystrokes("tab tab");
+
+        let state = view.read_with(cx, |view, _| view.state.clone());
+        state.update_in(cx, |state, window, cx| state.set_open(false, window, cx));
+        cx.run_until_parked();
+
+        assert_eq!(focused(&view, cx), None);
+        assert!(view.update_in(cx, |view, window, _| view.trigger.is_focused(window)));
+    }
+
+    #[gpui::test]
+    fn tab_is_left_to_the_page_while_closed(cx: &mut TestAppContext) {
+        let (view, cx) = modal(cx);
+
+        cx.simulate_keystrokes("tab");
+        assert_eq!(focused(&view, cx), None);
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod focus_trap {
+    use crate::components::modal::focus_trap::cycle;
+
+    #[test]
+    fn tab_moves_forward_and_wraps_to_the_first() {
+        assert_eq!(cycle(Some(0), 3, false), Some(1));
+        assert_eq!(cycle(Some(1), 3, false), Some(2));
+        assert_eq!(cycle(Some(2), 3, false), Some(0));
+    }
+
+    #[test]
+    fn shift_tab_moves_back_and_wraps_to_the_last() {
+        assert_eq!(cycle(Some(2), 3, true), Some(1));
+        assert_eq!(cycle(Some(1), 3, true), Some(0));
+        assert_eq!(cycle(Some(0), 3, true), Some(2));
+    }
+
+    #[test]
+    fn without_focus_starts_at_either_end() {
+        assert_eq!(cycle(None, 3, false), Some(0));
+        assert_eq!(cycle(None, 3, true), Some(2));
+    }
+
+    #[test]
+    fn a_single_element_keeps_focus() {
+        assert_eq!(cycle(Some(0), 1, false), Some(0));
+        assert_eq!(cycle(Some(0), 1, true), Some(0));
+    }
+
+    #[test]
+    fn an_empty_modal_has_nothing_to_focus() {
+        assert_eq!(cycle(None, 0, false), None);
+        assert_eq!(cycle(Some(0), 0, true), None);
+    }
+
+    #[test]
+    fn a_stale_index_starts_over() {
+        // The focused element was removed since the modal last rendered
+        assert_eq!(cycle(Some(5), 3, false), Some(0));
+        assert_eq!(cycle(Some(5), 3, true), Some(2));
+    }
+}
@@ -0,0 +1,4 @@
+mod dismiss;
+mod focus;
+mod focus_trap;
+mod support;
@@ -0,0 +1,119 @@
+use crate::components::{
+    input::{self, InputState, TextInput},
+    modal::{Modal, ModalState},
+};
+use gpui::*;
+
+/// A window with a trigger on the page and a modal holding a field, an input and
+/// another field, in that order
+pub(super) struct ModalView {
+    pub(super) state: Entity<ModalState>,
+    pub(super) trigger: FocusHandle,
+    pub(super) input: Entity<InputState>,
+    pub(super) fields: [FocusHandle; 2],
+}
+
+impl ModalView {
+    /// The handles Tab moves through in the modal
+    pub(super) fn focus_handles(&self, cx: &App) -> Vec<FocusHandle> {
+        vec![
+            self.fields[0].clone(),
+            self.input.focus_handle(cx),
+            self.fields[1].clone(),
+        ]
+    }
+}
+
+impl Render for ModalView {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .size_full()
+            .child(div().track_focus(&self.trigger).child("Open"))
+            .child(
+                Modal::new(self.state.clone())
+                    .focus_handles(self.focus_handles(cx))
+                    .child(div().track_focus(&self.fields[0]).child("First"))
+                    .child(TextInput::new(self.input.clone()))
+                    .child(div().track_focus(&self.fields[1]).child("Last")),
+            )
+    }
+}
+
+/// Open a window with the trigger focused and the modal closed
+pub(super) fn modal(cx: &mut TestAppContext) -> (Entity<ModalView>, &mut VisualTestContext) {
+    cx.update(input::init);
+    let (view, cx) = cx.add_window_view(|window, cx| ModalView {
+        state: cx.new(ModalState::new),
+        trigger: cx.focus_handle().tab_stop(true),
+        input: cx.new(|cx| InputState::new(window, cx)),
+        fields: [cx.focus_handle(), cx.focus_handle()],
+    });
+    view.update_in(cx, |view, window, _| view.trigger.focus(window));
+    cx.run_until_parked();
+    (view, cx)
+}
+
+/// Open the modal and render it
+pub(super) fn open(view: &Entity<ModalView>, cx: &mut VisualTestContext) {
+    let state = view.read_with(cx, |view, _| view.state.clone());
+    state.update_in(cx, |state, window, cx| state.set_open(true, window, cx));
+    cx.run_until_parked();
+}
+
+/// Which of the handles Tab moves through has focus, if any
+pub(super) fn focused(view: &Entity<ModalView>, cx: &mut VisualTestContext) -> Option<usize> {
+    view.update_in(cx, |view, window, cx| {
+        view.focus_handles(cx)
+            .iter()
+            .position(|handle| handle.is_focused(window))
+    })
+}
+
+/// A window with two modals, each built by its own function
+pub(super) struct StackedView {
+    pub(super) first: Entity<ModalState>,
+    pub(super) second: Entity<ModalState>,
+}
+
+impl Render for StackedView {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .size_full()
+            .child(
+                Modal::new(self.first.clone())
+                    .w(px(200.))
+                    .h(px(100.))
+                    .child("First"),
+            )
+            .child(
+                Modal::new(self.second.clone())
+                    .w(px(100.))
+                    .h(px(50.))
+                    .child("Second"),
+            )
+    }
+}
+
+/// Open a window with the modals built by `first` and `second`, both closed
+pub(super) fn stacked(
+    cx: &mut TestAppContext,
+    first: impl FnOnce(ModalState) -> ModalState,
+    second: impl FnOnce(ModalState) -> ModalState,
+) -> (Entity<StackedView>, &mut VisualTestContext) {
+    let (view, cx) = cx.add_window_view(|_, cx| StackedView {
+        first: cx.new(|cx| first(ModalState::new(cx))),
+        second: cx.new(|cx| second(ModalState::new(cx))),
+    });
+    cx.run_until_parked();
+    (view, cx)
+}
+
+/// Open the modal of `state` and render it
+pub(super) fn show(state: &Entity<ModalState>, cx: &mut VisualTestContext) {
+    state.update_in(cx, |state, window, cx| state.set_open(true, window, cx));
+    cx.run_until_parked();
+}
+
+pub(super) fn is_open(state: &Entity<ModalState>, cx: &mut VisualTestContext) -> bool {
+    state.read_with(cx, |state, _| state.is_open())
+}
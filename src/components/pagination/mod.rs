@@ -0,0 +1,237 @@
+use crate::primitives::h_flex;
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+mod pages;
+#[cfg(test)]
+mod tests;
+
+pub use pages::{PageItem, page_items};
+
+/// A control of a [`Pagination`], in the order they are laid out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaginationControl {
+    First,
+    Previous,
+    /// A page number, counting from 1
+    Page(usize),
+    /// A gap standing for the pages left out
+    Ellipsis,
+    Next,
+    Last,
+}
+
+impl PaginationControl {
+    fn label(&self) -> SharedString {
+        match self {
+            Self::First => "«".into(),
+            Self::Previous => "‹".into(),
+            Self::Page(page) => page.to_string().into(),
+            Self::Ellipsis => "…".into(),
+            Self::Next => "›".into(),
+            Self::Last => "»".into(),
+        }
+    }
+}
+
+/// What a control of a [`Pagination`] stands for, handed to [`Pagination::item`] to
+/// style it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PaginationItem {
+    pub control: PaginationControl,
+    /// Whether it is the current page
+    pub selected: bool,
+    /// Whether it leads nowhere, like Previous on the first page
+    pub disabled: bool,
+}
+
+/// Numbered pages between first, previous, next and last buttons.
+///
+/// Long ranges collapse into ellipses like `1 … 4 5 6 … 20`, keeping
+/// [`boundaries`](Self::boundaries) pages at either end and [`siblings`](Self::siblings)
+/// on either side of the current page. Once collapsed, the number of controls stays the
+/// same whatever the page, so they don't shift under the mouse. The page is controlled:
+/// clicking a control calls [`on_page_change`](Self::on_page_change) with the page it
+/// leads to, and the owner passes it back through [`page`](Self::page).
+///
+/// # Examples
+///
+/// ```rust
+/// Pagination::new("results-pages")
+///     .gap_1()
+///     .page(self.page)
+///     .page_count(20)
+///     .item(|item, info| item.px_2().when(info.selected, |this| this.bg(rgb(0x3b82f6))))
+///     .on_page_change(cx.listener(|this, page, _, cx| {
+///         this.page = *page;
+///         cx.notify();
+///     }))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Pagination {
+    base: Stateful<Div>,
+    page: usize,
+    page_count: usize,
+    siblings: usize,
+    boundaries: usize,
+    first_last: bool,
+    item: Option<Rc<dyn Fn(Stateful<Div>, &PaginationItem) -> Stateful<Div>>>,
+    on_page_change: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+}
+
+impl Pagination {
+    /// Creates a pagination control on page 1 of 1.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: h_flex().id(id),
+            page: 1,
+            page_count: 1,
+            siblings: 1,
+            boundaries: 1,
+            first_last: true,
+            item: None,
+            on_page_change: None,
+        }
+    }
+
+    /// Sets the current page, counting from 1.
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets the number of pages.
+    pub fn page_count(mut self, page_count: usize) -> Self {
+        self.page_count = page_count;
+        self
+    }
+
+    /// Sets how many pages are shown on either side of the current one, 1 by default.
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Sets how many pages are shown at either end, 1 by default.
+    pub fn boundaries(mut self, boundaries: usize) -> Self {
+        self.boundaries = boundaries;
+        self
+    }
+
+    /// Shows or hides the first and last buttons, which are shown by default.
+    pub fn first_last(mut self, first_last: bool) -> Self {
+        self.first_last = first_last;
+        self
+    }
+
+    /// Styles each control.
+    ///
+    /// Without it, the current page is bold and controls that lead nowhere are faded.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes a control and what it stands for, and returns
+    ///   the styled control
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let pagination = Pagination::new("pages").item(|item, info| {
+    ///     item.min_w(px(28))
+    ///         .when(info.selected, |this| this.border_1())
+    ///         .when(info.disabled, |this| this.opacity(0.3))
+    /// });
+    /// ```
+    pub fn item(
+        mut self,
+        handler: impl Fn(Stateful<Div>, &PaginationItem) -> Stateful<Div> + 'static,
+    ) -> Self {
+        self.item = Some(Rc::new(handler));
+        self
+    }
+
+    /// Sets the handler called with the page a control leads to when it is clicked.
+    pub fn on_page_change(
+        mut self,
+        on_page_change: impl Fn(&usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_page_change = Some(Rc::new(on_page_change));
+        self
+    }
+
+    fn default_item(item: Stateful<Div>, info: &PaginationItem) -> Stateful<Div> {
+        item.when(info.selected, |this| this.font_weight(FontWeight::BOLD))
+            .when(info.disabled, |this| this.opacity(0.5))
+    }
+
+    /// The page `control` leads to, if it leads anywhere else
+    fn target(&self, control: PaginationControl) -> Option<usize> {
+        let page = self.page.clamp(1, self.page_count.max(1));
+        let target = match control {
+            PaginationControl::First => 1,
+            PaginationControl::Previous => page.saturating_sub(1),
+            PaginationControl::Page(page) => page,
+            PaginationControl::Ellipsis => return None,
+            PaginationControl::Next => page + 1,
+            PaginationControl::Last => self.page_count,
+        };
+        (target != page && (1..=self.page_count).contains(&target)).then_some(target)
+    }
+
+    fn render_control(&self, ix: usize, control: PaginationControl) -> Stateful<Div> {
+        let target = self.target(control);
+        let info = PaginationItem {
+            control,
+            selected: control == PaginationControl::Page(self.page),
+            disabled: target.is_none() && control != PaginationControl::Ellipsis,
+        };
+        let item = div()
+            .id(("pagination-item", ix))
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(control.label())
+            .when_some(
+                target.zip(self.on_page_change.clone()),
+                |this, (target, on_page_change)| {
+                    this.cursor_pointer()
+                        .on_click(move |_, window, cx| on_page_change(&target, window, cx))
+                },
+            );
+        match &self.item {
+            Some(handler) => handler(item, &info),
+            None => Self::default_item(item, &info),
+        }
+    }
+}
+
+impl Styled for Pagination {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Pagination {
+    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let pages = page_items(self.page, self.page_count, self.siblings, self.boundaries)
+            .into_iter()
+            .map(|item| match item {
+                PageItem::Page(page) => PaginationControl::Page(page),
+                PageItem::Ellipsis => PaginationControl::Ellipsis,
+            });
+        let controls = self
+            .first_last
+            .then_some(PaginationControl::First)
+            .into_iter()
+            .chain([PaginationControl::Previous])
+            .chain(pages)
+            .chain([PaginationControl::Next])
+            .chain(self.first_last.then_some(PaginationControl::Last))
+            .enumerate()
+            .map(|(ix, control)| self.render_control(ix, control))
+            .collect::<Vec<_>>();
+
+        self.base.children(controls)
+    }
+}
@@ -0,0 +1,46 @@
+/// An entry of a pagination control between its previous and next buttons
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageItem {
+    /// A page number, counting from 1
+    Page(usize),
+    /// A gap standing for the pages left out
+    Ellipsis,
+}
+
+/// The pages to show for `page` out of `count`, collapsing long ranges like `1 … 4 5 6 … 20`
+///
+/// `boundaries` pages are kept at either end and `siblings` on either side of `page`.
+/// Once there are more pages than fit, the list always has the same length, so the
+/// controls don't shift as the page changes: an ellipsis only stands for two or more
+/// pages, and a single left out page is shown instead.
+pub fn page_items(page: usize, count: usize, siblings: usize, boundaries: usize) -> Vec<PageItem> {
+    // The boundaries, the siblings and the page, with an ellipsis on either side
+    let slots = 2 * boundaries + 2 * siblings + 3;
+    if count <= slots {
+        return (1..=count).map(PageItem::Page).collect();
+    }
+    let page = page.clamp(1, count);
+    // Keep the run of siblings the same length near either end
+    let start = page
+        .saturating_sub(siblings)
+        .min(count - boundaries - 2 * siblings - 1)
+        .max(boundaries + 2);
+    let end = (page + siblings)
+        .max(boundaries + 2 * siblings + 2)
+        .min(count - boundaries - 1);
+
+    let mut items: Vec<_> = (1..=boundaries).map(PageItem::Page).collect();
+    items.push(if start > boundaries + 2 {
+        PageItem::Ellipsis
+    } else {
+        PageItem::Page(boundaries + 1)
+    });
+    items.extend((start..=end).map(PageItem::Page));
+    items.push(if end < count - boundaries - 1 {
+        PageItem::Ellipsis
+    } else {
+        PageItem::Page(count - boundaries)
+    });
+    items.extend((count - boundaries + 1..=count).map(PageItem::Page));
+    items
+}
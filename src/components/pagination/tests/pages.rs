@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod pages {
+    use crate::components::pagination::pages::{PageItem, page_items};
+
+    /// Spell `items` like `1 … 4 5 6 … 20`
+    fn spell(items: Vec<PageItem>) -> String {
+        items
+            .iter()
+            .map(|item| match item {
+                PageItem::Page(page) => page.to_string(),
+                PageItem::Ellipsis => "…".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn short_ranges_show_every_page() {
+        assert_eq!(spell(page_items(1, 0, 1, 1)), "");
+        assert_eq!(spell(page_items(1, 1, 1, 1)), "1");
+        assert_eq!(spell(page_items(4, 7, 1, 1)), "1 2 3 4 5 6 7");
+    }
+
+    #[test]
+    fn collapses_the_end_near_the_start() {
+        assert_eq!(spell(page_items(1, 20, 1, 1)), "1 2 3 4 5 … 20");
+        assert_eq!(spell(page_items(3, 20, 1, 1)), "1 2 3 4 5 … 20");
+        assert_eq!(spell(page_items(4, 20, 1, 1)), "1 2 3 4 5 … 20");
+    }
+
+    #[test]
+    fn collapses_both_sides_in_the_middle() {
+        assert_eq!(spell(page_items(5, 20, 1, 1)), "1 … 4 5 6 … 20");
+        assert_eq!(spell(page_items(10, 20, 1, 1)), "1 … 9 10 11 … 20");
+        assert_eq!(spell(page_items(16, 20, 1, 1)), "1 … 15 16 17 … 20");
+    }
+
+    #[test]
+    fn collapses_the_start_near_the_end() {
+        assert_eq!(spell(page_items(17, 20, 1, 1)), "1 … 16 17 18 19 20");
+        assert_eq!(spell(page_items(20, 20, 1, 1)), "1 … 16 17 18 19 20");
+    }
+
+    #[test]
+    fn the_length_stays_the_same_once_collapsed() {
+        for page in 1..=100 {
+            assert_eq!(page_items(page, 100, 1, 1).len(), 7, "page {page}");
+            assert_eq!(page_items(page, 100, 2, 2).len(), 11, "page {page}");
+        }
+    }
+
+    #[test]
+    fn an_ellipsis_never_stands_for_a_single_page() {
+        // Page 2 would be the only one left out before the siblings of 4
+        assert_eq!(spell(page_items(4, 9, 1, 1)), "1 2 3 4 5 … 9");
+        assert_eq!(spell(page_items(6, 9, 1, 1)), "1 … 5 6 7 8 9");
+    }
+
+    #[test]
+    fn follows_the_sibling_and_boundary_counts() {
+        assert_eq!(spell(page_items(50, 100, 2, 1)), "1 … 48 49 50 51 52 … 100");
+        assert_eq!(spell(page_items(50, 100, 1, 2)), "1 2 … 49 50 51 … 99 100");
+        assert_eq!(spell(page_items(50, 100, 0, 1)), "1 … 50 … 100");
+        assert_eq!(spell(page_items(50, 100, 1, 0)), "… 49 50 51 …");
+        assert_eq!(spell(page_items(1, 100, 1, 0)), "1 2 3 4 …");
+    }
+
+    #[test]
+    fn clamps_the_page() {
+        assert_eq!(spell(page_items(0, 20, 1, 1)), "1 2 3 4 5 … 20");
+        assert_eq!(spell(page_items(99, 20, 1, 1)), "1 … 16 17 18 19 20");
+    }
+}
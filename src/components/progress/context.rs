@@ -1,17 +1,34 @@
 use std::rc::Rc;
 
+/// The width of the stripe that sweeps across an indeterminate track, as a fraction of it
+const STRIPE_WIDTH: f32 = 0.3;
+
+/// Where a [`ProgressFill`](super::ProgressFill) sits on its track at one point of its
+/// animation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillLayout {
+    /// A fill from the start of the track covering `width` of it
+    Determinate { width: f32 },
+    /// A stripe `width` of the track wide, starting `left` into it, which sweeps past either
+    /// end and loops
+    Sweep { left: f32, width: f32 },
+    /// The whole track, pulsing in `opacity` instead of moving for reduced motion
+    Pulse { opacity: f32 },
+}
+
 #[derive(Clone)]
 pub struct ProgressContext {
-    pub(super) value: f32,
+    pub(super) value: Option<f32>,
     pub(super) max_value: f32,
     pub(super) min_value: f32,
     pub(super) value_label: Option<Rc<Box<dyn Fn(&ProgressContext) -> String>>>,
+    pub(super) reduced_motion: bool,
 }
 
 impl ProgressContext {
     pub fn percentage(&self) -> f32 {
         if self.max_value > self.min_value {
-            ((self.value - self.min_value) / (self.max_value - self.min_value)).clamp(0.0, 1.0)
+            ((self.value() - self.min_value) / (self.max_value - self.min_value)).clamp(0.0, 1.0)
         } else {
             0.0
         }
@@ -29,8 +46,14 @@ impl ProgressContext {
         format!("{:.2}%", self.percentage() * 100.0)
     }
 
+    /// The value, or the minimum while indeterminate
     pub fn value(&self) -> f32 {
-        self.value
+        self.value.unwrap_or(self.min_value)
+    }
+
+    /// Whether the progress has no value because the total is unknown
+    pub fn is_indeterminate(&self) -> bool {
+        self.value.is_none()
     }
 
     pub fn min_value(&self) -> f32 {
@@ -41,6 +64,28 @@ impl ProgressContext {
         self.max_value
     }
 
+    /// The layout of the fill `delta` of the way through its animation, from 0 to 1
+    ///
+    /// A determinate fill covers the percentage whatever the `delta`.
+    pub fn fill_layout(&self, delta: f32) -> FillLayout {
+        let delta = delta.clamp(0., 1.);
+        if !self.is_indeterminate() {
+            FillLayout::Determinate {
+                width: self.percentage(),
+            }
+        } else if self.reduced_motion {
+            // Fade between 40% and full opacity and back once per cycle
+            FillLayout::Pulse {
+                opacity: 1. - 0.6 * (2. * delta - 1.).abs(),
+            }
+        } else {
+            FillLayout::Sweep {
+                left: -STRIPE_WIDTH + (1. + STRIPE_WIDTH) * delta,
+                width: STRIPE_WIDTH,
+            }
+        }
+    }
+
     pub fn value_label(&self) -> String {
         if let Some(label_fn) = &self.value_label {
             label_fn(self)
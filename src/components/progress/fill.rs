@@ -1,16 +1,48 @@
+use crate::components::progress::context::{FillLayout, ProgressContext};
 use gpui::*;
+use std::time::Duration;
+
+/// How long an indeterminate fill takes to sweep across the track or pulse once
+const INDETERMINATE_CYCLE: Duration = Duration::from_millis(1500);
 
 #[derive(IntoElement)]
 pub struct ProgressFill {
     base: Div,
+    progress: Option<ProgressContext>,
 }
 
 impl ProgressFill {
     pub fn new() -> Self {
         Self {
             base: div().relative(),
+            progress: None,
         }
     }
+
+    /// Sizes the fill from the progress instead of leaving its width to the caller.
+    ///
+    /// A determinate fill covers the percentage of the track. An indeterminate one loops
+    /// a stripe across it, or pulses in place with reduced motion, so give the track
+    /// `overflow_hidden` to clip the stripe at either end.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context of the enclosing [`Progress`](super::Progress)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// Progress::new().value(None).child_with_context(|context| {
+    ///     ProgressTrack::new()
+    ///         .h(px(4))
+    ///         .overflow_hidden()
+    ///         .child(ProgressFill::new().h_full().bg(rgb(0x3b82f6)).progress(&context))
+    /// })
+    /// ```
+    pub fn progress(mut self, context: &ProgressContext) -> Self {
+        self.progress = Some(context.clone());
+        self
+    }
 }
 
 impl Styled for ProgressFill {
@@ -21,6 +53,31 @@ impl Styled for ProgressFill {
 
 impl RenderOnce for ProgressFill {
     fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let Some(progress) = self.progress else {
+            return self.base.into_any_element();
+        };
+        if !progress.is_indeterminate() {
+            return self
+                .base
+                .w(relative(progress.percentage()))
+                .into_any_element();
+        }
+
         self.base
+            .with_animation(
+                "progress-indeterminate",
+                Animation::new(INDETERMINATE_CYCLE).repeat(),
+                move |this, delta| match progress.fill_layout(delta) {
+                    FillLayout::Determinate { width } => this.w(relative(width)),
+                    FillLayout::Sweep { left, width } => this
+                        .absolute()
+                        .top_0()
+                        .bottom_0()
+                        .left(relative(left))
+                        .w(relative(width)),
+                    FillLayout::Pulse { opacity } => this.w_full().opacity(opacity),
+                },
+            )
+            .into_any_element()
     }
 }
@@ -6,8 +6,12 @@ use std::rc::Rc;
 
 mod context;
 mod fill;
+#[cfg(test)]
+mod tests;
 mod track;
 
+pub use context::{FillLayout, ProgressContext};
+
 pub use fill::*;
 pub use track::*;
 
@@ -31,16 +35,33 @@ impl Progress {
 
             children: SmallVec::new(),
             state: ProgressContext {
-                value: 0.0,
+                value: Some(0.0),
                 min_value: 0.0,
                 max_value: 100.0,
                 value_label: None,
+                reduced_motion: false,
             },
         }
     }
 
-    pub fn value(mut self, value: f32) -> Self {
-        self.state.value = value;
+    /// Sets the value, or `None` when the total is unknown.
+    ///
+    /// While indeterminate, a [`ProgressFill`] given the context with
+    /// [`ProgressFill::progress`] loops a stripe across the track instead of covering
+    /// the value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value between the minimum and maximum, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// Progress::new()
+    ///     .value(self.download.total().map(|_| self.download.received()))
+    /// ```
+    pub fn value(mut self, value: impl Into<Option<f32>>) -> Self {
+        self.state.value = value.into();
         self
     }
 
@@ -54,6 +75,16 @@ impl Progress {
         self
     }
 
+    /// Pulses the whole fill instead of sweeping a stripe while indeterminate.
+    ///
+    /// # Arguments
+    ///
+    /// * `reduced_motion` - Whether to keep the indeterminate fill in place
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.state.reduced_motion = reduced_motion;
+        self
+    }
+
     pub fn value_label<F>(mut self, label_fn: F) -> Self
     where
         F: Fn(&ProgressContext) -> String + 'static,
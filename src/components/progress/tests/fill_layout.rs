@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod fill_layout {
+    use crate::components::progress::context::{FillLayout, ProgressContext};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+    }
+
+    fn opacity(layout: FillLayout) -> f32 {
+        let FillLayout::Pulse { opacity } = layout else {
+            panic!("expected a pulse, got {layout:?}");
+        };
+        opacity
+    }
+
+    fn progress(value: Option<f32>) -> ProgressContext {
+        ProgressContext {
+            value,
+            min_value: 0.,
+            max_value: 200.,
+            value_label: None,
+            reduced_motion: false,
+        }
+    }
+
+    #[test]
+    fn a_value_sizes_the_fill() {
+        let progress = progress(Some(50.));
+        assert!(!progress.is_indeterminate());
+        assert_eq!(
+            progress.fill_layout(0.),
+            FillLayout::Determinate { width: 0.25 }
+        );
+        // The animation doesn't move a determinate fill
+        assert_eq!(progress.fill_layout(0.7), progress.fill_layout(0.));
+    }
+
+    #[test]
+    fn no_value_sweeps_a_stripe_across_the_track() {
+        let progress = progress(None);
+        assert!(progress.is_indeterminate());
+        assert_eq!(progress.value(), 0.);
+        assert_eq!(progress.percentage(), 0.);
+        let FillLayout::Sweep { left, width } = progress.fill_layout(0.) else {
+            panic!("expected a sweeping stripe");
+        };
+        // The stripe starts just off the start of the track and ends just past its end
+        assert_eq!(left, -width);
+        let FillLayout::Sweep { left, .. } = progress.fill_layout(1.) else {
+            panic!("expected a sweeping stripe");
+        };
+        assert_close(left, 1.);
+    }
+
+    #[test]
+    fn switching_between_a_value_and_none_switches_the_layout() {
+        let mut progress = progress(None);
+        assert!(matches!(
+            progress.fill_layout(0.5),
+            FillLayout::Sweep { .. }
+        ));
+        progress.value = Some(200.);
+        assert_eq!(
+            progress.fill_layout(0.5),
+            FillLayout::Determinate { width: 1. }
+        );
+        progress.value = None;
+        assert!(matches!(
+            progress.fill_layout(0.5),
+            FillLayout::Sweep { .. }
+        ));
+    }
+
+    #[test]
+    fn reduced_motion_pulses_in_place() {
+        let mut progress = progress(None);
+        progress.reduced_motion = true;
+        assert_close(opacity(progress.fill_layout(0.)), 0.4);
+        assert_close(opacity(progress.fill_layout(0.5)), 1.);
+        assert_close(opacity(progress.fill_layout(1.)), 0.4);
+
+        // A value still sizes the fill
+        progress.value = Some(100.);
+        assert_eq!(
+            progress.fill_layout(0.),
+            FillLayout::Determinate { width: 0.5 }
+        );
+    }
+}
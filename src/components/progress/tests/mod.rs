@@ -0,0 +1 @@
+mod fill_layout;
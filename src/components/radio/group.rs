@@ -0,0 +1,211 @@
+use crate::{
+    Disableable,
+    components::radio::{Radio, options::RadioOptions},
+    primitives::v_flex,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// A set of radios of which only one can be selected.
+///
+/// The group checks the radio whose value is selected and reports every change through
+/// `on_change`. With a focus handle for each option, the arrow keys move both the focus and
+/// the selection to the next or previous option, skipping disabled ones and wrapping around
+/// at either end.
+///
+/// # Examples
+///
+/// ```rust
+/// RadioGroup::new("size")
+///     .value(self.size.clone())
+///     .radio("small", Radio::new("small"))
+///     .radio("medium", Radio::new("medium"))
+///     .radio("large", Radio::new("large").disabled(true))
+///     .focus_handles(self.focus_handles.clone())
+///     .on_change(cx.listener(|this, size: &SharedString, _window, cx| {
+///         this.size = size.clone();
+///         cx.notify();
+///     }))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct RadioGroup {
+    base: Stateful<Div>,
+    radios: Vec<(SharedString, Radio)>,
+    value: Option<SharedString>,
+    focus_handles: Vec<FocusHandle>,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl RadioGroup {
+    /// Creates a new, empty radio group with the specified ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A unique identifier for the group element
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = RadioGroup::new("size");
+    /// ```
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: v_flex().id(id),
+            radios: Vec::new(),
+            value: None,
+            focus_handles: Vec::new(),
+            on_change: None,
+        }
+    }
+
+    /// Sets the selected value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the selected option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = RadioGroup::new("size").value("medium");
+    /// ```
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Adds an option to the group.
+    ///
+    /// The group sets whether the radio is checked and handles its clicks, so any state or
+    /// `on_click` set on it is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value the option stands for
+    /// * `radio` - The radio to render for the option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = RadioGroup::new("size").radio("small", Radio::new("small"));
+    /// ```
+    pub fn radio(mut self, value: impl Into<SharedString>, radio: Radio) -> Self {
+        self.radios.push((value.into(), radio));
+        self
+    }
+
+    /// Sets the focus handles of the options, in the order they were added.
+    ///
+    /// Each option tracks its handle, and the arrow keys move focus and selection between
+    /// them.
+    ///
+    /// # Arguments
+    ///
+    /// * `handles` - One focus handle for each option
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = RadioGroup::new("size").focus_handles(self.focus_handles.clone());
+    /// ```
+    pub fn focus_handles(mut self, handles: impl IntoIterator<Item = FocusHandle>) -> Self {
+        self.focus_handles = handles.into_iter().collect();
+        self
+    }
+
+    /// Sets a callback function that is called when another option is selected.
+    ///
+    /// The callback receives the value of the newly selected option. Selecting the option
+    /// that is already selected doesn't call it.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_change` - A function that handles the selected value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let group = RadioGroup::new("size")
+    ///     .on_change(|size, _window, _cx| {
+    ///         println!("Size: {}", size);
+    ///     });
+    /// ```
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl Styled for RadioGroup {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for RadioGroup {
+    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let options = Rc::new(RadioOptions::new(
+            self.radios.iter().map(|(value, _)| value.clone()).collect(),
+            self.radios
+                .iter()
+                .map(|(_, radio)| radio.is_disabled())
+                .collect(),
+            self.value,
+        ));
+        let on_change = self.on_change;
+        let focus_handles = Rc::new(self.focus_handles);
+
+        let radios = self
+            .radios
+            .into_iter()
+            .enumerate()
+            .map(|(ix, (value, radio))| {
+                let options = options.clone();
+                radio
+                    .checked(options.is_selected(&value))
+                    .when_some(focus_handles.get(ix), |this, handle| {
+                        this.track_focus(handle)
+                    })
+                    .when_some(on_change.clone(), |this, on_change| {
+                        this.on_click(move |_, window, app| {
+                            if let Some(value) = options.select(ix) {
+                                on_change(&value, window, app);
+                            }
+                        })
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        self.base
+            .on_key_down(move |event, window, app| {
+                let forward = match event.keystroke.key.as_str() {
+                    "down" | "right" => true,
+                    "up" | "left" => false,
+                    _ => return,
+                };
+                let Some(focused) = focus_handles
+                    .iter()
+                    .position(|handle| handle.is_focused(window))
+                else {
+                    return;
+                };
+                app.stop_propagation();
+                let Some(next) = options.step(Some(focused), forward) else {
+                    return;
+                };
+                if let Some(handle) = focus_handles.get(next) {
+                    handle.focus(window);
+                }
+                if let Some(on_change) = on_change.as_ref()
+                    && let Some(value) = options.select(next)
+                {
+                    on_change(&value, window, app);
+                }
+            })
+            .children(radios)
+    }
+}
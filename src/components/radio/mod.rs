@@ -0,0 +1,183 @@
+use crate::{Disableable, primitives::h_flex_center};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+mod group;
+mod options;
+#[cfg(test)]
+mod tests;
+
+pub use group::*;
+
+/// A radio button, one of a set of options of which only one can be selected.
+///
+/// Radios are meant to be used in a [`RadioGroup`], which decides which one is selected
+/// and handles their clicks. Like the checkbox, it shows its indicator only while checked.
+///
+/// # Examples
+///
+/// ```rust
+/// Radio::new("small")
+///     .indicator(div().size(rems(0.5)).rounded_full().bg(rgb(0xffffff)))
+///     .rounded_full()
+///     .size(rems(1.))
+///     .when_checked(|this| this.bg(rgb(0x3b82f6)))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Radio {
+    base: Stateful<Div>,
+    disabled: bool,
+    checked: bool,
+    indicator: AnyElement,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    when_checked_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+}
+
+impl Radio {
+    /// Creates a new radio with the specified ID.
+    ///
+    /// The radio starts in an unchecked, enabled state with a default empty indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A unique identifier for the radio element
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let radio = Radio::new("size-small");
+    /// ```
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: h_flex_center().id(id),
+            disabled: false,
+            checked: false,
+            indicator: div().into_any_element(),
+            on_click: None,
+            when_checked_handler: None,
+        }
+    }
+
+    /// Sets the checked state of the radio.
+    ///
+    /// # Arguments
+    ///
+    /// * `checked` - Whether the radio should be checked (`true`) or unchecked (`false`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let radio = Radio::new("size-small").checked(true);
+    /// ```
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Sets a custom indicator element that appears when the radio is checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `indicator` - An element to display when the radio is checked
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let radio = Radio::new("size-small")
+    ///     .indicator(div().size(rems(0.5)).rounded_full().bg(rgb(0xffffff)));
+    /// ```
+    pub fn indicator(mut self, indicator: impl IntoElement) -> Self {
+        self.indicator = indicator.into_any_element();
+        self
+    }
+
+    /// Conditionally applies styling or modifications when the radio is checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the radio, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let radio = Radio::new("size-small")
+    ///     .checked(true)
+    ///     .when_checked(|this| this.bg(rgb(0x3b82f6)));
+    /// ```
+    pub fn when_checked(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_checked_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets a callback function that is called when the radio is clicked.
+    ///
+    /// This callback will not be triggered if the radio is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_click` - A function that handles the click
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let radio = Radio::new("size-small")
+    ///     .on_click(|_event, _window, _cx| {
+    ///         println!("Small was picked");
+    ///     });
+    /// ```
+    pub fn on_click(
+        mut self,
+        on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+}
+
+impl StatefulInteractiveElement for Radio {}
+
+impl Disableable for Radio {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Styled for Radio {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl InteractiveElement for Radio {
+    fn interactivity(&mut self) -> &mut Interactivity {
+        self.base.interactivity()
+    }
+}
+
+impl RenderOnce for Radio {
+    fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        if self.checked
+            && let Some(handler) = self.when_checked_handler.take()
+        {
+            self = handler(self);
+        }
+
+        self.base
+            .when_some(
+                self.on_click.filter(|_| !self.disabled),
+                |this, on_click| {
+                    this.on_click(move |event, window, cx| {
+                        cx.stop_propagation();
+                        on_click(event, window, cx);
+                    })
+                },
+            )
+            .when(self.checked, |this| this.child(self.indicator))
+    }
+}
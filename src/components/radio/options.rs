@@ -0,0 +1,61 @@
+use gpui::SharedString;
+
+/// The options of a [`RadioGroup`](super::RadioGroup) and the one that is selected.
+///
+/// Arrow key navigation skips disabled options and wraps around at either end.
+pub struct RadioOptions {
+    values: Vec<SharedString>,
+    disabled: Vec<bool>,
+    selected: Option<SharedString>,
+}
+
+impl RadioOptions {
+    pub fn new(
+        values: Vec<SharedString>,
+        disabled: Vec<bool>,
+        selected: Option<SharedString>,
+    ) -> Self {
+        Self {
+            values,
+            disabled,
+            selected,
+        }
+    }
+
+    pub fn is_selected(&self, value: &SharedString) -> bool {
+        self.selected.as_ref() == Some(value)
+    }
+
+    fn is_disabled(&self, ix: usize) -> bool {
+        self.disabled.get(ix).copied().unwrap_or(false)
+    }
+
+    /// Returns the value to emit for selecting the option at `ix`, or `None` when it is
+    /// disabled or already selected.
+    pub fn select(&self, ix: usize) -> Option<SharedString> {
+        let value = self.values.get(ix)?;
+        (!self.is_disabled(ix) && !self.is_selected(value)).then(|| value.clone())
+    }
+
+    /// Returns the next enabled option after `from`, or before it when not `forward`.
+    ///
+    /// Without a starting option, moving forward starts at the first option and moving back
+    /// at the last. Returns `None` when every option is disabled.
+    pub fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let len = self.values.len();
+        let start = match (from, forward) {
+            (Some(ix), _) => ix,
+            (None, true) => len.checked_sub(1)?,
+            (None, false) => 0,
+        };
+        (1..=len)
+            .map(|offset| {
+                if forward {
+                    (start + offset) % len
+                } else {
+                    (start + len - offset % len) % len
+                }
+            })
+            .find(|&ix| !self.is_disabled(ix))
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod options {
+    use crate::components::radio::options::RadioOptions;
+
+    fn options(disabled: &[bool], selected: Option<&'static str>) -> RadioOptions {
+        RadioOptions::new(
+            vec!["a".into(), "b".into(), "c".into(), "d".into()],
+            disabled.to_vec(),
+            selected.map(Into::into),
+        )
+    }
+
+    #[test]
+    fn arrows_cycle_through_the_options() {
+        let options = options(&[], Some("a"));
+        assert_eq!(options.step(Some(0), true), Some(1));
+        assert_eq!(options.step(Some(1), true), Some(2));
+        assert_eq!(options.step(Some(2), false), Some(1));
+    }
+
+    #[test]
+    fn arrows_wrap_around() {
+        let options = options(&[], None);
+        assert_eq!(options.step(Some(3), true), Some(0));
+        assert_eq!(options.step(Some(0), false), Some(3));
+    }
+
+    #[test]
+    fn arrows_skip_disabled_options() {
+        let options = options(&[false, true, true, false], None);
+        assert_eq!(options.step(Some(0), true), Some(3));
+        assert_eq!(options.step(Some(3), false), Some(0));
+        assert_eq!(options.step(Some(3), true), Some(0));
+    }
+
+    #[test]
+    fn stepping_without_a_start_begins_at_either_end() {
+        let options = options(&[true], None);
+        assert_eq!(options.step(None, true), Some(1));
+        assert_eq!(options.step(None, false), Some(3));
+    }
+
+    #[test]
+    fn stepping_stays_on_the_only_enabled_option() {
+        let options = options(&[true, false, true, true], None);
+        assert_eq!(options.step(Some(1), true), Some(1));
+        assert_eq!(options.step(Some(1), false), Some(1));
+    }
+
+    #[test]
+    fn stepping_with_every_option_disabled_goes_nowhere() {
+        let options = options(&[true, true, true, true], None);
+        assert_eq!(options.step(Some(0), true), None);
+        assert_eq!(options.step(None, false), None);
+    }
+
+    #[test]
+    fn selecting_emits_the_value() {
+        let options = options(&[], Some("a"));
+        assert_eq!(options.select(2), Some("c".into()));
+        assert!(options.is_selected(&"a".into()));
+        assert!(!options.is_selected(&"c".into()));
+    }
+
+    #[test]
+    fn selecting_the_selected_option_emits_nothing() {
+        let options = options(&[], Some("b"));
+        assert_eq!(options.select(1), None);
+    }
+
+    #[test]
+    fn selecting_a_disabled_option_emits_nothing() {
+        let options = options(&[false, true], None);
+        assert_eq!(options.select(1), None);
+        assert_eq!(options.select(7), None);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::components::scrollbar::{ScrollbarTheme, state::ScrollbarState};
+use gpui::{prelude::FluentBuilder, *};
+
+/// A thin overlay scrollbar for a scrollable container.
+///
+/// Place it in a `relative` parent next to the container, whose [`ScrollHandle`] the
+/// [`ScrollbarState`] follows, and it lines the parent's right edge, or its bottom edge
+/// when horizontal. The scrollbar shows while scrolling, thickens while hovered and fades
+/// out once idle. Dragging the thumb scrolls with it, and clicking the track beside the
+/// thumb scrolls a page toward the click. Colors and sizes come from [`ScrollbarTheme`].
+///
+/// # Examples
+///
+/// ```rust
+/// let scroll_handle = ScrollHandle::new();
+/// let scrollbar = cx.new(|_| ScrollbarState::new(&scroll_handle));
+///
+/// div()
+///     .relative()
+///     .size_full()
+///     .child(
+///         div()
+///             .id("messages")
+///             .size_full()
+///             .overflow_y_scroll()
+///             .track_scroll(&scroll_handle)
+///             .children(messages),
+///     )
+///     .child(Scrollbar::new(scrollbar))
+/// ```
+#[derive(IntoElement)]
+pub struct Scrollbar {
+    state: Entity<ScrollbarState>,
+}
+
+impl Scrollbar {
+    /// Creates a scrollbar for the given state.
+    pub fn new(state: Entity<ScrollbarState>) -> Self {
+        Self { state }
+    }
+}
+
+impl RenderOnce for Scrollbar {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        self.state.update(app, |state, cx| state.sync(cx));
+        let theme = ScrollbarTheme::global(app);
+        let state = self.state.read(app);
+        let vertical = state.scroll_axis() == Axis::Vertical;
+        let active = state.is_hovered() || state.is_dragging();
+        let thickness = if active {
+            theme.active_thickness
+        } else {
+            theme.thickness
+        };
+        let thumb = state
+            .metrics()
+            .filter(|metrics| metrics.is_scrollable())
+            .map(|metrics| {
+                (
+                    px(metrics.thumb_offset(state.scroll())),
+                    px(metrics.thumb_length()),
+                )
+            });
+        let visible = state.is_visible() && thumb.is_some();
+
+        let tracked = self.state.clone();
+        // Keep the track bounds for mapping mouse positions to scroll offsets
+        let bounds = canvas(
+            move |bounds, _, app| tracked.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        div()
+            .id("scrollbar")
+            .absolute()
+            .map(|this| {
+                if vertical {
+                    this.top_0().bottom_0().right_0().w(thickness)
+                } else {
+                    this.left_0().right_0().bottom_0().h(thickness)
+                }
+            })
+            // Stay hoverable while hidden, so pointing at the edge brings it back
+            .when(!visible, |this| this.opacity(0.))
+            .when(active, |this| this.bg(theme.track))
+            .child(bounds)
+            .when_some(thumb, |this, (offset, length)| {
+                this.child(
+                    div()
+                        .absolute()
+                        .rounded_full()
+                        .bg(if active {
+                            theme.thumb_active
+                        } else {
+                            theme.thumb
+                        })
+                        .map(|this| {
+                            if vertical {
+                                this.left_0().right_0().top(offset).h(length)
+                            } else {
+                                this.top_0().bottom_0().left(offset).w(length)
+                            }
+                        }),
+                )
+            })
+            .on_hover(window.listener_for(&self.state, ScrollbarState::on_hover))
+            .on_mouse_down(
+                MouseButton::Left,
+                window.listener_for(&self.state, ScrollbarState::on_mouse_down),
+            )
+            .on_mouse_up(
+                MouseButton::Left,
+                window.listener_for(&self.state, ScrollbarState::on_mouse_up),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                window.listener_for(&self.state, ScrollbarState::on_mouse_up),
+            )
+            .on_mouse_move(window.listener_for(&self.state, ScrollbarState::on_mouse_move))
+    }
+}
@@ -0,0 +1,73 @@
+/// The shortest the thumb gets, so it stays easy to grab on long content
+pub const MIN_THUMB_LENGTH: f32 = 16.;
+
+/// The sizes along the scroll direction that place the thumb on its track
+///
+/// Scroll positions count up from 0 at the start of the content to [`max_scroll`] at
+/// the end, and are clamped to that range.
+///
+/// [`max_scroll`]: ScrollbarMetrics::max_scroll
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollbarMetrics {
+    /// The visible length of the scrolled container
+    pub viewport: f32,
+    /// The full length of its content
+    pub content: f32,
+    /// The length of the track the thumb moves along
+    pub track: f32,
+}
+
+impl ScrollbarMetrics {
+    /// How far the content scrolls, or 0 when it fits in the viewport
+    pub fn max_scroll(&self) -> f32 {
+        (self.content - self.viewport).max(0.)
+    }
+
+    pub fn is_scrollable(&self) -> bool {
+        self.max_scroll() > 0.
+    }
+
+    pub fn clamp(&self, scroll: f32) -> f32 {
+        scroll.clamp(0., self.max_scroll())
+    }
+
+    /// The length of the thumb, in proportion to how much of the content is visible
+    pub fn thumb_length(&self) -> f32 {
+        if self.content <= self.viewport {
+            return self.track;
+        }
+        let length = self.track * self.viewport / self.content;
+        length.max(MIN_THUMB_LENGTH).min(self.track)
+    }
+
+    /// The distance from the start of the track to the start of the thumb at `scroll`
+    pub fn thumb_offset(&self, scroll: f32) -> f32 {
+        let max_scroll = self.max_scroll();
+        if max_scroll <= 0. {
+            return 0.;
+        }
+        (self.track - self.thumb_length()) * self.clamp(scroll) / max_scroll
+    }
+
+    /// The scroll that puts the start of the thumb `offset` along the track
+    pub fn scroll_at(&self, offset: f32) -> f32 {
+        let room = self.track - self.thumb_length();
+        if room <= 0. {
+            return 0.;
+        }
+        (offset / room).clamp(0., 1.) * self.max_scroll()
+    }
+
+    /// The scroll one page from `scroll` toward `position` on the track, or `scroll`
+    /// when `position` is on the thumb
+    pub fn page_toward(&self, scroll: f32, position: f32) -> f32 {
+        let start = self.thumb_offset(scroll);
+        if position < start {
+            self.clamp(scroll - self.viewport)
+        } else if position > start + self.thumb_length() {
+            self.clamp(scroll + self.viewport)
+        } else {
+            self.clamp(scroll)
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use gpui::*;
+
+mod component;
+mod metrics;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use component::Scrollbar;
+pub use metrics::ScrollbarMetrics;
+pub use state::*;
+
+/// The colors and sizes of every [`Scrollbar`]
+///
+/// Set it as a global to restyle the scrollbars, or leave it unset to use the defaults.
+///
+/// # Examples
+///
+/// ```rust
+/// cx.set_global(ScrollbarTheme {
+///     thumb: rgba(0xffffff40).into(),
+///     ..ScrollbarTheme::default()
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScrollbarTheme {
+    /// The track behind the thumb, shown while hovered
+    pub track: Hsla,
+    pub thumb: Hsla,
+    /// The thumb while hovered or dragged
+    pub thumb_active: Hsla,
+    /// The thickness of an idle scrollbar
+    pub thickness: Pixels,
+    /// The thickness while hovered or dragged
+    pub active_thickness: Pixels,
+}
+
+impl Global for ScrollbarTheme {}
+
+impl ScrollbarTheme {
+    /// Returns the theme set as a global, or the default one.
+    pub fn global(app: &App) -> Self {
+        app.try_global::<Self>().cloned().unwrap_or_default()
+    }
+}
+
+impl Default for ScrollbarTheme {
+    fn default() -> Self {
+        Self {
+            track: rgba(0x0000000d).into(),
+            thumb: rgba(0x00000059).into(),
+            thumb_active: rgba(0x00000080).into(),
+            thickness: px(4.),
+            active_thickness: px(8.),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::components::scrollbar::metrics::ScrollbarMetrics;
+use gpui::*;
+use std::time::Duration;
+
+/// How long an idle scrollbar stays visible after the last scroll
+pub const DEFAULT_HIDE_DELAY: Duration = Duration::from_secs(1);
+
+/// When a scrollbar shows, see [`ScrollbarState::show`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollbarShow {
+    /// Show while scrolling or hovered, and fade out once idle
+    #[default]
+    Auto,
+    /// Show whenever the content overflows, for platforms set to always show scrollbars
+    Always,
+}
+
+/// The state of a [`Scrollbar`](super::Scrollbar) attached to a scrollable container
+///
+/// Reads and moves the offset of the container's [`ScrollHandle`], and tracks hovering,
+/// dragging the thumb and when to hide.
+pub struct ScrollbarState {
+    scroll_handle: ScrollHandle,
+    axis: Axis,
+    show: ScrollbarShow,
+    hide_delay: Duration,
+    visible: bool,
+    hovered: bool,
+    /// Where the thumb was grabbed, from its start, while dragging it
+    drag: Option<f32>,
+    last_scroll: f32,
+    hide_epoch: usize,
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+}
+
+impl ScrollbarState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a vertical scrollbar for the container tracking `scroll_handle`
+    pub fn new(scroll_handle: &ScrollHandle) -> Self {
+        Self {
+            scroll_handle: scroll_handle.clone(),
+            axis: Axis::Vertical,
+            show: ScrollbarShow::default(),
+            hide_delay: DEFAULT_HIDE_DELAY,
+            visible: false,
+            hovered: false,
+            drag: None,
+            last_scroll: 0.,
+            hide_epoch: 0,
+            last_bounds: None,
+        }
+    }
+
+    /// Set the direction the scrollbar scrolls in
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set when the scrollbar shows
+    pub fn show(mut self, show: ScrollbarShow) -> Self {
+        self.show = show;
+        self
+    }
+
+    /// Set how long the scrollbar stays visible once idle
+    pub fn hide_delay(mut self, hide_delay: Duration) -> Self {
+        self.hide_delay = hide_delay;
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn scroll_axis(&self) -> Axis {
+        self.axis
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Whether the scrollbar is shown, though it still has no thumb when nothing overflows
+    pub fn is_visible(&self) -> bool {
+        self.show == ScrollbarShow::Always || self.visible || self.hovered || self.is_dragging()
+    }
+
+    /// How far the container is scrolled along the axis
+    pub fn scroll(&self) -> f32 {
+        -f32::from(self.scroll_handle.offset().along(self.axis))
+    }
+
+    /// Scroll the container to `scroll` along the axis, keeping the other axis
+    pub fn set_scroll(&mut self, scroll: f32, cx: &mut Context<Self>) {
+        let scroll = self
+            .metrics()
+            .map_or(scroll, |metrics| metrics.clamp(scroll));
+        let mut offset = self.scroll_handle.offset();
+        match self.axis {
+            Axis::Vertical => offset.y = px(-scroll),
+            Axis::Horizontal => offset.x = px(-scroll),
+        }
+        self.scroll_handle.set_offset(offset);
+        cx.notify();
+    }
+
+    /// The sizes of the container and the track laid out last
+    pub fn metrics(&self) -> Option<ScrollbarMetrics> {
+        let track = self.last_bounds?.size.along(self.axis);
+        let viewport = self.scroll_handle.bounds().size.along(self.axis);
+        Some(ScrollbarMetrics {
+            viewport: viewport.into(),
+            content: (viewport + self.scroll_handle.max_offset().along(self.axis)).into(),
+            track: track.into(),
+        })
+    }
+
+    // ============================================================================
+    // Visibility
+    // ============================================================================
+
+    /// Show the scrollbar when the container has scrolled since the last render
+    pub(super) fn sync(&mut self, cx: &mut Context<Self>) {
+        let scroll = self.scroll();
+        if scroll != self.last_scroll {
+            self.last_scroll = scroll;
+            self.reveal(cx);
+        }
+    }
+
+    /// Show the scrollbar and hide it again after the delay, unless it is in use by then
+    fn reveal(&mut self, cx: &mut Context<Self>) {
+        self.visible = true;
+        self.hide_epoch += 1;
+        let epoch = self.hide_epoch;
+        let hide_delay = self.hide_delay;
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            Timer::after(hide_delay).await;
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| this.hide(epoch, cx)).ok();
+            }
+        })
+        .detach();
+    }
+
+    fn hide(&mut self, epoch: usize, cx: &mut Context<Self>) {
+        if epoch != self.hide_epoch || self.hovered || self.is_dragging() {
+            return;
+        }
+        self.visible = false;
+        cx.notify();
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// The distance of `position` from the start of the track laid out last
+    fn track_position(&self, position: Point<Pixels>) -> Option<f32> {
+        let bounds = self.last_bounds?;
+        Some((position.along(self.axis) - bounds.origin.along(self.axis)).into())
+    }
+
+    pub(super) fn on_hover(&mut self, hovered: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.hovered = *hovered;
+        if self.hovered {
+            cx.notify();
+        } else {
+            self.reveal(cx);
+        }
+    }
+
+    /// Grab the thumb, or page toward the click when it lands beside it
+    pub(super) fn on_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (Some(metrics), Some(position)) = (self.metrics(), self.track_position(event.position))
+        else {
+            return;
+        };
+        cx.stop_propagation();
+        let scroll = self.scroll();
+        let start = metrics.thumb_offset(scroll);
+        if (start..=start + metrics.thumb_length()).contains(&position) {
+            self.drag = Some(position - start);
+            cx.notify();
+        } else {
+            self.set_scroll(metrics.page_toward(scroll, position), cx);
+        }
+    }
+
+    pub(super) fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (Some(grab), Some(metrics), Some(position)) = (
+            self.drag,
+            self.metrics(),
+            self.track_position(event.position),
+        ) else {
+            return;
+        };
+        self.set_scroll(metrics.scroll_at(position - grab), cx);
+    }
+
+    pub(super) fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if self.drag.take().is_some() {
+            self.reveal(cx);
+        }
+    }
+}
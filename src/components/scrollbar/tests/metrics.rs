@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod metrics {
+    use crate::components::scrollbar::metrics::{MIN_THUMB_LENGTH, ScrollbarMetrics};
+
+    fn metrics(viewport: f32, content: f32, track: f32) -> ScrollbarMetrics {
+        ScrollbarMetrics {
+            viewport,
+            content,
+            track,
+        }
+    }
+
+    #[test]
+    fn thumb_length_follows_the_visible_fraction() {
+        assert_eq!(metrics(100., 400., 100.).thumb_length(), 25.);
+        assert_eq!(metrics(200., 400., 100.).thumb_length(), 50.);
+    }
+
+    #[test]
+    fn content_that_fits_fills_the_track() {
+        let fits = metrics(200., 150., 100.);
+        assert!(!fits.is_scrollable());
+        assert_eq!(fits.thumb_length(), 100.);
+        assert_eq!(fits.thumb_offset(30.), 0.);
+        assert_eq!(fits.scroll_at(30.), 0.);
+    }
+
+    #[test]
+    fn thumb_length_has_a_minimum() {
+        assert_eq!(
+            metrics(100., 100_000., 100.).thumb_length(),
+            MIN_THUMB_LENGTH
+        );
+        // The minimum never exceeds the track
+        assert_eq!(metrics(10., 1000., 8.).thumb_length(), 8.);
+    }
+
+    #[test]
+    fn thumb_offset_follows_the_scroll() {
+        let metrics = metrics(100., 400., 100.);
+        assert_eq!(metrics.thumb_offset(0.), 0.);
+        assert_eq!(metrics.thumb_offset(150.), 37.5);
+        assert_eq!(metrics.thumb_offset(300.), 75.);
+        assert_eq!(metrics.thumb_offset(500.), 75.);
+    }
+
+    #[test]
+    fn dragging_the_thumb_maps_back_to_the_scroll() {
+        let metrics = metrics(100., 400., 100.);
+        assert_eq!(metrics.scroll_at(37.5), 150.);
+        assert_eq!(metrics.scroll_at(-10.), 0.);
+        assert_eq!(metrics.scroll_at(90.), 300.);
+        assert_eq!(metrics.scroll_at(metrics.thumb_offset(120.)), 120.);
+    }
+
+    #[test]
+    fn clicking_the_track_pages_toward_the_click() {
+        let metrics = metrics(100., 400., 100.);
+        // The thumb spans 37.5..62.5 at a scroll of 150
+        assert_eq!(metrics.page_toward(150., 10.), 50.);
+        assert_eq!(metrics.page_toward(150., 90.), 250.);
+        assert_eq!(metrics.page_toward(150., 50.), 150.);
+        assert_eq!(metrics.page_toward(250., 95.), 300.);
+        assert_eq!(metrics.page_toward(50., 0.), 0.);
+    }
+}
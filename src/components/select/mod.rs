@@ -0,0 +1,370 @@
+use crate::{
+    components::{Chip, ChipVariant, input::TextInput},
+    primitives::{h_flex, v_flex},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{ops::Range, rc::Rc};
+
+mod options;
+mod state;
+#[cfg(test)]
+mod tests;
+mod viewport;
+
+pub use options::SelectOption;
+pub use state::*;
+
+/// What a row of the open list stands for, handed to [`Select::option`] to style it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectRow {
+    pub value: SharedString,
+    pub label: SharedString,
+    pub selected: bool,
+    /// Whether the keyboard highlight is on the row, so Enter picks it
+    pub highlighted: bool,
+    pub disabled: bool,
+}
+
+/// A button showing the selected option, which opens the list of options below it.
+///
+/// Clicking an option, or pressing Enter on the highlighted one, selects it and closes
+/// the list. On the trigger the arrow keys, Enter and Space open the list; in the open
+/// list the arrow keys, Home and End move the highlight, skipping disabled options, and
+/// Escape closes it. With a [`searchable`](SelectState::searchable) state the list starts
+/// with a search box, and typing in it lists only the options whose label contains the
+/// text, regardless of case, with the matching part in bold. The arrow keys and Enter
+/// then work on the options listed, and a "No results" row stands in for them when
+/// there are none.
+///
+/// Only the rows in view are laid out, so a list of thousands of options stays quick to
+/// open and scroll, and moving the highlight with the keyboard scrolls it into view.
+///
+/// A [`multiple`](SelectState::multiple) state shows each selected option as a chip in
+/// the trigger, whose remove button deselects it, and marks the selected rows of the
+/// list with a checkmark. Picking a row then toggles it, and the list stays open.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     SelectState::new(window, cx)
+///         .options(countries.iter().map(|country| SelectOption::new(country.code, country.name)))
+///         .placeholder("Country")
+///         .searchable(true)
+///         .visible_rows(10)
+///         .on_change(|codes, _window, _cx| println!("Country: {:?}", codes.first()))
+/// });
+///
+/// Select::new(state)
+///     .w(px(240))
+///     .trigger(|trigger| trigger.px_2().border_1().rounded_md())
+///     .menu(|menu| menu.bg(rgb(0xffffff)).border_1().rounded_md())
+///     .option(|row, option| row.px_2().when(option.highlighted, |row| row.bg(rgb(0xe5e7eb))))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Select {
+    base: Div,
+    state: Entity<SelectState>,
+    trigger: Option<Box<dyn FnOnce(Stateful<Div>) -> Stateful<Div>>>,
+    search: Option<Box<dyn FnOnce(TextInput) -> TextInput>>,
+    menu: Div,
+    option: Option<Rc<dyn Fn(Stateful<Div>, &SelectRow) -> Stateful<Div>>>,
+    chip: Option<Rc<dyn Fn(Chip) -> Chip>>,
+    empty: Option<AnyElement>,
+}
+
+impl Select {
+    /// Creates a select for the given state.
+    pub fn new(state: Entity<SelectState>) -> Self {
+        Self {
+            base: div(),
+            state,
+            trigger: None,
+            search: None,
+            menu: v_flex(),
+            option: None,
+            chip: None,
+            empty: None,
+        }
+    }
+
+    /// Customizes the button showing the selected option, which opens and closes the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the trigger, applying modifications
+    pub fn trigger(
+        mut self,
+        handler: impl FnOnce(Stateful<Div>) -> Stateful<Div> + 'static,
+    ) -> Self {
+        self.trigger = Some(Box::new(handler));
+        self
+    }
+
+    /// Customizes the search box of a [`searchable`](SelectState::searchable) list.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the input, applying modifications
+    pub fn search(mut self, handler: impl FnOnce(TextInput) -> TextInput + 'static) -> Self {
+        self.search = Some(Box::new(handler));
+        self
+    }
+
+    /// Customizes the list below the trigger.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the list, applying modifications
+    pub fn menu(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.menu = handler(self.menu);
+        self
+    }
+
+    /// Styles each row of the open list.
+    ///
+    /// Without it, the highlighted row is shaded, the selected one is in the accent color
+    /// and disabled ones are faded.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes a row and what it stands for, and returns the
+    ///   styled row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let select = Select::new(state).option(|row, option| {
+    ///     row.h(px(28))
+    ///         .when(option.highlighted, |row| row.bg(rgb(0xe5e7eb)))
+    ///         .when(option.disabled, |row| row.opacity(0.3))
+    /// });
+    /// ```
+    pub fn option(
+        mut self,
+        handler: impl Fn(Stateful<Div>, &SelectRow) -> Stateful<Div> + 'static,
+    ) -> Self {
+        self.option = Some(Rc::new(handler));
+        self
+    }
+
+    /// Styles the chip of each selected option of a [`multiple`](SelectState::multiple)
+    /// select.
+    ///
+    /// Without it, the chips are [`Neutral`](ChipVariant::Neutral).
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns a chip, applying modifications
+    pub fn chip(mut self, handler: impl Fn(Chip) -> Chip + 'static) -> Self {
+        self.chip = Some(Rc::new(handler));
+        self
+    }
+
+    /// Replaces the "No results" row shown when no option matches the search.
+    pub fn empty(mut self, empty: impl IntoElement) -> Self {
+        self.empty = Some(empty.into_any_element());
+        self
+    }
+
+    fn default_option(row: Stateful<Div>, option: &SelectRow) -> Stateful<Div> {
+        row.when(option.highlighted, |row| row.bg(rgb(0xe5e7eb)))
+            .when(option.selected, |row| row.text_color(rgb(0x1d4ed8)))
+            .when(option.disabled, |row| row.opacity(0.5))
+    }
+
+    /// The selected option, a chip for each selected option in multiple mode, or the
+    /// placeholder
+    fn render_value(&self, state: &SelectState) -> Div {
+        let mut selected = state.selected_options().peekable();
+        if selected.peek().is_none() {
+            return div().opacity(0.5).child(state.placeholder.clone());
+        }
+        if !state.is_multiple() {
+            return div().children(selected.next().map(|option| option.label.clone()));
+        }
+        let chips = selected.enumerate().map(|(ix, option)| {
+            let value = option.value.clone();
+            let remove_selector = format!("select-chip-remove-{value}");
+            let tracked = self.state.clone();
+            let chip = Chip::new(("select-chip", ix))
+                .debug_selector({
+                    let value = value.clone();
+                    move || format!("select-chip-{value}")
+                })
+                .child(option.label.clone())
+                .remove_button(div().debug_selector(move || remove_selector).child("×"))
+                .on_remove(move |window, app| {
+                    tracked.update(app, |state, cx| state.deselect(&value, window, cx))
+                });
+            match &self.chip {
+                Some(chip_handler) => chip_handler(chip),
+                None => chip.variant(ChipVariant::Neutral).px_1().rounded_sm(),
+            }
+        });
+        h_flex().flex_wrap().gap_1().children(chips)
+    }
+
+    /// The rows in `range` of those listed
+    fn render_rows(
+        &self,
+        state: &SelectState,
+        range: Range<usize>,
+        window: &mut Window,
+    ) -> Vec<Stateful<Div>> {
+        let list = &state.list;
+        let selected = state.selected();
+        let bold = HighlightStyle {
+            font_weight: Some(FontWeight::BOLD),
+            ..Default::default()
+        };
+        let row_height = state.row_height;
+        list.rows()[range.clone()]
+            .iter()
+            .zip(range)
+            .map(|(row, ix)| {
+                let option = &list.options()[row.option];
+                let info = SelectRow {
+                    value: option.value.clone(),
+                    label: option.label.clone(),
+                    selected: selected.contains(&option.value),
+                    highlighted: list.highlighted() == Some(ix),
+                    disabled: option.disabled,
+                };
+                let option_ix = row.option;
+                let label = StyledText::new(option.label.clone()).with_highlights(
+                    (!row.matched.is_empty()).then(|| (row.matched.clone(), bold)),
+                );
+                let on_hover = window
+                    .listener_for(&self.state, move |state, _: &MouseMoveEvent, _, cx| {
+                        state.highlight(ix, cx)
+                    });
+                let on_click = window
+                    .listener_for(&self.state, move |state, _: &ClickEvent, window, cx| {
+                        state.select(option_ix, window, cx)
+                    });
+                let cell = div()
+                    .id(("select-option", option_ix))
+                    .debug_selector(move || format!("select-option-{option_ix}"))
+                    .flex_none()
+                    .h(row_height)
+                    .flex()
+                    .items_center()
+                    .when(state.is_multiple(), |this| {
+                        this.child(
+                            div()
+                                .flex_none()
+                                .w(px(16.))
+                                .children(info.selected.then_some("✓")),
+                        )
+                    })
+                    .child(label)
+                    .on_mouse_move(on_hover)
+                    .when(!info.disabled, |this| {
+                        this.cursor_pointer().on_click(on_click)
+                    });
+                match &self.option {
+                    Some(option) => option(cell, &info),
+                    None => Self::default_option(cell, &info),
+                }
+            })
+            .collect()
+    }
+}
+
+impl Styled for Select {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Select {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = self.state.read(app);
+        let label = self.render_value(state);
+        let mut trigger = div()
+            .id("select-trigger")
+            .track_focus(state.focus_handle())
+            .flex()
+            .items_center()
+            .justify_between()
+            .cursor_pointer()
+            .child(label)
+            .child("▾")
+            .on_click(window.listener_for(&self.state, SelectState::toggle_open))
+            .on_key_down(window.listener_for(&self.state, SelectState::on_trigger_key_down));
+        if let Some(handler) = self.trigger.take() {
+            trigger = handler(trigger);
+        }
+
+        let list = state.is_open().then(|| {
+            let search = state.is_searchable().then(|| {
+                let search = TextInput::new(state.query().clone());
+                match self.search.take() {
+                    Some(handler) => handler(search),
+                    None => search,
+                }
+            });
+            let viewport = state.viewport();
+            let range = viewport.visible_range(state.scroll());
+            let row_height = f32::from(state.row_height);
+            // Stand in for the rows out of view with space, so the list scrolls as if
+            // every row was laid out
+            let rows = v_flex()
+                .id("select-rows")
+                .flex_none()
+                .h(px(viewport.height))
+                .overflow_y_scroll()
+                .track_scroll(state.scroll_handle())
+                .child(div().flex_none().h(px(range.start as f32 * row_height)))
+                .children(self.render_rows(state, range.clone(), window))
+                .child(
+                    div()
+                        .flex_none()
+                        .h(px((viewport.rows - range.end) as f32 * row_height)),
+                );
+            let empty = (viewport.rows == 0).then(|| {
+                div().debug_selector(|| "select-empty".into()).child(
+                    self.empty
+                        .take()
+                        .unwrap_or_else(|| "No results".into_any_element()),
+                )
+            });
+            (search, rows, empty)
+        });
+        let list_focus_handle = state.list_focus_handle().clone();
+
+        let tracked = self.state.clone();
+        // Keep the trigger bounds, so pressing the trigger doesn't count as a press outside
+        let bounds = canvas(
+            move |bounds, _, app| tracked.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        self.base.relative().child(bounds).child(trigger).when_some(
+            list,
+            |this, (search, rows, empty)| {
+                this.child(deferred(
+                    self.menu
+                        .absolute()
+                        .top_full()
+                        .left_0()
+                        .min_w_full()
+                        .track_focus(&list_focus_handle)
+                        .on_key_down(
+                            window.listener_for(&self.state, SelectState::on_list_key_down),
+                        )
+                        .on_mouse_down_out(
+                            window.listener_for(&self.state, SelectState::on_mouse_down_out),
+                        )
+                        .children(search)
+                        .child(rows)
+                        .children(empty),
+                ))
+            },
+        )
+    }
+}
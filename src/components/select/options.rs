@@ -0,0 +1,224 @@
+use gpui::SharedString;
+use std::ops::Range;
+
+/// An option of a [`Select`](super::Select)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectOption {
+    /// What the select reports when the option is picked
+    pub value: SharedString,
+    /// What the select shows for the option, and what the search box matches
+    pub label: SharedString,
+    pub disabled: bool,
+}
+
+impl SelectOption {
+    /// Create an enabled option showing `label` for `value`
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Set whether the option can't be picked, though it is still listed
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// An option listed for the query, as a row of the open list
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionRow {
+    /// The index of the option
+    pub option: usize,
+    /// The bytes of the label the query matches, empty without a query
+    pub matched: Range<usize>,
+}
+
+/// The options of a select narrowed down to those whose label contains the query, and
+/// the row the keyboard highlights.
+///
+/// The query matches a part of the label regardless of case. Moving the highlight skips
+/// disabled options and wraps around at either end of the rows.
+pub struct OptionList {
+    options: Vec<SelectOption>,
+    query: String,
+    rows: Vec<OptionRow>,
+    highlighted: Option<usize>,
+}
+
+impl OptionList {
+    pub fn new(options: Vec<SelectOption>) -> Self {
+        let mut list = Self {
+            options,
+            query: String::new(),
+            rows: Vec::new(),
+            highlighted: None,
+        };
+        list.filter();
+        list
+    }
+
+    pub fn options(&self) -> &[SelectOption] {
+        &self.options
+    }
+
+    /// Replace the options, keeping the query
+    pub fn set_options(&mut self, options: Vec<SelectOption>) {
+        self.options = options;
+        self.filter();
+    }
+
+    /// Returns the selected values after toggling `value`, in the order of the options
+    pub fn toggled(&self, selected: &[SharedString], value: &SharedString) -> Vec<SharedString> {
+        self.options
+            .iter()
+            .filter(|option| selected.contains(&option.value) != (&option.value == value))
+            .map(|option| option.value.clone())
+            .collect()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// List the options whose label contains `query`, highlighting the first enabled one
+    pub fn set_query(&mut self, query: &str) {
+        if self.query != query {
+            self.query = query.to_string();
+            self.filter();
+        }
+    }
+
+    fn filter(&mut self) {
+        self.rows = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(option, candidate)| {
+                find_match(&candidate.label, &self.query)
+                    .map(|matched| OptionRow { option, matched })
+            })
+            .collect();
+        self.highlighted = self.first_enabled();
+    }
+
+    /// The rows listed for the query, in the order of the options
+    pub fn rows(&self) -> &[OptionRow] {
+        &self.rows
+    }
+
+    /// The option listed at `row`
+    pub fn option_at(&self, row: usize) -> Option<&SelectOption> {
+        self.rows.get(row).map(|row| &self.options[row.option])
+    }
+
+    /// The highlighted row
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// The index of the option at the highlighted row
+    pub fn highlighted_option(&self) -> Option<usize> {
+        self.highlighted.map(|row| self.rows[row].option)
+    }
+
+    fn is_enabled(&self, row: usize) -> bool {
+        self.option_at(row).is_some_and(|option| !option.disabled)
+    }
+
+    /// Highlight `row`, unless it is out of the rows or disabled
+    pub fn highlight(&mut self, row: usize) {
+        if self.is_enabled(row) {
+            self.highlighted = Some(row);
+        }
+    }
+
+    /// Highlight the row of the option with `value`, or the first enabled row when it isn't
+    /// listed or can't be picked
+    pub fn highlight_value(&mut self, value: Option<&SharedString>) {
+        let row = value.and_then(|value| {
+            self.rows
+                .iter()
+                .position(|row| &self.options[row.option].value == value)
+        });
+        self.highlighted = row
+            .filter(|&row| self.is_enabled(row))
+            .or_else(|| self.first_enabled());
+    }
+
+    /// Move the highlight to the next enabled row, or the previous one when not
+    /// `forward`, wrapping around at either end
+    ///
+    /// Without a highlight, moving forward starts at the first row and moving back at the
+    /// last.
+    pub fn step(&mut self, forward: bool) {
+        let len = self.rows.len();
+        let Some(last) = len.checked_sub(1) else {
+            return;
+        };
+        let start = match (self.highlighted, forward) {
+            (Some(row), _) => row,
+            (None, true) => last,
+            (None, false) => 0,
+        };
+        let next = (1..=len)
+            .map(|offset| {
+                if forward {
+                    (start + offset) % len
+                } else {
+                    (start + len - offset % len) % len
+                }
+            })
+            .find(|&row| self.is_enabled(row));
+        if next.is_some() {
+            self.highlighted = next;
+        }
+    }
+
+    /// Highlight the first enabled row
+    pub fn highlight_first(&mut self) {
+        if let Some(row) = self.first_enabled() {
+            self.highlighted = Some(row);
+        }
+    }
+
+    /// Highlight the last enabled row
+    pub fn highlight_last(&mut self) {
+        if let Some(row) = (0..self.rows.len()).rev().find(|&row| self.is_enabled(row)) {
+            self.highlighted = Some(row);
+        }
+    }
+
+    fn first_enabled(&self) -> Option<usize> {
+        (0..self.rows.len()).find(|&row| self.is_enabled(row))
+    }
+}
+
+/// The bytes of the first part of `label` that reads as `query` regardless of case, or
+/// an empty range at the start for an empty query
+pub fn find_match(label: &str, query: &str) -> Option<Range<usize>> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some(0..0);
+    }
+    label.char_indices().find_map(|(start, _)| {
+        let mut remaining = query.as_slice();
+        for (offset, ch) in label[start..].char_indices() {
+            for lower in ch.to_lowercase() {
+                match remaining.split_first() {
+                    Some((&expected, rest)) if expected == lower => remaining = rest,
+                    Some(_) => return None,
+                    // The query ends within the lowercase form of the character
+                    None => break,
+                }
+            }
+            if remaining.is_empty() {
+                return Some(start..start + offset + ch.len_utf8());
+            }
+        }
+        None
+    })
+}
@@ -0,0 +1,392 @@
+use crate::components::{
+    input::InputState,
+    select::{
+        options::{OptionList, SelectOption},
+        viewport::RowViewport,
+    },
+};
+use gpui::*;
+
+/// The state of a [`Select`](super::Select)
+///
+/// Owns the options, the selected ones and the search box of the open list. Typing in the
+/// search box narrows the list down to the options whose label contains the text, and
+/// the arrow keys and Enter work on the narrowed list. In [`multiple`](Self::multiple)
+/// mode picking an option adds it to the selection, or removes it when already selected,
+/// and the list stays open.
+///
+/// The list lays out only the rows in view, all of them [`row_height`](Self::row_height)
+/// tall, and scrolls to keep the highlighted row in view as the keyboard moves it.
+#[allow(clippy::type_complexity)]
+pub struct SelectState {
+    pub(super) list: OptionList,
+    selected: Vec<SharedString>,
+    multiple: bool,
+    pub(super) placeholder: SharedString,
+    searchable: bool,
+    query: Entity<InputState>,
+    focus_handle: FocusHandle,
+    list_focus_handle: FocusHandle,
+    open: bool,
+    scroll_handle: ScrollHandle,
+    pub(super) row_height: Pixels,
+    visible_rows: usize,
+    /// The bounds of the trigger, where a press doesn't close the list
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    on_change: Option<Box<dyn Fn(&[SharedString], &mut Window, &mut Context<Self>)>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl SelectState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new SelectState without options
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let query = cx.new(|cx| InputState::new(window, cx).placeholder("Search"));
+        let _subscriptions =
+            vec![cx.observe_in(&query, window, |this, _, _, cx| this.sync_query(cx))];
+
+        Self {
+            list: OptionList::new(Vec::new()),
+            selected: Vec::new(),
+            multiple: false,
+            placeholder: SharedString::default(),
+            searchable: false,
+            query,
+            focus_handle: cx.focus_handle().tab_stop(true),
+            list_focus_handle: cx.focus_handle(),
+            open: false,
+            scroll_handle: ScrollHandle::new(),
+            row_height: px(28.),
+            visible_rows: 8,
+            last_bounds: None,
+            on_change: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set the options to pick from
+    pub fn options(mut self, options: impl IntoIterator<Item = SelectOption>) -> Self {
+        self.list.set_options(options.into_iter().collect());
+        self
+    }
+
+    /// Set the initially selected option by its value
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.selected = vec![value.into()];
+        self
+    }
+
+    /// Set the initially selected options of a [`multiple`](Self::multiple) select by
+    /// their values
+    pub fn values(mut self, values: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.selected = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Let more than one option be selected, showing each as a removable chip in the
+    /// trigger, and keep the list open after picking
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the text shown while no option is selected
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Show a search box at the top of the open list that narrows it down as the user
+    /// types
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Set the height of every row of the list, 28px by default
+    ///
+    /// The list needs it to lay out only the rows in view, so rows styled with
+    /// [`Select::option`](super::Select::option) keep this height.
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into();
+        self
+    }
+
+    /// Set how many rows the list shows before it scrolls, 8 by default
+    pub fn visible_rows(mut self, visible_rows: usize) -> Self {
+        self.visible_rows = visible_rows.max(1);
+        self
+    }
+
+    /// Call `on_change` with the values of the selected options whenever they change
+    ///
+    /// Outside [`multiple`](Self::multiple) mode there is at most one value.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn current_options(&self) -> &[SelectOption] {
+        self.list.options()
+    }
+
+    /// Replace the options, keeping the selected value
+    pub fn set_options(&mut self, options: Vec<SelectOption>, cx: &mut Context<Self>) {
+        self.list.set_options(options);
+        self.list.highlight_value(self.selected.first());
+        cx.notify();
+    }
+
+    /// The values of the selected options
+    pub fn selected(&self) -> &[SharedString] {
+        &self.selected
+    }
+
+    /// The selected options in the order of the options, leaving out values that aren't
+    /// among them
+    pub fn selected_options(&self) -> impl Iterator<Item = &SelectOption> {
+        self.list
+            .options()
+            .iter()
+            .filter(|option| self.selected.contains(&option.value))
+    }
+
+    pub fn is_multiple(&self) -> bool {
+        self.multiple
+    }
+
+    pub fn is_searchable(&self) -> bool {
+        self.searchable
+    }
+
+    /// The search box of the open list, to render with a
+    /// [`TextInput`](crate::components::input::TextInput)
+    pub fn query(&self) -> &Entity<InputState> {
+        &self.query
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show or hide the list, focusing it when shown
+    ///
+    /// The list opens with an empty search box and the selected option highlighted, and
+    /// focus goes back to the trigger when it closes.
+    pub fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if self.open == open {
+            return;
+        }
+        self.open = open;
+        if open {
+            self.query.update(cx, |query, cx| query.set_value("", cx));
+            self.list.set_query("");
+            self.list.highlight_value(self.selected.first());
+            self.scroll_to(0.);
+            self.reveal_highlighted();
+            if self.searchable {
+                self.query.focus_handle(cx).focus(window);
+            } else {
+                self.list_focus_handle.focus(window);
+            }
+        } else if self.list_focus_handle.contains_focused(window, cx) {
+            self.focus_handle.focus(window);
+        }
+        cx.notify();
+    }
+
+    pub(super) fn toggle_open(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.set_open(!self.open, window, cx);
+    }
+
+    /// Close the list on a press outside of it and the trigger
+    pub(super) fn on_mouse_down_out(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self
+            .last_bounds
+            .is_none_or(|bounds| !bounds.contains(&event.position))
+        {
+            self.set_open(false, window, cx);
+        }
+    }
+
+    pub(super) fn scroll_handle(&self) -> &ScrollHandle {
+        &self.scroll_handle
+    }
+
+    /// The rows of the open list against its viewport, which is as tall as the visible
+    /// rows
+    pub(super) fn viewport(&self) -> RowViewport {
+        let rows = self.list.rows().len();
+        RowViewport {
+            row_height: self.row_height.into(),
+            height: rows.min(self.visible_rows) as f32 * f32::from(self.row_height),
+            rows,
+        }
+    }
+
+    /// How far the list is scrolled
+    pub(super) fn scroll(&self) -> f32 {
+        -f32::from(self.scroll_handle.offset().y)
+    }
+
+    fn scroll_to(&self, scroll: f32) {
+        self.scroll_handle.set_offset(point(px(0.), px(-scroll)));
+    }
+
+    /// Scroll the list as little as needed to show the highlighted row
+    fn reveal_highlighted(&self) {
+        if let Some(row) = self.list.highlighted() {
+            self.scroll_to(self.viewport().reveal(self.scroll(), row));
+        }
+    }
+
+    pub(super) fn focus_handle(&self) -> &FocusHandle {
+        &self.focus_handle
+    }
+
+    pub(super) fn list_focus_handle(&self) -> &FocusHandle {
+        &self.list_focus_handle
+    }
+
+    /// Pick the option at `ix` of the options, unless it is disabled
+    ///
+    /// The option replaces the selected one and the list closes, or in
+    /// [`multiple`](Self::multiple) mode it is added to the selection, or removed when
+    /// already selected, and the list stays open.
+    pub fn select(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(option) = self
+            .list
+            .options()
+            .get(ix)
+            .filter(|option| !option.disabled)
+        else {
+            return;
+        };
+        let selected = if self.multiple {
+            self.list.toggled(&self.selected, &option.value)
+        } else {
+            vec![option.value.clone()]
+        };
+        self.set_selected(selected, window, cx);
+        if !self.multiple {
+            self.set_open(false, window, cx);
+        }
+    }
+
+    /// Remove the option with `value` from the selection, like the remove button of its
+    /// chip does
+    pub fn deselect(&mut self, value: &SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let selected = self
+            .selected
+            .iter()
+            .filter(|selected| *selected != value)
+            .cloned()
+            .collect();
+        self.set_selected(selected, window, cx);
+    }
+
+    fn set_selected(
+        &mut self,
+        selected: Vec<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if selected == self.selected {
+            return;
+        }
+        self.selected = selected;
+        if let Some(on_change) = &self.on_change {
+            on_change(&self.selected, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Highlight the row under the pointer
+    pub(super) fn highlight(&mut self, row: usize, cx: &mut Context<Self>) {
+        if self.list.highlighted() != Some(row) {
+            self.list.highlight(row);
+            cx.notify();
+        }
+    }
+
+    /// Narrow the list down to the text of the search box once it changes
+    fn sync_query(&mut self, cx: &mut Context<Self>) {
+        let query = self.query.read(cx).formatted_value().clone();
+        if *query != *self.list.query() {
+            self.list.set_query(&query);
+            self.scroll_to(0.);
+            cx.notify();
+        }
+    }
+
+    // ============================================================================
+    // Keyboard Handling
+    // ============================================================================
+
+    /// Open the list with the arrow keys, Enter or Space on the trigger
+    pub(super) fn on_trigger_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.open {
+            return;
+        }
+        match event.keystroke.key.as_str() {
+            "up" | "down" | "enter" | "space" => self.set_open(true, window, cx),
+            _ => return,
+        }
+        cx.stop_propagation();
+    }
+
+    /// Move the highlight over the listed rows with the arrow keys, Home and End, pick it
+    /// with Enter, and close with Escape
+    ///
+    /// Keys the search box handles, like Home and End, don't reach the list while it is
+    /// focused, and Space types into it rather than picking.
+    pub(super) fn on_list_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match event.keystroke.key.as_str() {
+            "up" => self.list.step(false),
+            "down" => self.list.step(true),
+            "home" => self.list.highlight_first(),
+            "end" => self.list.highlight_last(),
+            "space" if self.searchable => return,
+            "enter" | "space" => {
+                if let Some(ix) = self.list.highlighted_option() {
+                    self.select(ix, window, cx);
+                }
+            }
+            "escape" => self.set_open(false, window, cx),
+            _ => return,
+        }
+        self.reveal_highlighted();
+        cx.stop_propagation();
+        cx.notify();
+    }
+}
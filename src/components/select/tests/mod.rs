@@ -0,0 +1,6 @@
+mod multiple;
+mod options;
+mod scrolling;
+mod search;
+mod support;
+mod viewport;
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod multiple {
+    use crate::components::select::{
+        SelectState,
+        tests::support::{fruits, select},
+    };
+    use gpui::{Entity, Modifiers, SharedString, TestAppContext, VisualTestContext};
+    use std::{cell::RefCell, rc::Rc};
+
+    type Changes = Rc<RefCell<Vec<Vec<SharedString>>>>;
+
+    /// Open a window with a multiple select of the fruits, recording what `on_change` gets
+    fn multiple_select(
+        cx: &mut TestAppContext,
+    ) -> (Entity<SelectState>, Changes, &mut VisualTestContext) {
+        let changes = Changes::default();
+        let recorded = changes.clone();
+        let (state, cx) = select(cx, |state| {
+            state
+                .options(fruits())
+                .multiple(true)
+                .on_change(move |values, _, _| recorded.borrow_mut().push(values.to_vec()))
+        });
+        (state, changes, cx)
+    }
+
+    fn shown(cx: &mut VisualTestContext, selector: &'static str) -> bool {
+        cx.debug_bounds(selector).is_some()
+    }
+
+    #[gpui::test]
+    fn picking_adds_to_the_selection_and_keeps_the_list_open(cx: &mut TestAppContext) {
+        let (state, changes, cx) = multiple_select(cx);
+
+        cx.simulate_keystrokes("enter");
+        // Apple is highlighted first
+        cx.simulate_keystrokes("enter");
+        cx.simulate_keystrokes("down down enter");
+
+        state.read_with(cx, |state, _| {
+            assert_eq!(state.selected(), ["apple", "cherry"]);
+            assert!(state.is_open());
+        });
+        assert_eq!(
+            *changes.borrow(),
+            [vec!["apple".into()], vec!["apple".into(), "cherry".into()]]
+        );
+        assert!(shown(cx, "select-chip-apple"));
+        assert!(shown(cx, "select-chip-cherry"));
+        assert!(!shown(cx, "select-chip-banana"));
+    }
+
+    #[gpui::test]
+    fn picking_a_selected_option_deselects_it(cx: &mut TestAppContext) {
+        let (state, changes, cx) = multiple_select(cx);
+
+        cx.simulate_keystrokes("enter");
+        cx.simulate_keystrokes("enter down enter up enter");
+
+        state.read_with(cx, |state, _| assert_eq!(state.selected(), ["banana"]));
+        assert_eq!(
+            changes.borrow().last().unwrap(),
+            &[SharedString::from("banana")]
+        );
+        assert!(!shown(cx, "select-chip-apple"));
+        assert!(shown(cx, "select-chip-banana"));
+    }
+
+    #[gpui::test]
+    fn removing_a_chip_deselects_its_option(cx: &mut TestAppContext) {
+        let (state, changes, cx) = multiple_select(cx);
+        state.update_in(cx, |state, window, cx| {
+            state.select(1, window, cx);
+            state.select(3, window, cx);
+        });
+        cx.run_until_parked();
+        assert!(!state.read_with(cx, |state, _| state.is_open()));
+
+        let remove = cx.debug_bounds("select-chip-remove-banana").unwrap();
+        cx.simulate_click(remove.center(), Modifiers::none());
+
+        state.read_with(cx, |state, _| {
+            assert_eq!(state.selected(), ["mango"]);
+            // The press on the chip doesn't open the list
+            assert!(!state.is_open());
+        });
+        assert_eq!(
+            changes.borrow().last().unwrap(),
+            &[SharedString::from("mango")]
+        );
+        assert!(!shown(cx, "select-chip-banana"));
+        assert!(shown(cx, "select-chip-mango"));
+    }
+
+    #[gpui::test]
+    fn single_selection_replaces_the_option_and_closes(cx: &mut TestAppContext) {
+        let (state, cx) = select(cx, |state| state.options(fruits()).value("apple"));
+
+        cx.simulate_keystrokes("enter down enter");
+
+        state.read_with(cx, |state, _| {
+            assert_eq!(state.selected(), ["banana"]);
+            assert!(!state.is_open());
+        });
+        assert!(!shown(cx, "select-chip-banana"));
+    }
+}
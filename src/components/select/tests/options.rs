@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod options {
+    use crate::components::select::options::{OptionList, SelectOption, find_match};
+
+    fn list(labels: &[&str]) -> OptionList {
+        OptionList::new(
+            labels
+                .iter()
+                .map(|label| SelectOption::new(label.to_lowercase(), label.to_string()))
+                .collect(),
+        )
+    }
+
+    fn labels(list: &OptionList) -> Vec<&str> {
+        (0..list.rows().len())
+            .map(|row| list.option_at(row).unwrap().label.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn matches_a_substring_regardless_of_case() {
+        assert_eq!(find_match("Blueberry", "BERRY"), Some(4..9));
+        assert_eq!(find_match("Blueberry", "lue"), Some(1..4));
+        assert_eq!(find_match("Blueberry", "rasp"), None);
+        assert_eq!(find_match("Blueberry", ""), Some(0..0));
+    }
+
+    #[test]
+    fn matches_within_multibyte_labels() {
+        assert_eq!(find_match("Crème brûlée", "BRÛ"), Some(7..11));
+        assert_eq!(find_match("Ärger", "är"), Some(0..3));
+    }
+
+    #[test]
+    fn lists_every_option_without_a_query() {
+        let list = list(&["Apple", "Banana", "Cherry"]);
+        assert_eq!(labels(&list), ["Apple", "Banana", "Cherry"]);
+        assert!(list.rows().iter().all(|row| row.matched.is_empty()));
+        assert_eq!(list.highlighted(), Some(0));
+    }
+
+    #[test]
+    fn query_lists_only_matching_options() {
+        let mut list = list(&["Apple", "Banana", "Cherry", "Mango", "Orange"]);
+        list.set_query("AN");
+        assert_eq!(labels(&list), ["Banana", "Mango", "Orange"]);
+        assert_eq!(
+            list.rows()
+                .iter()
+                .map(|row| row.matched.clone())
+                .collect::<Vec<_>>(),
+            [1..3, 1..3, 2..4]
+        );
+        assert_eq!(list.highlighted_option(), Some(1));
+
+        list.set_query("");
+        assert_eq!(labels(&list).len(), 5);
+    }
+
+    #[test]
+    fn query_without_matches_lists_nothing() {
+        let mut list = list(&["Apple", "Banana"]);
+        list.set_query("kiwi");
+        assert!(list.rows().is_empty());
+        assert_eq!(list.highlighted(), None);
+
+        list.step(true);
+        list.highlight_last();
+        assert_eq!(list.highlighted(), None);
+    }
+
+    #[test]
+    fn highlight_moves_over_the_listed_rows_and_wraps() {
+        let mut list = list(&["Apple", "Banana", "Cherry", "Mango", "Orange"]);
+        list.set_query("an");
+        list.step(true);
+        assert_eq!(list.highlighted_option(), Some(3));
+        list.step(true);
+        list.step(true);
+        assert_eq!(list.highlighted_option(), Some(1));
+        list.step(false);
+        assert_eq!(list.highlighted_option(), Some(4));
+        list.highlight_first();
+        assert_eq!(list.highlighted_option(), Some(1));
+    }
+
+    #[test]
+    fn highlight_skips_disabled_options() {
+        let mut list = OptionList::new(vec![
+            SelectOption::new("a", "Apple").disabled(true),
+            SelectOption::new("b", "Banana"),
+            SelectOption::new("c", "Cherry").disabled(true),
+            SelectOption::new("d", "Date"),
+        ]);
+        assert_eq!(list.highlighted(), Some(1));
+        list.step(true);
+        assert_eq!(list.highlighted(), Some(3));
+        list.step(true);
+        assert_eq!(list.highlighted(), Some(1));
+        list.highlight(2);
+        assert_eq!(list.highlighted(), Some(1));
+        list.highlight_last();
+        assert_eq!(list.highlighted(), Some(3));
+    }
+
+    #[test]
+    fn toggling_keeps_the_order_of_the_options() {
+        let list = list(&["Apple", "Banana", "Cherry"]);
+        let selected = list.toggled(&[], &"cherry".into());
+        assert_eq!(selected, ["cherry"]);
+        let selected = list.toggled(&selected, &"apple".into());
+        assert_eq!(selected, ["apple", "cherry"]);
+        let selected = list.toggled(&selected, &"cherry".into());
+        assert_eq!(selected, ["apple"]);
+        assert!(list.toggled(&selected, &"apple".into()).is_empty());
+    }
+
+    #[test]
+    fn highlights_the_row_of_a_value() {
+        let mut list = list(&["Apple", "Banana", "Cherry"]);
+        list.highlight_value(Some(&"cherry".into()));
+        assert_eq!(list.highlighted(), Some(2));
+
+        // Falls back to the first row once the value isn't listed
+        list.set_query("an");
+        list.highlight_value(Some(&"cherry".into()));
+        assert_eq!(list.highlighted_option(), Some(1));
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod scrolling {
+    use crate::components::select::{SelectOption, tests::support::select};
+    use gpui::{TestAppContext, px};
+
+    fn numbered(count: usize) -> Vec<SelectOption> {
+        (0..count)
+            .map(|ix| SelectOption::new(ix.to_string(), format!("Option {ix}")))
+            .collect()
+    }
+
+    #[gpui::test]
+    fn lays_out_only_the_rows_in_view(cx: &mut TestAppContext) {
+        let (_, cx) = select(cx, |state| state.options(numbered(5000)));
+
+        cx.simulate_keystrokes("enter");
+        // The 8 rows in view and the 4 below them
+        assert!(cx.debug_bounds("select-option-0").is_some());
+        assert!(cx.debug_bounds("select-option-11").is_some());
+        assert!(cx.debug_bounds("select-option-12").is_none());
+        assert!(cx.debug_bounds("select-option-4999").is_none());
+    }
+
+    #[gpui::test]
+    fn arrows_scroll_the_highlighted_row_into_view(cx: &mut TestAppContext) {
+        let (state, cx) = select(cx, |state| {
+            state.options(numbered(5000)).row_height(px(20.))
+        });
+
+        cx.simulate_keystrokes("enter");
+        for _ in 0..9 {
+            cx.simulate_keystrokes("down");
+        }
+        // Row 9 ends 40px below the 8 rows in view
+        assert_eq!(state.read_with(cx, |state, _| state.scroll()), 40.);
+
+        cx.simulate_keystrokes("up up");
+        assert_eq!(state.read_with(cx, |state, _| state.scroll()), 40.);
+    }
+
+    #[gpui::test]
+    fn end_and_home_jump_to_the_edges(cx: &mut TestAppContext) {
+        let (state, cx) = select(cx, |state| {
+            state.options(numbered(5000)).row_height(px(20.))
+        });
+
+        cx.simulate_keystrokes("enter end");
+        assert_eq!(
+            state.read_with(cx, |state, _| state.scroll()),
+            5000. * 20. - 8. * 20.
+        );
+        assert!(cx.debug_bounds("select-option-4999").is_some());
+        assert!(cx.debug_bounds("select-option-0").is_none());
+
+        cx.simulate_keystrokes("enter");
+        assert_eq!(
+            state.read_with(cx, |state, _| state.selected().to_vec()),
+            ["4999"]
+        );
+
+        cx.simulate_keystrokes("enter");
+        // The list opens scrolled to the selected option, and Home goes back to the top
+        assert!(cx.debug_bounds("select-option-4999").is_some());
+        cx.simulate_keystrokes("home");
+        assert_eq!(state.read_with(cx, |state, _| state.scroll()), 0.);
+        assert!(cx.debug_bounds("select-option-0").is_some());
+    }
+}
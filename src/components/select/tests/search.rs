@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod search {
+    use crate::components::select::tests::support::{fruits, listed, select};
+    use gpui::{SharedString, TestAppContext};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[gpui::test]
+    fn typing_narrows_the_list_down(cx: &mut TestAppContext) {
+        let (state, cx) = select(cx, |state| state.options(fruits()).searchable(true));
+
+        cx.simulate_keystrokes("enter");
+        assert!(state.read_with(cx, |state, _| state.is_open()));
+        cx.simulate_input("AN");
+        assert_eq!(listed(&state, cx), ["Banana", "Mango", "Orange"]);
+
+        cx.simulate_keystrokes("backspace backspace");
+        assert_eq!(listed(&state, cx).len(), 5);
+    }
+
+    #[gpui::test]
+    fn enter_selects_the_highlighted_filtered_option(cx: &mut TestAppContext) {
+        let changes = Rc::new(RefCell::new(Vec::<Vec<SharedString>>::new()));
+        let recorded = changes.clone();
+        let (state, cx) = select(cx, |state| {
+            state
+                .options(fruits())
+                .searchable(true)
+                .on_change(move |values, _, _| recorded.borrow_mut().push(values.to_vec()))
+        });
+
+        cx.simulate_keystrokes("down");
+        cx.simulate_input("an");
+        // Banana is highlighted first, so one step down is Mango
+        cx.simulate_keystrokes("down enter");
+
+        state.read_with(cx, |state, _| {
+            assert_eq!(state.selected(), ["mango"]);
+            assert!(!state.is_open());
+        });
+        assert_eq!(*changes.borrow(), [vec![SharedString::from("mango")]]);
+    }
+
+    #[gpui::test]
+    fn shows_no_results_without_matches(cx: &mut TestAppContext) {
+        let (state, cx) = select(cx, |state| state.options(fruits()).searchable(true));
+
+        cx.simulate_keystrokes("enter");
+        cx.simulate_input("kiwi");
+        assert!(listed(&state, cx).is_empty());
+        assert!(cx.debug_bounds("select-empty").is_some());
+
+        // Enter has nothing to pick, and the list stays open
+        cx.simulate_keystrokes("enter");
+        state.read_with(cx, |state, _| {
+            assert!(state.selected().is_empty());
+            assert!(state.is_open());
+        });
+    }
+
+    #[gpui::test]
+    fn escape_closes_and_focuses_the_trigger(cx: &mut TestAppContext) {
+        let (state, cx) = select(cx, |state| state.options(fruits()).searchable(true));
+
+        cx.simulate_keystrokes("enter");
+        cx.simulate_input("ch");
+        cx.simulate_keystrokes("escape");
+        state.update_in(cx, |state, window, _| {
+            assert!(!state.is_open());
+            assert!(state.focus_handle().is_focused(window));
+        });
+
+        // The list opens again with every option
+        cx.simulate_keystrokes("enter");
+        assert_eq!(listed(&state, cx).len(), 5);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::components::{
+    input,
+    select::{Select, SelectOption, SelectState},
+};
+use gpui::*;
+
+/// A window rendering a single [`Select`]
+pub(super) struct SelectView {
+    pub(super) state: Entity<SelectState>,
+}
+
+impl Render for SelectView {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        div().size_full().child(Select::new(self.state.clone()))
+    }
+}
+
+/// Open a window with a select built by `build`, with its trigger focused
+pub(super) fn select(
+    cx: &mut TestAppContext,
+    build: impl FnOnce(SelectState) -> SelectState,
+) -> (Entity<SelectState>, &mut VisualTestContext) {
+    cx.update(input::init);
+    let (view, cx) = cx.add_window_view(|window, cx| SelectView {
+        state: cx.new(|cx| build(SelectState::new(window, cx))),
+    });
+    let state = view.read_with(cx, |view, _| view.state.clone());
+    state.update_in(cx, |state, window, _| state.focus_handle().focus(window));
+    cx.run_until_parked();
+    (state, cx)
+}
+
+/// Options for the fruits, with their lowercase names as values
+pub(super) fn fruits() -> Vec<SelectOption> {
+    ["Apple", "Banana", "Cherry", "Mango", "Orange"]
+        .into_iter()
+        .map(|name| SelectOption::new(name.to_lowercase(), name))
+        .collect()
+}
+
+/// The labels of the options the open list shows
+pub(super) fn listed(state: &Entity<SelectState>, cx: &mut VisualTestContext) -> Vec<String> {
+    state.read_with(cx, |state, _| {
+        state
+            .list
+            .rows()
+            .iter()
+            .map(|row| state.current_options()[row.option].label.to_string())
+            .collect()
+    })
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod viewport {
+    use crate::components::select::viewport::{OVERSCAN, RowViewport};
+
+    /// A thousand rows of 20px seen through a viewport of 10 rows
+    fn viewport() -> RowViewport {
+        RowViewport {
+            row_height: 20.,
+            height: 200.,
+            rows: 1000,
+        }
+    }
+
+    #[test]
+    fn lays_out_the_rows_in_view_and_the_overscan() {
+        assert_eq!(viewport().visible_range(0.), 0..10 + OVERSCAN);
+        assert_eq!(
+            viewport().visible_range(2000.),
+            100 - OVERSCAN..110 + OVERSCAN
+        );
+    }
+
+    #[test]
+    fn counts_partly_visible_rows() {
+        // Rows 100 and 110 are half in view
+        assert_eq!(
+            viewport().visible_range(2010.),
+            100 - OVERSCAN..111 + OVERSCAN
+        );
+    }
+
+    #[test]
+    fn stops_at_the_ends() {
+        let viewport = viewport();
+        assert_eq!(viewport.max_scroll(), 19_800.);
+        assert_eq!(viewport.visible_range(19_800.), 990 - OVERSCAN..1000);
+        // Scrolling past either end is clamped
+        assert_eq!(viewport.visible_range(50_000.), 990 - OVERSCAN..1000);
+        assert_eq!(viewport.visible_range(-100.), 0..10 + OVERSCAN);
+    }
+
+    #[test]
+    fn short_lists_lay_out_every_row() {
+        let viewport = RowViewport {
+            rows: 3,
+            ..viewport()
+        };
+        assert_eq!(viewport.max_scroll(), 0.);
+        assert_eq!(viewport.visible_range(0.), 0..3);
+        let empty = RowViewport {
+            rows: 0,
+            ..viewport
+        };
+        assert_eq!(empty.visible_range(0.), 0..0);
+    }
+
+    #[test]
+    fn reveals_rows_by_scrolling_as_little_as_possible() {
+        let viewport = viewport();
+        // A row in view doesn't scroll
+        assert_eq!(viewport.reveal(100., 9), 100.);
+        // A row below the viewport scrolls to its bottom edge
+        assert_eq!(viewport.reveal(100., 20), 220.);
+        // A row above scrolls to its top edge
+        assert_eq!(viewport.reveal(100., 2), 40.);
+        assert_eq!(viewport.reveal(0., 999), 19_800.);
+        assert_eq!(viewport.reveal(19_800., 0), 0.);
+    }
+}
@@ -0,0 +1,57 @@
+use std::ops::Range;
+
+/// How many rows past either edge of the viewport are laid out, so scrolling a little
+/// doesn't show a gap before the next render
+pub const OVERSCAN: usize = 4;
+
+/// The rows of equal height a scrolled list lays out, so a list of thousands of options
+/// only builds the few in view.
+///
+/// Scroll positions count up from 0 with the first row at the top of the viewport.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RowViewport {
+    pub row_height: f32,
+    /// The visible height of the list
+    pub height: f32,
+    /// How many rows the list has
+    pub rows: usize,
+}
+
+impl RowViewport {
+    /// The height of all of the rows
+    pub fn content_height(&self) -> f32 {
+        self.rows as f32 * self.row_height
+    }
+
+    /// How far the list scrolls, or 0 when every row fits
+    pub fn max_scroll(&self) -> f32 {
+        (self.content_height() - self.height).max(0.)
+    }
+
+    /// The rows at least partly in view at `scroll`, and the [`OVERSCAN`] rows before and
+    /// after them
+    pub fn visible_range(&self, scroll: f32) -> Range<usize> {
+        if self.rows == 0 || self.row_height <= 0. {
+            return 0..0;
+        }
+        let scroll = scroll.clamp(0., self.max_scroll());
+        let first = (scroll / self.row_height).floor() as usize;
+        let last = ((scroll + self.height) / self.row_height).ceil() as usize;
+        first.saturating_sub(OVERSCAN)..(last + OVERSCAN).min(self.rows)
+    }
+
+    /// The scroll that brings `row` fully into view from `scroll`, moving as little as
+    /// possible, or `scroll` itself when the row is already in view
+    pub fn reveal(&self, scroll: f32, row: usize) -> f32 {
+        let top = row.min(self.rows.saturating_sub(1)) as f32 * self.row_height;
+        let bottom = top + self.row_height;
+        let scroll = if top < scroll {
+            top
+        } else if bottom > scroll + self.height {
+            bottom - self.height
+        } else {
+            scroll
+        };
+        scroll.clamp(0., self.max_scroll())
+    }
+}
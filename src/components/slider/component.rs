@@ -0,0 +1,164 @@
+use crate::{
+    Disableable,
+    components::slider::state::{CONTEXT, SliderState},
+};
+use gpui::{prelude::FluentBuilder, *};
+
+/// A track with a thumb that picks a value between a minimum and a maximum.
+///
+/// The value, bounds and step live in a [`SliderState`]. The slider renders the track,
+/// a fill from the start of the track to the value, and the thumb at the value. Dragging
+/// the thumb or clicking the track snaps to the step, and the focused slider follows the
+/// arrow keys, PageUp and PageDown, Home and End.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     SliderState::new(cx)
+///         .min(0.)
+///         .max(10.)
+///         .step(0.5)
+///         .on_commit(|value, _window, _cx| println!("Volume: {}", value))
+/// });
+///
+/// Slider::new(state)
+///     .h(px(4))
+///     .w(px(200))
+///     .bg(rgb(0xe5e7eb))
+///     .fill(|fill| fill.bg(rgb(0x3b82f6)))
+///     .thumb(|thumb| thumb.size(px(12)).ml(px(-6)).rounded_full().bg(rgb(0xffffff)))
+/// ```
+#[derive(IntoElement)]
+pub struct Slider {
+    base: Div,
+    state: Entity<SliderState>,
+    fill: Div,
+    thumb: Div,
+    disabled: bool,
+}
+
+impl Slider {
+    /// Creates a slider for the given state.
+    pub fn new(state: Entity<SliderState>) -> Self {
+        Self {
+            base: div(),
+            state,
+            fill: div(),
+            thumb: div(),
+            disabled: false,
+        }
+    }
+
+    /// Customizes the fill between the start of the track and the value.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the fill, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let slider = Slider::new(state).fill(|fill| fill.bg(rgb(0x3b82f6)));
+    /// ```
+    pub fn fill(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.fill = handler(self.fill);
+        self
+    }
+
+    /// Customizes the thumb.
+    ///
+    /// The thumb's left edge sits at the value, so give it a negative left margin of half
+    /// its width to center it.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the thumb, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let slider = Slider::new(state)
+    ///     .thumb(|thumb| thumb.size(px(12)).ml(px(-6)).rounded_full().bg(rgb(0xffffff)));
+    /// ```
+    pub fn thumb(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.thumb = handler(self.thumb);
+        self
+    }
+}
+
+impl Disableable for Slider {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Styled for Slider {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Slider {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let fraction = self.state.read(app).fraction();
+        let state = self.state.clone();
+        // Keep the track bounds for mapping mouse positions to values
+        let bounds = canvas(
+            move |bounds, _, app| state.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        self.base
+            .relative()
+            .child(bounds)
+            .child(
+                self.fill
+                    .absolute()
+                    .left_0()
+                    .top_0()
+                    .bottom_0()
+                    .w(relative(fraction)),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left(relative(fraction))
+                    .flex()
+                    .items_center()
+                    .child(self.thumb),
+            )
+            .when(!self.disabled, |this| {
+                this.key_context(CONTEXT)
+                    .track_focus(&self.state.focus_handle(app))
+                    .on_action(window.listener_for(&self.state, SliderState::increment))
+                    .on_action(window.listener_for(&self.state, SliderState::decrement))
+                    .on_action(window.listener_for(&self.state, SliderState::increment_large))
+                    .on_action(window.listener_for(&self.state, SliderState::decrement_large))
+                    .on_action(window.listener_for(&self.state, SliderState::to_min))
+                    .on_action(window.listener_for(&self.state, SliderState::to_max))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        window.listener_for(&self.state, SliderState::on_mouse_down),
+                    )
+                    .on_mouse_up(
+                        MouseButton::Left,
+                        window.listener_for(&self.state, SliderState::on_mouse_up),
+                    )
+                    .on_mouse_up_out(
+                        MouseButton::Left,
+                        window.listener_for(&self.state, SliderState::on_mouse_up),
+                    )
+                    .on_mouse_move(window.listener_for(&self.state, SliderState::on_mouse_move))
+            })
+    }
+}
@@ -0,0 +1,12 @@
+mod component;
+mod range;
+mod range_slider;
+mod range_state;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use component::Slider;
+pub use range_slider::RangeSlider;
+pub use range_state::*;
+pub use state::*;
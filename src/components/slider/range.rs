@@ -0,0 +1,120 @@
+/// The bounds and step of a slider, and the arithmetic on its values
+///
+/// Values are clamped to `min..=max` and snapped to the nearest multiple of `step` from
+/// `min`. A step of zero or less lets the value move freely.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SliderRange {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub large_step: Option<f32>,
+}
+
+impl Default for SliderRange {
+    fn default() -> Self {
+        Self {
+            min: 0.,
+            max: 100.,
+            step: 1.,
+            large_step: None,
+        }
+    }
+}
+
+impl SliderRange {
+    fn max(&self) -> f32 {
+        self.max.max(self.min)
+    }
+
+    /// The step of PageUp and PageDown, ten steps unless set
+    pub fn large_step(&self) -> f32 {
+        self.large_step.unwrap_or(self.step * 10.)
+    }
+
+    pub fn clamp(&self, value: f32) -> f32 {
+        value.clamp(self.min, self.max())
+    }
+
+    /// Clamp `value` and round it to the nearest step
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = self.clamp(value);
+        if self.step <= 0. {
+            return value;
+        }
+        let steps = ((value - self.min) / self.step).round();
+        self.clamp(self.min + steps * self.step)
+    }
+
+    /// Move `value` by `delta` and snap the result
+    pub fn offset(&self, value: f32, delta: f32) -> f32 {
+        self.snap(value + delta)
+    }
+
+    /// How far along the track `value` is, from 0 at `min` to 1 at `max`
+    pub fn fraction(&self, value: f32) -> f32 {
+        let span = self.max() - self.min;
+        if span <= 0. {
+            return 0.;
+        }
+        (self.clamp(value) - self.min) / span
+    }
+
+    /// The snapped value `fraction` of the way along the track
+    pub fn value_at(&self, fraction: f32) -> f32 {
+        let span = self.max() - self.min;
+        self.snap(self.min + fraction.clamp(0., 1.) * span)
+    }
+}
+
+/// One of the two thumbs of a range slider
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Thumb {
+    Lower,
+    Upper,
+}
+
+impl SliderRange {
+    /// Snap both ends of a range and put them in order
+    pub fn snap_pair(&self, (lower, upper): (f32, f32)) -> (f32, f32) {
+        let (lower, upper) = (self.snap(lower), self.snap(upper));
+        (lower.min(upper), lower.max(upper))
+    }
+
+    /// The thumb closest to `value`, the lower one on a tie
+    pub fn nearest_thumb(&self, (lower, upper): (f32, f32), value: f32) -> Thumb {
+        if value <= lower || value - lower <= upper - value {
+            Thumb::Lower
+        } else {
+            Thumb::Upper
+        }
+    }
+
+    /// Move `thumb` to `value` without crossing the other one
+    ///
+    /// Stacked thumbs come apart in the direction of the move, so the thumb that moves is
+    /// returned along with the new range.
+    pub fn move_thumb(
+        &self,
+        (lower, upper): (f32, f32),
+        thumb: Thumb,
+        value: f32,
+    ) -> ((f32, f32), Thumb) {
+        let value = self.snap(value);
+        let thumb = match thumb {
+            _ if lower == upper && value > upper => Thumb::Upper,
+            _ if lower == upper && value < lower => Thumb::Lower,
+            thumb => thumb,
+        };
+        match thumb {
+            Thumb::Lower => ((value.min(upper), upper), thumb),
+            Thumb::Upper => ((lower, value.max(lower)), thumb),
+        }
+    }
+
+    /// Move both ends of a range by `delta`, keeping its width within the bounds
+    pub fn move_span(&self, (lower, upper): (f32, f32), delta: f32) -> (f32, f32) {
+        let delta = delta.clamp(self.min - lower, self.max() - upper);
+        let moved = self.snap(lower + delta);
+        (moved, self.clamp(moved + upper - lower))
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    Disableable,
+    components::slider::{range::Thumb, range_state::RangeSliderState},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// A track with two thumbs that pick a lower and an upper value.
+///
+/// The values, bounds and step live in a [`RangeSliderState`]. The thumbs can't cross.
+/// Clicking the track moves the nearest thumb there, and dragging the fill between the
+/// thumbs moves both at once.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|cx| {
+///     RangeSliderState::new(cx)
+///         .max(1000.)
+///         .step(10.)
+///         .value((200., 600.))
+///         .on_commit(|(lower, upper), _window, _cx| println!("{} to {}", lower, upper))
+/// });
+///
+/// RangeSlider::new(state)
+///     .h(px(4))
+///     .w(px(200))
+///     .bg(rgb(0xe5e7eb))
+///     .fill(|fill| fill.bg(rgb(0x3b82f6)))
+///     .thumb(|thumb| thumb.size(px(12)).ml(px(-6)).rounded_full().bg(rgb(0xffffff)))
+/// ```
+#[derive(IntoElement)]
+pub struct RangeSlider {
+    base: Div,
+    state: Entity<RangeSliderState>,
+    fill: Div,
+    thumb: Rc<dyn Fn(Div) -> Div>,
+    disabled: bool,
+}
+
+impl RangeSlider {
+    /// Creates a range slider for the given state.
+    pub fn new(state: Entity<RangeSliderState>) -> Self {
+        Self {
+            base: div(),
+            state,
+            fill: div(),
+            thumb: Rc::new(|thumb| thumb),
+            disabled: false,
+        }
+    }
+
+    /// Customizes the fill between the two thumbs.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the fill, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let slider = RangeSlider::new(state).fill(|fill| fill.bg(rgb(0x3b82f6)));
+    /// ```
+    pub fn fill(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.fill = handler(self.fill);
+        self
+    }
+
+    /// Customizes both thumbs.
+    ///
+    /// The handler runs once for each thumb. Each thumb's left edge sits at its value, so give it a negative left margin of half
+    /// its width to center it.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns a thumb, applying modifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let slider = RangeSlider::new(state)
+    ///     .thumb(|thumb| thumb.size(px(12)).ml(px(-6)).rounded_full().bg(rgb(0xffffff)));
+    /// ```
+    pub fn thumb(mut self, handler: impl Fn(Div) -> Div + 'static) -> Self {
+        self.thumb = Rc::new(handler);
+        self
+    }
+
+    fn thumb_element(&self, thumb: Thumb, fraction: f32, window: &mut Window) -> Div {
+        div()
+            .absolute()
+            .top_0()
+            .bottom_0()
+            .left(relative(fraction))
+            .flex()
+            .items_center()
+            .child((self.thumb)(div()).when(!self.disabled, |this| {
+                this.on_mouse_down(
+                    MouseButton::Left,
+                    window.listener_for(
+                        &self.state,
+                        move |state, _: &MouseDownEvent, window, cx| {
+                            state.on_thumb_mouse_down(thumb, window, cx)
+                        },
+                    ),
+                )
+            }))
+    }
+}
+
+impl Disableable for RangeSlider {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Styled for RangeSlider {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for RangeSlider {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let (lower, upper) = self.state.read(app).fractions();
+        let state = self.state.clone();
+        // Keep the track bounds for mapping mouse positions to values
+        let bounds = canvas(
+            move |bounds, _, app| state.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+        let lower_thumb = self.thumb_element(Thumb::Lower, lower, window);
+        let upper_thumb = self.thumb_element(Thumb::Upper, upper, window);
+
+        self.base
+            .relative()
+            .child(bounds)
+            .child(
+                self.fill
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left(relative(lower))
+                    .w(relative(upper - lower))
+                    .when(!self.disabled, |this| {
+                        this.on_mouse_down(
+                            MouseButton::Left,
+                            window.listener_for(&self.state, RangeSliderState::on_span_mouse_down),
+                        )
+                    }),
+            )
+            .child(lower_thumb)
+            .child(upper_thumb)
+            .when(!self.disabled, |this| {
+                this.track_focus(&self.state.focus_handle(app))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        window.listener_for(&self.state, RangeSliderState::on_track_mouse_down),
+                    )
+                    .on_mouse_up(
+                        MouseButton::Left,
+                        window.listener_for(&self.state, RangeSliderState::on_mouse_up),
+                    )
+                    .on_mouse_up_out(
+                        MouseButton::Left,
+                        window.listener_for(&self.state, RangeSliderState::on_mouse_up),
+                    )
+                    .on_mouse_move(
+                        window.listener_for(&self.state, RangeSliderState::on_mouse_move),
+                    )
+            })
+    }
+}
@@ -0,0 +1,263 @@
+use crate::components::slider::range::{SliderRange, Thumb};
+use gpui::*;
+
+/// What the mouse is dragging on a range slider
+#[derive(Clone, Copy)]
+enum RangeDrag {
+    /// One thumb, following the mouse
+    Thumb(Thumb),
+    /// The part of the track between the thumbs, moving both from where the drag started
+    Span {
+        anchor: f32,
+        start: (f32, f32),
+        moved: bool,
+    },
+}
+
+/// The state of a [`RangeSlider`](super::RangeSlider)
+///
+/// Handles the lower and upper values, their bounds and step, and dragging either thumb
+/// or the span between them.
+#[allow(clippy::type_complexity)]
+pub struct RangeSliderState {
+    focus_handle: FocusHandle,
+    value: Option<(f32, f32)>,
+    range: SliderRange,
+    drag: Option<RangeDrag>,
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    on_change: Option<Box<dyn Fn(&(f32, f32), &mut Window, &mut Context<Self>)>>,
+    on_commit: Option<Box<dyn Fn(&(f32, f32), &mut Window, &mut Context<Self>)>>,
+}
+
+impl RangeSliderState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new RangeSliderState going from 0 to 100 in steps of 1, with the whole
+    /// range selected
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            value: None,
+            range: SliderRange::default(),
+            drag: None,
+            last_bounds: None,
+            on_change: None,
+            on_commit: None,
+        }
+    }
+
+    /// Set the lowest value
+    pub fn min(mut self, min: f32) -> Self {
+        self.range.min = min;
+        self
+    }
+
+    /// Set the highest value
+    pub fn max(mut self, max: f32) -> Self {
+        self.range.max = max;
+        self
+    }
+
+    /// Set the step values snap to, or zero to move freely
+    pub fn step(mut self, step: f32) -> Self {
+        self.range.step = step;
+        self
+    }
+
+    /// Set the initial lower and upper values, the whole range by default
+    ///
+    /// They are put in order, clamped and snapped once the bounds and step are all set, so
+    /// the builders can be called in any order.
+    pub fn value(mut self, value: (f32, f32)) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Call `on_change` with the new lower and upper values whenever either changes,
+    /// including every step of a drag
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&(f32, f32), &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Call `on_commit` with the final values once a drag is released
+    pub fn on_commit(
+        mut self,
+        on_commit: impl Fn(&(f32, f32), &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_commit = Some(Box::new(on_commit));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    /// The lower and upper values, in order and snapped to the step
+    pub fn current_value(&self) -> (f32, f32) {
+        let value = self.value.unwrap_or((self.range.min, self.range.max));
+        self.range.snap_pair(value)
+    }
+
+    /// Set the values without calling `on_change` or `on_commit`
+    pub fn set_value(&mut self, value: (f32, f32), cx: &mut Context<Self>) {
+        self.value = Some(self.range.snap_pair(value));
+        cx.notify();
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// How far along the track each value is, from 0 to 1
+    pub fn fractions(&self) -> (f32, f32) {
+        let (lower, upper) = self.current_value();
+        (self.range.fraction(lower), self.range.fraction(upper))
+    }
+
+    fn change_to(&mut self, value: (f32, f32), window: &mut Window, cx: &mut Context<Self>) {
+        if value == self.current_value() {
+            return;
+        }
+        self.value = Some(value);
+        if let Some(on_change) = &self.on_change {
+            on_change(&value, window, cx);
+        }
+        cx.notify();
+    }
+
+    fn move_thumb(
+        &mut self,
+        thumb: Thumb,
+        value: f32,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (value, thumb) = self.range.move_thumb(self.current_value(), thumb, value);
+        self.drag = Some(RangeDrag::Thumb(thumb));
+        self.change_to(value, window, cx);
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// The value under `position`, along the track laid out last
+    fn value_for_position(&self, position: Point<Pixels>) -> Option<f32> {
+        let bounds = self.last_bounds?;
+        if bounds.size.width <= px(0.) {
+            return None;
+        }
+        let fraction = (position.x - bounds.left()) / bounds.size.width;
+        Some(self.range.value_at(fraction))
+    }
+
+    /// Move the thumb nearest to the mouse there and start dragging it
+    pub(super) fn on_track_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.focus_handle.focus(window);
+        let Some(value) = self.value_for_position(event.position) else {
+            return;
+        };
+        let thumb = self.range.nearest_thumb(self.current_value(), value);
+        self.move_thumb(thumb, value, window, cx);
+    }
+
+    /// Start dragging `thumb`
+    pub(super) fn on_thumb_mouse_down(
+        &mut self,
+        thumb: Thumb,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.focus_handle.focus(window);
+        self.drag = Some(RangeDrag::Thumb(thumb));
+        cx.stop_propagation();
+    }
+
+    /// Start dragging both thumbs from the track between them
+    pub(super) fn on_span_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.focus_handle.focus(window);
+        cx.stop_propagation();
+        let Some(anchor) = self.value_for_position(event.position) else {
+            return;
+        };
+        self.drag = Some(RangeDrag::Span {
+            anchor,
+            start: self.current_value(),
+            moved: false,
+        });
+    }
+
+    pub(super) fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (Some(drag), Some(value)) = (self.drag, self.value_for_position(event.position)) else {
+            return;
+        };
+        match drag {
+            RangeDrag::Thumb(thumb) => self.move_thumb(thumb, value, window, cx),
+            RangeDrag::Span { anchor, start, .. } => {
+                if value == anchor {
+                    return;
+                }
+                self.drag = Some(RangeDrag::Span {
+                    anchor,
+                    start,
+                    moved: true,
+                });
+                let value = self.range.move_span(start, value - anchor);
+                self.change_to(value, window, cx);
+            }
+        }
+    }
+
+    /// End a drag, committing its final values
+    ///
+    /// Releasing the track between the thumbs without dragging it counts as a click, and
+    /// moves the nearest thumb.
+    pub(super) fn on_mouse_up(
+        &mut self,
+        event: &MouseUpEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        if let RangeDrag::Span { moved: false, .. } = drag
+            && let Some(value) = self.value_for_position(event.position)
+        {
+            let thumb = self.range.nearest_thumb(self.current_value(), value);
+            let (value, _) = self.range.move_thumb(self.current_value(), thumb, value);
+            self.change_to(value, window, cx);
+        }
+        if let Some(on_commit) = &self.on_commit {
+            on_commit(&self.current_value(), window, cx);
+        }
+        cx.notify();
+    }
+}
+
+impl Focusable for RangeSliderState {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
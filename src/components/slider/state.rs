@@ -0,0 +1,276 @@
+use crate::components::slider::range::SliderRange;
+use gpui::*;
+
+/// Context identifier for slider key bindings
+pub(super) const CONTEXT: &str = "slider";
+
+/// Initialize slider key bindings and actions
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("right", Increment, Some(CONTEXT)),
+        KeyBinding::new("up", Increment, Some(CONTEXT)),
+        KeyBinding::new("left", Decrement, Some(CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(CONTEXT)),
+        KeyBinding::new("pageup", IncrementLarge, Some(CONTEXT)),
+        KeyBinding::new("pagedown", DecrementLarge, Some(CONTEXT)),
+        KeyBinding::new("home", ToMin, Some(CONTEXT)),
+        KeyBinding::new("end", ToMax, Some(CONTEXT)),
+    ]);
+}
+
+actions!(
+    slider,
+    [
+        Increment,
+        Decrement,
+        IncrementLarge,
+        DecrementLarge,
+        ToMin,
+        ToMax,
+    ]
+);
+
+/// The state of a [`Slider`](super::Slider)
+///
+/// Handles the value, its bounds and step, dragging and keyboard steps.
+#[allow(clippy::type_complexity)]
+pub struct SliderState {
+    focus_handle: FocusHandle,
+    value: f32,
+    range: SliderRange,
+    dragging: bool,
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    on_change: Option<Box<dyn Fn(&f32, &mut Window, &mut Context<Self>)>>,
+    on_commit: Option<Box<dyn Fn(&f32, &mut Window, &mut Context<Self>)>>,
+}
+
+impl SliderState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new SliderState going from 0 to 100 in steps of 1
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            value: 0.,
+            range: SliderRange::default(),
+            dragging: false,
+            last_bounds: None,
+            on_change: None,
+            on_commit: None,
+        }
+    }
+
+    /// Set the lowest value
+    pub fn min(mut self, min: f32) -> Self {
+        self.range.min = min;
+        self
+    }
+
+    /// Set the highest value
+    pub fn max(mut self, max: f32) -> Self {
+        self.range.max = max;
+        self
+    }
+
+    /// Set the step values snap to, or zero to move freely
+    pub fn step(mut self, step: f32) -> Self {
+        self.range.step = step;
+        self
+    }
+
+    /// Set the step of PageUp and PageDown, ten steps by default
+    pub fn large_step(mut self, large_step: f32) -> Self {
+        self.range.large_step = Some(large_step);
+        self
+    }
+
+    /// Set the initial value
+    ///
+    /// It is clamped and snapped once the bounds and step are all set, so the builders can
+    /// be called in any order.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Call `on_change` with the new value whenever it changes, including every step of a
+    /// drag
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&f32, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Call `on_commit` with the final value once a drag is released, and after every key
+    /// press
+    pub fn on_commit(
+        mut self,
+        on_commit: impl Fn(&f32, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_commit = Some(Box::new(on_commit));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    /// The value, clamped and snapped to the step
+    pub fn current_value(&self) -> f32 {
+        self.range.snap(self.value)
+    }
+
+    /// Set the value without calling `on_change` or `on_commit`
+    pub fn set_value(&mut self, value: f32, cx: &mut Context<Self>) {
+        self.value = self.range.snap(value);
+        cx.notify();
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// How far along the track the value is, from 0 to 1
+    pub fn fraction(&self) -> f32 {
+        self.range.fraction(self.current_value())
+    }
+
+    fn change_to(&mut self, value: f32, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.range.snap(value);
+        if value == self.current_value() {
+            return;
+        }
+        self.value = value;
+        if let Some(on_change) = &self.on_change {
+            on_change(&self.value, window, cx);
+        }
+        cx.notify();
+    }
+
+    fn commit(&self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(on_commit) = &self.on_commit {
+            on_commit(&self.current_value(), window, cx);
+        }
+    }
+
+    fn change_and_commit(&mut self, value: f32, window: &mut Window, cx: &mut Context<Self>) {
+        let previous = self.current_value();
+        self.change_to(value, window, cx);
+        if self.current_value() != previous {
+            self.commit(window, cx);
+        }
+    }
+
+    // ============================================================================
+    // Keyboard Actions
+    // ============================================================================
+
+    pub(super) fn increment(&mut self, _: &Increment, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.range.offset(self.current_value(), self.range.step);
+        self.change_and_commit(value, window, cx);
+    }
+
+    pub(super) fn decrement(&mut self, _: &Decrement, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.range.offset(self.current_value(), -self.range.step);
+        self.change_and_commit(value, window, cx);
+    }
+
+    pub(super) fn increment_large(
+        &mut self,
+        _: &IncrementLarge,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self
+            .range
+            .offset(self.current_value(), self.range.large_step());
+        self.change_and_commit(value, window, cx);
+    }
+
+    pub(super) fn decrement_large(
+        &mut self,
+        _: &DecrementLarge,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = self
+            .range
+            .offset(self.current_value(), -self.range.large_step());
+        self.change_and_commit(value, window, cx);
+    }
+
+    pub(super) fn to_min(&mut self, _: &ToMin, window: &mut Window, cx: &mut Context<Self>) {
+        self.change_and_commit(self.range.min, window, cx);
+    }
+
+    pub(super) fn to_max(&mut self, _: &ToMax, window: &mut Window, cx: &mut Context<Self>) {
+        self.change_and_commit(self.range.max, window, cx);
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// The value under `position`, along the track laid out last
+    fn value_for_position(&self, position: Point<Pixels>) -> Option<f32> {
+        let bounds = self.last_bounds?;
+        if bounds.size.width <= px(0.) {
+            return None;
+        }
+        let fraction = (position.x - bounds.left()) / bounds.size.width;
+        Some(self.range.value_at(fraction))
+    }
+
+    /// Start a drag and jump to the value under the mouse
+    pub(super) fn on_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.dragging = true;
+        self.focus_handle.focus(window);
+        if let Some(value) = self.value_for_position(event.position) {
+            self.change_to(value, window, cx);
+        }
+    }
+
+    pub(super) fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.dragging {
+            return;
+        }
+        if let Some(value) = self.value_for_position(event.position) {
+            self.change_to(value, window, cx);
+        }
+    }
+
+    /// End a drag, committing its final value
+    pub(super) fn on_mouse_up(
+        &mut self,
+        _: &MouseUpEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.dragging {
+            return;
+        }
+        self.dragging = false;
+        self.commit(window, cx);
+        cx.notify();
+    }
+}
+
+impl Focusable for SliderState {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
@@ -0,0 +1,2 @@
+mod range;
+mod state;
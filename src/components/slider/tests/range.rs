@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod range {
+    use crate::components::slider::range::{SliderRange, Thumb};
+
+    fn range(min: f32, max: f32, step: f32) -> SliderRange {
+        SliderRange {
+            min,
+            max,
+            step,
+            large_step: None,
+        }
+    }
+
+    #[test]
+    fn values_snap_to_the_nearest_step() {
+        let range = range(0., 10., 2.);
+        assert_eq!(range.snap(4.9), 4.);
+        assert_eq!(range.snap(5.1), 6.);
+        assert_eq!(range.snap(6.), 6.);
+    }
+
+    #[test]
+    fn steps_count_from_the_minimum() {
+        let range = range(1., 11., 2.);
+        assert_eq!(range.snap(4.2), 5.);
+        assert_eq!(range.snap(1.9), 1.);
+    }
+
+    #[test]
+    fn zero_step_moves_freely() {
+        let range = range(0., 1., 0.);
+        assert_eq!(range.snap(0.37), 0.37);
+    }
+
+    #[test]
+    fn values_clamp_to_the_bounds() {
+        let range = range(-5., 5., 1.);
+        assert_eq!(range.snap(-7.), -5.);
+        assert_eq!(range.snap(9.), 5.);
+    }
+
+    #[test]
+    fn snapping_never_leaves_the_bounds() {
+        let range = range(0., 10., 3.);
+        assert_eq!(range.snap(10.), 9.);
+        assert_eq!(range.clamp(10.), 10.);
+    }
+
+    #[test]
+    fn keys_move_by_a_step() {
+        let range = range(0., 100., 5.);
+        assert_eq!(range.offset(40., range.step), 45.);
+        assert_eq!(range.offset(40., -range.step), 35.);
+    }
+
+    #[test]
+    fn page_keys_move_by_a_large_step() {
+        let mut range = range(0., 100., 1.);
+        assert_eq!(range.large_step(), 10.);
+        assert_eq!(range.offset(42., range.large_step()), 52.);
+
+        range.large_step = Some(25.);
+        assert_eq!(range.offset(42., -range.large_step()), 17.);
+    }
+
+    #[test]
+    fn keys_stop_at_the_bounds() {
+        let range = range(0., 10., 1.);
+        assert_eq!(range.offset(10., range.step), 10.);
+        assert_eq!(range.offset(0., -range.step), 0.);
+        assert_eq!(range.offset(95., range.large_step()), 10.);
+        assert_eq!(range.offset(3., -range.large_step()), 0.);
+    }
+
+    #[test]
+    fn positions_map_to_snapped_values() {
+        let range = range(0., 10., 1.);
+        assert_eq!(range.value_at(0.), 0.);
+        assert_eq!(range.value_at(0.44), 4.);
+        assert_eq!(range.value_at(1.), 10.);
+        assert_eq!(range.value_at(-0.5), 0.);
+        assert_eq!(range.value_at(1.5), 10.);
+    }
+
+    #[test]
+    fn values_map_to_track_fractions() {
+        let range = range(10., 20., 1.);
+        assert_eq!(range.fraction(10.), 0.);
+        assert_eq!(range.fraction(15.), 0.5);
+        assert_eq!(range.fraction(25.), 1.);
+    }
+
+    #[test]
+    fn empty_range_stays_at_the_minimum() {
+        let range = range(5., 5., 1.);
+        assert_eq!(range.snap(7.), 5.);
+        assert_eq!(range.fraction(5.), 0.);
+        assert_eq!(range.value_at(0.8), 5.);
+    }
+
+    #[test]
+    fn inverted_range_collapses_to_the_minimum() {
+        let range = range(5., 0., 1.);
+        assert_eq!(range.snap(3.), 5.);
+    }
+
+    #[test]
+    fn nearest_thumb_is_picked_from_either_side() {
+        let range = range(0., 100., 1.);
+        assert_eq!(range.nearest_thumb((20., 60.), 5.), Thumb::Lower);
+        assert_eq!(range.nearest_thumb((20., 60.), 30.), Thumb::Lower);
+        assert_eq!(range.nearest_thumb((20., 60.), 50.), Thumb::Upper);
+        assert_eq!(range.nearest_thumb((20., 60.), 90.), Thumb::Upper);
+    }
+
+    #[test]
+    fn nearest_thumb_ties_go_to_the_lower_one() {
+        let range = range(0., 100., 1.);
+        assert_eq!(range.nearest_thumb((20., 60.), 40.), Thumb::Lower);
+        assert_eq!(range.nearest_thumb((50., 50.), 50.), Thumb::Lower);
+    }
+
+    #[test]
+    fn thumbs_cannot_cross() {
+        let range = range(0., 100., 1.);
+        assert_eq!(
+            range.move_thumb((20., 60.), Thumb::Lower, 80.),
+            ((60., 60.), Thumb::Lower)
+        );
+        assert_eq!(
+            range.move_thumb((20., 60.), Thumb::Upper, 10.),
+            ((20., 20.), Thumb::Upper)
+        );
+    }
+
+    #[test]
+    fn moving_a_thumb_snaps_it() {
+        let range = range(0., 100., 5.);
+        assert_eq!(
+            range.move_thumb((20., 60.), Thumb::Lower, 33.),
+            ((35., 60.), Thumb::Lower)
+        );
+    }
+
+    #[test]
+    fn stacked_thumbs_separate_in_the_direction_of_the_move() {
+        let range = range(0., 100., 1.);
+        assert_eq!(
+            range.move_thumb((50., 50.), Thumb::Lower, 70.),
+            ((50., 70.), Thumb::Upper)
+        );
+        assert_eq!(
+            range.move_thumb((50., 50.), Thumb::Upper, 30.),
+            ((30., 50.), Thumb::Lower)
+        );
+    }
+
+    #[test]
+    fn dragging_the_span_moves_both_thumbs() {
+        let range = range(0., 100., 1.);
+        assert_eq!(range.move_span((20., 60.), 15.), (35., 75.));
+        assert_eq!(range.move_span((20., 60.), -5.), (15., 55.));
+    }
+
+    #[test]
+    fn dragging_the_span_keeps_its_width_at_the_bounds() {
+        let range = range(0., 100., 1.);
+        assert_eq!(range.move_span((20., 60.), 70.), (60., 100.));
+        assert_eq!(range.move_span((20., 60.), -70.), (0., 40.));
+    }
+
+    #[test]
+    fn pairs_are_snapped_and_ordered() {
+        let range = range(0., 10., 1.);
+        assert_eq!(range.snap_pair((7.6, 2.2)), (2., 8.));
+        assert_eq!(range.snap_pair((-3., 14.)), (0., 10.));
+    }
+}
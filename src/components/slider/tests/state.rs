@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod state {
+    use crate::components::slider::{RangeSliderState, SliderState};
+    use gpui::{AppContext, TestAppContext};
+
+    #[gpui::test]
+    fn builders_apply_in_any_order(cx: &mut TestAppContext) {
+        let unstepped = cx.new(|cx| SliderState::new(cx).value(150.).max(200.));
+        let value_first = cx.new(|cx| SliderState::new(cx).value(150.).max(200.).step(20.));
+        let bounds_first = cx.new(|cx| SliderState::new(cx).step(20.).max(200.).value(150.));
+        cx.read(|cx| {
+            assert_eq!(unstepped.read(cx).current_value(), 150.);
+            assert_eq!(value_first.read(cx).current_value(), 160.);
+            assert_eq!(bounds_first.read(cx).current_value(), 160.);
+        });
+    }
+
+    #[gpui::test]
+    fn value_is_clamped_to_the_final_bounds(cx: &mut TestAppContext) {
+        let above = cx.new(|cx| SliderState::new(cx).value(150.).max(120.));
+        let below = cx.new(|cx| SliderState::new(cx).value(10.).min(50.));
+        cx.read(|cx| {
+            assert_eq!(above.read(cx).current_value(), 120.);
+            assert_eq!(below.read(cx).current_value(), 50.);
+        });
+    }
+
+    #[gpui::test]
+    fn range_builders_apply_in_any_order(cx: &mut TestAppContext) {
+        let value_first = cx.new(|cx| RangeSliderState::new(cx).value((150., 20.)).max(200.));
+        let bounds_first = cx.new(|cx| RangeSliderState::new(cx).max(200.).value((150., 20.)));
+        let whole_range = cx.new(|cx| RangeSliderState::new(cx).min(-50.).max(200.));
+        cx.read(|cx| {
+            assert_eq!(value_first.read(cx).current_value(), (20., 150.));
+            assert_eq!(bounds_first.read(cx).current_value(), (20., 150.));
+            assert_eq!(whole_range.read(cx).current_value(), (-50., 200.));
+        });
+    }
+}
@@ -0,0 +1,55 @@
+use std::{f32::consts::TAU, time::Duration};
+
+/// How many dots make up the ring of a spinner
+pub const SPINNER_DOTS: usize = 8;
+
+/// The ring of a spinner, resolved from its size and dot thickness
+///
+/// The dots sit evenly around a circle that fits in a `size` square, starting at the top
+/// and going clockwise. The thickness defaults to a sixth of the size and is kept to a
+/// quarter of it, so neighboring dots never overlap.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpinnerMetrics {
+    pub size: f32,
+    pub thickness: f32,
+}
+
+impl SpinnerMetrics {
+    pub fn new(size: f32, thickness: Option<f32>) -> Self {
+        let size = size.max(0.);
+        Self {
+            size,
+            thickness: thickness.unwrap_or(size / 6.).clamp(0., size / 4.),
+        }
+    }
+
+    /// The distance from the center of the ring to the center of each dot
+    pub fn radius(&self) -> f32 {
+        (self.size - self.thickness) / 2.
+    }
+
+    /// The top-left corner of dot `ix`, from the top-left of the ring
+    pub fn dot_origin(&self, ix: usize) -> (f32, f32) {
+        let angle = TAU * ix as f32 / SPINNER_DOTS as f32;
+        let center = self.size / 2.;
+        let offset = self.thickness / 2.;
+        (
+            center + self.radius() * angle.sin() - offset,
+            center - self.radius() * angle.cos() - offset,
+        )
+    }
+
+    /// The opacity of dot `ix` when the animation is `delta` of the way through a turn
+    ///
+    /// The leading dot is opaque and the ones behind it fade out, so the ring appears to
+    /// turn as the lead moves on.
+    pub fn dot_opacity(ix: usize, delta: f32) -> f32 {
+        let age = (delta - ix as f32 / SPINNER_DOTS as f32).rem_euclid(1.);
+        1. - 0.75 * age
+    }
+
+    /// How long one turn takes at `speed` turns per second, or `None` when it doesn't turn
+    pub fn turn_duration(speed: f32) -> Option<Duration> {
+        (speed > 0. && speed.is_finite()).then(|| Duration::from_secs_f32(1. / speed))
+    }
+}
@@ -0,0 +1,126 @@
+use gpui::*;
+
+mod metrics;
+#[cfg(test)]
+mod tests;
+
+use metrics::{SPINNER_DOTS, SpinnerMetrics};
+
+/// A loading indicator: a ring of dots with a bright lead that circles around.
+///
+/// The spinner is 16px square by default, with dots a sixth of its size, in the current
+/// text color, turning once a second. With reduced motion it holds still, showing the
+/// ring with its fading trail as a static busy indicator.
+///
+/// # Examples
+///
+/// ```rust
+/// Spinner::new()
+///     .size(px(24))
+///     .thickness(px(3))
+///     .color(rgb(0x3b82f6))
+///     .speed(1.5)
+/// ```
+#[derive(IntoElement)]
+pub struct Spinner {
+    size: Pixels,
+    thickness: Option<Pixels>,
+    color: Option<Hsla>,
+    speed: f32,
+    reduced_motion: bool,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Spinner {
+    /// Creates a 16px spinner that turns once a second.
+    pub fn new() -> Self {
+        Self {
+            size: px(16.),
+            thickness: None,
+            color: None,
+            speed: 1.,
+            reduced_motion: false,
+        }
+    }
+
+    /// Sets the width and height of the ring.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Sets the diameter of each dot, at most a quarter of the size.
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = Some(thickness.into());
+        self
+    }
+
+    /// Sets the color of the dots instead of using the text color.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets how many turns the spinner makes per second.
+    ///
+    /// # Arguments
+    ///
+    /// * `speed` - Turns per second, where zero or less holds the spinner still
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets whether to hold the spinner still.
+    ///
+    /// # Arguments
+    ///
+    /// * `reduced_motion` - Whether to show the static ring instead of turning it
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+}
+
+impl RenderOnce for Spinner {
+    fn render(self, window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let metrics = SpinnerMetrics::new(
+            self.size.into(),
+            self.thickness.map(|thickness| thickness.into()),
+        );
+        let color = self.color.unwrap_or_else(|| window.text_style().color);
+        let turn = SpinnerMetrics::turn_duration(self.speed).filter(|_| !self.reduced_motion);
+
+        div()
+            .relative()
+            .flex_none()
+            .size(self.size)
+            .children((0..SPINNER_DOTS).map(|ix| {
+                let (left, top) = metrics.dot_origin(ix);
+                let dot = div()
+                    .absolute()
+                    .left(px(left))
+                    .top(px(top))
+                    .size(px(metrics.thickness))
+                    .rounded_full()
+                    .bg(color);
+                match turn {
+                    Some(turn) => dot
+                        .with_animation(
+                            ("spinner-dot", ix),
+                            Animation::new(turn).repeat(),
+                            move |this, delta| this.opacity(SpinnerMetrics::dot_opacity(ix, delta)),
+                        )
+                        .into_any_element(),
+                    None => dot
+                        .opacity(SpinnerMetrics::dot_opacity(ix, 0.))
+                        .into_any_element(),
+                }
+            }))
+    }
+}
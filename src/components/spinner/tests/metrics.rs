@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod metrics {
+    use crate::components::spinner::metrics::{SPINNER_DOTS, SpinnerMetrics};
+    use std::time::Duration;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn thickness_defaults_to_a_sixth_of_the_size() {
+        for (size, thickness, radius) in [(12., 2., 5.), (18., 3., 7.5), (48., 8., 20.)] {
+            let metrics = SpinnerMetrics::new(size, None);
+            assert_eq!(metrics.size, size);
+            assert_close(metrics.thickness, thickness);
+            assert_close(metrics.radius(), radius);
+        }
+    }
+
+    #[test]
+    fn thickness_is_kept_to_a_quarter_of_the_size() {
+        assert_eq!(SpinnerMetrics::new(16., Some(3.)).thickness, 3.);
+        assert_eq!(SpinnerMetrics::new(16., Some(10.)).thickness, 4.);
+        assert_eq!(SpinnerMetrics::new(16., Some(-1.)).thickness, 0.);
+    }
+
+    #[test]
+    fn negative_sizes_resolve_to_nothing() {
+        let metrics = SpinnerMetrics::new(-8., Some(2.));
+        assert_eq!(metrics.size, 0.);
+        assert_eq!(metrics.thickness, 0.);
+    }
+
+    #[test]
+    fn dots_go_clockwise_from_the_top_inside_the_ring() {
+        let metrics = SpinnerMetrics::new(24., Some(4.));
+        let (x, y) = metrics.dot_origin(0);
+        assert_close(x, 10.);
+        assert_close(y, 0.);
+        let (x, y) = metrics.dot_origin(SPINNER_DOTS / 4);
+        assert_close(x, 20.);
+        assert_close(y, 10.);
+
+        for size in [12., 16., 32., 64.] {
+            let metrics = SpinnerMetrics::new(size, None);
+            for ix in 0..SPINNER_DOTS {
+                let (x, y) = metrics.dot_origin(ix);
+                for edge in [x, y] {
+                    assert!(edge >= -1e-4 && edge + metrics.thickness <= size + 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn the_leading_dot_is_opaque_and_the_trail_fades() {
+        assert_close(SpinnerMetrics::dot_opacity(0, 0.), 1.);
+        assert_close(
+            SpinnerMetrics::dot_opacity(SPINNER_DOTS - 1, 0.),
+            1. - 0.75 / 8.,
+        );
+        assert_close(SpinnerMetrics::dot_opacity(1, 0.), 1. - 0.75 * 7. / 8.);
+        // Half a turn later the lead is across the ring
+        assert_close(SpinnerMetrics::dot_opacity(SPINNER_DOTS / 2, 0.5), 1.);
+    }
+
+    #[test]
+    fn speed_sets_the_turn_duration() {
+        assert_eq!(
+            SpinnerMetrics::turn_duration(1.),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            SpinnerMetrics::turn_duration(2.),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(SpinnerMetrics::turn_duration(0.), None);
+        assert_eq!(SpinnerMetrics::turn_duration(f32::INFINITY), None);
+    }
+}
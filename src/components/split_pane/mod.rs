@@ -0,0 +1,162 @@
+use gpui::{prelude::FluentBuilder, *};
+
+mod split;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use split::Pane;
+pub use state::*;
+
+/// Two panes side by side or stacked, with a divider between them that resizes them.
+///
+/// The ratio, the size limits of the panes and the drag live in a [`SplitPaneState`].
+/// Dragging the divider resizes the panes within their limits, and with a
+/// [`collapsible`](SplitPaneState::collapsible) pane, a double click on the divider
+/// collapses that pane and another one restores it. Persist the ratio reported by
+/// [`on_resize`](SplitPaneState::on_resize) and pass it back through
+/// [`ratio`](SplitPaneState::ratio) to restore the layout.
+///
+/// # Examples
+///
+/// ```rust
+/// let state = cx.new(|_| {
+///     SplitPaneState::new()
+///         .ratio(settings.sidebar_ratio)
+///         .min_size(Pane::First, px(160))
+///         .min_size(Pane::Second, px(320))
+///         .collapsible(Pane::First)
+///         .on_resize(|ratio, _window, cx| save_sidebar_ratio(*ratio, cx))
+/// });
+///
+/// SplitPane::new(state)
+///     .size_full()
+///     .first(sidebar)
+///     .second(editor)
+///     .divider(|divider| divider.bg(rgb(0xe5e7eb)).hover(|this| this.bg(rgb(0x3b82f6))))
+/// ```
+#[derive(IntoElement)]
+pub struct SplitPane {
+    base: Div,
+    state: Entity<SplitPaneState>,
+    first: Option<AnyElement>,
+    second: Option<AnyElement>,
+    divider: Div,
+}
+
+impl SplitPane {
+    /// Creates a split pane for the given state, with both panes empty.
+    pub fn new(state: Entity<SplitPaneState>) -> Self {
+        Self {
+            base: div(),
+            state,
+            first: None,
+            second: None,
+            divider: div(),
+        }
+    }
+
+    /// Sets the content of the left or top pane.
+    pub fn first(mut self, first: impl IntoElement) -> Self {
+        self.first = Some(first.into_any_element());
+        self
+    }
+
+    /// Sets the content of the right or bottom pane.
+    pub fn second(mut self, second: impl IntoElement) -> Self {
+        self.second = Some(second.into_any_element());
+        self
+    }
+
+    /// Customizes the divider between the panes, which is as thick as the
+    /// [`divider_size`](SplitPaneState::divider_size) of the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the divider, applying modifications
+    pub fn divider(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.divider = handler(self.divider);
+        self
+    }
+}
+
+impl Styled for SplitPane {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for SplitPane {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = self.state.read(app);
+        let horizontal = state.axis() == Axis::Horizontal;
+        // Before the container is laid out, size the first pane by the ratio alone
+        let first_size = state
+            .first_size()
+            .map_or(relative(state.current_ratio()), Into::into);
+        let divider_size = state.divider_size;
+
+        let tracked = self.state.clone();
+        // Keep the container bounds for mapping mouse positions to pane sizes
+        let bounds = canvas(
+            move |bounds, _, app| tracked.update(app, |state, _| state.last_bounds = Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let first = div()
+            .flex_none()
+            .overflow_hidden()
+            .map(|this| {
+                if horizontal {
+                    this.h_full().w(first_size)
+                } else {
+                    this.w_full().h(first_size)
+                }
+            })
+            .children(self.first);
+        let divider = self
+            .divider
+            .flex_none()
+            .map(|this| {
+                if horizontal {
+                    this.h_full()
+                        .w(divider_size)
+                        .cursor(CursorStyle::ResizeLeftRight)
+                } else {
+                    this.w_full()
+                        .h(divider_size)
+                        .cursor(CursorStyle::ResizeUpDown)
+                }
+            })
+            .on_mouse_down(
+                MouseButton::Left,
+                window.listener_for(&self.state, SplitPaneState::on_divider_mouse_down),
+            );
+        let second = div()
+            .flex_1()
+            .min_w(px(0.))
+            .min_h(px(0.))
+            .overflow_hidden()
+            .children(self.second);
+
+        self.base
+            .relative()
+            .flex()
+            .when(!horizontal, |this| this.flex_col())
+            .child(bounds)
+            .child(first)
+            .child(divider)
+            .child(second)
+            .on_mouse_up(
+                MouseButton::Left,
+                window.listener_for(&self.state, SplitPaneState::on_mouse_up),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                window.listener_for(&self.state, SplitPaneState::on_mouse_up),
+            )
+            .on_mouse_move(window.listener_for(&self.state, SplitPaneState::on_mouse_move))
+    }
+}
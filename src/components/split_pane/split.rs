@@ -0,0 +1,108 @@
+/// One of the two panes of a split
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pane {
+    /// The left or top pane
+    First,
+    /// The right or bottom pane
+    Second,
+}
+
+/// The smallest and largest size of a pane
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PaneLimits {
+    pub min: f32,
+    pub max: Option<f32>,
+}
+
+/// How the space of a split is shared between its panes
+///
+/// The split keeps the ratio of the space given to the first pane, and sizes the panes
+/// from it once the space is known, within the limits of both panes. So the ratio a
+/// caller persists is the one set by dragging, even when a small window squeezes a pane
+/// to its minimum. A collapsible pane can be shrunk to nothing past its minimum, and
+/// restored to the ratio it had before.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Split {
+    pub first: PaneLimits,
+    pub second: PaneLimits,
+    pub collapsible: Option<Pane>,
+    ratio: f32,
+    collapsed: bool,
+}
+
+impl Split {
+    /// Create a split giving `ratio` of the space to the first pane
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            first: PaneLimits::default(),
+            second: PaneLimits::default(),
+            collapsible: None,
+            ratio: ratio.clamp(0., 1.),
+            collapsed: false,
+        }
+    }
+
+    /// The share of the space given to the first pane, from 0 to 1, or 0 or 1 while a
+    /// pane is collapsed
+    pub fn ratio(&self) -> f32 {
+        match self.collapsed_pane() {
+            Some(Pane::First) => 0.,
+            Some(Pane::Second) => 1.,
+            None => self.ratio,
+        }
+    }
+
+    /// Give `ratio` of the space to the first pane, restoring a collapsed pane
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.clamp(0., 1.);
+        self.collapsed = false;
+    }
+
+    pub fn collapsed_pane(&self) -> Option<Pane> {
+        self.collapsible.filter(|_| self.collapsed)
+    }
+
+    /// The size of the first pane in `total`, within the limits of both panes
+    ///
+    /// The first pane's limits win when both can't be met.
+    pub fn first_size(&self, total: f32) -> f32 {
+        match self.collapsed_pane() {
+            Some(Pane::First) => return 0.,
+            Some(Pane::Second) => return total.max(0.),
+            None => {}
+        }
+        // The first pane grows to keep the second one within its maximum
+        let second_max = self.second.max.map_or(0., |max| total - max);
+        let first_max = self.first.max.unwrap_or(total);
+        (self.ratio * total)
+            .min(total - self.second.min)
+            .max(second_max)
+            .min(first_max)
+            .max(self.first.min)
+            .clamp(0., total.max(0.))
+    }
+
+    /// Follow a drag that puts the divider `offset` from the start of `total`, returning
+    /// whether the ratio changed
+    ///
+    /// The ratio stays within the limits of both panes, and dragging a collapsed pane
+    /// restores it.
+    pub fn drag_to(&mut self, offset: f32, total: f32) -> bool {
+        if total <= 0. {
+            return false;
+        }
+        let previous = self.ratio();
+        self.set_ratio(offset / total);
+        self.ratio = self.first_size(total) / total;
+        self.ratio() != previous
+    }
+
+    /// Collapse the collapsible pane, or restore it, returning whether anything changed
+    pub fn toggle_collapsed(&mut self) -> bool {
+        if self.collapsible.is_none() {
+            return false;
+        }
+        self.collapsed = !self.collapsed;
+        true
+    }
+}
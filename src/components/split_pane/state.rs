@@ -0,0 +1,199 @@
+use crate::components::split_pane::split::{Pane, Split};
+use gpui::*;
+
+/// The state of a [`SplitPane`](super::SplitPane)
+///
+/// Handles the split ratio, the size limits of the panes, dragging the divider and
+/// collapsing a pane with a double click on it.
+#[allow(clippy::type_complexity)]
+pub struct SplitPaneState {
+    axis: Axis,
+    split: Split,
+    pub(super) divider_size: Pixels,
+    dragging: bool,
+    /// The bounds of the container, for mapping mouse positions to pane sizes
+    pub(super) last_bounds: Option<Bounds<Pixels>>,
+    on_resize: Option<Box<dyn Fn(&f32, &mut Window, &mut Context<Self>)>>,
+}
+
+impl Default for SplitPaneState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SplitPaneState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new SplitPaneState with two panes side by side, sharing the space evenly
+    pub fn new() -> Self {
+        Self {
+            axis: Axis::Horizontal,
+            split: Split::new(0.5),
+            divider_size: px(4.),
+            dragging: false,
+            last_bounds: None,
+            on_resize: None,
+        }
+    }
+
+    /// Stack the panes, the first above the second
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.axis = if vertical {
+            Axis::Vertical
+        } else {
+            Axis::Horizontal
+        };
+        self
+    }
+
+    /// Set the initial share of the space given to the first pane, from 0 to 1, like one
+    /// persisted from [`on_resize`](Self::on_resize)
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.split.set_ratio(ratio);
+        self
+    }
+
+    /// Set the smallest size of `pane`
+    pub fn min_size(mut self, pane: Pane, size: impl Into<Pixels>) -> Self {
+        let size = f32::from(size.into());
+        match pane {
+            Pane::First => self.split.first.min = size,
+            Pane::Second => self.split.second.min = size,
+        }
+        self
+    }
+
+    /// Set the largest size of `pane`
+    pub fn max_size(mut self, pane: Pane, size: impl Into<Pixels>) -> Self {
+        let size = Some(f32::from(size.into()));
+        match pane {
+            Pane::First => self.split.first.max = size,
+            Pane::Second => self.split.second.max = size,
+        }
+        self
+    }
+
+    /// Let a double click on the divider collapse `pane` past its minimum size, and
+    /// another one restore it
+    pub fn collapsible(mut self, pane: Pane) -> Self {
+        self.split.collapsible = Some(pane);
+        self
+    }
+
+    /// Set the thickness of the divider, 4px by default
+    pub fn divider_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.divider_size = size.into();
+        self
+    }
+
+    /// Call `on_resize` with the share of the space given to the first pane whenever it
+    /// changes, including every step of a drag and collapsing or restoring a pane
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(&f32, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// The share of the space given to the first pane, 0 or 1 while a pane is collapsed
+    pub fn current_ratio(&self) -> f32 {
+        self.split.ratio()
+    }
+
+    /// Set the share of the space given to the first pane without calling `on_resize`,
+    /// restoring a collapsed pane
+    pub fn set_ratio(&mut self, ratio: f32, cx: &mut Context<Self>) {
+        self.split.set_ratio(ratio);
+        cx.notify();
+    }
+
+    /// The pane collapsed with a double click on the divider, if any
+    pub fn collapsed_pane(&self) -> Option<Pane> {
+        self.split.collapsed_pane()
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// The space shared by the panes in the container laid out last, without the divider
+    fn total(&self) -> Option<f32> {
+        let bounds = self.last_bounds?;
+        Some(f32::from(bounds.size.along(self.axis) - self.divider_size))
+    }
+
+    /// The size of the first pane in the container laid out last
+    pub(super) fn first_size(&self) -> Option<Pixels> {
+        self.total().map(|total| px(self.split.first_size(total)))
+    }
+
+    fn resized(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(on_resize) = &self.on_resize {
+            on_resize(&self.split.ratio(), window, cx);
+        }
+        cx.notify();
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// Start a drag, or collapse or restore the collapsible pane on a double click
+    pub(super) fn on_divider_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        cx.stop_propagation();
+        if event.click_count == 2 {
+            if self.split.toggle_collapsed() {
+                self.resized(window, cx);
+            }
+            return;
+        }
+        self.dragging = true;
+        cx.notify();
+    }
+
+    pub(super) fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.dragging {
+            return;
+        }
+        let (Some(bounds), Some(total)) = (self.last_bounds, self.total()) else {
+            return;
+        };
+        // Center the divider on the pointer
+        let offset = event.position.along(self.axis)
+            - bounds.origin.along(self.axis)
+            - self.divider_size / 2.;
+        if self.split.drag_to(f32::from(offset), total) {
+            self.resized(window, cx);
+        }
+    }
+
+    /// End a drag
+    pub(super) fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if self.dragging {
+            self.dragging = false;
+            cx.notify();
+        }
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod split {
+    use crate::components::split_pane::split::{Pane, PaneLimits, Split};
+
+    fn limited() -> Split {
+        let mut split = Split::new(0.5);
+        split.first = PaneLimits {
+            min: 100.,
+            max: Some(600.),
+        };
+        split.second = PaneLimits {
+            min: 200.,
+            max: None,
+        };
+        split
+    }
+
+    #[test]
+    fn sizes_the_first_pane_from_the_ratio() {
+        let split = Split::new(0.25);
+        assert_eq!(split.first_size(800.), 200.);
+        assert_eq!(split.first_size(400.), 100.);
+        assert_eq!(split.first_size(0.), 0.);
+    }
+
+    #[test]
+    fn drags_follow_the_pointer() {
+        let mut split = Split::new(0.5);
+        assert!(split.drag_to(300., 1000.));
+        assert_eq!(split.ratio(), 0.3);
+        assert_eq!(split.first_size(1000.), 300.);
+        // The same position again changes nothing
+        assert!(!split.drag_to(300., 1000.));
+    }
+
+    #[test]
+    fn drags_stop_at_the_limits_of_either_pane() {
+        let mut split = limited();
+        split.drag_to(20., 1000.);
+        assert_eq!(split.first_size(1000.), 100.);
+        assert_eq!(split.ratio(), 0.1);
+
+        split.drag_to(950., 1000.);
+        // Stopped by the first pane's maximum before the second one's minimum
+        assert_eq!(split.first_size(1000.), 600.);
+        split.drag_to(950., 2000.);
+        assert_eq!(split.first_size(2000.), 600.);
+
+        split.drag_to(750., 900.);
+        assert_eq!(split.first_size(900.), 600.);
+        split.drag_to(850., 700.);
+        assert_eq!(split.first_size(700.), 500.);
+    }
+
+    #[test]
+    fn keeps_the_ratio_when_the_space_shrinks() {
+        let mut split = limited();
+        split.drag_to(600., 1000.);
+        // Squeezed by the second pane's minimum, without forgetting the ratio
+        assert_eq!(split.first_size(400.), 200.);
+        assert_eq!(split.ratio(), 0.6);
+        assert_eq!(split.first_size(1000.), 600.);
+    }
+
+    #[test]
+    fn the_first_panes_minimum_wins_when_both_cant_fit() {
+        let split = limited();
+        assert_eq!(split.first_size(250.), 100.);
+        assert_eq!(split.first_size(50.), 50.);
+    }
+
+    #[test]
+    fn the_second_panes_maximum_grows_the_first() {
+        let mut split = Split::new(0.2);
+        split.second.max = Some(300.);
+        assert_eq!(split.first_size(1000.), 700.);
+        assert_eq!(split.first_size(400.), 100.);
+    }
+
+    #[test]
+    fn double_clicks_collapse_and_restore() {
+        let mut split = limited();
+        split.collapsible = Some(Pane::First);
+        split.drag_to(400., 1000.);
+
+        assert!(split.toggle_collapsed());
+        assert_eq!(split.collapsed_pane(), Some(Pane::First));
+        // Past the first pane's minimum
+        assert_eq!(split.first_size(1000.), 0.);
+        assert_eq!(split.ratio(), 0.);
+
+        assert!(split.toggle_collapsed());
+        assert_eq!(split.collapsed_pane(), None);
+        assert_eq!(split.first_size(1000.), 400.);
+        assert_eq!(split.ratio(), 0.4);
+    }
+
+    #[test]
+    fn the_second_pane_collapses_to_the_end() {
+        let mut split = limited();
+        split.collapsible = Some(Pane::Second);
+        split.toggle_collapsed();
+        assert_eq!(split.first_size(1000.), 1000.);
+        assert_eq!(split.ratio(), 1.);
+    }
+
+    #[test]
+    fn only_collapsible_splits_collapse() {
+        let mut split = limited();
+        assert!(!split.toggle_collapsed());
+        assert_eq!(split.collapsed_pane(), None);
+        assert_eq!(split.first_size(1000.), 500.);
+    }
+
+    #[test]
+    fn dragging_restores_a_collapsed_pane() {
+        let mut split = limited();
+        split.collapsible = Some(Pane::First);
+        split.toggle_collapsed();
+        assert!(split.drag_to(300., 1000.));
+        assert_eq!(split.collapsed_pane(), None);
+        assert_eq!(split.first_size(1000.), 300.);
+    }
+}
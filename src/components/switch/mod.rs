@@ -1,5 +1,11 @@
 use crate::{Disableable, primitives::h_flex};
 use gpui::{prelude::FluentBuilder, *};
+use motion::SwitchMotion;
+use std::{rc::Rc, time::Duration};
+
+mod motion;
+#[cfg(test)]
+mod tests;
 
 /// A toggle switch component that allows users to switch between on/off states.
 ///
@@ -18,6 +24,18 @@ use gpui::{prelude::FluentBuilder, *};
 ///     })
 /// ```
 ///
+/// With a thumb that slides across the track:
+/// ```rust
+/// Switch::new("animated-switch")
+///     .checked(self.enabled)
+///     .w(px(36))
+///     .p(px(2))
+///     .rounded_full()
+///     .thumb(|thumb| thumb.size(px(16)).rounded_full().bg(rgb(0xffffff)))
+///     .thumb_travel(px(16))
+///     .reduced_motion(self.prefers_reduced_motion)
+/// ```
+///
 /// With custom styling and thumb:
 /// ```rust
 /// Switch::new("styled-switch")
@@ -44,8 +62,10 @@ pub struct Switch {
     base: Stateful<Div>,
     disabled: bool,
     checked: bool,
-    on_change: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
     thumb: SwitchThumb,
+    thumb_travel: Option<Pixels>,
+    motion: SwitchMotion,
     when_checked_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
 }
 
@@ -70,6 +90,8 @@ impl Switch {
             checked: false,
             on_change: None,
             thumb: SwitchThumb::new(),
+            thumb_travel: None,
+            motion: SwitchMotion::default(),
             when_checked_handler: None,
         }
     }
@@ -110,6 +132,67 @@ impl Switch {
         self
     }
 
+    /// Makes the thumb slide across the track when the switch is toggled.
+    ///
+    /// The thumb sits at its place in the track while off and is offset by `travel` while
+    /// on. It slides between the two with a short eased animation, unless reduced motion
+    /// is requested. Without a travel the thumb is left where it is laid out.
+    ///
+    /// # Arguments
+    ///
+    /// * `travel` - How far the thumb moves from the off to the on position
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let switch = Switch::new("my-switch")
+    ///     .w(px(36))
+    ///     .thumb(|thumb| thumb.size(px(16)))
+    ///     .thumb_travel(px(20));
+    /// ```
+    pub fn thumb_travel(mut self, travel: impl Into<Pixels>) -> Self {
+        self.thumb_travel = Some(travel.into());
+        self
+    }
+
+    /// Sets how long the thumb takes to slide, 150ms by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The length of the animation, where zero makes the thumb jump
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let switch = Switch::new("my-switch")
+    ///     .thumb_travel(px(20))
+    ///     .animation_duration(Duration::from_millis(250));
+    /// ```
+    pub fn animation_duration(mut self, duration: Duration) -> Self {
+        self.motion = self.motion.with_duration(duration);
+        self
+    }
+
+    /// Makes the thumb jump between positions instead of sliding.
+    ///
+    /// Set it from the user's reduced motion preference.
+    ///
+    /// # Arguments
+    ///
+    /// * `reduced_motion` - Whether to skip the thumb animation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let switch = Switch::new("my-switch")
+    ///     .thumb_travel(px(20))
+    ///     .reduced_motion(true);
+    /// ```
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.motion = self.motion.with_reduced_motion(reduced_motion);
+        self
+    }
+
     /// Conditionally applies styling or modifications when the switch is checked.
     ///
     /// This method allows you to apply different styles or properties based on the
@@ -133,7 +216,8 @@ impl Switch {
 
     /// Sets a callback function that is called when the switch state changes.
     ///
-    /// The callback receives the new checked state as a boolean value. This callback
+    /// The callback receives the new checked state as a boolean value. When the switch
+    /// tracks a focus handle and is focused, releasing Space toggles it too. This callback
     /// will not be triggered if the switch is disabled.
     ///
     /// # Arguments
@@ -156,7 +240,7 @@ impl Switch {
     where
         F: Fn(&bool, &mut Window, &mut App) + 'static,
     {
-        self.on_change = Some(Box::new(on_change));
+        self.on_change = Some(Rc::new(on_change));
         self
     }
 }
@@ -194,18 +278,47 @@ impl RenderOnce for Switch {
             }
         }
 
+        let checked = self.checked;
+        let motion = self.motion;
+        let thumb = match (self.thumb_travel, motion.duration()) {
+            (None, _) => self.thumb.into_any_element(),
+            (Some(travel), None) => self
+                .thumb
+                .left(travel * motion.position(checked, 1.))
+                .into_any_element(),
+            (Some(travel), Some(duration)) => self
+                .thumb
+                .with_animation(
+                    ("switch-thumb", checked as u32),
+                    Animation::new(duration).with_easing(ease_in_out),
+                    move |thumb, delta| thumb.left(travel * motion.position(checked, delta)),
+                )
+                .into_any_element(),
+        };
+
         self.base
             .when_some(
                 self.on_change.filter(|_| !self.disabled),
                 |this, on_click| {
+                    let on_key_up = on_click.clone();
                     this.on_click(move |_, window, cx| {
                         cx.stop_propagation();
-                        let checked = !self.checked;
-                        on_click(&checked, window, cx);
+                        on_click(&!checked, window, cx);
+                    })
+                    .on_key_down(|event, _window, cx| {
+                        if event.keystroke.key == "space" {
+                            cx.stop_propagation();
+                        }
+                    })
+                    .on_key_up(move |event, window, cx| {
+                        if event.keystroke.key == "space" {
+                            cx.stop_propagation();
+                            on_key_up(&!checked, window, cx);
+                        }
                     })
                 },
             )
-            .child(self.thumb)
+            .child(thumb)
     }
 }
 
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+/// How the thumb of a [`Switch`](super::Switch) moves between its positions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwitchMotion {
+    duration: Duration,
+    reduced_motion: bool,
+}
+
+impl Default for SwitchMotion {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(150),
+            reduced_motion: false,
+        }
+    }
+}
+
+impl SwitchMotion {
+    pub fn with_duration(self, duration: Duration) -> Self {
+        Self { duration, ..self }
+    }
+
+    pub fn with_reduced_motion(self, reduced_motion: bool) -> Self {
+        Self {
+            reduced_motion,
+            ..self
+        }
+    }
+
+    /// Returns how long the thumb slides for, or `None` when it should jump instead.
+    pub fn duration(&self) -> Option<Duration> {
+        (!self.reduced_motion && !self.duration.is_zero()).then_some(self.duration)
+    }
+
+    /// Returns the position of the thumb, from 0 when off to 1 when on, `delta` of the
+    /// way into sliding to the `checked` position.
+    pub fn position(&self, checked: bool, delta: f32) -> f32 {
+        let delta = delta.clamp(0., 1.);
+        if checked { delta } else { 1. - delta }
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod motion {
+    use crate::components::switch::motion::SwitchMotion;
+    use std::time::Duration;
+
+    #[test]
+    fn thumb_slides_by_default() {
+        let motion = SwitchMotion::default();
+        assert_eq!(motion.duration(), Some(Duration::from_millis(150)));
+    }
+
+    #[test]
+    fn reduced_motion_disables_the_tween() {
+        let motion = SwitchMotion::default().with_reduced_motion(true);
+        assert_eq!(motion.duration(), None);
+    }
+
+    #[test]
+    fn zero_duration_disables_the_tween() {
+        let motion = SwitchMotion::default().with_duration(Duration::ZERO);
+        assert_eq!(motion.duration(), None);
+    }
+
+    #[test]
+    fn turning_on_slides_towards_the_end() {
+        let motion = SwitchMotion::default();
+        assert_eq!(motion.position(true, 0.), 0.);
+        assert_eq!(motion.position(true, 0.25), 0.25);
+        assert_eq!(motion.position(true, 1.), 1.);
+    }
+
+    #[test]
+    fn turning_off_slides_towards_the_start() {
+        let motion = SwitchMotion::default();
+        assert_eq!(motion.position(false, 0.), 1.);
+        assert_eq!(motion.position(false, 0.25), 0.75);
+        assert_eq!(motion.position(false, 1.), 0.);
+    }
+
+    #[test]
+    fn position_stays_within_the_track() {
+        let motion = SwitchMotion::default();
+        assert_eq!(motion.position(true, 1.5), 1.);
+        assert_eq!(motion.position(false, -0.5), 1.);
+    }
+}
@@ -0,0 +1,52 @@
+use gpui::{Pixels, SharedString, px};
+
+/// A column of a [`Table`](super::Table)
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableColumn {
+    /// What the header of the column shows
+    pub label: SharedString,
+    pub width: Pixels,
+    /// How narrow resizing the column can make it
+    pub min_width: Pixels,
+    /// Whether dragging the divider at the right edge of the header resizes the column
+    pub resizable: bool,
+    /// Whether clicking the header sorts the table by the column
+    pub sortable: bool,
+}
+
+impl TableColumn {
+    /// Create a sortable and resizable column 150px wide, headed by `label`
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            width: px(150.),
+            min_width: px(40.),
+            resizable: true,
+            sortable: true,
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set how narrow resizing the column can make it, 40px by default
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into();
+        self
+    }
+
+    /// Set whether dragging the divider at the right edge of the header resizes the
+    /// column
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Set whether clicking the header sorts the table by the column
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
@@ -0,0 +1,208 @@
+use crate::primitives::{h_flex, v_flex};
+use gpui::{prelude::FluentBuilder, *};
+
+mod column;
+mod resize;
+mod sort;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use column::TableColumn;
+pub use sort::SortDirection;
+pub use state::*;
+
+/// Rows of cells under a header naming the columns.
+///
+/// Clicking the header of a sortable column sorts the rows by it, cycling from ascending
+/// to descending to unsorted, with an arrow next to the label showing the direction. The
+/// owner reorders the rows in [`on_sort`](TableState::on_sort), or the table orders them
+/// with the comparison given to [`sort_by`](TableState::sort_by).
+///
+/// Dragging the divider at the right edge of a header resizes its column, down to the
+/// [`min_width`](TableColumn::min_width) of the column, and double clicking the divider
+/// fits the column to the widest of its header and cells.
+///
+/// # Examples
+///
+/// ```rust
+/// let people = Rc::new(people);
+/// let state = cx.new(|_| {
+///     let people = people.clone();
+///     TableState::new()
+///         .columns([TableColumn::new("Name"), TableColumn::new("Age").width(px(80))])
+///         .row_count(people.len())
+///         .sort_by(move |a, b, column| match column {
+///             0 => people[a].name.cmp(&people[b].name),
+///             _ => people[a].age.cmp(&people[b].age),
+///         })
+/// });
+///
+/// Table::new(state.clone(), move |row, column, _window, _cx| match column {
+///     0 => people[row].name.clone().into_any_element(),
+///     _ => people[row].age.to_string().into_any_element(),
+/// })
+/// .header(|header| header.border_b_1().font_weight(FontWeight::BOLD))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Table {
+    base: Div,
+    state: Entity<TableState>,
+    header: Div,
+    render_cell: Box<dyn Fn(usize, usize, &mut Window, &mut App) -> AnyElement>,
+}
+
+impl Table {
+    /// Creates a table for the given state, whose cells `render_cell` builds from the
+    /// index of the row and of the column.
+    pub fn new(
+        state: Entity<TableState>,
+        render_cell: impl Fn(usize, usize, &mut Window, &mut App) -> AnyElement + 'static,
+    ) -> Self {
+        Self {
+            base: v_flex(),
+            state,
+            header: h_flex(),
+            render_cell: Box::new(render_cell),
+        }
+    }
+
+    /// Customizes the row of headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the header row, applying
+    ///   modifications
+    pub fn header(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.header = handler(self.header);
+        self
+    }
+}
+
+impl Styled for Table {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Table {
+    /// A canvas covering its parent that records the parent's width as a natural width
+    /// of `column`, for fitting the column to it
+    fn measure(state: &Entity<TableState>, column: usize) -> impl IntoElement {
+        let state = state.clone();
+        canvas(
+            move |bounds, _, app| {
+                state.update(app, |state, _| {
+                    state.record_cell_width(column, bounds.size.width)
+                })
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full()
+    }
+}
+
+impl RenderOnce for Table {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        self.state.update(app, |state, _| state.clear_cell_widths());
+        let state = self.state.read(app);
+        let columns = state.current_columns().to_vec();
+        let rows = state.rows().to_vec();
+        let directions: Vec<_> = (0..columns.len())
+            .map(|ix| state.sort_direction(ix))
+            .collect();
+
+        let headers =
+            columns
+                .iter()
+                .zip(directions)
+                .enumerate()
+                .map(|(ix, (column, direction))| {
+                    // Laid out at its natural width, so the column can be fit to it
+                    let label = h_flex()
+                        .flex_none()
+                        .relative()
+                        .items_center()
+                        .gap_1()
+                        .child(Self::measure(&self.state, ix))
+                        .child(column.label.clone())
+                        .when_some(direction, |this, direction| {
+                            this.child(
+                                div()
+                                    .debug_selector(|| format!("table-sort-{ix}-{direction:?}"))
+                                    .child(direction.indicator()),
+                            )
+                        });
+                    let divider = column.resizable.then(|| {
+                        let state = self.state.clone();
+                        div()
+                            .debug_selector(|| format!("table-divider-{ix}"))
+                            .absolute()
+                            .top_0()
+                            .right_0()
+                            .h_full()
+                            .w(px(4.))
+                            .cursor(CursorStyle::ResizeLeftRight)
+                            .on_mouse_down(MouseButton::Left, move |event, window, app| {
+                                state.update(app, |state, cx| {
+                                    state.on_divider_mouse_down(ix, event, window, cx)
+                                })
+                            })
+                    });
+                    h_flex()
+                        .id(("table-header", ix))
+                        .debug_selector(|| format!("table-header-{ix}"))
+                        .relative()
+                        .flex_none()
+                        .w(column.width)
+                        .overflow_hidden()
+                        .items_center()
+                        .child(label)
+                        .children(divider)
+                        .when(column.sortable, |this| {
+                            this.cursor_pointer().on_click(window.listener_for(
+                                &self.state,
+                                move |state, _: &ClickEvent, window, cx| {
+                                    state.toggle_sort(ix, window, cx)
+                                },
+                            ))
+                        })
+                });
+        let header = self.header.children(headers.collect::<Vec<_>>());
+
+        let body = rows.iter().enumerate().map(|(position, &row)| {
+            h_flex()
+                .debug_selector(|| format!("table-row-{position}"))
+                .children(columns.iter().enumerate().map(|(ix, column)| {
+                    h_flex()
+                        .debug_selector(|| format!("table-cell-{position}-{ix}"))
+                        .flex_none()
+                        .w(column.width)
+                        .overflow_hidden()
+                        .child(
+                            div()
+                                .flex_none()
+                                .relative()
+                                .child(Self::measure(&self.state, ix))
+                                .child((self.render_cell)(row, ix, window, app)),
+                        )
+                }))
+        });
+        let body = v_flex().children(body.collect::<Vec<_>>());
+
+        self.base
+            .child(header)
+            .child(body)
+            .on_mouse_up(
+                MouseButton::Left,
+                window.listener_for(&self.state, TableState::on_mouse_up),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                window.listener_for(&self.state, TableState::on_mouse_up),
+            )
+            .on_mouse_move(window.listener_for(&self.state, TableState::on_mouse_move))
+    }
+}
@@ -0,0 +1,24 @@
+/// A drag of the divider at the right edge of a column of a [`Table`](super::Table),
+/// resizing the column
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColumnDrag {
+    pub column: usize,
+    /// Where the pointer pressed the divider
+    pub start_x: f32,
+    /// How wide the column was then
+    pub start_width: f32,
+}
+
+impl ColumnDrag {
+    /// How wide the column is with the pointer at `x`, following the pointer but no
+    /// narrower than `min_width`
+    pub fn width_at(&self, x: f32, min_width: f32) -> f32 {
+        (self.start_width + x - self.start_x).max(min_width)
+    }
+}
+
+/// How wide a column is to fit the widest of `widths`, the natural widths of its header
+/// and cells, but no narrower than `min_width`
+pub fn fit_width(widths: impl IntoIterator<Item = f32>, min_width: f32) -> f32 {
+    widths.into_iter().fold(min_width, f32::max)
+}
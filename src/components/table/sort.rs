@@ -0,0 +1,98 @@
+use std::cmp::Ordering;
+
+/// Which way a column of a [`Table`](super::Table) is sorted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// The arrow shown next to the label of a column sorted this way
+    pub fn indicator(self) -> &'static str {
+        match self {
+            Self::Ascending => "▲",
+            Self::Descending => "▼",
+        }
+    }
+}
+
+/// The columns a table is sorted by, each with its direction, from the one compared
+/// first to the one compared last.
+///
+/// Clicking a header cycles its column from ascending to descending to unsorted. Unless
+/// sorting by several columns, the column clicked replaces the one sorted before.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableSort {
+    keys: Vec<(usize, SortDirection)>,
+}
+
+impl TableSort {
+    /// The sorted columns with their directions, the one compared first first
+    pub fn keys(&self) -> &[(usize, SortDirection)] {
+        &self.keys
+    }
+
+    /// Which way `column` is sorted, if at all
+    pub fn direction(&self, column: usize) -> Option<SortDirection> {
+        self.keys
+            .iter()
+            .find(|(key, _)| *key == column)
+            .map(|(_, direction)| *direction)
+    }
+
+    /// Move `column` on to its next direction after a click on its header, returning it
+    ///
+    /// With `multiple`, the other columns stay sorted, and a column sorted anew is
+    /// compared after them.
+    pub fn cycle(&mut self, column: usize, multiple: bool) -> Option<SortDirection> {
+        let next = match self.direction(column) {
+            None => Some(SortDirection::Ascending),
+            Some(SortDirection::Ascending) => Some(SortDirection::Descending),
+            Some(SortDirection::Descending) => None,
+        };
+        if !multiple {
+            self.keys.retain(|(key, _)| *key == column);
+        }
+        let position = self.keys.iter().position(|(key, _)| *key == column);
+        match (position, next) {
+            (Some(ix), Some(direction)) => self.keys[ix].1 = direction,
+            (Some(ix), None) => {
+                self.keys.remove(ix);
+            }
+            (None, Some(direction)) => self.keys.push((column, direction)),
+            (None, None) => {}
+        }
+        next
+    }
+
+    /// Stop sorting by the columns from `count` on, once the table has only `count`
+    pub fn truncate(&mut self, count: usize) {
+        self.keys.retain(|(key, _)| *key < count);
+    }
+
+    /// The order to show `count` rows in, as their indices
+    ///
+    /// `compare` orders two rows by a column, ascending, and rows that compare equal by
+    /// every sorted column keep their order.
+    pub fn sorted_rows(
+        &self,
+        count: usize,
+        compare: impl Fn(usize, usize, usize) -> Ordering,
+    ) -> Vec<usize> {
+        let mut rows: Vec<usize> = (0..count).collect();
+        if !self.keys.is_empty() {
+            rows.sort_by(|&a, &b| {
+                self.keys
+                    .iter()
+                    .map(|&(column, direction)| match direction {
+                        SortDirection::Ascending => compare(a, b, column),
+                        SortDirection::Descending => compare(b, a, column),
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+        rows
+    }
+}
@@ -0,0 +1,278 @@
+use crate::components::table::{
+    column::TableColumn,
+    resize::{self, ColumnDrag},
+    sort::{SortDirection, TableSort},
+};
+use gpui::*;
+use std::cmp::Ordering;
+
+/// The state of a [`Table`](super::Table)
+///
+/// Keeps the columns, how many rows there are and which columns the rows are sorted by.
+/// Clicking the header of a sortable column cycles it from ascending to descending to
+/// unsorted, and calls [`on_sort`](Self::on_sort) so the owner can reorder the rows.
+/// With a [`sort_by`](Self::sort_by) comparison, the table orders the rows itself.
+/// Only the column clicked last is sorted, unless [`multi_sort`](Self::multi_sort) lets
+/// each column clicked add to the sort.
+///
+/// Dragging the divider at the right edge of the header of a resizable column resizes it,
+/// down to its minimum width, and double clicking the divider fits the column to its
+/// widest cell. [`on_column_resize`](Self::on_column_resize) reports the new width once
+/// the drag ends, to persist it.
+#[allow(clippy::type_complexity)]
+pub struct TableState {
+    columns: Vec<TableColumn>,
+    row_count: usize,
+    sort: TableSort,
+    multi_sort: bool,
+    compare: Option<Box<dyn Fn(usize, usize, usize) -> Ordering>>,
+    /// The rows in the order they show, as their indices
+    rows: Vec<usize>,
+    drag: Option<ColumnDrag>,
+    /// The natural widths of the headers and cells of each column, as last laid out
+    cell_widths: Vec<Vec<f32>>,
+    on_sort: Option<Box<dyn Fn(usize, Option<SortDirection>, &mut Window, &mut Context<Self>)>>,
+    on_column_resize: Option<Box<dyn Fn(usize, Pixels, &mut Window, &mut Context<Self>)>>,
+}
+
+impl Default for TableState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableState {
+    // ============================================================================
+    // Constructor and Builder Methods
+    // ============================================================================
+
+    /// Create a new TableState without columns or rows
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            row_count: 0,
+            sort: TableSort::default(),
+            multi_sort: false,
+            compare: None,
+            rows: Vec::new(),
+            drag: None,
+            cell_widths: Vec::new(),
+            on_sort: None,
+            on_column_resize: None,
+        }
+    }
+
+    pub fn columns(mut self, columns: impl IntoIterator<Item = TableColumn>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self.sort.truncate(self.columns.len());
+        self.resort();
+        self
+    }
+
+    /// Set how many rows the table has
+    pub fn row_count(mut self, row_count: usize) -> Self {
+        self.row_count = row_count;
+        self.resort();
+        self
+    }
+
+    /// Let each column clicked add to the sort, compared after the columns sorted before
+    /// it, rather than replace it
+    pub fn multi_sort(mut self, multi_sort: bool) -> Self {
+        self.multi_sort = multi_sort;
+        self
+    }
+
+    /// Order the rows by the sorted columns with `compare`, which takes two rows and a
+    /// column and returns how the first row compares to the second, ascending
+    pub fn sort_by(mut self, compare: impl Fn(usize, usize, usize) -> Ordering + 'static) -> Self {
+        self.compare = Some(Box::new(compare));
+        self.resort();
+        self
+    }
+
+    /// Call `on_sort` with a column and its direction, `None` once unsorted, whenever a
+    /// click on its header sorts it
+    pub fn on_sort(
+        mut self,
+        on_sort: impl Fn(usize, Option<SortDirection>, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Call `on_column_resize` with a column and its width once a drag of its divider
+    /// ends or a double click fits it, like to persist the width and pass it back through
+    /// [`TableColumn::width`]
+    pub fn on_column_resize(
+        mut self,
+        on_column_resize: impl Fn(usize, Pixels, &mut Window, &mut Context<Self>) + 'static,
+    ) -> Self {
+        self.on_column_resize = Some(Box::new(on_column_resize));
+        self
+    }
+
+    // ============================================================================
+    // Getters and Setters
+    // ============================================================================
+
+    pub fn current_columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    /// Set the width of `column` without calling `on_column_resize`, no narrower than its
+    /// minimum width
+    pub fn set_column_width(&mut self, column: usize, width: Pixels, cx: &mut Context<Self>) {
+        if let Some(column) = self.columns.get_mut(column) {
+            column.width = width.max(column.min_width);
+            cx.notify();
+        }
+    }
+
+    pub fn is_resizing(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Forget the widths of the cells laid out before, as the table lays out again
+    pub(super) fn clear_cell_widths(&mut self) {
+        self.cell_widths = vec![Vec::new(); self.columns.len()];
+    }
+
+    pub(super) fn record_cell_width(&mut self, column: usize, width: Pixels) {
+        if let Some(widths) = self.cell_widths.get_mut(column) {
+            widths.push(f32::from(width));
+        }
+    }
+
+    fn resized(&mut self, column: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(on_column_resize) = &self.on_column_resize {
+            on_column_resize(column, self.columns[column].width, window, cx);
+        }
+        cx.notify();
+    }
+
+    pub fn current_row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Change how many rows the table has, keeping the sort
+    pub fn set_row_count(&mut self, row_count: usize, cx: &mut Context<Self>) {
+        self.row_count = row_count;
+        self.resort();
+        cx.notify();
+    }
+
+    pub fn is_multi_sort(&self) -> bool {
+        self.multi_sort
+    }
+
+    /// The sorted columns with their directions, the one compared first first
+    pub fn sort_keys(&self) -> &[(usize, SortDirection)] {
+        self.sort.keys()
+    }
+
+    /// Which way `column` is sorted, if at all
+    pub fn sort_direction(&self, column: usize) -> Option<SortDirection> {
+        self.sort.direction(column)
+    }
+
+    /// The rows in the order they show, as their indices, which are sorted only with a
+    /// [`sort_by`](Self::sort_by) comparison
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    fn resort(&mut self) {
+        self.rows = match &self.compare {
+            Some(compare) => self.sort.sorted_rows(self.row_count, compare),
+            None => (0..self.row_count).collect(),
+        };
+    }
+
+    /// Move `column` on to its next direction, like a click on its header, unless it
+    /// isn't sortable
+    pub fn toggle_sort(&mut self, column: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if !self
+            .columns
+            .get(column)
+            .is_some_and(|column| column.sortable)
+        {
+            return;
+        }
+        let direction = self.sort.cycle(column, self.multi_sort);
+        self.resort();
+        if let Some(on_sort) = &self.on_sort {
+            on_sort(column, direction, window, cx);
+        }
+        cx.notify();
+    }
+
+    // ============================================================================
+    // Mouse Event Handlers
+    // ============================================================================
+
+    /// Start resizing `column`, or fit it to its widest cell on a double click
+    pub(super) fn on_divider_mouse_down(
+        &mut self,
+        column: usize,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // Keep the press from sorting by the column
+        cx.stop_propagation();
+        let Some(current) = self.columns.get(column).filter(|column| column.resizable) else {
+            return;
+        };
+        if event.click_count == 2 {
+            let min_width = f32::from(current.min_width);
+            let widths = self.cell_widths.get(column).into_iter().flatten().copied();
+            let width = px(resize::fit_width(widths, min_width));
+            if width != current.width {
+                self.columns[column].width = width;
+                self.resized(column, window, cx);
+            }
+            return;
+        }
+        self.drag = Some(ColumnDrag {
+            column,
+            start_x: f32::from(event.position.x),
+            start_width: f32::from(current.width),
+        });
+        cx.notify();
+    }
+
+    pub(super) fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(drag) = self.drag else {
+            return;
+        };
+        let column = &mut self.columns[drag.column];
+        let width = px(drag.width_at(f32::from(event.position.x), f32::from(column.min_width)));
+        if column.width != width {
+            column.width = width;
+            cx.notify();
+        }
+    }
+
+    /// End a resize, reporting the new width
+    pub(super) fn on_mouse_up(
+        &mut self,
+        _: &MouseUpEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        if f32::from(self.columns[drag.column].width) != drag.start_width {
+            self.resized(drag.column, window, cx);
+        } else {
+            cx.notify();
+        }
+    }
+}
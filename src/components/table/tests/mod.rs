@@ -0,0 +1,4 @@
+mod resize;
+mod resizing;
+mod sort;
+mod sorting;
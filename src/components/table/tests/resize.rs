@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod resize {
+    use crate::components::table::resize::{ColumnDrag, fit_width};
+
+    const DRAG: ColumnDrag = ColumnDrag {
+        column: 0,
+        start_x: 150.,
+        start_width: 150.,
+    };
+
+    #[test]
+    fn the_width_follows_the_pointer() {
+        assert_eq!(DRAG.width_at(150., 40.), 150.);
+        assert_eq!(DRAG.width_at(210., 40.), 210.);
+        assert_eq!(DRAG.width_at(100., 40.), 100.);
+    }
+
+    #[test]
+    fn the_width_follows_the_pointer_from_where_it_pressed() {
+        let drag = ColumnDrag {
+            start_x: 400.,
+            ..DRAG
+        };
+
+        assert_eq!(drag.width_at(425., 40.), 175.);
+        assert_eq!(drag.width_at(350., 40.), 100.);
+    }
+
+    #[test]
+    fn the_width_stops_at_the_minimum() {
+        assert_eq!(DRAG.width_at(40., 40.), 40.);
+        assert_eq!(DRAG.width_at(0., 40.), 40.);
+        assert_eq!(DRAG.width_at(-500., 40.), 40.);
+    }
+
+    #[test]
+    fn fitting_takes_the_widest_cell() {
+        assert_eq!(fit_width([60., 230., 90.], 40.), 230.);
+    }
+
+    #[test]
+    fn fitting_keeps_the_minimum() {
+        assert_eq!(fit_width([12., 30.], 40.), 40.);
+        assert_eq!(fit_width([], 40.), 40.);
+    }
+}
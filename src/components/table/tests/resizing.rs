@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod resizing {
+    use crate::components::table::{Table, TableColumn, TableState};
+    use gpui::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    struct TableView {
+        state: Entity<TableState>,
+    }
+
+    impl Render for TableView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            // The cells of the first column are 200px to 230px wide
+            div().size_full().child(Table::new(
+                self.state.clone(),
+                |row, column, _, _| match column {
+                    0 => div().w(px(200. + row as f32 * 10.)).into_any_element(),
+                    _ => div().w(px(20.)).into_any_element(),
+                },
+            ))
+        }
+    }
+
+    type Resizes = Rc<RefCell<Vec<(usize, Pixels)>>>;
+
+    /// Open a window with a table of four rows, a 150px column resizable down to 60px
+    /// and a fixed one, recording every resize it reports
+    fn table(cx: &mut TestAppContext) -> (Entity<TableState>, Resizes, &mut VisualTestContext) {
+        let resizes = Resizes::default();
+        let recorded = resizes.clone();
+        let (view, cx) = cx.add_window_view(|_, cx| TableView {
+            state: cx.new(|_| {
+                TableState::new()
+                    .columns([
+                        TableColumn::new("Name").min_width(px(60.)),
+                        TableColumn::new("Size").resizable(false),
+                    ])
+                    .row_count(4)
+                    .on_column_resize(move |column, width, _, _| {
+                        recorded.borrow_mut().push((column, width))
+                    })
+            }),
+        });
+        let state = view.read_with(cx, |view, _| view.state.clone());
+        cx.run_until_parked();
+        (state, resizes, cx)
+    }
+
+    fn width(state: &Entity<TableState>, column: usize, cx: &mut VisualTestContext) -> Pixels {
+        state.read_with(cx, |state, _| state.current_columns()[column].width)
+    }
+
+    /// Drag the divider of the first column `by` across
+    fn drag(cx: &mut VisualTestContext, by: f32) {
+        let start = cx.debug_bounds("table-divider-0").unwrap().center();
+        let end = point(start.x + px(by), start.y);
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(end, MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_up(end, MouseButton::Left, Modifiers::none());
+    }
+
+    #[gpui::test]
+    fn dragging_the_divider_resizes_the_column(cx: &mut TestAppContext) {
+        let (state, resizes, cx) = table(cx);
+
+        drag(cx, 50.);
+        assert_eq!(width(&state, 0, cx), px(200.));
+        assert_eq!(
+            cx.debug_bounds("table-header-0").unwrap().size.width,
+            px(200.)
+        );
+        assert_eq!(
+            cx.debug_bounds("table-cell-0-0").unwrap().size.width,
+            px(200.)
+        );
+
+        drag(cx, -30.);
+        assert_eq!(width(&state, 0, cx), px(170.));
+        assert_eq!(*resizes.borrow(), [(0, px(200.)), (0, px(170.))]);
+    }
+
+    #[gpui::test]
+    fn the_column_is_reported_once_the_drag_ends(cx: &mut TestAppContext) {
+        let (state, resizes, cx) = table(cx);
+        let start = cx.debug_bounds("table-divider-0").unwrap().center();
+
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(
+            point(start.x + px(40.), start.y),
+            MouseButton::Left,
+            Modifiers::none(),
+        );
+        assert!(state.read_with(cx, |state, _| state.is_resizing()));
+        assert!(resizes.borrow().is_empty());
+
+        cx.simulate_mouse_up(start, MouseButton::Left, Modifiers::none());
+        assert!(!state.read_with(cx, |state, _| state.is_resizing()));
+        // Back where it started, so nothing changed
+        assert!(resizes.borrow().is_empty());
+    }
+
+    #[gpui::test]
+    fn the_column_stops_at_its_minimum_width(cx: &mut TestAppContext) {
+        let (state, resizes, cx) = table(cx);
+
+        drag(cx, -140.);
+        assert_eq!(width(&state, 0, cx), px(60.));
+        assert_eq!(*resizes.borrow(), [(0, px(60.))]);
+    }
+
+    #[gpui::test]
+    fn pressing_the_divider_doesnt_sort(cx: &mut TestAppContext) {
+        let (state, _, cx) = table(cx);
+
+        let divider = cx.debug_bounds("table-divider-0").unwrap().center();
+        cx.simulate_click(divider, Modifiers::none());
+        assert!(state.read_with(cx, |state, _| state.sort_keys().is_empty()));
+    }
+
+    #[gpui::test]
+    fn double_clicking_the_divider_fits_the_widest_cell(cx: &mut TestAppContext) {
+        let (state, resizes, cx) = table(cx);
+        let divider = cx.debug_bounds("table-divider-0").unwrap().center();
+
+        cx.simulate_event(MouseDownEvent {
+            button: MouseButton::Left,
+            position: divider,
+            modifiers: Modifiers::none(),
+            click_count: 2,
+            first_mouse: false,
+        });
+        cx.simulate_event(MouseUpEvent {
+            button: MouseButton::Left,
+            position: divider,
+            modifiers: Modifiers::none(),
+            click_count: 2,
+        });
+        assert_eq!(width(&state, 0, cx), px(230.));
+        assert_eq!(*resizes.borrow(), [(0, px(230.))]);
+    }
+
+    #[gpui::test]
+    fn fixed_columns_have_no_divider(cx: &mut TestAppContext) {
+        let (_, _, cx) = table(cx);
+
+        assert!(cx.debug_bounds("table-divider-0").is_some());
+        assert!(cx.debug_bounds("table-divider-1").is_none());
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod sort {
+    use crate::components::table::sort::{SortDirection, TableSort};
+
+    use SortDirection::{Ascending, Descending};
+
+    #[test]
+    fn a_column_cycles_from_ascending_to_descending_to_unsorted() {
+        let mut sort = TableSort::default();
+
+        assert_eq!(sort.cycle(1, false), Some(Ascending));
+        assert_eq!(sort.keys(), [(1, Ascending)]);
+        assert_eq!(sort.cycle(1, false), Some(Descending));
+        assert_eq!(sort.keys(), [(1, Descending)]);
+        assert_eq!(sort.cycle(1, false), None);
+        assert!(sort.keys().is_empty());
+        assert_eq!(sort.cycle(1, false), Some(Ascending));
+    }
+
+    #[test]
+    fn another_column_replaces_the_sorted_one() {
+        let mut sort = TableSort::default();
+        sort.cycle(0, false);
+        sort.cycle(0, false);
+
+        assert_eq!(sort.cycle(2, false), Some(Ascending));
+        assert_eq!(sort.keys(), [(2, Ascending)]);
+        assert_eq!(sort.direction(0), None);
+    }
+
+    #[test]
+    fn multiple_columns_sort_in_the_order_clicked() {
+        let mut sort = TableSort::default();
+        sort.cycle(2, true);
+        sort.cycle(0, true);
+        sort.cycle(2, true);
+
+        assert_eq!(sort.keys(), [(2, Descending), (0, Ascending)]);
+        // Unsorting the first key leaves the other
+        sort.cycle(2, true);
+        assert_eq!(sort.keys(), [(0, Ascending)]);
+        sort.cycle(2, true);
+        assert_eq!(sort.keys(), [(0, Ascending), (2, Ascending)]);
+    }
+
+    #[test]
+    fn a_single_click_after_multiple_keeps_only_its_column() {
+        let mut sort = TableSort::default();
+        sort.cycle(0, true);
+        sort.cycle(1, true);
+
+        assert_eq!(sort.cycle(1, false), Some(Descending));
+        assert_eq!(sort.keys(), [(1, Descending)]);
+    }
+
+    #[test]
+    fn removed_columns_stop_sorting() {
+        let mut sort = TableSort::default();
+        sort.cycle(0, true);
+        sort.cycle(3, true);
+        sort.truncate(2);
+        assert_eq!(sort.keys(), [(0, Ascending)]);
+    }
+
+    /// Rows of a letter and a number
+    const ROWS: [(char, u32); 5] = [('b', 2), ('a', 3), ('b', 1), ('c', 2), ('a', 1)];
+
+    fn sorted(sort: &TableSort) -> Vec<usize> {
+        sort.sorted_rows(ROWS.len(), |a, b, column| match column {
+            0 => ROWS[a].0.cmp(&ROWS[b].0),
+            _ => ROWS[a].1.cmp(&ROWS[b].1),
+        })
+    }
+
+    #[test]
+    fn unsorted_rows_keep_their_order() {
+        assert_eq!(sorted(&TableSort::default()), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rows_sort_by_a_column_either_way_and_keep_ties_in_order() {
+        let mut sort = TableSort::default();
+        sort.cycle(0, false);
+        assert_eq!(sorted(&sort), [1, 4, 0, 2, 3]);
+
+        sort.cycle(0, false);
+        assert_eq!(sorted(&sort), [3, 0, 2, 1, 4]);
+    }
+
+    #[test]
+    fn rows_sort_by_later_columns_among_ties() {
+        let mut sort = TableSort::default();
+        sort.cycle(0, true);
+        sort.cycle(1, true);
+        sort.cycle(1, true);
+        assert_eq!(sort.keys(), [(0, Ascending), (1, Descending)]);
+
+        assert_eq!(sorted(&sort), [1, 4, 0, 2, 3]);
+        sort.cycle(1, true);
+        sort.cycle(1, true);
+        assert_eq!(sorted(&sort), [4, 1, 2, 0, 3]);
+    }
+}
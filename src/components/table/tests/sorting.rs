@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod sorting {
+    use crate::components::table::{SortDirection, Table, TableColumn, TableState};
+    use gpui::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    use SortDirection::{Ascending, Descending};
+
+    const NAMES: [&str; 4] = ["Cherry", "Apple", "Date", "Banana"];
+
+    struct TableView {
+        state: Entity<TableState>,
+    }
+
+    impl Render for TableView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div().size_full().child(Table::new(
+                self.state.clone(),
+                |row, column, _, _| match column {
+                    0 => NAMES[row].into_any_element(),
+                    1 => NAMES[row].len().to_string().into_any_element(),
+                    _ => "-".into_any_element(),
+                },
+            ))
+        }
+    }
+
+    type Sorts = Rc<RefCell<Vec<(usize, Option<SortDirection>)>>>;
+
+    /// Open a window with a table of fruit names, their lengths and an unsortable
+    /// column, built further by `build`, recording every sort it reports
+    fn table(
+        cx: &mut TestAppContext,
+        build: impl FnOnce(TableState) -> TableState,
+    ) -> (Entity<TableState>, Sorts, &mut VisualTestContext) {
+        let sorts = Sorts::default();
+        let recorded = sorts.clone();
+        let (view, cx) = cx.add_window_view(|_, cx| TableView {
+            state: cx.new(|_| {
+                build(
+                    TableState::new()
+                        .columns([
+                            TableColumn::new("Name"),
+                            TableColumn::new("Length"),
+                            TableColumn::new("Notes").sortable(false),
+                        ])
+                        .row_count(NAMES.len())
+                        .on_sort(move |column, direction, _, _| {
+                            recorded.borrow_mut().push((column, direction))
+                        }),
+                )
+            }),
+        });
+        let state = view.read_with(cx, |view, _| view.state.clone());
+        cx.run_until_parked();
+        (state, sorts, cx)
+    }
+
+    fn click(cx: &mut VisualTestContext, header: &'static str) {
+        let bounds = cx.debug_bounds(header).unwrap();
+        cx.simulate_click(bounds.center(), Modifiers::none());
+    }
+
+    fn keys(state: &Entity<TableState>, cx: &mut VisualTestContext) -> Vec<(usize, SortDirection)> {
+        state.read_with(cx, |state, _| state.sort_keys().to_vec())
+    }
+
+    #[gpui::test]
+    fn header_clicks_cycle_the_sort(cx: &mut TestAppContext) {
+        let (state, sorts, cx) = table(cx, |state| state);
+
+        click(cx, "table-header-0");
+        assert_eq!(keys(&state, cx), [(0, Ascending)]);
+        assert!(cx.debug_bounds("table-sort-0-Ascending").is_some());
+
+        click(cx, "table-header-0");
+        assert_eq!(keys(&state, cx), [(0, Descending)]);
+        assert!(cx.debug_bounds("table-sort-0-Ascending").is_none());
+        assert!(cx.debug_bounds("table-sort-0-Descending").is_some());
+
+        click(cx, "table-header-0");
+        assert!(keys(&state, cx).is_empty());
+        assert!(cx.debug_bounds("table-sort-0-Descending").is_none());
+
+        assert_eq!(
+            *sorts.borrow(),
+            [(0, Some(Ascending)), (0, Some(Descending)), (0, None)]
+        );
+    }
+
+    #[gpui::test]
+    fn one_column_is_sorted_at_a_time(cx: &mut TestAppContext) {
+        let (state, sorts, cx) = table(cx, |state| state);
+
+        click(cx, "table-header-0");
+        click(cx, "table-header-1");
+        assert_eq!(keys(&state, cx), [(1, Ascending)]);
+        assert!(cx.debug_bounds("table-sort-0-Ascending").is_none());
+        assert!(cx.debug_bounds("table-sort-1-Ascending").is_some());
+        assert_eq!(sorts.borrow().last(), Some(&(1, Some(Ascending))));
+    }
+
+    #[gpui::test]
+    fn multi_sort_keeps_the_columns_sorted_before(cx: &mut TestAppContext) {
+        let (state, _, cx) = table(cx, |state| state.multi_sort(true));
+
+        click(cx, "table-header-1");
+        click(cx, "table-header-0");
+        click(cx, "table-header-0");
+        assert_eq!(keys(&state, cx), [(1, Ascending), (0, Descending)]);
+        assert!(cx.debug_bounds("table-sort-1-Ascending").is_some());
+        assert!(cx.debug_bounds("table-sort-0-Descending").is_some());
+    }
+
+    #[gpui::test]
+    fn unsortable_columns_ignore_clicks(cx: &mut TestAppContext) {
+        let (state, sorts, cx) = table(cx, |state| state);
+
+        click(cx, "table-header-2");
+        assert!(keys(&state, cx).is_empty());
+        assert!(sorts.borrow().is_empty());
+    }
+
+    #[gpui::test]
+    fn the_rows_are_left_in_order_without_a_comparison(cx: &mut TestAppContext) {
+        let (state, _, cx) = table(cx, |state| state);
+
+        click(cx, "table-header-0");
+        assert_eq!(
+            state.read_with(cx, |state, _| state.rows().to_vec()),
+            [0, 1, 2, 3]
+        );
+    }
+
+    #[gpui::test]
+    fn a_comparison_orders_the_rows(cx: &mut TestAppContext) {
+        let (state, _, cx) = table(cx, |state| {
+            state.sort_by(|a, b, column| match column {
+                0 => NAMES[a].cmp(NAMES[b]),
+                _ => NAMES[a].len().cmp(&NAMES[b].len()),
+            })
+        });
+        let rows =
+            |cx: &mut VisualTestContext| state.read_with(cx, |state, _| state.rows().to_vec());
+
+        click(cx, "table-header-0");
+        assert_eq!(rows(cx), [1, 3, 0, 2]);
+        click(cx, "table-header-0");
+        assert_eq!(rows(cx), [2, 0, 3, 1]);
+
+        // Cherry and Banana are as long, and keep their order
+        click(cx, "table-header-1");
+        assert_eq!(rows(cx), [2, 1, 0, 3]);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{Selectable, components::tabs::TabsTrigger, primitives::h_flex};
+use crate::{
+    Selectable,
+    components::tabs::{TabCloseButton, TabsTrigger, navigation::TabNavigation},
+    primitives::h_flex,
+};
 use gpui::{prelude::FluentBuilder, *};
 use smallvec::SmallVec;
 use std::rc::Rc;
@@ -9,6 +13,10 @@ pub struct TabsList {
     triggers: SmallVec<[TabsTrigger; 1]>,
     pub(super) selected_index: Option<usize>,
     pub(super) on_change: Option<Rc<dyn Fn(&usize, &mut Window, &mut App)>>,
+    pub(super) focus_handle: Option<FocusHandle>,
+    pub(super) vertical: bool,
+    pub(super) close_button: Option<TabCloseButton>,
+    pub(super) on_close: Option<Rc<dyn Fn(&usize, &mut Window, &mut App)>>,
 }
 
 impl TabsList {
@@ -18,6 +26,10 @@ impl TabsList {
             triggers: SmallVec::new(),
             selected_index: None,
             on_change: None,
+            focus_handle: None,
+            vertical: false,
+            close_button: None,
+            on_close: None,
         }
     }
 
@@ -40,8 +52,56 @@ impl Styled for TabsList {
 
 impl RenderOnce for TabsList {
     fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let navigation = TabNavigation::new(
+            self.triggers
+                .iter()
+                .map(|trigger| trigger.disabled)
+                .collect(),
+        );
+        let selected_index = self.selected_index;
+        let on_change = self.on_change.clone();
+        let close = self.on_close.clone().map(|on_close| {
+            let navigation = navigation.clone();
+            let on_change = self.on_change.clone();
+            Rc::new(move |ix: usize, window: &mut Window, cx: &mut App| {
+                on_close(&ix, window, cx);
+                // Keep the selection on the same tab, or a neighbor if it was closed
+                if let Some(selected) = selected_index.filter(|&selected| ix <= selected)
+                    && let Some(next) = navigation.after_close(selected, ix)
+                    && let Some(on_change) = on_change.as_ref()
+                {
+                    on_change(&next, window, cx);
+                }
+            }) as Rc<dyn Fn(usize, &mut Window, &mut App)>
+        });
+        let (previous, next) = if self.vertical {
+            ("up", "down")
+        } else {
+            ("left", "right")
+        };
+
         self.base
             .id("tabs-list")
+            .when(self.vertical, |this| this.flex_col())
+            .when_some(self.focus_handle, |this, focus_handle| {
+                this.track_focus(&focus_handle)
+                    .on_key_down(move |event, window, cx| {
+                        let key = event.keystroke.key.as_str();
+                        let target = match key {
+                            _ if key == next => navigation.step(selected_index, true),
+                            _ if key == previous => navigation.step(selected_index, false),
+                            "home" => navigation.first(),
+                            "end" => navigation.last(),
+                            _ => return,
+                        };
+                        cx.stop_propagation();
+                        if let Some(ix) = target.filter(|&ix| Some(ix) != selected_index)
+                            && let Some(on_change) = on_change.as_ref()
+                        {
+                            on_change(&ix, window, cx);
+                        }
+                    })
+            })
             .children(self.triggers.into_iter().enumerate().map(|(ix, trigger)| {
                 trigger
                     .id(ix)
@@ -51,6 +111,12 @@ impl RenderOnce for TabsList {
                     .when_some(self.on_change.clone(), move |this, on_click| {
                         this.on_click(move |_, window, cx| on_click(&ix, window, cx))
                     })
+                    .when_some(
+                        self.close_button.zip(close.clone()),
+                        move |this, (button, close)| {
+                            this.closeable(button, move |window, cx| close(ix, window, cx))
+                        },
+                    )
             }))
     }
 }
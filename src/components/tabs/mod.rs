@@ -2,6 +2,9 @@ use gpui::*;
 use std::rc::Rc;
 
 mod list;
+mod navigation;
+#[cfg(test)]
+mod tests;
 mod trigger;
 
 pub use list::*;
@@ -11,12 +14,26 @@ pub use trigger::*;
 #[action(namespace = tab_bar, no_json)]
 pub struct SelectTab(usize);
 
+/// When a closeable tab shows its close button, see [`Tabs::closeable`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabCloseButton {
+    /// Show the button on every tab
+    #[default]
+    Always,
+    /// Show the button while the tab is hovered or selected
+    OnHover,
+}
+
 #[derive(IntoElement)]
 pub struct Tabs {
     base: Stateful<Div>,
     list: TabsList,
     on_change: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
     value: Option<usize>,
+    focus_handle: Option<FocusHandle>,
+    vertical: bool,
+    close_button: Option<TabCloseButton>,
+    on_close: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
 }
 
 impl Tabs {
@@ -26,6 +43,10 @@ impl Tabs {
             list: TabsList::new(),
             on_change: None,
             value: None,
+            focus_handle: None,
+            vertical: false,
+            close_button: None,
+            on_close: None,
         }
     }
 
@@ -40,6 +61,55 @@ impl Tabs {
         self
     }
 
+    /// Makes the tab list focusable with `focus_handle`
+    ///
+    /// While the list is focused, the arrow keys select the previous or next tab, wrapping
+    /// around, and Home and End the first or last one. Disabled tabs are skipped, and the
+    /// selection goes through `on_change` so the content follows it.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
+    /// Lays the tabs out in a column, moving between them with Up and Down instead of
+    /// Left and Right
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// Adds an "×" button to each enabled tab that closes it, as does middle-clicking it
+    ///
+    /// Closing goes through `on_close` with the index of the tab, and the owner removes
+    /// it. When the selected tab is closed, `on_change` selects the next enabled tab, or
+    /// the previous one if it was last; closing a tab before the selection reports the
+    /// selection's new index the same way.
+    ///
+    /// # Arguments
+    ///
+    /// * `close_button` - Whether the button is always shown or only on hover
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// Tabs::new("editors")
+    ///     .value(selected)
+    ///     .closeable(TabCloseButton::OnHover)
+    ///     .on_close(cx.listener(|this, ix, _, _| {
+    ///         this.files.remove(*ix);
+    ///     }))
+    ///     .on_change(cx.listener(|this, ix, _, _| this.selected = *ix))
+    /// ```
+    pub fn closeable(mut self, close_button: TabCloseButton) -> Self {
+        self.close_button = Some(close_button);
+        self
+    }
+
+    pub fn on_close(mut self, on_close: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_close = Some(Rc::new(on_close));
+        self
+    }
+
     pub fn on_change(
         mut self,
         on_change: impl Fn(&usize, &mut Window, &mut App) + 'static,
@@ -57,7 +127,11 @@ impl Styled for Tabs {
 }
 
 impl RenderOnce for Tabs {
-    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+    fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        self.list.focus_handle = self.focus_handle;
+        self.list.vertical = self.vertical;
+        self.list.close_button = self.close_button;
+        self.list.on_close = self.on_close;
         self.base
             .on_action({
                 let on_click = self.on_change;
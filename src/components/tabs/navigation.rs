@@ -0,0 +1,65 @@
+/// Which tab the keyboard moves to, skipping disabled tabs
+#[derive(Clone)]
+pub struct TabNavigation {
+    disabled: Vec<bool>,
+}
+
+impl TabNavigation {
+    pub fn new(disabled: Vec<bool>) -> Self {
+        Self { disabled }
+    }
+
+    /// The next enabled tab after `from`, or before it when not `forward`, wrapping around
+    /// at either end
+    ///
+    /// Without a selected tab, moving forward starts at the first tab and moving back at
+    /// the last.
+    pub fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let len = self.disabled.len();
+        let start = match (from, forward) {
+            (Some(ix), _) => ix.min(len.checked_sub(1)?),
+            (None, true) => len.checked_sub(1)?,
+            (None, false) => 0,
+        };
+        (1..=len)
+            .map(|offset| {
+                if forward {
+                    (start + offset) % len
+                } else {
+                    (start + len - offset % len) % len
+                }
+            })
+            .find(|&ix| !self.disabled[ix])
+    }
+
+    /// The first enabled tab
+    pub fn first(&self) -> Option<usize> {
+        self.disabled.iter().position(|disabled| !disabled)
+    }
+
+    /// The last enabled tab
+    pub fn last(&self) -> Option<usize> {
+        self.disabled.iter().rposition(|disabled| !disabled)
+    }
+
+    /// The index of the selected tab once the tab at `closed` is removed
+    ///
+    /// Closing the selected tab moves to the next enabled tab, or the previous one when it
+    /// was the last, and closing a tab before the selection shifts it back by one. Returns
+    /// `None` when no enabled tab is left.
+    pub fn after_close(&self, selected: usize, closed: usize) -> Option<usize> {
+        if closed >= self.disabled.len() {
+            return Some(selected);
+        }
+        if closed != selected {
+            return Some(if closed < selected {
+                selected - 1
+            } else {
+                selected
+            });
+        }
+        let next = (closed + 1..self.disabled.len()).find(|&ix| !self.disabled[ix]);
+        let previous = (0..closed).rev().find(|&ix| !self.disabled[ix]);
+        next.map(|ix| ix - 1).or(previous)
+    }
+}
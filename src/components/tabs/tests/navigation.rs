@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod navigation {
+    use crate::components::tabs::navigation::TabNavigation;
+
+    #[test]
+    fn arrows_cycle_through_the_tabs() {
+        let tabs = TabNavigation::new(vec![false; 3]);
+        assert_eq!(tabs.step(Some(0), true), Some(1));
+        assert_eq!(tabs.step(Some(1), true), Some(2));
+        assert_eq!(tabs.step(Some(2), false), Some(1));
+    }
+
+    #[test]
+    fn arrows_wrap_around() {
+        let tabs = TabNavigation::new(vec![false; 3]);
+        assert_eq!(tabs.step(Some(2), true), Some(0));
+        assert_eq!(tabs.step(Some(0), false), Some(2));
+    }
+
+    #[test]
+    fn arrows_skip_disabled_tabs() {
+        let tabs = TabNavigation::new(vec![false, true, false, true]);
+        assert_eq!(tabs.step(Some(0), true), Some(2));
+        assert_eq!(tabs.step(Some(2), true), Some(0));
+        assert_eq!(tabs.step(Some(0), false), Some(2));
+    }
+
+    #[test]
+    fn arrows_without_a_selection_start_at_either_end() {
+        let tabs = TabNavigation::new(vec![false, false, true]);
+        assert_eq!(tabs.step(None, true), Some(0));
+        assert_eq!(tabs.step(None, false), Some(1));
+    }
+
+    #[test]
+    fn selection_past_the_end_is_treated_as_the_last_tab() {
+        let tabs = TabNavigation::new(vec![false; 3]);
+        assert_eq!(tabs.step(Some(7), true), Some(0));
+    }
+
+    #[test]
+    fn home_and_end_skip_disabled_tabs() {
+        let tabs = TabNavigation::new(vec![true, false, false, true]);
+        assert_eq!(tabs.first(), Some(1));
+        assert_eq!(tabs.last(), Some(2));
+    }
+
+    #[test]
+    fn all_disabled_goes_nowhere() {
+        let tabs = TabNavigation::new(vec![true; 2]);
+        assert_eq!(tabs.step(Some(0), true), None);
+        assert_eq!(tabs.first(), None);
+        assert_eq!(tabs.last(), None);
+
+        let empty = TabNavigation::new(Vec::new());
+        assert_eq!(empty.step(None, true), None);
+        assert_eq!(empty.step(Some(0), false), None);
+    }
+
+    #[test]
+    fn closing_the_active_tab_selects_the_next_one() {
+        let tabs = TabNavigation::new(vec![false; 4]);
+        // The next tab takes the closed tab's place
+        assert_eq!(tabs.after_close(1, 1), Some(1));
+
+        let tabs = TabNavigation::new(vec![false, false, true, false]);
+        assert_eq!(tabs.after_close(1, 1), Some(2));
+    }
+
+    #[test]
+    fn closing_the_first_tab_selects_its_neighbor() {
+        let tabs = TabNavigation::new(vec![false; 3]);
+        assert_eq!(tabs.after_close(0, 0), Some(0));
+        assert_eq!(tabs.after_close(2, 0), Some(1));
+    }
+
+    #[test]
+    fn closing_the_last_tab_selects_the_previous_one() {
+        let tabs = TabNavigation::new(vec![false; 3]);
+        assert_eq!(tabs.after_close(2, 2), Some(1));
+        assert_eq!(tabs.after_close(0, 2), Some(0));
+
+        let tabs = TabNavigation::new(vec![false, true, false]);
+        assert_eq!(tabs.after_close(2, 2), Some(0));
+    }
+
+    #[test]
+    fn closing_the_only_enabled_tab_selects_nothing() {
+        let tabs = TabNavigation::new(vec![true, false, true]);
+        assert_eq!(tabs.after_close(1, 1), None);
+        assert_eq!(TabNavigation::new(vec![false]).after_close(0, 0), None);
+    }
+}
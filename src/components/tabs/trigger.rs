@@ -1,8 +1,10 @@
-use crate::{Disableable, Selectable};
+use crate::{Disableable, Selectable, components::tabs::TabCloseButton};
 use gpui::{prelude::FluentBuilder, *};
 use smallvec::SmallVec;
 use std::rc::Rc;
 
+const GROUP: &str = "tabs-trigger";
+
 #[derive(IntoElement)]
 pub struct TabsTrigger {
     base: Div,
@@ -12,6 +14,7 @@ pub struct TabsTrigger {
     pub(super) selected: bool,
     on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
     when_selected_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    close: Option<(TabCloseButton, Rc<dyn Fn(&mut Window, &mut App) + 'static>)>,
 }
 
 impl TabsTrigger {
@@ -24,6 +27,7 @@ impl TabsTrigger {
             on_click: None,
             id: 0.into(),
             when_selected_handler: None,
+            close: None,
         }
     }
 
@@ -40,6 +44,15 @@ impl TabsTrigger {
         self
     }
 
+    pub(super) fn closeable(
+        mut self,
+        close_button: TabCloseButton,
+        on_close: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.close = Some((close_button, Rc::new(on_close)));
+        self
+    }
+
     pub fn when_selected(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
         self.when_selected_handler = Some(Box::new(handler));
         self
@@ -108,5 +121,35 @@ impl RenderOnce for TabsTrigger {
                 })
             })
             .children(self.children)
+            .when_some(
+                self.close.filter(|_| !self.disabled),
+                |this, (button, on_close)| {
+                    let hidden = button == TabCloseButton::OnHover && !self.selected;
+                    this.group(GROUP)
+                        .on_mouse_down(MouseButton::Middle, {
+                            let on_close = on_close.clone();
+                            move |_, window, cx| {
+                                cx.stop_propagation();
+                                on_close(window, cx);
+                            }
+                        })
+                        .child(
+                            div()
+                                .id("close")
+                                .flex_none()
+                                .cursor_pointer()
+                                .child("×")
+                                .when(hidden, |this| {
+                                    this.invisible().group_hover(GROUP, |style| style.visible())
+                                })
+                                // Don't let the press select the tab it closes
+                                .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                                .on_click(move |_, window, cx| {
+                                    cx.stop_propagation();
+                                    on_close(window, cx);
+                                }),
+                        )
+                },
+            )
     }
 }
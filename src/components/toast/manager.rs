@@ -0,0 +1,154 @@
+use crate::{
+    components::{
+        Button,
+        toast::{Toast, queue::ToastQueue},
+    },
+    primitives::{h_flex, span, v_flex},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::time::Instant;
+
+/// How many toasts show at once unless set
+const DEFAULT_MAX_VISIBLE: usize = 3;
+
+/// Shows pushed toasts as a stack and dismisses them once their time runs out
+///
+/// Render the manager wherever the toasts should appear, for example in an absolutely
+/// positioned corner of the window. Toasts beyond the ones that fit wait their turn, and
+/// hovering a toast pauses its time.
+pub struct ToastManager {
+    queue: ToastQueue<Toast>,
+    advanced_at: Instant,
+    epoch: usize,
+}
+
+impl ToastManager {
+    pub fn new(_cx: &mut Context<Self>) -> Self {
+        Self {
+            queue: ToastQueue::new(DEFAULT_MAX_VISIBLE),
+            advanced_at: Instant::now(),
+            epoch: 0,
+        }
+    }
+
+    /// Set how many toasts show at once
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.queue.set_max_visible(max_visible);
+        self
+    }
+
+    /// Queue `toast`, returning the id to dismiss it with
+    pub fn push_toast(&mut self, toast: Toast, cx: &mut Context<Self>) -> usize {
+        self.advance();
+        let duration = toast.duration;
+        let id = self.queue.push(toast, duration);
+        self.schedule(cx);
+        cx.notify();
+        id
+    }
+
+    /// Remove the toast with `id`, whether it shows or waits
+    pub fn dismiss(&mut self, id: usize, cx: &mut Context<Self>) {
+        self.advance();
+        if self.queue.dismiss(id).is_some() {
+            self.schedule(cx);
+            cx.notify();
+        }
+    }
+
+    /// How many toasts wait for room to show
+    pub fn queued(&self) -> usize {
+        self.queue.queued()
+    }
+
+    fn set_hovered(&mut self, id: usize, hovered: bool, cx: &mut Context<Self>) {
+        self.advance();
+        self.queue.set_hovered(id, hovered);
+        self.schedule(cx);
+    }
+
+    /// Count the time since the last advance against the toasts that show
+    fn advance(&mut self) -> bool {
+        let now = Instant::now();
+        let expired = self.queue.advance(now - self.advanced_at);
+        self.advanced_at = now;
+        !expired.is_empty()
+    }
+
+    /// Wake up when the next toast runs out of time, dropping any earlier wake up
+    fn schedule(&mut self, cx: &mut Context<Self>) {
+        self.epoch += 1;
+        let epoch = self.epoch;
+        let Some(deadline) = self.queue.next_deadline() else {
+            return;
+        };
+        cx.spawn(async move |this, cx| {
+            Timer::after(deadline).await;
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| this.tick(epoch, cx)).ok();
+            }
+        })
+        .detach();
+    }
+
+    fn tick(&mut self, epoch: usize, cx: &mut Context<Self>) {
+        if epoch != self.epoch {
+            return;
+        }
+        if self.advance() {
+            cx.notify();
+        }
+        self.schedule(cx);
+    }
+
+    fn render_toast(&self, id: usize, toast: &Toast, cx: &mut Context<Self>) -> impl IntoElement {
+        let action = toast.action.clone().map(|(label, on_click)| {
+            Button::new(("toast-action", id))
+                .child(label)
+                .text_color(toast.severity.color())
+                .on_click(cx.listener(move |this, _, window, cx| {
+                    on_click(window, cx);
+                    this.dismiss(id, cx);
+                }))
+        });
+
+        h_flex()
+            .id(("toast", id))
+            .gap_3()
+            .px_4()
+            .py_3()
+            .min_w(px(280.))
+            .rounded_md()
+            .border_l_4()
+            .border_color(toast.severity.color())
+            .bg(rgb(0xffffff))
+            .shadow_md()
+            .on_hover(
+                cx.listener(move |this, hovered: &bool, _, cx| this.set_hovered(id, *hovered, cx)),
+            )
+            .child(
+                v_flex()
+                    .flex_1()
+                    .child(span(toast.title.clone()).font_weight(FontWeight::SEMIBOLD))
+                    .when_some(toast.message.clone(), |this, message| {
+                        this.child(span(message).text_sm())
+                    }),
+            )
+            .children(action)
+            .child(
+                Button::icon(("toast-dismiss", id), "×", "Dismiss")
+                    .on_click(cx.listener(move |this, _, _, cx| this.dismiss(id, cx))),
+            )
+    }
+}
+
+impl Render for ToastManager {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex().gap_2().children(
+            self.queue
+                .visible()
+                .map(|(id, toast)| self.render_toast(id, toast, cx))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
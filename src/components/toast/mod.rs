@@ -0,0 +1,109 @@
+use gpui::*;
+use std::{rc::Rc, time::Duration};
+
+mod manager;
+mod queue;
+#[cfg(test)]
+mod tests;
+
+pub use manager::*;
+
+/// How long a toast shows unless given its own duration
+pub const DEFAULT_TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// How important a toast is, which sets its accent color
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ToastSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    /// The accent color of the severity
+    pub fn color(self) -> Hsla {
+        match self {
+            Self::Info => rgb(0x3b82f6),
+            Self::Success => rgb(0x10b981),
+            Self::Warning => rgb(0xf59e0b),
+            Self::Error => rgb(0xef4444),
+        }
+        .into()
+    }
+}
+
+/// A notification pushed to a [`ToastManager`]
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct Toast {
+    severity: ToastSeverity,
+    title: SharedString,
+    message: Option<SharedString>,
+    duration: Option<Duration>,
+    action: Option<(SharedString, Rc<dyn Fn(&mut Window, &mut App)>)>,
+}
+
+impl Toast {
+    /// Create an info toast with `title` that dismisses itself after
+    /// [`DEFAULT_TOAST_DURATION`]
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            severity: ToastSeverity::default(),
+            title: title.into(),
+            message: None,
+            duration: Some(DEFAULT_TOAST_DURATION),
+            action: None,
+        }
+    }
+
+    pub fn info(title: impl Into<SharedString>) -> Self {
+        Self::new(title).severity(ToastSeverity::Info)
+    }
+
+    pub fn success(title: impl Into<SharedString>) -> Self {
+        Self::new(title).severity(ToastSeverity::Success)
+    }
+
+    pub fn warning(title: impl Into<SharedString>) -> Self {
+        Self::new(title).severity(ToastSeverity::Warning)
+    }
+
+    pub fn error(title: impl Into<SharedString>) -> Self {
+        Self::new(title).severity(ToastSeverity::Error)
+    }
+
+    pub fn severity(mut self, severity: ToastSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Show `message` below the title
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Dismiss the toast after it has shown for `duration`, not counting time hovered
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Keep the toast until it is dismissed
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    /// Show a button labeled `label` that calls `on_click` and dismisses the toast
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        on_click: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Rc::new(on_click)));
+        self
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+struct Entry<T> {
+    id: usize,
+    item: T,
+    remaining: Option<Duration>,
+    hovered: bool,
+}
+
+impl<T> Entry<T> {
+    fn is_running(&self) -> bool {
+        !self.hovered && self.remaining.is_some()
+    }
+}
+
+/// The toasts waiting to be shown and the ones showing, without the timers that drive it
+///
+/// Up to `max_visible` toasts show at once, oldest first, and the rest wait in order. A
+/// toast's time only runs out while it shows and isn't hovered.
+pub struct ToastQueue<T> {
+    entries: Vec<Entry<T>>,
+    max_visible: usize,
+    next_id: usize,
+}
+
+impl<T> ToastQueue<T> {
+    pub fn new(max_visible: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            max_visible,
+            next_id: 0,
+        }
+    }
+
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        self.max_visible = max_visible;
+    }
+
+    /// Queue `item` to show for `duration`, or until dismissed when `None`, returning its id
+    pub fn push(&mut self, item: T, duration: Option<Duration>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push(Entry {
+            id,
+            item,
+            remaining: duration,
+            hovered: false,
+        });
+        id
+    }
+
+    /// Remove the toast with `id`, whether it shows or waits
+    pub fn dismiss(&mut self, id: usize) -> Option<T> {
+        let ix = self.entries.iter().position(|entry| entry.id == id)?;
+        Some(self.entries.remove(ix).item)
+    }
+
+    /// The toasts that show, oldest first
+    pub fn visible(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.entries
+            .iter()
+            .take(self.max_visible)
+            .map(|entry| (entry.id, &entry.item))
+    }
+
+    /// How many toasts wait for room to show
+    pub fn queued(&self) -> usize {
+        self.entries.len().saturating_sub(self.max_visible)
+    }
+
+    /// Pause the time of the toast with `id` while it is hovered
+    pub fn set_hovered(&mut self, id: usize, hovered: bool) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.hovered = hovered;
+        }
+    }
+
+    /// Let `elapsed` pass for the toasts that show, returning the ones whose time ran out
+    ///
+    /// Waiting toasts that get room start their time from the next call.
+    pub fn advance(&mut self, elapsed: Duration) -> Vec<T> {
+        let mut expired = Vec::new();
+        let running = self.entries.iter_mut().take(self.max_visible);
+        for entry in running.filter(|entry| entry.is_running()) {
+            let remaining = entry.remaining.unwrap_or_default().saturating_sub(elapsed);
+            entry.remaining = Some(remaining);
+            if remaining.is_zero() {
+                expired.push(entry.id);
+            }
+        }
+        expired
+            .into_iter()
+            .filter_map(|id| self.dismiss(id))
+            .collect()
+    }
+
+    /// How long until the next toast that shows runs out of time, if any is running
+    pub fn next_deadline(&self) -> Option<Duration> {
+        self.entries
+            .iter()
+            .take(self.max_visible)
+            .filter(|entry| entry.is_running())
+            .filter_map(|entry| entry.remaining)
+            .min()
+    }
+}
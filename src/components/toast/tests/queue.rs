@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod queue {
+    use crate::components::toast::queue::ToastQueue;
+    use std::time::Duration;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    fn visible(queue: &ToastQueue<&'static str>) -> Vec<&'static str> {
+        queue.visible().map(|(_, toast)| *toast).collect()
+    }
+
+    #[test]
+    fn shows_up_to_the_limit_in_order() {
+        let mut queue = ToastQueue::new(2);
+        queue.push("a", Some(SECOND));
+        queue.push("b", Some(SECOND));
+        queue.push("c", Some(SECOND));
+
+        assert_eq!(visible(&queue), ["a", "b"]);
+        assert_eq!(queue.queued(), 1);
+    }
+
+    #[test]
+    fn expired_toasts_make_room_for_queued_ones() {
+        let mut queue = ToastQueue::new(2);
+        queue.push("a", Some(SECOND));
+        queue.push("b", Some(3 * SECOND));
+        queue.push("c", Some(SECOND));
+
+        assert_eq!(queue.advance(SECOND), ["a"]);
+        assert_eq!(visible(&queue), ["b", "c"]);
+        assert_eq!(queue.queued(), 0);
+    }
+
+    #[test]
+    fn queued_toasts_start_their_time_once_shown() {
+        let mut queue = ToastQueue::new(1);
+        queue.push("a", Some(SECOND));
+        queue.push("b", Some(SECOND));
+
+        assert_eq!(queue.advance(SECOND), ["a"]);
+        assert_eq!(queue.next_deadline(), Some(SECOND));
+        assert_eq!(queue.advance(SECOND / 2), Vec::<&str>::new());
+        assert_eq!(queue.advance(SECOND / 2), ["b"]);
+    }
+
+    #[test]
+    fn queue_drains_as_time_passes() {
+        let mut queue = ToastQueue::new(2);
+        for toast in ["a", "b", "c", "d", "e"] {
+            queue.push(toast, Some(SECOND));
+        }
+
+        assert_eq!(queue.advance(SECOND), ["a", "b"]);
+        assert_eq!(queue.advance(SECOND), ["c", "d"]);
+        assert_eq!(queue.advance(SECOND), ["e"]);
+        assert_eq!(visible(&queue), Vec::<&str>::new());
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn next_deadline_is_the_soonest_visible_one() {
+        let mut queue = ToastQueue::new(2);
+        queue.push("a", Some(3 * SECOND));
+        queue.push("b", Some(2 * SECOND));
+        queue.push("c", Some(SECOND));
+
+        assert_eq!(queue.next_deadline(), Some(2 * SECOND));
+        queue.advance(SECOND / 2);
+        assert_eq!(queue.next_deadline(), Some(SECOND + SECOND / 2));
+    }
+
+    #[test]
+    fn hovering_pauses_the_timer() {
+        let mut queue = ToastQueue::new(2);
+        let a = queue.push("a", Some(SECOND));
+        queue.push("b", Some(SECOND));
+
+        queue.set_hovered(a, true);
+        assert_eq!(queue.advance(5 * SECOND), ["b"]);
+        assert_eq!(visible(&queue), ["a"]);
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn unhovering_resumes_with_the_time_left() {
+        let mut queue = ToastQueue::new(1);
+        let a = queue.push("a", Some(SECOND));
+
+        queue.advance(SECOND / 4);
+        queue.set_hovered(a, true);
+        queue.advance(10 * SECOND);
+        queue.set_hovered(a, false);
+
+        assert_eq!(queue.next_deadline(), Some(SECOND * 3 / 4));
+        assert_eq!(queue.advance(SECOND * 3 / 4), ["a"]);
+    }
+
+    #[test]
+    fn persistent_toasts_stay_until_dismissed() {
+        let mut queue = ToastQueue::new(1);
+        let a = queue.push("a", None);
+        queue.push("b", Some(SECOND));
+
+        assert_eq!(queue.advance(60 * SECOND), Vec::<&str>::new());
+        assert_eq!(queue.next_deadline(), None);
+        assert_eq!(queue.dismiss(a), Some("a"));
+        assert_eq!(visible(&queue), ["b"]);
+    }
+
+    #[test]
+    fn waiting_toasts_can_be_dismissed() {
+        let mut queue = ToastQueue::new(1);
+        queue.push("a", Some(SECOND));
+        let b = queue.push("b", Some(SECOND));
+
+        assert_eq!(queue.dismiss(b), Some("b"));
+        assert_eq!(queue.dismiss(b), None);
+        assert_eq!(queue.queued(), 0);
+    }
+}
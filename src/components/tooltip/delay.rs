@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Phase<T> {
+    Hidden,
+    /// The pointer is on `anchor`, and its tooltip shows once the show delay is over
+    Showing {
+        anchor: T,
+        at: Instant,
+    },
+    Shown {
+        anchor: T,
+    },
+    /// The pointer left `anchor`, and its tooltip lingers until the hide delay is over
+    Hiding {
+        anchor: T,
+        at: Instant,
+    },
+}
+
+/// When the tooltip of one of several anchors shows, as the pointer enters and leaves
+/// them.
+///
+/// A tooltip shows once the pointer has stayed on its anchor for the show delay, and
+/// lingers for the hide delay after the pointer leaves. While one is shown or lingering,
+/// entering another anchor shows that one's tooltip right away, so moving along a row of
+/// anchors swaps the tooltip instead of hiding it and waiting again.
+///
+/// Entering and leaving return when the state changes next, if it does; [`tick`] applies
+/// the change once that time has come, and ticking at any other time is harmless.
+///
+/// [`tick`]: Self::tick
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TooltipDelay<T> {
+    phase: Phase<T>,
+}
+
+impl<T> Default for TooltipDelay<T> {
+    fn default() -> Self {
+        Self {
+            phase: Phase::Hidden,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> TooltipDelay<T> {
+    /// The anchor whose tooltip is shown, including while it lingers
+    pub fn visible(&self) -> Option<&T> {
+        match &self.phase {
+            Phase::Shown { anchor } | Phase::Hiding { anchor, .. } => Some(anchor),
+            Phase::Hidden | Phase::Showing { .. } => None,
+        }
+    }
+
+    /// The pointer entered `anchor` at `now`
+    pub fn enter(&mut self, anchor: T, show_delay: Duration, now: Instant) -> Option<Instant> {
+        match self.phase {
+            Phase::Shown { .. } | Phase::Hiding { .. } => {
+                self.phase = Phase::Shown { anchor };
+                None
+            }
+            Phase::Hidden | Phase::Showing { .. } if show_delay.is_zero() => {
+                self.phase = Phase::Shown { anchor };
+                None
+            }
+            Phase::Hidden | Phase::Showing { .. } => {
+                let at = now + show_delay;
+                self.phase = Phase::Showing { anchor, at };
+                Some(at)
+            }
+        }
+    }
+
+    /// The pointer left `anchor` at `now`, which changes nothing once another anchor was
+    /// entered
+    pub fn leave(&mut self, anchor: &T, hide_delay: Duration, now: Instant) -> Option<Instant> {
+        match &self.phase {
+            Phase::Showing {
+                anchor: current, ..
+            } if current == anchor => {
+                self.phase = Phase::Hidden;
+                None
+            }
+            Phase::Shown { anchor: current } if current == anchor => {
+                if hide_delay.is_zero() {
+                    self.phase = Phase::Hidden;
+                    return None;
+                }
+                let at = now + hide_delay;
+                self.phase = Phase::Hiding {
+                    anchor: current.clone(),
+                    at,
+                };
+                Some(at)
+            }
+            _ => None,
+        }
+    }
+
+    /// Hide the tooltip of `anchor` right away, or stop waiting to show it, like when the
+    /// anchor is pressed
+    pub fn dismiss(&mut self, anchor: &T) {
+        match &self.phase {
+            Phase::Showing {
+                anchor: current, ..
+            }
+            | Phase::Shown { anchor: current }
+            | Phase::Hiding {
+                anchor: current, ..
+            } if current == anchor => {
+                self.phase = Phase::Hidden;
+            }
+            _ => {}
+        }
+    }
+
+    /// Show or hide the tooltip once its delay is over at `now`, returning whether that
+    /// changed what is visible
+    pub fn tick(&mut self, now: Instant) -> bool {
+        match &self.phase {
+            Phase::Showing { anchor, at } if *at <= now => {
+                self.phase = Phase::Shown {
+                    anchor: anchor.clone(),
+                };
+                true
+            }
+            Phase::Hiding { at, .. } if *at <= now => {
+                self.phase = Phase::Hidden;
+                true
+            }
+            _ => false,
+        }
+    }
+}
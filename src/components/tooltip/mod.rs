@@ -0,0 +1,290 @@
+use crate::primitives::span;
+use gpui::{prelude::FluentBuilder, *};
+use smallvec::SmallVec;
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+mod delay;
+mod placement;
+#[cfg(test)]
+mod tests;
+
+use delay::TooltipDelay;
+pub use placement::TooltipSide;
+use placement::{Rect, TooltipFit};
+
+/// How long the pointer stays on an element before its tooltip shows
+pub const DEFAULT_SHOW_DELAY: Duration = Duration::from_millis(500);
+
+/// How long a tooltip lingers once the pointer leaves its element
+pub const DEFAULT_HIDE_DELAY: Duration = Duration::from_millis(150);
+
+/// The space between a tooltip and its element
+const GAP: Pixels = px(4.);
+
+/// The tooltip shown in the app, shared by every [`Tooltip`] so the pointer can move from
+/// one to the next without waiting again
+#[derive(Default)]
+struct ActiveTooltip(TooltipDelay<ElementId>);
+
+impl Global for ActiveTooltip {}
+
+impl ActiveTooltip {
+    fn is_visible(id: &ElementId, app: &App) -> bool {
+        app.try_global::<Self>()
+            .is_some_and(|active| active.0.visible() == Some(id))
+    }
+
+    fn hover(
+        id: &ElementId,
+        hovered: bool,
+        delays: (Duration, Duration),
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        let now = Instant::now();
+        let active = &mut app.default_global::<Self>().0;
+        let was_visible = active.visible().cloned();
+        let wake_at = if hovered {
+            active.enter(id.clone(), delays.0, now)
+        } else {
+            active.leave(id, delays.1, now)
+        };
+        if active.visible() != was_visible.as_ref() {
+            window.refresh();
+        }
+        if let Some(wake_at) = wake_at {
+            window
+                .spawn(app, async move |cx| {
+                    Timer::after(wake_at - now).await;
+                    cx.update(|window, app| {
+                        if app.default_global::<Self>().0.tick(Instant::now()) {
+                            window.refresh();
+                        }
+                    })
+                    .ok();
+                })
+                .detach();
+        }
+    }
+
+    fn dismiss(id: &ElementId, window: &mut Window, app: &mut App) {
+        let active = &mut app.default_global::<Self>().0;
+        if active.visible() == Some(id) {
+            window.refresh();
+        }
+        active.dismiss(id);
+    }
+}
+
+/// The bounds of an element and the size of its tooltip, as last laid out, which place
+/// the tooltip on the next render
+#[derive(Default)]
+struct TooltipLayout {
+    anchor: Option<Bounds<Pixels>>,
+    size: Option<Size<Pixels>>,
+}
+
+impl TooltipLayout {
+    fn fit(&self, viewport: Size<Pixels>) -> Option<TooltipFit> {
+        let (anchor, size) = (self.anchor?, self.size?);
+        Some(TooltipFit {
+            anchor: Rect {
+                x: f32::from(anchor.origin.x),
+                y: f32::from(anchor.origin.y),
+                width: f32::from(anchor.size.width),
+                height: f32::from(anchor.size.height),
+            },
+            width: f32::from(size.width),
+            height: f32::from(size.height),
+            window_width: f32::from(viewport.width),
+            window_height: f32::from(viewport.height),
+            gap: f32::from(GAP),
+        })
+    }
+}
+
+/// An element that shows a tooltip while the pointer rests on it.
+///
+/// The tooltip shows once the pointer has stayed on the element for the
+/// [`show_delay`](Self::show_delay), and lingers for the [`hide_delay`](Self::hide_delay)
+/// after it leaves, so it doesn't flicker as the pointer crosses the element. While a
+/// tooltip is shown or lingering, moving onto another element with a tooltip shows that
+/// one right away. Pressing the element hides its tooltip.
+///
+/// The tooltip shows centered on the [`placement`](Self::placement) side of the element.
+/// When the window has no room for it there, it flips to the opposite side, and it shifts
+/// along the side to stay within the window.
+///
+/// # Examples
+///
+/// ```rust
+/// Tooltip::new("save-tooltip")
+///     .text("Save (⌘S)")
+///     .show_delay(Duration::from_millis(300))
+///     .placement(TooltipSide::Bottom)
+///     .popup(|popup| popup.px_2().py_1().bg(rgb(0x111827)).text_color(rgb(0xffffff)).rounded_md())
+///     .child(Button::new("save").child("Save"))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Tooltip {
+    id: ElementId,
+    base: Div,
+    children: SmallVec<[AnyElement; 1]>,
+    content: Option<Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>>,
+    popup: Div,
+    placement: TooltipSide,
+    show_delay: Duration,
+    hide_delay: Duration,
+}
+
+impl Tooltip {
+    /// Creates an element with the specified ID and an empty tooltip.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            children: SmallVec::new(),
+            content: None,
+            popup: div(),
+            placement: TooltipSide::default(),
+            show_delay: DEFAULT_SHOW_DELAY,
+            hide_delay: DEFAULT_HIDE_DELAY,
+        }
+    }
+
+    /// Sets the text of the tooltip.
+    pub fn text(self, text: impl Into<SharedString>) -> Self {
+        let text = text.into();
+        self.content(move |_, _| span(text.clone()).into_any_element())
+    }
+
+    /// Sets the content of the tooltip, built each time it renders.
+    pub fn content(
+        mut self,
+        content: impl Fn(&mut Window, &mut App) -> AnyElement + 'static,
+    ) -> Self {
+        self.content = Some(Rc::new(content));
+        self
+    }
+
+    /// Customizes the box around the content of the tooltip.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that takes and returns the box, applying modifications
+    pub fn popup(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.popup = handler(self.popup);
+        self
+    }
+
+    /// Sets the side of the element the tooltip shows on when it fits there, above the
+    /// element by default.
+    pub fn placement(mut self, placement: TooltipSide) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets how long the pointer stays on the element before the tooltip shows, 500ms by
+    /// default.
+    pub fn show_delay(mut self, show_delay: Duration) -> Self {
+        self.show_delay = show_delay;
+        self
+    }
+
+    /// Sets how long the tooltip lingers once the pointer leaves the element, 150ms by
+    /// default.
+    pub fn hide_delay(mut self, hide_delay: Duration) -> Self {
+        self.hide_delay = hide_delay;
+        self
+    }
+}
+
+impl ParentElement for Tooltip {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Tooltip {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Tooltip {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let content = self
+            .content
+            .filter(|_| ActiveTooltip::is_visible(&self.id, app))
+            .map(|content| content(window, app));
+        let delays = (self.show_delay, self.hide_delay);
+        let hovered_id = self.id.clone();
+        let pressed_id = self.id.clone();
+
+        let layout = window.use_keyed_state(self.id.clone(), app, |_, _| TooltipLayout::default());
+        let placement = layout
+            .read(app)
+            .fit(window.viewport_size())
+            .map(|fit| fit.resolve(self.placement));
+
+        let anchored_layout = layout.clone();
+        let anchor = canvas(
+            move |bounds, _, app| {
+                anchored_layout.update(app, |layout, cx| {
+                    if layout.anchor != Some(bounds) {
+                        layout.anchor = Some(bounds);
+                        cx.notify();
+                    }
+                })
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        self.base
+            .id(self.id)
+            .relative()
+            .on_hover(move |hovered, window, app| {
+                ActiveTooltip::hover(&hovered_id, *hovered, delays, window, app)
+            })
+            .on_mouse_down(MouseButton::Left, move |_, window, app| {
+                ActiveTooltip::dismiss(&pressed_id, window, app)
+            })
+            .child(anchor)
+            .children(self.children)
+            .when_some(content, |this, content| {
+                // The popup is laid out out of sight until its size is known, then placed
+                let size = canvas(
+                    move |bounds, _, app| {
+                        layout.update(app, |layout, cx| {
+                            if layout.size != Some(bounds.size) {
+                                layout.size = Some(bounds.size);
+                                cx.notify();
+                            }
+                        })
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full();
+                let origin = placement.map_or(Point::default(), |placement| {
+                    point(px(placement.x), px(placement.y))
+                });
+                this.child(deferred(
+                    anchored().position(origin).child(
+                        self.popup
+                            .debug_selector(|| "tooltip".into())
+                            .relative()
+                            .when(placement.is_none(), |this| this.invisible())
+                            .child(size)
+                            .child(content),
+                    ),
+                ))
+            })
+    }
+}
@@ -0,0 +1,123 @@
+/// The side of its element a [`Tooltip`](super::Tooltip) shows on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TooltipSide {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl TooltipSide {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// A rectangle in window coordinates
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Where a tooltip shows, with the origin of its box in window coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub side: TooltipSide,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// How a tooltip of `width` by `height` fits next to its anchor in a window of
+/// `window_width` by `window_height`, `gap` away from the anchor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TooltipFit {
+    pub anchor: Rect,
+    pub width: f32,
+    pub height: f32,
+    pub window_width: f32,
+    pub window_height: f32,
+    pub gap: f32,
+}
+
+impl TooltipFit {
+    /// The space between the anchor and the window edge on `side`
+    fn room(&self, side: TooltipSide) -> f32 {
+        let anchor = self.anchor;
+        match side {
+            TooltipSide::Top => anchor.y,
+            TooltipSide::Bottom => self.window_height - (anchor.y + anchor.height),
+            TooltipSide::Left => anchor.x,
+            TooltipSide::Right => self.window_width - (anchor.x + anchor.width),
+        }
+    }
+
+    fn fits(&self, side: TooltipSide) -> bool {
+        let length = if side.is_vertical() {
+            self.height
+        } else {
+            self.width
+        };
+        self.room(side) >= length + self.gap
+    }
+
+    /// Place the tooltip on `preferred`, or on the opposite side when only that one has
+    /// room for it, or else on whichever of the two has more room
+    ///
+    /// Along the side the tooltip is centered on the anchor, and shifted to stay within
+    /// the window. When it doesn't fit on either side, it is also kept within the window
+    /// across the side, over the anchor.
+    pub fn resolve(&self, preferred: TooltipSide) -> Placement {
+        let opposite = preferred.opposite();
+        let side = if self.fits(preferred) {
+            preferred
+        } else if self.fits(opposite) || self.room(opposite) > self.room(preferred) {
+            opposite
+        } else {
+            preferred
+        };
+
+        let anchor = self.anchor;
+        let (x, y) = match side {
+            TooltipSide::Top => (
+                anchor.x + (anchor.width - self.width) / 2.,
+                anchor.y - self.gap - self.height,
+            ),
+            TooltipSide::Bottom => (
+                anchor.x + (anchor.width - self.width) / 2.,
+                anchor.y + anchor.height + self.gap,
+            ),
+            TooltipSide::Left => (
+                anchor.x - self.gap - self.width,
+                anchor.y + (anchor.height - self.height) / 2.,
+            ),
+            TooltipSide::Right => (
+                anchor.x + anchor.width + self.gap,
+                anchor.y + (anchor.height - self.height) / 2.,
+            ),
+        };
+        Placement {
+            side,
+            x: clamp_into(x, self.width, self.window_width),
+            y: clamp_into(y, self.height, self.window_height),
+        }
+    }
+}
+
+/// Move a span of `length` starting at `start` into `0..window`, keeping the start in the
+/// window when the span is longer than it
+fn clamp_into(start: f32, length: f32, window: f32) -> f32 {
+    start.min(window - length).max(0.)
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod delay {
+    use crate::components::tooltip::delay::TooltipDelay;
+    use std::time::{Duration, Instant};
+
+    const SHOW: Duration = Duration::from_millis(500);
+    const HIDE: Duration = Duration::from_millis(150);
+
+    fn ms(start: Instant, ms: u64) -> Instant {
+        start + Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn shows_once_the_pointer_stays_for_the_show_delay() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        assert_eq!(delay.enter("save", SHOW, start), Some(ms(start, 500)));
+        assert!(!delay.tick(ms(start, 499)));
+        assert_eq!(delay.visible(), None);
+        assert!(delay.tick(ms(start, 500)));
+        assert_eq!(delay.visible(), Some(&"save"));
+    }
+
+    #[test]
+    fn leaving_before_the_show_delay_never_shows() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        delay.enter("save", SHOW, start);
+        assert_eq!(delay.leave(&"save", HIDE, ms(start, 200)), None);
+        assert!(!delay.tick(ms(start, 500)));
+        assert_eq!(delay.visible(), None);
+    }
+
+    #[test]
+    fn lingers_for_the_hide_delay() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        delay.enter("save", SHOW, start);
+        delay.tick(ms(start, 500));
+        assert_eq!(
+            delay.leave(&"save", HIDE, ms(start, 1000)),
+            Some(ms(start, 1150))
+        );
+        assert!(!delay.tick(ms(start, 1100)));
+        assert_eq!(delay.visible(), Some(&"save"));
+        assert!(delay.tick(ms(start, 1150)));
+        assert_eq!(delay.visible(), None);
+    }
+
+    #[test]
+    fn coming_back_within_the_hide_delay_keeps_it_shown() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        delay.enter("save", SHOW, start);
+        delay.tick(ms(start, 500));
+        delay.leave(&"save", HIDE, ms(start, 1000));
+        assert_eq!(delay.enter("save", SHOW, ms(start, 1100)), None);
+        // The tick for the hide delay that was cancelled changes nothing
+        assert!(!delay.tick(ms(start, 1150)));
+        assert_eq!(delay.visible(), Some(&"save"));
+    }
+
+    #[test]
+    fn moving_to_an_adjacent_anchor_swaps_without_delay() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        delay.enter("save", SHOW, start);
+        delay.tick(ms(start, 500));
+
+        // The pointer leaves one anchor before entering the next
+        delay.leave(&"save", HIDE, ms(start, 1000));
+        assert_eq!(delay.enter("open", SHOW, ms(start, 1050)), None);
+        assert_eq!(delay.visible(), Some(&"open"));
+        assert!(!delay.tick(ms(start, 1150)));
+        assert_eq!(delay.visible(), Some(&"open"));
+
+        // Or enters the next before leaving the one it was on
+        assert_eq!(delay.enter("close", SHOW, ms(start, 2000)), None);
+        assert_eq!(delay.leave(&"open", HIDE, ms(start, 2000)), None);
+        assert_eq!(delay.visible(), Some(&"close"));
+    }
+
+    #[test]
+    fn moving_on_after_the_hide_delay_waits_again() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        delay.enter("save", SHOW, start);
+        delay.tick(ms(start, 500));
+        delay.leave(&"save", HIDE, ms(start, 1000));
+        delay.tick(ms(start, 1150));
+
+        assert_eq!(
+            delay.enter("open", SHOW, ms(start, 1200)),
+            Some(ms(start, 1700))
+        );
+        assert_eq!(delay.visible(), None);
+    }
+
+    #[test]
+    fn entering_another_anchor_while_waiting_restarts_the_delay() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        delay.enter("save", SHOW, start);
+        assert_eq!(
+            delay.enter("open", SHOW, ms(start, 300)),
+            Some(ms(start, 800))
+        );
+        // Leaving the first anchor changes nothing anymore
+        delay.leave(&"save", HIDE, ms(start, 300));
+        assert!(!delay.tick(ms(start, 500)));
+        assert!(delay.tick(ms(start, 800)));
+        assert_eq!(delay.visible(), Some(&"open"));
+    }
+
+    #[test]
+    fn zero_delays_show_and_hide_right_away() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        assert_eq!(delay.enter("save", Duration::ZERO, start), None);
+        assert_eq!(delay.visible(), Some(&"save"));
+        assert_eq!(delay.leave(&"save", Duration::ZERO, start), None);
+        assert_eq!(delay.visible(), None);
+    }
+
+    #[test]
+    fn dismissing_hides_only_its_anchor() {
+        let start = Instant::now();
+        let mut delay = TooltipDelay::default();
+        delay.enter("save", Duration::ZERO, start);
+        delay.dismiss(&"open");
+        assert_eq!(delay.visible(), Some(&"save"));
+        delay.dismiss(&"save");
+        assert_eq!(delay.visible(), None);
+
+        // Waiting to show is cancelled too
+        delay.enter("save", SHOW, start);
+        delay.dismiss(&"save");
+        assert!(!delay.tick(ms(start, 500)));
+        assert_eq!(delay.visible(), None);
+    }
+}
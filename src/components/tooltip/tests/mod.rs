@@ -0,0 +1,2 @@
+mod delay;
+mod placement;
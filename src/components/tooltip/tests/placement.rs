@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod placement {
+    use crate::components::tooltip::placement::{Placement, Rect, TooltipFit, TooltipSide};
+
+    /// A 100 by 30 tooltip, 4px away from a 40 by 20 anchor at `(x, y)` in an 800 by 600
+    /// window
+    fn fit(x: f32, y: f32) -> TooltipFit {
+        TooltipFit {
+            anchor: Rect {
+                x,
+                y,
+                width: 40.,
+                height: 20.,
+            },
+            width: 100.,
+            height: 30.,
+            window_width: 800.,
+            window_height: 600.,
+            gap: 4.,
+        }
+    }
+
+    fn placement(side: TooltipSide, x: f32, y: f32) -> Placement {
+        Placement { side, x, y }
+    }
+
+    #[test]
+    fn centers_on_the_preferred_side_when_it_fits() {
+        let fit = fit(400., 300.);
+        assert_eq!(
+            fit.resolve(TooltipSide::Top),
+            placement(TooltipSide::Top, 370., 266.)
+        );
+        assert_eq!(
+            fit.resolve(TooltipSide::Bottom),
+            placement(TooltipSide::Bottom, 370., 324.)
+        );
+        assert_eq!(
+            fit.resolve(TooltipSide::Left),
+            placement(TooltipSide::Left, 296., 295.)
+        );
+        assert_eq!(
+            fit.resolve(TooltipSide::Right),
+            placement(TooltipSide::Right, 444., 295.)
+        );
+    }
+
+    #[test]
+    fn flips_below_at_the_top_edge() {
+        assert_eq!(
+            fit(400., 10.).resolve(TooltipSide::Top),
+            placement(TooltipSide::Bottom, 370., 34.)
+        );
+    }
+
+    #[test]
+    fn flips_above_at_the_bottom_edge() {
+        assert_eq!(
+            fit(400., 570.).resolve(TooltipSide::Bottom),
+            placement(TooltipSide::Top, 370., 536.)
+        );
+    }
+
+    #[test]
+    fn flips_right_at_the_left_edge() {
+        assert_eq!(
+            fit(50., 300.).resolve(TooltipSide::Left),
+            placement(TooltipSide::Right, 94., 295.)
+        );
+    }
+
+    #[test]
+    fn flips_left_at_the_right_edge() {
+        assert_eq!(
+            fit(700., 300.).resolve(TooltipSide::Right),
+            placement(TooltipSide::Left, 596., 295.)
+        );
+    }
+
+    #[test]
+    fn exactly_enough_room_does_not_flip() {
+        // 30px of tooltip and 4px of gap above the anchor
+        assert_eq!(
+            fit(400., 34.).resolve(TooltipSide::Top).side,
+            TooltipSide::Top
+        );
+        assert_eq!(
+            fit(400., 33.).resolve(TooltipSide::Top).side,
+            TooltipSide::Bottom
+        );
+    }
+
+    #[test]
+    fn shifts_along_the_side_to_stay_in_the_window() {
+        // Centered on an anchor at the left edge, the tooltip would start at -30
+        assert_eq!(
+            fit(0., 300.).resolve(TooltipSide::Top),
+            placement(TooltipSide::Top, 0., 266.)
+        );
+        assert_eq!(
+            fit(760., 300.).resolve(TooltipSide::Bottom),
+            placement(TooltipSide::Bottom, 700., 324.)
+        );
+        // Beside an anchor at the bottom edge, it shifts up
+        assert_eq!(
+            fit(400., 590.).resolve(TooltipSide::Right),
+            placement(TooltipSide::Right, 444., 570.)
+        );
+    }
+
+    #[test]
+    fn flips_and_shifts_in_a_corner() {
+        assert_eq!(
+            fit(0., 0.).resolve(TooltipSide::Top),
+            placement(TooltipSide::Bottom, 0., 24.)
+        );
+    }
+
+    #[test]
+    fn without_room_on_either_side_takes_the_roomier_one() {
+        let mut fit = fit(400., 40.);
+        fit.window_height = 90.;
+        // 40px above and 30px below, neither enough for 34
+        assert_eq!(
+            fit.resolve(TooltipSide::Bottom),
+            placement(TooltipSide::Top, 370., 6.)
+        );
+        assert_eq!(
+            fit.resolve(TooltipSide::Top),
+            placement(TooltipSide::Top, 370., 6.)
+        );
+    }
+
+    #[test]
+    fn a_tooltip_wider_than_the_window_starts_at_its_edge() {
+        let mut fit = fit(20., 300.);
+        fit.window_width = 80.;
+        assert_eq!(fit.resolve(TooltipSide::Top).x, 0.);
+    }
+}